@@ -13,7 +13,7 @@ use {
     gluesql_core::{
         ast::{Expr, SetExpr, Statement, ToSql, Values},
         data::Value,
-        store::{DataRow, GStore, GStoreMut, Store, Transaction},
+        store::{DataRow, GStore, GStoreMut, Store, Transaction, View},
     },
     itertools::Itertools,
     json_storage::JsonStorage,
@@ -116,7 +116,11 @@ pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()
 
     block_on(async {
         storage.begin(true).await?;
-        let schemas = storage.fetch_all_schemas().await?;
+        let (materialized_views, schemas): (Vec<_>, Vec<_>) = storage
+            .fetch_all_schemas()
+            .await?
+            .into_iter()
+            .partition(|schema| schema.view_query.is_some());
         for schema in schemas {
             writeln!(&file, "{}", schema.to_ddl())?;
 
@@ -147,11 +151,14 @@ pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()
                     table_name: schema.table_name.clone(),
                     columns: Vec::new(),
                     source: gluesql_core::ast::Query {
+                        with: None,
                         body: SetExpr::Values(Values(exprs_list)),
                         order_by: Vec::new(),
                         limit: None,
                         offset: None,
                     },
+                    on_conflict: None,
+                    returning: Vec::new(),
                 }
                 .to_sql();
 
@@ -161,6 +168,15 @@ pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()
             writeln!(&file)?;
         }
 
+        for view in storage.fetch_all_views().await? {
+            writeln!(&file, "{}", view.to_ddl())?;
+        }
+
+        // rows of materialized views are recomputed from their defining queries
+        for schema in materialized_views {
+            writeln!(&file, "{}", schema.to_ddl())?;
+        }
+
         Ok(())
     })
 }
@@ -106,9 +106,13 @@ impl<'a, W: Write> Print<W> {
             Payload::Create => self.writeln("Table created")?,
             Payload::DropTable => self.writeln("Table dropped")?,
             Payload::DropFunction => self.writeln("Function dropped")?,
+            Payload::DropView => self.writeln("View dropped")?,
+            Payload::DropSequence => self.writeln("Sequence dropped")?,
+            Payload::RefreshMaterializedView => self.writeln("Materialized view refreshed")?,
             Payload::AlterTable => self.writeln("Table altered")?,
             Payload::CreateIndex => self.writeln("Index created")?,
             Payload::DropIndex => self.writeln("Index dropped")?,
+            Payload::Analyze => self.writeln("Table analyzed")?,
             Payload::Commit => self.writeln("Commit completed")?,
             Payload::Rollback => self.writeln("Rollback completed")?,
             Payload::StartTransaction => self.writeln("Transaction started")?,
@@ -132,6 +136,14 @@ impl<'a, W: Write> Print<W> {
                 let table = self.build_table(table);
                 self.writeln(table)?;
             }
+            Payload::ShowVariable(PayloadVariable::Views(names)) => {
+                let mut table = self.get_table(["views"]);
+                for name in names {
+                    table.add_record([name]);
+                }
+                let table = self.build_table(table);
+                self.writeln(table)?;
+            }
             Payload::ShowColumns(columns) => {
                 let mut table = self.get_table(vec!["Field", "Type"]);
                 for (field, field_type) in columns {
@@ -392,7 +404,14 @@ mod tests {
         test!(Payload::AlterTable, "Table altered");
         test!(Payload::CreateIndex, "Index created");
         test!(Payload::DropIndex, "Index dropped");
+        test!(Payload::Analyze, "Table analyzed");
         test!(Payload::DropFunction, "Function dropped");
+        test!(Payload::DropView, "View dropped");
+        test!(Payload::DropSequence, "Sequence dropped");
+        test!(
+            Payload::RefreshMaterializedView,
+            "Materialized view refreshed"
+        );
         test!(Payload::Commit, "Commit completed");
         test!(Payload::Rollback, "Rollback completed");
         test!(Payload::StartTransaction, "Transaction started");
@@ -414,6 +433,13 @@ mod tests {
             Payload::ShowVariable(PayloadVariable::Functions(Vec::new())),
             "
 | functions |"
+        );
+        test!(
+            Payload::ShowVariable(PayloadVariable::Views(vec!["Foo".to_owned()])),
+            "
+| views |
+|-------|
+| Foo   |"
         );
         test!(
             Payload::ShowVariable(PayloadVariable::Tables(
@@ -56,6 +56,7 @@ async fn dump_and_import() {
          );"#,
         "CREATE INDEX Foo_int ON Foo (int);",
         "CREATE TABLE Bar AS SELECT N FROM SERIES(101);",
+        "CREATE MATERIALIZED VIEW BarSum AS SELECT SUM(N) AS total FROM Bar;",
         "CREATE TABLE Baz;",
         r#"
         INSERT INTO Baz VALUES
@@ -97,6 +98,11 @@ async fn dump_and_import() {
     let target_data = target_glue.execute(sql).await.unwrap();
     assert_eq!(source_data, target_data);
 
+    let sql = "SELECT * FROM BarSum;";
+    let source_data = source_glue.execute(sql).await.unwrap();
+    let target_data = target_glue.execute(sql).await.unwrap();
+    assert_eq!(source_data, target_data);
+
     let sql = "SELECT * FROM Baz;";
     let source_data = source_glue.execute(sql).await.unwrap();
     let target_data = target_glue.execute(sql).await.unwrap();
@@ -31,6 +31,8 @@ pub struct ColumnDef {
     pub default: Option<Expr>,
     /// `{ PRIMARY KEY | UNIQUE }`
     pub unique: Option<ColumnUniqueOption>,
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <sequence_options> ) ]`
+    pub identity: Option<ColumnIdentity>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -38,6 +40,71 @@ pub struct ColumnUniqueOption {
     pub is_primary: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ColumnIdentity {
+    pub generation: IdentityGeneration,
+    pub options: SequenceOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IdentityGeneration {
+    /// Explicit values are rejected on `INSERT`
+    Always,
+    /// Explicit values take precedence over generated ones
+    ByDefault,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SequenceOptions {
+    /// `INCREMENT [ BY ] <increment>`
+    pub increment: Option<i64>,
+    /// `MINVALUE <min_value>`
+    pub min_value: Option<i64>,
+    /// `MAXVALUE <max_value>`
+    pub max_value: Option<i64>,
+    /// `START [ WITH ] <start>`
+    pub start: Option<i64>,
+    /// `[ NO ] CYCLE`
+    pub cycle: bool,
+}
+
+/// `[ CONSTRAINT <name> ] FOREIGN KEY ( <columns> ) REFERENCES <referenced_table_name>
+/// [ ( <referenced_columns> ) ] [ ON DELETE <action> ] [ ON UPDATE <action> ]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ForeignKey {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub referenced_table_name: String,
+    /// Empty when omitted, the primary key of the referenced table is used instead
+    pub referenced_columns: Vec<String>,
+    pub on_delete: ReferentialAction,
+    pub on_update: ReferentialAction,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferentialAction {
+    #[default]
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
+/// `{ PRIMARY KEY | UNIQUE } ( <column> [, ...] )` on more than one column, single column
+/// constraints are kept in [`ColumnDef::unique`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UniqueConstraint {
+    pub columns: Vec<String>,
+    pub is_primary: bool,
+}
+
+/// `[ CONSTRAINT <name> ] CHECK ( <expr> )`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Check {
+    pub name: String,
+    pub expr: Expr,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OperateFunctionArg {
     pub name: String,
@@ -78,6 +145,7 @@ impl ToSql for ColumnDef {
             nullable,
             default,
             unique,
+            identity,
         } = self;
         {
             let nullable = match nullable {
@@ -89,8 +157,9 @@ impl ToSql for ColumnDef {
                 .as_ref()
                 .map(|expr| format!("DEFAULT {}", expr.to_sql()));
             let unique = unique.as_ref().map(ToSql::to_sql);
+            let identity = identity.as_ref().map(ToSql::to_sql);
 
-            [Some(column_def), default, unique]
+            [Some(column_def), default, unique, identity]
                 .into_iter()
                 .flatten()
                 .collect::<Vec<_>>()
@@ -110,6 +179,123 @@ impl ToSql for ColumnUniqueOption {
     }
 }
 
+impl ToSql for ColumnIdentity {
+    fn to_sql(&self) -> String {
+        let ColumnIdentity {
+            generation,
+            options,
+        } = self;
+        let generation = match generation {
+            IdentityGeneration::Always => "ALWAYS",
+            IdentityGeneration::ByDefault => "BY DEFAULT",
+        };
+
+        match options.to_sql().as_str() {
+            "" => format!("GENERATED {generation} AS IDENTITY"),
+            options => format!("GENERATED {generation} AS IDENTITY ({options})"),
+        }
+    }
+}
+
+impl ToSql for SequenceOptions {
+    fn to_sql(&self) -> String {
+        let SequenceOptions {
+            increment,
+            min_value,
+            max_value,
+            start,
+            cycle,
+        } = self;
+        let increment = increment.map(|v| format!("INCREMENT BY {v}"));
+        let min_value = min_value.map(|v| format!("MINVALUE {v}"));
+        let max_value = max_value.map(|v| format!("MAXVALUE {v}"));
+        let start = start.map(|v| format!("START WITH {v}"));
+        let cycle = cycle.then(|| "CYCLE".to_owned());
+
+        [increment, min_value, max_value, start, cycle]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl ToSql for ForeignKey {
+    fn to_sql(&self) -> String {
+        let ForeignKey {
+            name,
+            columns,
+            referenced_table_name,
+            referenced_columns,
+            on_delete,
+            on_update,
+        } = self;
+        let quote = |columns: &[String]| {
+            columns
+                .iter()
+                .map(|column| format!(r#""{column}""#))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let foreign_key = format!(
+            r#"CONSTRAINT "{name}" FOREIGN KEY ({}) REFERENCES "{referenced_table_name}""#,
+            quote(columns)
+        );
+        let referenced_columns =
+            (!referenced_columns.is_empty()).then(|| format!("({})", quote(referenced_columns)));
+        let on_delete = (on_delete != &ReferentialAction::NoAction)
+            .then(|| format!("ON DELETE {}", on_delete.to_sql()));
+        let on_update = (on_update != &ReferentialAction::NoAction)
+            .then(|| format!("ON UPDATE {}", on_update.to_sql()));
+
+        [Some(foreign_key), referenced_columns, on_delete, on_update]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl ToSql for ReferentialAction {
+    fn to_sql(&self) -> String {
+        match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+        }
+        .to_owned()
+    }
+}
+
+impl ToSql for UniqueConstraint {
+    fn to_sql(&self) -> String {
+        let UniqueConstraint {
+            columns,
+            is_primary,
+        } = self;
+        let columns = columns
+            .iter()
+            .map(|column| format!(r#""{column}""#))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match is_primary {
+            true => format!("PRIMARY KEY ({columns})"),
+            false => format!("UNIQUE ({columns})"),
+        }
+    }
+}
+
+impl ToSql for Check {
+    fn to_sql(&self) -> String {
+        let Check { name, expr } = self;
+
+        format!(r#"CONSTRAINT "{name}" CHECK ({})"#, expr.to_sql())
+    }
+}
+
 impl ToSql for OperateFunctionArg {
     fn to_sql(&self) -> String {
         let OperateFunctionArg {
@@ -128,7 +314,9 @@ impl ToSql for OperateFunctionArg {
 #[cfg(test)]
 mod tests {
     use crate::ast::{
-        AstLiteral, ColumnDef, ColumnUniqueOption, DataType, Expr, OperateFunctionArg, ToSql,
+        AstLiteral, Check, ColumnDef, ColumnIdentity, ColumnUniqueOption, DataType, Expr,
+        ForeignKey, IdentityGeneration, OperateFunctionArg, ReferentialAction, SequenceOptions,
+        ToSql, UniqueConstraint,
     };
 
     #[test]
@@ -141,6 +329,7 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                identity: None,
             }
             .to_sql()
         );
@@ -153,6 +342,7 @@ mod tests {
                 nullable: true,
                 default: None,
                 unique: None,
+                identity: None,
             }
             .to_sql()
         );
@@ -165,6 +355,7 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                identity: None,
             }
             .to_sql()
         );
@@ -177,6 +368,7 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: None,
+                identity: None,
             }
             .to_sql()
         );
@@ -189,6 +381,119 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                identity: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""id" INT NOT NULL PRIMARY KEY GENERATED ALWAYS AS IDENTITY"#,
+            ColumnDef {
+                name: "id".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: Some(ColumnUniqueOption { is_primary: true }),
+                identity: Some(ColumnIdentity {
+                    generation: IdentityGeneration::Always,
+                    options: SequenceOptions::default(),
+                }),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""num" INT32 NOT NULL GENERATED BY DEFAULT AS IDENTITY (INCREMENT BY -1 START WITH 10 CYCLE)"#,
+            ColumnDef {
+                name: "num".to_owned(),
+                data_type: DataType::Int32,
+                nullable: false,
+                default: None,
+                unique: None,
+                identity: Some(ColumnIdentity {
+                    generation: IdentityGeneration::ByDefault,
+                    options: SequenceOptions {
+                        increment: Some(-1),
+                        start: Some(10),
+                        cycle: true,
+                        ..SequenceOptions::default()
+                    },
+                }),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_check() {
+        assert_eq!(
+            r#"CONSTRAINT "name_check" CHECK ("name" IS NOT NULL)"#,
+            Check {
+                name: "name_check".to_owned(),
+                expr: Expr::IsNotNull(Box::new(Expr::Identifier("name".to_owned()))),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_unique_constraint() {
+        assert_eq!(
+            r#"PRIMARY KEY ("tenant_id", "id")"#,
+            UniqueConstraint {
+                columns: vec!["tenant_id".to_owned(), "id".to_owned()],
+                is_primary: true,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"UNIQUE ("name", "email")"#,
+            UniqueConstraint {
+                columns: vec!["name".to_owned(), "email".to_owned()],
+                is_primary: false,
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_foreign_key() {
+        assert_eq!(
+            r#"CONSTRAINT "Child_parent_id_fkey" FOREIGN KEY ("parent_id") REFERENCES "Parent" ("id")"#,
+            ForeignKey {
+                name: "Child_parent_id_fkey".to_owned(),
+                columns: vec!["parent_id".to_owned()],
+                referenced_table_name: "Parent".to_owned(),
+                referenced_columns: vec!["id".to_owned()],
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::NoAction,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CONSTRAINT "fk" FOREIGN KEY ("a", "b") REFERENCES "Parent" ON DELETE CASCADE ON UPDATE SET NULL"#,
+            ForeignKey {
+                name: "fk".to_owned(),
+                columns: vec!["a".to_owned(), "b".to_owned()],
+                referenced_table_name: "Parent".to_owned(),
+                referenced_columns: Vec::new(),
+                on_delete: ReferentialAction::Cascade,
+                on_update: ReferentialAction::SetNull,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CONSTRAINT "fk" FOREIGN KEY ("a") REFERENCES "Parent" ("id") ON UPDATE RESTRICT"#,
+            ForeignKey {
+                name: "fk".to_owned(),
+                columns: vec!["a".to_owned()],
+                referenced_table_name: "Parent".to_owned(),
+                referenced_columns: vec!["id".to_owned()],
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::Restrict,
             }
             .to_sql()
         );
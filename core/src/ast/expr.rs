@@ -1,7 +1,7 @@
 use {
     super::{
         Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, Query, ToSql,
-        ToSqlUnquoted, UnaryOperator,
+        ToSqlUnquoted, UnaryOperator, Window,
     },
     serde::{Deserialize, Serialize},
 };
@@ -58,6 +58,7 @@ pub enum Expr {
     },
     Function(Box<Function>),
     Aggregate(Box<Aggregate>),
+    Window(Box<Window>),
     Exists {
         subquery: Box<Query>,
         negated: bool,
@@ -77,6 +78,8 @@ pub enum Expr {
         leading_field: Option<DateTimeField>,
         last_field: Option<DateTimeField>,
     },
+    /// Bind parameter `$n`, numbered from 1
+    Placeholder(usize),
 }
 
 impl ToSql for Expr {
@@ -213,6 +216,7 @@ impl Expr {
                 }
             }
             Expr::Aggregate(a) => a.to_sql(),
+            Expr::Window(window) => window.to_sql(),
             Expr::Function(func) => func.to_sql(),
             Expr::InSubquery {
                 expr,
@@ -256,6 +260,7 @@ impl Expr {
                     None => format!("INTERVAL {expr} {leading_field}"),
                 }
             }
+            Expr::Placeholder(n) => format!("${n}"),
         }
     }
 }
@@ -431,7 +436,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -444,6 +451,7 @@ mod tests {
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
+                        plan_id: None,
                     })),
                     order_by: Vec::new(),
                     limit: None,
@@ -459,7 +467,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -472,6 +482,7 @@ mod tests {
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
+                        plan_id: None,
                     })),
                     order_by: Vec::new(),
                     limit: None,
@@ -486,7 +497,9 @@ mod tests {
             r#"EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -499,6 +512,7 @@ mod tests {
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
+                        plan_id: None,
                     })),
                     order_by: Vec::new(),
                     limit: None,
@@ -513,7 +527,9 @@ mod tests {
             r#"NOT EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -526,6 +542,7 @@ mod tests {
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
+                        plan_id: None,
                     })),
                     order_by: Vec::new(),
                     limit: None,
@@ -539,7 +556,9 @@ mod tests {
         assert_eq!(
             r#"(SELECT * FROM "FOO")"#,
             Expr::Subquery(Box::new(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -552,6 +571,7 @@ mod tests {
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 order_by: Vec::new(),
                 limit: None,
@@ -682,5 +702,15 @@ mod tests {
             }
             .to_sql()
         );
+
+        assert_eq!(
+            r#""id" = $1"#,
+            Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("id".to_owned())),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Placeholder(1)),
+            }
+            .to_sql()
+        );
     }
 }
@@ -1,6 +1,7 @@
 use {
-    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr},
+    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr, OrderByExpr},
     crate::ast::ToSql,
+    itertools::Itertools,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
 };
@@ -156,6 +157,8 @@ pub enum Function {
     Ascii(Expr),
     Chr(Expr),
     Md5(Expr),
+    NextVal(Expr),
+    CurrVal(Expr),
     Append {
         expr: Expr,
         value: Expr,
@@ -369,6 +372,8 @@ impl ToSql for Function {
             Function::Ascii(e) => format!("ASCII({})", e.to_sql()),
             Function::Chr(e) => format!("CHR({})", e.to_sql()),
             Function::Md5(e) => format!("MD5({})", e.to_sql()),
+            Function::NextVal(e) => format!("NEXTVAL({})", e.to_sql()),
+            Function::CurrVal(e) => format!("CURRVAL({})", e.to_sql()),
             Function::Append { expr, value } => {
                 format!(
                     "APPEND({items}, {value})",
@@ -412,7 +417,13 @@ impl ToSql for Function {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Aggregate {
+pub struct Aggregate {
+    pub func: AggregateFunction,
+    pub distinct: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AggregateFunction {
     Count(CountArgExpr),
     Sum(Expr),
     Max(Expr),
@@ -424,14 +435,17 @@ pub enum Aggregate {
 
 impl ToSql for Aggregate {
     fn to_sql(&self) -> String {
-        match self {
-            Aggregate::Count(cae) => format!("COUNT({})", cae.to_sql()),
-            Aggregate::Sum(e) => format!("SUM({})", e.to_sql()),
-            Aggregate::Max(e) => format!("MAX({})", e.to_sql()),
-            Aggregate::Min(e) => format!("MIN({})", e.to_sql()),
-            Aggregate::Avg(e) => format!("AVG({})", e.to_sql()),
-            Aggregate::Variance(e) => format!("VARIANCE({})", e.to_sql()),
-            Aggregate::Stdev(e) => format!("STDEV({})", e.to_sql()),
+        let Aggregate { func, distinct } = self;
+        let distinct = if *distinct { "DISTINCT " } else { "" };
+
+        match func {
+            AggregateFunction::Count(cae) => format!("COUNT({distinct}{})", cae.to_sql()),
+            AggregateFunction::Sum(e) => format!("SUM({distinct}{})", e.to_sql()),
+            AggregateFunction::Max(e) => format!("MAX({distinct}{})", e.to_sql()),
+            AggregateFunction::Min(e) => format!("MIN({distinct}{})", e.to_sql()),
+            AggregateFunction::Avg(e) => format!("AVG({distinct}{})", e.to_sql()),
+            AggregateFunction::Variance(e) => format!("VARIANCE({distinct}{})", e.to_sql()),
+            AggregateFunction::Stdev(e) => format!("STDEV({distinct}{})", e.to_sql()),
         }
     }
 }
@@ -451,12 +465,159 @@ impl ToSql for CountArgExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Window {
+    pub func: WindowFunction,
+    pub over: WindowSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    Lead {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    FirstValue(Expr),
+    LastValue(Expr),
+    Aggregate(Aggregate),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WindowSpec {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub frame: Option<WindowFrame>,
+}
+
+/// `ROWS | RANGE BETWEEN start AND end`, `ROWS start` is stored with `end` of `CURRENT ROW`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start: WindowFrameBound,
+    pub end: WindowFrameBound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFrameBound {
+    CurrentRow,
+    /// `N PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<Expr>),
+    /// `N FOLLOWING` or `UNBOUNDED FOLLOWING`
+    Following(Option<Expr>),
+}
+
+impl ToSql for Window {
+    fn to_sql(&self) -> String {
+        let Window { func, over } = self;
+
+        format!("{} OVER ({})", func.to_sql(), over.to_sql())
+    }
+}
+
+impl ToSql for WindowFunction {
+    fn to_sql(&self) -> String {
+        let lag_lead = |name: &str, expr: &Expr, offset: &Option<Expr>, default: &Option<Expr>| {
+            let args = std::iter::once(expr)
+                .chain(offset.iter())
+                .chain(default.iter())
+                .map(ToSql::to_sql)
+                .join(", ");
+
+            format!("{name}({args})")
+        };
+
+        match self {
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_owned(),
+            WindowFunction::Rank => "RANK()".to_owned(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_owned(),
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            } => lag_lead("LAG", expr, offset, default),
+            WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => lag_lead("LEAD", expr, offset, default),
+            WindowFunction::FirstValue(expr) => format!("FIRST_VALUE({})", expr.to_sql()),
+            WindowFunction::LastValue(expr) => format!("LAST_VALUE({})", expr.to_sql()),
+            WindowFunction::Aggregate(aggregate) => aggregate.to_sql(),
+        }
+    }
+}
+
+impl ToSql for WindowSpec {
+    fn to_sql(&self) -> String {
+        let WindowSpec {
+            partition_by,
+            order_by,
+            frame,
+        } = self;
+
+        let partition_by = match partition_by.is_empty() {
+            true => "".to_owned(),
+            false => format!(
+                "PARTITION BY {}",
+                partition_by.iter().map(ToSql::to_sql).join(", ")
+            ),
+        };
+        let order_by = match order_by.is_empty() {
+            true => "".to_owned(),
+            false => format!("ORDER BY {}", order_by.iter().map(ToSql::to_sql).join(", ")),
+        };
+        let frame = frame.as_ref().map(ToSql::to_sql).unwrap_or_default();
+
+        [partition_by, order_by, frame]
+            .into_iter()
+            .filter(|sql| !sql.is_empty())
+            .join(" ")
+    }
+}
+
+impl ToSql for WindowFrame {
+    fn to_sql(&self) -> String {
+        let WindowFrame { units, start, end } = self;
+
+        format!("{units} BETWEEN {} AND {}", start.to_sql(), end.to_sql())
+    }
+}
+
+impl ToSql for WindowFrameBound {
+    fn to_sql(&self) -> String {
+        match self {
+            WindowFrameBound::CurrentRow => "CURRENT ROW".to_owned(),
+            WindowFrameBound::Preceding(None) => "UNBOUNDED PRECEDING".to_owned(),
+            WindowFrameBound::Preceding(Some(expr)) => format!("{} PRECEDING", expr.to_sql()),
+            WindowFrameBound::Following(None) => "UNBOUNDED FOLLOWING".to_owned(),
+            WindowFrameBound::Following(Some(expr)) => format!("{} FOLLOWING", expr.to_sql()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
         crate::ast::{
-            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function, ToSql,
-            TrimWhereField,
+            Aggregate, AggregateFunction, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr,
+            Function, OrderByExpr, ToSql, TrimWhereField, Window, WindowFrame, WindowFrameBound,
+            WindowFrameUnits, WindowFunction, WindowSpec,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -1049,6 +1210,22 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "NEXTVAL('seq')",
+            &Expr::Function(Box::new(Function::NextVal(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "CURRVAL('seq')",
+            &Expr::Function(Box::new(Function::CurrVal(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"CHR(72)"#,
             &Expr::Function(Box::new(Function::Chr(Expr::Literal(AstLiteral::Number(
@@ -1180,44 +1357,182 @@ mod tests {
     fn to_sql_aggregate() {
         assert_eq!(
             r#"MAX("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Max(Expr::Identifier("id".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Max(Expr::Identifier("id".to_owned())),
+                distinct: false,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             "COUNT(*)",
-            Expr::Aggregate(Box::new(Aggregate::Count(CountArgExpr::Wildcard))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Count(CountArgExpr::Wildcard),
+                distinct: false,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             r#"MIN("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Min(Expr::Identifier("id".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Min(Expr::Identifier("id".to_owned())),
+                distinct: false,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             r#"SUM("price")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Sum(Expr::Identifier(
-                "price".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Sum(Expr::Identifier("price".to_owned())),
+                distinct: false,
+            }))
             .to_sql()
         );
 
         assert_eq!(
             r#"AVG("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Avg(Expr::Identifier("pay".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Avg(Expr::Identifier("pay".to_owned())),
+                distinct: false,
+            }))
+            .to_sql()
         );
+
         assert_eq!(
             r#"VARIANCE("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Variance(Expr::Identifier(
-                "pay".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Variance(Expr::Identifier("pay".to_owned())),
+                distinct: false,
+            }))
             .to_sql()
         );
+
         assert_eq!(
             r#"STDEV("total")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Stdev(Expr::Identifier(
-                "total".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Stdev(Expr::Identifier("total".to_owned())),
+                distinct: false,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"COUNT(DISTINCT "id")"#,
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Count(CountArgExpr::Expr(Expr::Identifier(
+                    "id".to_owned()
+                ))),
+                distinct: true,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"SUM(DISTINCT "price")"#,
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Sum(Expr::Identifier("price".to_owned())),
+                distinct: true,
+            }))
             .to_sql()
         );
     }
+
+    #[test]
+    fn to_sql_window() {
+        let id = || Expr::Identifier("id".to_owned());
+        let window = |func, over| Expr::Window(Box::new(Window { func, over })).to_sql();
+
+        assert_eq!(
+            "ROW_NUMBER() OVER ()",
+            window(
+                WindowFunction::RowNumber,
+                WindowSpec {
+                    partition_by: Vec::new(),
+                    order_by: Vec::new(),
+                    frame: None,
+                }
+            )
+        );
+
+        assert_eq!(
+            r#"RANK() OVER (PARTITION BY "team" ORDER BY "id" DESC)"#,
+            window(
+                WindowFunction::Rank,
+                WindowSpec {
+                    partition_by: vec![Expr::Identifier("team".to_owned())],
+                    order_by: vec![OrderByExpr {
+                        expr: id(),
+                        asc: Some(false),
+                        nulls_first: None,
+                    }],
+                    frame: None,
+                }
+            )
+        );
+
+        assert_eq!(
+            r#"LAG("name", 2, 'none') OVER (ORDER BY "id")"#,
+            window(
+                WindowFunction::Lag {
+                    expr: Expr::Identifier("name".to_owned()),
+                    offset: Some(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("2").unwrap()
+                    ))),
+                    default: Some(Expr::Literal(AstLiteral::QuotedString("none".to_owned()))),
+                },
+                WindowSpec {
+                    partition_by: Vec::new(),
+                    order_by: vec![OrderByExpr {
+                        expr: id(),
+                        asc: None,
+                        nulls_first: None,
+                    }],
+                    frame: None,
+                }
+            )
+        );
+
+        assert_eq!(
+            r#"SUM("price") OVER (ORDER BY "id" ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING)"#,
+            window(
+                WindowFunction::Aggregate(Aggregate {
+                    func: AggregateFunction::Sum(Expr::Identifier("price".to_owned())),
+                    distinct: false,
+                }),
+                WindowSpec {
+                    partition_by: Vec::new(),
+                    order_by: vec![OrderByExpr {
+                        expr: id(),
+                        asc: None,
+                        nulls_first: None,
+                    }],
+                    frame: Some(WindowFrame {
+                        units: WindowFrameUnits::Rows,
+                        start: WindowFrameBound::Preceding(Some(Expr::Literal(
+                            AstLiteral::Number(BigDecimal::from_str("1").unwrap())
+                        ))),
+                        end: WindowFrameBound::Following(None),
+                    }),
+                }
+            )
+        );
+
+        assert_eq!(
+            r#"LAST_VALUE("id") OVER (RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"#,
+            window(
+                WindowFunction::LastValue(id()),
+                WindowSpec {
+                    partition_by: Vec::new(),
+                    order_by: Vec::new(),
+                    frame: Some(WindowFrame {
+                        units: WindowFrameUnits::Range,
+                        start: WindowFrameBound::Preceding(None),
+                        end: WindowFrameBound::CurrentRow,
+                    }),
+                }
+            )
+        );
+    }
 }
@@ -11,7 +11,10 @@ pub use {
     data_type::DataType,
     ddl::*,
     expr::Expr,
-    function::{Aggregate, CountArgExpr, Function},
+    function::{
+        Aggregate, AggregateFunction, CountArgExpr, Function, Window, WindowFrame,
+        WindowFrameBound, WindowFrameUnits, WindowFunction, WindowSpec,
+    },
     operator::*,
     query::*,
 };
@@ -41,6 +44,10 @@ pub enum Statement {
         columns: Vec<String>,
         /// A SQL query that specifies what to insert
         source: Query,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// UPDATE
     Update {
@@ -48,15 +55,27 @@ pub enum Statement {
         table_name: String,
         /// Column assignments
         assignments: Vec<Assignment>,
+        /// FROM
+        from: Option<TableWithJoins>,
+        /// How the rows of FROM are matched with the rows of the table
+        join_executor: JoinExecutor,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// DELETE
     Delete {
         /// FROM
         table_name: String,
+        /// USING
+        using: Option<TableWithJoins>,
+        /// How the rows of USING are matched with the rows of the table
+        join_executor: JoinExecutor,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// CREATE TABLE
     CreateTable {
@@ -67,6 +86,12 @@ pub enum Statement {
         columns: Option<Vec<ColumnDef>>,
         source: Option<Box<Query>>,
         engine: Option<String>,
+        /// `[ CONSTRAINT <name> ] FOREIGN KEY` and `REFERENCES` constraints
+        foreign_keys: Vec<ForeignKey>,
+        /// `[ CONSTRAINT <name> ] CHECK ( <expr> )` constraints
+        checks: Vec<Check>,
+        /// `{ PRIMARY KEY | UNIQUE } ( <column> [, ...] )` constraints on several columns
+        unique_constraints: Vec<UniqueConstraint>,
     },
     /// CREATE FUNCTION
     CreateFunction {
@@ -88,6 +113,8 @@ pub enum Statement {
         if_exists: bool,
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<String>,
+        /// Whether `CASCADE` was specified, drops foreign keys referencing the tables.
+        cascade: bool,
     },
     /// DROP FUNCTION
     DropFunction {
@@ -96,11 +123,50 @@ pub enum Statement {
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<String>,
     },
+    /// CREATE VIEW
+    CreateView {
+        or_replace: bool,
+        name: String,
+        /// Optional column aliases
+        columns: Vec<String>,
+        query: Box<Query>,
+    },
+    /// CREATE MATERIALIZED VIEW
+    CreateMaterializedView {
+        name: String,
+        /// Optional column aliases
+        columns: Vec<String>,
+        query: Box<Query>,
+    },
+    /// REFRESH MATERIALIZED VIEW
+    RefreshMaterializedView {
+        name: String,
+    },
+    /// DROP VIEW
+    DropView {
+        /// An optional `IF EXISTS` clause. (Non-standard.)
+        if_exists: bool,
+        /// One or more objects to drop. (ANSI SQL requires exactly one.)
+        names: Vec<String>,
+    },
+    /// CREATE SEQUENCE
+    CreateSequence {
+        if_not_exists: bool,
+        name: String,
+        options: SequenceOptions,
+    },
+    /// DROP SEQUENCE
+    DropSequence {
+        /// An optional `IF EXISTS` clause. (Non-standard.)
+        if_exists: bool,
+        /// One or more objects to drop. (ANSI SQL requires exactly one.)
+        names: Vec<String>,
+    },
     /// CREATE INDEX
     CreateIndex {
         name: String,
         table_name: String,
-        column: OrderByExpr,
+        columns: Vec<OrderByExpr>,
     },
     /// DROP INDEX
     DropIndex {
@@ -116,6 +182,18 @@ pub enum Statement {
     /// SHOW VARIABLE
     ShowVariable(Variable),
     ShowIndexes(String),
+    /// EXPLAIN
+    Explain {
+        /// `ANALYZE`, runs the statement and shows the rows and time of each step
+        analyze: bool,
+        /// The statement to show the plan of
+        statement: Box<Statement>,
+    },
+    /// ANALYZE
+    Analyze {
+        /// Table to collect the statistics of, all tables if omitted
+        table_name: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -124,10 +202,29 @@ pub struct Assignment {
     pub value: Expr,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OnConflict {
+    /// Conflict target columns, empty when not specified
+    pub columns: Vec<String>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnConflictAction {
+    /// DO NOTHING
+    DoNothing,
+    /// DO UPDATE SET ... WHERE ...
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        selection: Option<Expr>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
     Functions,
+    Views,
     Version,
 }
 
@@ -141,47 +238,75 @@ impl ToSql for Statement {
                 table_name,
                 columns,
                 source,
+                on_conflict,
+                returning,
             } => {
                 let columns = match columns.is_empty() {
                     true => "".to_owned(),
                     false => format!("({}) ", columns.join(", ")),
                 };
+                let on_conflict = match on_conflict {
+                    Some(on_conflict) => format!(" {}", on_conflict.to_sql()),
+                    None => "".to_owned(),
+                };
 
-                format!("INSERT INTO {table_name} {columns}{};", source.to_sql())
+                format!(
+                    "INSERT INTO {table_name} {columns}{}{on_conflict}{};",
+                    source.to_sql(),
+                    returning_to_sql(returning)
+                )
             }
             Statement::Update {
                 table_name,
                 assignments,
+                from,
+                join_executor,
                 selection,
+                returning,
             } => {
                 let assignments = assignments
                     .iter()
                     .map(ToSql::to_sql)
                     .collect::<Vec<_>>()
                     .join(", ");
-                match selection {
-                    Some(expr) => {
-                        format!(
-                            r#"UPDATE "{table_name}" SET {assignments} WHERE {};"#,
-                            expr.to_sql()
-                        )
-                    }
-                    None => format!(r#"UPDATE "{table_name}" SET {assignments};"#),
-                }
+                let from = match from {
+                    Some(from) => format!(" FROM {}", from.to_sql()),
+                    None => "".to_owned(),
+                };
+                let selection = selection_to_sql(join_executor, selection.as_ref());
+
+                format!(
+                    r#"UPDATE "{table_name}" SET {assignments}{from}{selection}{};"#,
+                    returning_to_sql(returning)
+                )
             }
             Statement::Delete {
                 table_name,
+                using,
+                join_executor,
                 selection,
-            } => match selection {
-                Some(expr) => format!(r#"DELETE FROM "{table_name}" WHERE {};"#, expr.to_sql()),
-                None => format!(r#"DELETE FROM "{table_name}";"#),
-            },
+                returning,
+            } => {
+                let using = match using {
+                    Some(using) => format!(" USING {}", using.to_sql()),
+                    None => "".to_owned(),
+                };
+                let selection = selection_to_sql(join_executor, selection.as_ref());
+
+                format!(
+                    r#"DELETE FROM "{table_name}"{using}{selection}{};"#,
+                    returning_to_sql(returning)
+                )
+            }
             Statement::CreateTable {
                 if_not_exists,
                 name,
                 columns,
                 source,
                 engine,
+                foreign_keys,
+                checks,
+                unique_constraints,
             } => {
                 let if_not_exists = if_not_exists.then_some("IF NOT EXISTS");
                 let body = match source {
@@ -189,15 +314,14 @@ impl ToSql for Statement {
                     None if columns.is_none() => None,
                     None => {
                         let columns = columns
-                            .as_ref()
-                            .map(|columns| {
-                                columns
-                                    .iter()
-                                    .map(ToSql::to_sql)
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            })
-                            .unwrap_or_else(|| "".to_owned());
+                            .iter()
+                            .flatten()
+                            .map(ToSql::to_sql)
+                            .chain(unique_constraints.iter().map(ToSql::to_sql))
+                            .chain(foreign_keys.iter().map(ToSql::to_sql))
+                            .chain(checks.iter().map(ToSql::to_sql))
+                            .collect::<Vec<_>>()
+                            .join(", ");
 
                         Some(format!("({columns})"))
                     }
@@ -236,15 +360,20 @@ impl ToSql for Statement {
             Statement::AlterTable { name, operation } => {
                 format!(r#"ALTER TABLE "{name}" {};"#, operation.to_sql())
             }
-            Statement::DropTable { if_exists, names } => {
+            Statement::DropTable {
+                if_exists,
+                names,
+                cascade,
+            } => {
                 let names = names
                     .iter()
                     .map(|name| format!(r#""{name}""#))
                     .collect::<Vec<_>>()
                     .join(", ");
+                let cascade = cascade.then_some(" CASCADE").unwrap_or("");
                 match if_exists {
-                    true => format!("DROP TABLE IF EXISTS {};", names),
-                    false => format!("DROP TABLE {};", names),
+                    true => format!("DROP TABLE IF EXISTS {names}{cascade};"),
+                    false => format!("DROP TABLE {names}{cascade};"),
                 }
             }
             Statement::DropFunction { if_exists, names } => {
@@ -254,16 +383,108 @@ impl ToSql for Statement {
                     false => format!("DROP FUNCTION {};", names),
                 }
             }
-            Statement::CreateIndex {
+            Statement::CreateView {
+                or_replace,
                 name,
-                table_name,
-                column,
+                columns,
+                query,
             } => {
+                let or_replace = or_replace.then_some(" OR REPLACE").unwrap_or("");
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => {
+                        let columns = columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        format!(" ({columns})")
+                    }
+                };
+
                 format!(
-                    r#"CREATE INDEX "{name}" ON "{table_name}" ({});"#,
-                    column.to_sql()
+                    r#"CREATE{or_replace} VIEW "{name}"{columns} AS {};"#,
+                    query.to_sql()
                 )
             }
+            Statement::CreateMaterializedView {
+                name,
+                columns,
+                query,
+            } => {
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => {
+                        let columns = columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        format!(" ({columns})")
+                    }
+                };
+
+                format!(
+                    r#"CREATE MATERIALIZED VIEW "{name}"{columns} AS {};"#,
+                    query.to_sql()
+                )
+            }
+            Statement::RefreshMaterializedView { name } => {
+                format!(r#"REFRESH MATERIALIZED VIEW "{name}";"#)
+            }
+            Statement::DropView { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match if_exists {
+                    true => format!("DROP VIEW IF EXISTS {};", names),
+                    false => format!("DROP VIEW {};", names),
+                }
+            }
+            Statement::CreateSequence {
+                if_not_exists,
+                name,
+                options,
+            } => {
+                let if_not_exists = match if_not_exists {
+                    true => " IF NOT EXISTS",
+                    false => "",
+                };
+                let options = match options.to_sql().as_str() {
+                    "" => "".to_owned(),
+                    options => format!(" {options}"),
+                };
+
+                format!(r#"CREATE SEQUENCE{if_not_exists} "{name}"{options};"#)
+            }
+            Statement::DropSequence { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match if_exists {
+                    true => format!("DROP SEQUENCE IF EXISTS {};", names),
+                    false => format!("DROP SEQUENCE {};", names),
+                }
+            }
+            Statement::CreateIndex {
+                name,
+                table_name,
+                columns,
+            } => {
+                let columns = columns
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({columns});"#)
+            }
             Statement::DropIndex { name, table_name } => {
                 format!("DROP INDEX {table_name}.{name};")
             }
@@ -273,11 +494,21 @@ impl ToSql for Statement {
             Statement::ShowVariable(variable) => match variable {
                 Variable::Tables => "SHOW TABLES;".to_owned(),
                 Variable::Functions => "SHOW FUNCTIONS;".to_owned(),
+                Variable::Views => "SHOW VIEWS;".to_owned(),
                 Variable::Version => "SHOW VERSIONS;".to_owned(),
             },
             Statement::ShowIndexes(object_name) => {
                 format!(r#"SHOW INDEXES FROM "{object_name}";"#)
             }
+            Statement::Explain { analyze, statement } => {
+                let analyze = analyze.then_some(" ANALYZE").unwrap_or("");
+
+                format!("EXPLAIN{analyze} {}", statement.to_sql())
+            }
+            Statement::Analyze { table_name } => match table_name {
+                Some(table_name) => format!(r#"ANALYZE "{table_name}";"#),
+                None => "ANALYZE;".to_owned(),
+            },
             _ => "(..statement..)".to_owned(),
         }
     }
@@ -289,13 +520,86 @@ impl ToSql for Assignment {
     }
 }
 
+impl ToSql for OnConflict {
+    fn to_sql(&self) -> String {
+        let OnConflict { columns, action } = self;
+        let columns = match columns.is_empty() {
+            true => "".to_owned(),
+            false => format!(
+                " ({})",
+                columns
+                    .iter()
+                    .map(|column| format!(r#""{column}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        match action {
+            OnConflictAction::DoNothing => format!("ON CONFLICT{columns} DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match selection {
+                    Some(expr) => format!(
+                        "ON CONFLICT{columns} DO UPDATE SET {assignments} WHERE {}",
+                        expr.to_sql()
+                    ),
+                    None => format!("ON CONFLICT{columns} DO UPDATE SET {assignments}"),
+                }
+            }
+        }
+    }
+}
+
+fn returning_to_sql(returning: &[SelectItem]) -> String {
+    match returning.is_empty() {
+        true => "".to_owned(),
+        false => format!(
+            " RETURNING {}",
+            returning
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// The WHERE clause of `UPDATE` and `DELETE`, with the join key of FROM or USING
+/// moved back into it by the planner
+fn selection_to_sql(join_executor: &JoinExecutor, selection: Option<&Expr>) -> String {
+    let join_key = match join_executor {
+        JoinExecutor::NestedLoop => None,
+        JoinExecutor::Hash { .. } => Some(join_executor.to_sql()),
+    };
+    let conditions = join_key
+        .into_iter()
+        .chain(selection.map(ToSql::to_sql))
+        .collect::<Vec<_>>();
+
+    match conditions.is_empty() {
+        true => "".to_owned(),
+        false => format!(" WHERE {}", conditions.join(" AND ")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
         crate::ast::{
-            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, ColumnDef, DataType, Expr,
-            OperateFunctionArg, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
-            TableFactor, TableWithJoins, ToSql, Values, Variable,
+            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, Check, ColumnDef,
+            DataType, Expr, ForeignKey, JoinExecutor, OnConflict, OnConflictAction,
+            OperateFunctionArg, OrderByExpr, Query, ReferentialAction, Select, SelectItem,
+            SequenceOptions, SetExpr, Statement, TableFactor, TableWithJoins, ToSql, Values,
+            Variable,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -320,6 +624,7 @@ mod tests {
                 table_name: "Test".into(),
                 columns: vec!["id".to_owned(), "num".to_owned(), "name".to_owned()],
                 source: Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
@@ -328,7 +633,100 @@ mod tests {
                     order_by: vec![],
                     limit: None,
                     offset: None
-                }
+                },
+                on_conflict: None,
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"INSERT INTO Test (id, num) VALUES (1, 2) ON CONFLICT ("id") DO UPDATE SET "num" = "EXCLUDED"."num" WHERE "Test"."num" < 2;"#,
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: vec!["id".to_owned(), "num".to_owned()],
+                source: Query {
+                    with: None,
+                    body: SetExpr::Values(Values(vec![vec![
+                        Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
+                        Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
+                    ]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict {
+                    columns: vec!["id".to_owned()],
+                    action: OnConflictAction::DoUpdate {
+                        assignments: vec![Assignment {
+                            id: "num".to_owned(),
+                            value: Expr::CompoundIdentifier {
+                                alias: "EXCLUDED".to_owned(),
+                                ident: "num".to_owned(),
+                            },
+                        }],
+                        selection: Some(Expr::BinaryOp {
+                            left: Box::new(Expr::CompoundIdentifier {
+                                alias: "Test".to_owned(),
+                                ident: "num".to_owned(),
+                            }),
+                            op: BinaryOperator::Lt,
+                            right: Box::new(Expr::Literal(AstLiteral::Number(
+                                BigDecimal::from_str("2").unwrap()
+                            ))),
+                        }),
+                    },
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            "INSERT INTO Test VALUES (1) ON CONFLICT DO NOTHING;",
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: vec![],
+                source: Query {
+                    with: None,
+                    body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    ))]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict {
+                    columns: vec![],
+                    action: OnConflictAction::DoNothing,
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"INSERT INTO Test VALUES (1) RETURNING *, "id" AS "new_id";"#,
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: vec![],
+                source: Query {
+                    with: None,
+                    body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    ))]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: None,
+                returning: vec![
+                    SelectItem::Wildcard,
+                    SelectItem::Expr {
+                        expr: Expr::Identifier("id".to_owned()),
+                        label: "new_id".to_owned()
+                    }
+                ]
             }
             .to_sql()
         );
@@ -352,7 +750,10 @@ mod tests {
                         value: Expr::Literal(AstLiteral::QuotedString("blue".to_owned()))
                     }
                 ],
-                selection: None
+                from: None,
+                join_executor: JoinExecutor::NestedLoop,
+                selection: None,
+                returning: vec![]
             }
             .to_sql()
         );
@@ -365,11 +766,66 @@ mod tests {
                     id: "name".to_owned(),
                     value: Expr::Literal(AstLiteral::QuotedString("first".to_owned()))
                 }],
+                from: None,
+                join_executor: JoinExecutor::NestedLoop,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("a".to_owned())),
                     op: BinaryOperator::Gt,
                     right: Box::new(Expr::Identifier("b".to_owned()))
-                })
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"UPDATE "Foo" SET "name" = "Bar"."name" FROM "Bar" WHERE "Foo"."id" = "Bar"."id";"#,
+            Statement::Update {
+                table_name: "Foo".into(),
+                assignments: vec![Assignment {
+                    id: "name".to_owned(),
+                    value: Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "name".to_owned()
+                    }
+                }],
+                from: Some(TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None
+                    },
+                    joins: vec![]
+                }),
+                join_executor: JoinExecutor::NestedLoop,
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "Foo".to_owned(),
+                        ident: "id".to_owned()
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "id".to_owned()
+                    })
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"UPDATE "Foo" SET "id" = 4 RETURNING "Foo".*;"#,
+            Statement::Update {
+                table_name: "Foo".into(),
+                assignments: vec![Assignment {
+                    id: "id".to_owned(),
+                    value: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("4").unwrap()))
+                }],
+                from: None,
+                join_executor: JoinExecutor::NestedLoop,
+                selection: None,
+                returning: vec![SelectItem::QualifiedWildcard("Foo".to_owned())]
             }
             .to_sql()
         )
@@ -381,7 +837,10 @@ mod tests {
             r#"DELETE FROM "Foo";"#,
             Statement::Delete {
                 table_name: "Foo".into(),
-                selection: None
+                using: None,
+                join_executor: JoinExecutor::NestedLoop,
+                selection: None,
+                returning: vec![]
             }
             .to_sql()
         );
@@ -390,11 +849,91 @@ mod tests {
             r#"DELETE FROM "Foo" WHERE "item" = 'glue';"#,
             Statement::Delete {
                 table_name: "Foo".into(),
+                using: None,
+                join_executor: JoinExecutor::NestedLoop,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("item".to_owned())),
                     op: BinaryOperator::Eq,
                     right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned())))
-                })
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" USING "Bar" WHERE "Foo"."id" = "Bar"."id";"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                using: Some(TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None
+                    },
+                    joins: vec![]
+                }),
+                join_executor: JoinExecutor::NestedLoop,
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "Foo".to_owned(),
+                        ident: "id".to_owned()
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "id".to_owned()
+                    })
+                }),
+                returning: vec![]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" RETURNING "item";"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                using: None,
+                join_executor: JoinExecutor::NestedLoop,
+                selection: None,
+                returning: vec![SelectItem::Expr {
+                    expr: Expr::Identifier("item".to_owned()),
+                    label: "".to_owned()
+                }]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" USING "Bar" WHERE "Bar"."id" = "Foo"."id" AND "flag" = TRUE;"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                using: Some(TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None
+                    },
+                    joins: vec![]
+                }),
+                join_executor: JoinExecutor::Hash {
+                    key_expr: Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "id".to_owned()
+                    },
+                    value_expr: Expr::CompoundIdentifier {
+                        alias: "Foo".to_owned(),
+                        ident: "id".to_owned()
+                    },
+                    where_clause: None
+                },
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("flag".to_owned())),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Literal(AstLiteral::Boolean(true)))
+                }),
+                returning: vec![]
             }
             .to_sql()
         );
@@ -410,6 +949,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -422,6 +964,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -437,9 +982,13 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    identity: None,
                 },]),
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -456,6 +1005,7 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        identity: None,
                     },
                     ColumnDef {
                         name: "num".to_owned(),
@@ -463,6 +1013,7 @@ mod tests {
                         nullable: true,
                         default: None,
                         unique: None,
+                        identity: None,
                     },
                     ColumnDef {
                         name: "name".to_owned(),
@@ -470,10 +1021,88 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        identity: None,
                     }
                 ]),
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_table_with_foreign_key() {
+        assert_eq!(
+            r#"CREATE TABLE "Child" ("id" INT NOT NULL, "parent_id" INT NULL, CONSTRAINT "Child_parent_id_fkey" FOREIGN KEY ("parent_id") REFERENCES "Parent" ("id") ON DELETE CASCADE);"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                name: "Child".into(),
+                columns: Some(vec![
+                    ColumnDef {
+                        name: "id".to_owned(),
+                        data_type: DataType::Int,
+                        nullable: false,
+                        default: None,
+                        unique: None,
+                        identity: None,
+                    },
+                    ColumnDef {
+                        name: "parent_id".to_owned(),
+                        data_type: DataType::Int,
+                        nullable: true,
+                        default: None,
+                        unique: None,
+                        identity: None,
+                    },
+                ]),
+                source: None,
+                engine: None,
+                foreign_keys: vec![ForeignKey {
+                    name: "Child_parent_id_fkey".to_owned(),
+                    columns: vec!["parent_id".to_owned()],
+                    referenced_table_name: "Parent".to_owned(),
+                    referenced_columns: vec!["id".to_owned()],
+                    on_delete: ReferentialAction::Cascade,
+                    on_update: ReferentialAction::NoAction,
+                }],
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_table_with_check() {
+        assert_eq!(
+            r#"CREATE TABLE "Item" ("price" INT NULL, CONSTRAINT "Item_price_check" CHECK ("price" > 0));"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                name: "Item".into(),
+                columns: Some(vec![ColumnDef {
+                    name: "price".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                    identity: None,
+                }]),
+                source: None,
+                engine: None,
+                foreign_keys: Vec::new(),
+                checks: vec![Check {
+                    name: "Item_price_check".to_owned(),
+                    expr: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("price".to_owned())),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(AstLiteral::Number(BigDecimal::from(0)))),
+                    },
+                }],
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -488,7 +1117,9 @@ mod tests {
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![
                             SelectItem::Expr {
                                 expr: Expr::Identifier("id".to_owned()),
@@ -509,13 +1140,17 @@ mod tests {
                         },
                         selection: None,
                         group_by: vec![],
-                        having: None
+                        having: None,
+                        plan_id: None,
                     })),
                     order_by: vec![],
                     limit: None,
                     offset: None
                 })),
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -527,6 +1162,7 @@ mod tests {
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Boolean(
                         true
                     ))]])),
@@ -535,6 +1171,9 @@ mod tests {
                     offset: None
                 })),
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -550,6 +1189,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: Some("MEMORY".to_owned()),
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -565,9 +1207,13 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    identity: None,
                 },]),
                 source: None,
                 engine: Some("SLED".to_owned()),
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }
             .to_sql()
         );
@@ -618,6 +1264,7 @@ mod tests {
                             BigDecimal::from_str("10").unwrap()
                         ))),
                         unique: None,
+                        identity: None,
                     }
                 }
             }
@@ -678,7 +1325,8 @@ mod tests {
             r#"DROP TABLE "Test";"#,
             Statement::DropTable {
                 if_exists: false,
-                names: vec!["Test".into()]
+                names: vec!["Test".into()],
+                cascade: false,
             }
             .to_sql()
         );
@@ -687,7 +1335,18 @@ mod tests {
             r#"DROP TABLE IF EXISTS "Test";"#,
             Statement::DropTable {
                 if_exists: true,
-                names: vec!["Test".into()]
+                names: vec!["Test".into()],
+                cascade: false,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP TABLE "Test" CASCADE;"#,
+            Statement::DropTable {
+                if_exists: false,
+                names: vec!["Test".into()],
+                cascade: true,
             }
             .to_sql()
         );
@@ -696,7 +1355,8 @@ mod tests {
             r#"DROP TABLE "Foo", "Bar";"#,
             Statement::DropTable {
                 if_exists: false,
-                names: vec!["Foo".into(), "Bar".into(),]
+                names: vec!["Foo".into(), "Bar".into(),],
+                cascade: false,
             }
             .to_sql()
         );
@@ -732,6 +1392,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_create_view() {
+        let query = Query {
+            with: None,
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Expr {
+                    expr: Expr::Identifier("id".to_owned()),
+                    label: "".to_owned(),
+                }],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: vec![],
+                },
+                selection: None,
+                group_by: vec![],
+                having: None,
+                plan_id: None,
+            })),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        };
+
+        assert_eq!(
+            r#"CREATE VIEW "Foo" AS SELECT "id" FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: false,
+                name: "Foo".into(),
+                columns: vec![],
+                query: Box::new(query.clone()),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE OR REPLACE VIEW "Foo" ("num") AS SELECT "id" FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: true,
+                name: "Foo".into(),
+                columns: vec!["num".into()],
+                query: Box::new(query),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_materialized_view() {
+        assert_eq!(
+            r#"CREATE MATERIALIZED VIEW "Foo" ("num") AS SELECT "id" FROM "Bar";"#,
+            Statement::CreateMaterializedView {
+                name: "Foo".into(),
+                columns: vec!["num".into()],
+                query: Box::new(Query {
+                    with: None,
+                    body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
+                        projection: vec![SelectItem::Expr {
+                            expr: Expr::Identifier("id".to_owned()),
+                            label: "".to_owned(),
+                        }],
+                        from: TableWithJoins {
+                            relation: TableFactor::Table {
+                                name: "Bar".to_owned(),
+                                alias: None,
+                                index: None,
+                            },
+                            joins: vec![],
+                        },
+                        selection: None,
+                        group_by: vec![],
+                        having: None,
+                        plan_id: None,
+                    })),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                }),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REFRESH MATERIALIZED VIEW "Foo";"#,
+            Statement::RefreshMaterializedView { name: "Foo".into() }.to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_view() {
+        assert_eq!(
+            r#"DROP VIEW "Foo";"#,
+            Statement::DropView {
+                if_exists: false,
+                names: vec!["Foo".into()]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP VIEW IF EXISTS "Foo", "Bar";"#,
+            Statement::DropView {
+                if_exists: true,
+                names: vec!["Foo".into(), "Bar".into()]
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_sequence() {
+        assert_eq!(
+            r#"CREATE SEQUENCE "Foo";"#,
+            Statement::CreateSequence {
+                if_not_exists: false,
+                name: "Foo".into(),
+                options: SequenceOptions::default(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE SEQUENCE IF NOT EXISTS "Foo" INCREMENT BY 2 MINVALUE 1 MAXVALUE 9 START WITH 3 CYCLE;"#,
+            Statement::CreateSequence {
+                if_not_exists: true,
+                name: "Foo".into(),
+                options: SequenceOptions {
+                    increment: Some(2),
+                    min_value: Some(1),
+                    max_value: Some(9),
+                    start: Some(3),
+                    cycle: true,
+                },
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP SEQUENCE IF EXISTS "Foo", "Bar";"#,
+            Statement::DropSequence {
+                if_exists: true,
+                names: vec!["Foo".into(), "Bar".into()]
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_create_index() {
         assert_eq!(
@@ -739,10 +1551,32 @@ mod tests {
             Statement::CreateIndex {
                 name: "idx_name".into(),
                 table_name: "Test".into(),
-                column: OrderByExpr {
+                columns: vec![OrderByExpr {
                     expr: Expr::Identifier("LastName".to_owned()),
-                    asc: None
-                }
+                    asc: None,
+                    nulls_first: None,
+                }]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE INDEX "idx_tenant" ON "Test" ("tenant_id", "created_at" DESC);"#,
+            Statement::CreateIndex {
+                name: "idx_tenant".into(),
+                table_name: "Test".into(),
+                columns: vec![
+                    OrderByExpr {
+                        expr: Expr::Identifier("tenant_id".to_owned()),
+                        asc: None,
+                        nulls_first: None,
+                    },
+                    OrderByExpr {
+                        expr: Expr::Identifier("created_at".to_owned()),
+                        asc: Some(false),
+                        nulls_first: None,
+                    }
+                ]
             }
             .to_sql()
         );
@@ -777,6 +1611,10 @@ mod tests {
             "SHOW FUNCTIONS;",
             Statement::ShowVariable(Variable::Functions).to_sql()
         );
+        assert_eq!(
+            "SHOW VIEWS;",
+            Statement::ShowVariable(Variable::Views).to_sql()
+        );
         assert_eq!(
             "SHOW VERSIONS;",
             Statement::ShowVariable(Variable::Version).to_sql()
@@ -791,6 +1629,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_explain() {
+        assert_eq!(
+            r#"EXPLAIN DELETE FROM "Foo" WHERE "id" = 1;"#,
+            Statement::Explain {
+                analyze: false,
+                statement: Box::new(Statement::Delete {
+                    table_name: "Foo".into(),
+                    using: None,
+                    join_executor: JoinExecutor::NestedLoop,
+                    selection: Some(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("id".to_owned())),
+                        op: BinaryOperator::Eq,
+                        right: Box::new(Expr::Literal(AstLiteral::Number(
+                            BigDecimal::from_str("1").unwrap()
+                        )))
+                    }),
+                    returning: vec![]
+                })
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"EXPLAIN ANALYZE SHOW INDEXES FROM "Foo";"#,
+            Statement::Explain {
+                analyze: true,
+                statement: Box::new(Statement::ShowIndexes("Foo".into()))
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_analyze() {
+        assert_eq!(
+            r#"ANALYZE "Foo";"#,
+            Statement::Analyze {
+                table_name: Some("Foo".into())
+            }
+            .to_sql()
+        );
+        assert_eq!("ANALYZE;", Statement::Analyze { table_name: None }.to_sql());
+    }
+
     #[test]
     fn to_sql_assignment() {
         assert_eq!(
@@ -3,31 +3,71 @@ use {
     crate::ast::ToSql,
     itertools::Itertools,
     serde::{Deserialize, Serialize},
+    std::ops::Bound,
     strum_macros::Display,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Query {
+    pub with: Option<With>,
     pub body: SetExpr,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Expr>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct With {
+    pub recursive: bool,
+    pub cte_tables: Vec<Cte>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cte {
+    pub alias: TableAlias,
+    pub query: Box<Query>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    SetOperation {
+        op: SetOperator,
+        all: bool,
+        left: Box<Query>,
+        right: Box<Query>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Select {
+    pub distinct: Option<Distinct>,
     pub projection: Vec<SelectItem>,
     pub from: TableWithJoins,
     /// WHERE
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
     pub having: Option<Expr>,
+    /// Assigned by the planner of `EXPLAIN`, to match the stages measured while running the
+    /// query with the steps of its plan
+    pub plan_id: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Distinct {
+    /// `DISTINCT`
+    Distinct,
+    /// `DISTINCT ON (exprs)`
+    On(Vec<Expr>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -49,13 +89,47 @@ pub struct TableWithJoins {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IndexItem {
     PrimaryKey(Expr),
+    /// Scan of the primary keys in the range, in ascending or descending key order
+    PrimaryKeyRange {
+        lower: Bound<Expr>,
+        upper: Bound<Expr>,
+        asc: bool,
+    },
     NonClustered {
         name: String,
         asc: Option<bool>,
-        cmp_expr: Option<(IndexOperator, Expr)>,
+        /// Values of the leading columns of a composite index, matched by equality
+        prefix: Vec<Expr>,
+        /// Condition on the column right after `prefix`
+        condition: Option<IndexCondition>,
     },
 }
 
+/// Condition of a non-clustered index scan, `NULL` bounds place `NULL`s after every value
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndexCondition {
+    Range {
+        lower: Bound<Expr>,
+        upper: Bound<Expr>,
+    },
+    /// Values equal to any of the list, scanned one after another in index order
+    InList(Vec<Expr>),
+}
+
+impl From<(IndexOperator, Expr)> for IndexCondition {
+    fn from((index_op, expr): (IndexOperator, Expr)) -> Self {
+        let (lower, upper) = match index_op {
+            IndexOperator::Gt => (Bound::Excluded(expr), Bound::Unbounded),
+            IndexOperator::GtEq => (Bound::Included(expr), Bound::Unbounded),
+            IndexOperator::Lt => (Bound::Unbounded, Bound::Excluded(expr)),
+            IndexOperator::LtEq => (Bound::Unbounded, Bound::Included(expr)),
+            IndexOperator::Eq => (Bound::Included(expr.clone()), Bound::Included(expr)),
+        };
+
+        IndexCondition::Range { lower, upper }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TableFactor {
     Table {
@@ -114,11 +188,16 @@ pub enum JoinExecutor {
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
+    RightOuter(JoinConstraint),
+    FullOuter(JoinConstraint),
+    CrossJoin,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JoinConstraint {
     On(Expr),
+    Using(Vec<String>),
+    Natural,
     None,
 }
 
@@ -126,6 +205,7 @@ pub enum JoinConstraint {
 pub struct OrderByExpr {
     pub expr: Expr,
     pub asc: Option<bool>,
+    pub nulls_first: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -151,12 +231,18 @@ impl Query {
         };
 
         let Query {
+            with,
             body,
             order_by,
             limit,
             offset,
         } = self;
 
+        let with = match with {
+            Some(with) => with.to_sql_with(quoted),
+            None => "".to_owned(),
+        };
+
         let order_by = if order_by.is_empty() {
             "".to_owned()
         } else {
@@ -179,16 +265,78 @@ impl Query {
             _ => "".to_owned(),
         };
 
-        let string = vec![order_by, limit, offset]
+        vec![with, body.to_sql_with(quoted), order_by, limit, offset]
             .iter()
             .filter(|sql| !sql.is_empty())
-            .join(" ");
+            .join(" ")
+    }
+}
 
-        if string.is_empty() {
-            body.to_sql_with(quoted)
-        } else {
-            format!("{} {}", body.to_sql_with(quoted), string)
-        }
+impl ToSql for With {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for With {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl With {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let With {
+            recursive,
+            cte_tables,
+        } = self;
+
+        let recursive = if *recursive { " RECURSIVE" } else { "" };
+        let cte_tables = cte_tables
+            .iter()
+            .map(|cte| cte.to_sql_with(quoted))
+            .join(", ");
+
+        format!("WITH{recursive} {cte_tables}")
+    }
+}
+
+impl ToSql for Cte {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for Cte {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl Cte {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let Cte {
+            alias: TableAlias { name, columns },
+            query,
+        } = self;
+
+        let quote = |name: &str| match quoted {
+            true => format!(r#""{name}""#),
+            false => name.to_owned(),
+        };
+        let columns = match columns.is_empty() {
+            true => "".to_owned(),
+            false => format!(
+                " ({})",
+                columns.iter().map(|column| quote(column)).join(", ")
+            ),
+        };
+
+        format!(
+            "{}{columns} AS ({})",
+            quote(name),
+            query.to_sql_with(quoted)
+        )
     }
 }
 
@@ -211,6 +359,29 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                _,
+            ) => {
+                let operand = |query: &Query| match query {
+                    Query {
+                        with: None,
+                        body: SetExpr::Select(_) | SetExpr::Values(_),
+                        order_by,
+                        limit: None,
+                        offset: None,
+                    } if order_by.is_empty() => query.to_sql_with(quoted),
+                    _ => format!("({})", query.to_sql_with(quoted)),
+                };
+                let all = if *all { " ALL" } else { "" };
+
+                format!("{} {op}{all} {}", operand(left), operand(right))
+            }
         }
     }
 }
@@ -235,16 +406,22 @@ impl Select {
         };
 
         let Select {
+            distinct,
             projection,
             from,
             selection,
             group_by,
             having,
+            plan_id: _,
         } = self;
         let projection = projection
             .iter()
             .map(|item| item.to_sql_with(quoted))
             .join(", ");
+        let projection = match distinct {
+            Some(distinct) => format!("{} {projection}", distinct.to_sql_with(quoted)),
+            None => projection,
+        };
 
         let selection = match selection {
             Some(expr) => format!("WHERE {}", to_sql(expr)),
@@ -281,6 +458,37 @@ impl Select {
     }
 }
 
+impl ToSql for Distinct {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for Distinct {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl Distinct {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        match self {
+            Distinct::Distinct => "DISTINCT".to_owned(),
+            Distinct::On(exprs) => {
+                let exprs = exprs
+                    .iter()
+                    .map(|expr| match quoted {
+                        true => expr.to_sql(),
+                        false => expr.to_sql_unquoted(),
+                    })
+                    .join(", ");
+
+                format!("DISTINCT ON ({exprs})")
+            }
+        }
+    }
+}
+
 impl ToSql for SelectItem {
     fn to_sql(&self) -> String {
         self.to_sql_with(true)
@@ -440,8 +648,32 @@ impl Join {
         let (join_operator, join_constraint) = match join_operator {
             JoinOperator::Inner(join_constraint) => ("INNER JOIN", join_constraint),
             JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER JOIN", join_constraint),
+            JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER JOIN", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER JOIN", join_constraint),
+            JoinOperator::CrossJoin => ("CROSS JOIN", &JoinConstraint::None),
         };
 
+        match join_constraint {
+            JoinConstraint::Using(columns) => {
+                let columns = columns
+                    .iter()
+                    .map(|column| match quoted {
+                        true => format!(r#""{column}""#),
+                        false => column.to_owned(),
+                    })
+                    .join(", ");
+
+                return format!(
+                    "{join_operator} {} USING ({columns})",
+                    relation.to_sql_with(quoted)
+                );
+            }
+            JoinConstraint::Natural => {
+                return format!("NATURAL {join_operator} {}", relation.to_sql_with(quoted));
+            }
+            JoinConstraint::On(_) | JoinConstraint::None => {}
+        }
+
         let (join_constraint, join_executor) = match quoted {
             true => (join_constraint.to_sql(), join_executor.to_sql()),
             false => (
@@ -519,7 +751,9 @@ impl JoinConstraint {
         match (self, quoted) {
             (JoinConstraint::On(expr), true) => expr.to_sql(),
             (JoinConstraint::On(expr), false) => expr.to_sql_unquoted(),
-            (JoinConstraint::None, _) => "".to_owned(),
+            (JoinConstraint::Using(_) | JoinConstraint::Natural | JoinConstraint::None, _) => {
+                "".to_owned()
+            }
         }
     }
 }
@@ -538,16 +772,26 @@ impl ToSqlUnquoted for OrderByExpr {
 
 impl OrderByExpr {
     fn to_sql_with(&self, quoted: bool) -> String {
-        let OrderByExpr { expr, asc } = self;
+        let OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        } = self;
         let expr = match quoted {
             true => expr.to_sql(),
             false => expr.to_sql_unquoted(),
         };
 
-        match asc {
+        let expr = match asc {
             Some(true) => format!("{} ASC", expr),
             Some(false) => format!("{} DESC", expr),
             None => expr,
+        };
+
+        match nulls_first {
+            Some(true) => format!("{expr} NULLS FIRST"),
+            Some(false) => format!("{expr} NULLS LAST"),
+            None => expr,
         }
     }
 }
@@ -590,9 +834,10 @@ mod tests {
     use {
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                AstLiteral, BinaryOperator, Cte, Dictionary, Distinct, Expr, Join, JoinConstraint,
+                JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+                SetOperator, TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                With,
             },
             parse_sql::parse_expr,
             translate::translate_expr,
@@ -612,11 +857,14 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual =
             r#"SELECT * FROM "FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -632,6 +880,7 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             })),
             order_by,
             limit: Some(Expr::Literal(AstLiteral::Number(
@@ -650,10 +899,13 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual = "SELECT * FROM FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -669,6 +921,7 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             })),
             order_by,
             limit: Some(Expr::Literal(AstLiteral::Number(
@@ -682,10 +935,77 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_sql_with() {
+        let with = With {
+            recursive: true,
+            cte_tables: vec![
+                Cte {
+                    alias: TableAlias {
+                        name: "T".to_owned(),
+                        columns: vec!["n".to_owned()],
+                    },
+                    query: Box::new(Query {
+                        with: None,
+                        body: SetExpr::Values(Values(vec![vec![expr("1")]])),
+                        order_by: Vec::new(),
+                        limit: None,
+                        offset: None,
+                    }),
+                },
+                Cte {
+                    alias: TableAlias {
+                        name: "U".to_owned(),
+                        columns: Vec::new(),
+                    },
+                    query: Box::new(Query {
+                        with: None,
+                        body: SetExpr::Values(Values(vec![vec![expr("2")]])),
+                        order_by: Vec::new(),
+                        limit: None,
+                        offset: None,
+                    }),
+                },
+            ],
+        };
+        let query = Query {
+            with: Some(with),
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "T".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+                plan_id: None,
+            })),
+            order_by: Vec::new(),
+            limit: Some(expr("1")),
+            offset: None,
+        };
+
+        assert_eq!(
+            r#"WITH RECURSIVE "T" ("n") AS (VALUES (1)), "U" AS (VALUES (2)) SELECT * FROM "T" LIMIT 1"#,
+            query.to_sql()
+        );
+        assert_eq!(
+            "WITH RECURSIVE T (n) AS (VALUES (1)), U AS (VALUES (2)) SELECT * FROM T LIMIT 1",
+            query.to_sql_unquoted()
+        );
+    }
+
     #[test]
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "FOO" AS "F" INNER JOIN "PlayerItem""#.to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -709,6 +1029,7 @@ mod tests {
             selection: None,
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         }))
         .to_sql();
         assert_eq!(actual, expected);
@@ -728,12 +1049,74 @@ mod tests {
         ]))
         .to_sql();
         assert_eq!(actual, expected);
+        let select = |name: &str| Query {
+            with: None,
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Expr {
+                    expr: Expr::Identifier("id".to_owned()),
+                    label: "".to_owned(),
+                }],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: name.to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+                plan_id: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+
+        let actual = r#"SELECT "id" FROM "Foo" UNION ALL SELECT "id" FROM "Bar""#.to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: Box::new(select("Foo")),
+            right: Box::new(select("Bar")),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"(SELECT "id" FROM "Foo" INTERSECT SELECT "id" FROM "Bar") EXCEPT (SELECT "id" FROM "Baz" LIMIT 1)"#.to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: false,
+            left: Box::new(Query {
+                with: None,
+                body: SetExpr::SetOperation {
+                    op: SetOperator::Intersect,
+                    all: false,
+                    left: Box::new(select("Foo")),
+                    right: Box::new(select("Bar")),
+                },
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+            }),
+            right: Box::new(Query {
+                limit: Some(Expr::Literal(AstLiteral::Number(
+                    BigDecimal::from_str("1").unwrap(),
+                ))),
+                ..select("Baz")
+            }),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
     fn to_sql_unquoted_set_expr() {
         let actual = "SELECT * FROM FOO AS F INNER JOIN PlayerItem".to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -757,6 +1140,7 @@ mod tests {
             selection: None,
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         }))
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -797,6 +1181,7 @@ mod tests {
         let actual =
             r#"SELECT * FROM "FOO" AS "F" GROUP BY "name" HAVING "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -816,12 +1201,14 @@ mod tests {
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
             }),
+            plan_id: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
 
         let actual = r#"SELECT * FROM "FOO" WHERE "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -838,6 +1225,7 @@ mod tests {
             }),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -847,6 +1235,7 @@ mod tests {
     fn to_sql_unquoted_select() {
         let actual = "SELECT * FROM FOO AS F GROUP BY name HAVING name = 'glue'".to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -866,12 +1255,14 @@ mod tests {
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
             }),
+            plan_id: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
         let actual = "SELECT * FROM FOO WHERE name = 'glue'".to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -888,9 +1279,61 @@ mod tests {
             }),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
+        let actual = r#"SELECT DISTINCT "name" FROM "FOO""#.to_owned();
+        let expected = Select {
+            distinct: Some(Distinct::Distinct),
+            projection: vec![SelectItem::Expr {
+                expr: Expr::Identifier("name".to_owned()),
+                label: "".to_owned(),
+            }],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+            plan_id: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"SELECT DISTINCT ON ("name") "id", "name" FROM "FOO""#.to_owned();
+        let expected = Select {
+            distinct: Some(Distinct::On(vec![Expr::Identifier("name".to_owned())])),
+            projection: vec![
+                SelectItem::Expr {
+                    expr: Expr::Identifier("id".to_owned()),
+                    label: "".to_owned(),
+                },
+                SelectItem::Expr {
+                    expr: Expr::Identifier("name".to_owned()),
+                    label: "".to_owned(),
+                },
+            ],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+            plan_id: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -910,6 +1353,57 @@ mod tests {
         let actual = "*".to_owned();
         let expected = SelectItem::Wildcard.to_sql();
         assert_eq!(actual, expected);
+        let actual = "SELECT DISTINCT name FROM FOO".to_owned();
+        let expected = Select {
+            distinct: Some(Distinct::Distinct),
+            projection: vec![SelectItem::Expr {
+                expr: Expr::Identifier("name".to_owned()),
+                label: "".to_owned(),
+            }],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+            plan_id: None,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "SELECT DISTINCT ON (name) id, name FROM FOO".to_owned();
+        let expected = Select {
+            distinct: Some(Distinct::On(vec![Expr::Identifier("name".to_owned())])),
+            projection: vec![
+                SelectItem::Expr {
+                    expr: Expr::Identifier("id".to_owned()),
+                    label: "".to_owned(),
+                },
+                SelectItem::Expr {
+                    expr: Expr::Identifier("name".to_owned()),
+                    label: "".to_owned(),
+                },
+            ],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+            plan_id: None,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -980,7 +1474,9 @@ mod tests {
         let actual = r#"(SELECT * FROM "FOO") AS "F""#;
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -993,6 +1489,7 @@ mod tests {
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 order_by: Vec::new(),
                 limit: None,
@@ -1046,7 +1543,9 @@ mod tests {
         let actual = "(SELECT * FROM FOO) AS F";
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1059,6 +1558,7 @@ mod tests {
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 order_by: Vec::new(),
                 limit: None,
@@ -1197,6 +1697,80 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"RIGHT OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::RightOuter(JoinConstraint::On(expr(
+                r#""PlayerItem"."user_id" = "Player"."id""#,
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"FULL OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::None),
+            join_executor: JoinExecutor::Hash {
+                key_expr: expr("PlayerItem.user_id"),
+                value_expr: expr("Player.id"),
+                where_clause: None,
+            },
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"CROSS JOIN "PlayerItem""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::CrossJoin,
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"INNER JOIN "PlayerItem" USING ("user_id", "item_id")"#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Using(vec![
+                "user_id".to_owned(),
+                "item_id".to_owned(),
+            ])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"NATURAL LEFT OUTER JOIN "PlayerItem""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::LeftOuter(JoinConstraint::Natural),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -1233,6 +1807,34 @@ mod tests {
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
+        let actual = "FULL OUTER JOIN PlayerItem USING (user_id)";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::Using(vec![
+                "user_id".to_owned()
+            ])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "NATURAL INNER JOIN PlayerItem";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Natural),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
         let actual = "LEFT OUTER JOIN PlayerItem";
         let expected = Join {
             relation: TableFactor::Table {
@@ -1291,6 +1893,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1299,6 +1902,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1307,6 +1911,16 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""foo" DESC NULLS LAST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: Some(false),
+            nulls_first: Some(false),
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1318,6 +1932,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1326,6 +1941,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1334,6 +1950,16 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "foo NULLS FIRST";
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: None,
+            nulls_first: Some(true),
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1,7 +1,7 @@
 use {
-    super::{Build, ExprNode},
+    super::{Build, ExprNode, SelectItemList},
     crate::{
-        ast::{Expr, Statement},
+        ast::{Expr, JoinExecutor, Statement},
         result::Result,
     },
 };
@@ -10,6 +10,7 @@ use {
 pub struct DeleteNode<'a> {
     table_name: String,
     filter_expr: Option<ExprNode<'a>>,
+    returning: Option<SelectItemList<'a>>,
 }
 
 impl<'a> DeleteNode<'a> {
@@ -17,6 +18,7 @@ impl<'a> DeleteNode<'a> {
         Self {
             table_name,
             filter_expr: None,
+            returning: None,
         }
     }
 
@@ -25,16 +27,28 @@ impl<'a> DeleteNode<'a> {
 
         self
     }
+
+    pub fn returning<T: Into<SelectItemList<'a>>>(mut self, select_items: T) -> Self {
+        self.returning = Some(select_items.into());
+
+        self
+    }
 }
 
 impl<'a> Build for DeleteNode<'a> {
     fn build(self) -> Result<Statement> {
         let table_name = self.table_name;
         let selection = self.filter_expr.map(Expr::try_from).transpose()?;
+        let returning = self
+            .returning
+            .map_or_else(|| Ok(vec![]), TryInto::try_into)?;
 
         Ok(Statement::Delete {
             table_name,
+            using: None,
+            join_executor: JoinExecutor::NestedLoop,
             selection,
+            returning,
         })
     }
 }
@@ -69,5 +83,13 @@ mod tests {
             .build();
         let expected = "DELETE FROM Person WHERE name IS NULL";
         test(actual, expected);
+
+        let actual = table("Person")
+            .delete()
+            .filter("id = 1")
+            .returning(vec!["id", "name"])
+            .build();
+        let expected = "DELETE FROM Person WHERE id = 1 RETURNING id, name";
+        test(actual, expected);
     }
 }
@@ -23,7 +23,11 @@ impl Build for DropTableNode {
         let names = vec![self.table_name];
         let if_exists = self.if_exists;
 
-        Ok(Statement::DropTable { names, if_exists })
+        Ok(Statement::DropTable {
+            names,
+            if_exists,
+            cascade: false,
+        })
     }
 }
 
@@ -4,4 +4,10 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 pub enum AstBuilderError {
     #[error("failed to parse numeric value: {0}")]
     FailedToParseNumeric(String),
+
+    #[error("window function must be an aggregate: {0}")]
+    NonAggregateWindowFunction(String),
+
+    #[error("cross join cannot have a join constraint")]
+    CrossJoinWithConstraint,
 }
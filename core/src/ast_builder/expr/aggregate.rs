@@ -1,7 +1,7 @@
 use {
     super::ExprNode,
     crate::{
-        ast::{Aggregate, CountArgExpr},
+        ast::{Aggregate, AggregateFunction, CountArgExpr},
         parse_sql::parse_expr,
         result::{Error, Result},
         translate::translate_expr,
@@ -9,7 +9,13 @@ use {
 };
 
 #[derive(Clone, Debug)]
-pub enum AggregateNode<'a> {
+pub struct AggregateNode<'a> {
+    func: AggregateFunctionNode<'a>,
+    distinct: bool,
+}
+
+#[derive(Clone, Debug)]
+pub enum AggregateFunctionNode<'a> {
     Count(CountArgExprNode<'a>),
     Sum(ExprNode<'a>),
     Min(ExprNode<'a>),
@@ -57,17 +63,24 @@ impl<'a> TryFrom<AggregateNode<'a>> for Aggregate {
     type Error = Error;
 
     fn try_from(aggr_node: AggregateNode<'a>) -> Result<Self> {
-        match aggr_node {
-            AggregateNode::Count(count_arg_expr_node) => {
-                count_arg_expr_node.try_into().map(Aggregate::Count)
+        let AggregateNode { func, distinct } = aggr_node;
+        let func = match func {
+            AggregateFunctionNode::Count(count_arg_expr_node) => {
+                AggregateFunction::Count(count_arg_expr_node.try_into()?)
             }
-            AggregateNode::Sum(expr_node) => expr_node.try_into().map(Aggregate::Sum),
-            AggregateNode::Min(expr_node) => expr_node.try_into().map(Aggregate::Min),
-            AggregateNode::Max(expr_node) => expr_node.try_into().map(Aggregate::Max),
-            AggregateNode::Avg(expr_node) => expr_node.try_into().map(Aggregate::Avg),
-            AggregateNode::Variance(expr_node) => expr_node.try_into().map(Aggregate::Variance),
-            AggregateNode::Stdev(expr_node) => expr_node.try_into().map(Aggregate::Stdev),
-        }
+            AggregateFunctionNode::Sum(expr_node) => AggregateFunction::Sum(expr_node.try_into()?),
+            AggregateFunctionNode::Min(expr_node) => AggregateFunction::Min(expr_node.try_into()?),
+            AggregateFunctionNode::Max(expr_node) => AggregateFunction::Max(expr_node.try_into()?),
+            AggregateFunctionNode::Avg(expr_node) => AggregateFunction::Avg(expr_node.try_into()?),
+            AggregateFunctionNode::Variance(expr_node) => {
+                AggregateFunction::Variance(expr_node.try_into()?)
+            }
+            AggregateFunctionNode::Stdev(expr_node) => {
+                AggregateFunction::Stdev(expr_node.try_into()?)
+            }
+        };
+
+        Ok(Aggregate { func, distinct })
     }
 }
 
@@ -76,62 +89,125 @@ impl<'a> ExprNode<'a> {
         count(self)
     }
 
+    pub fn count_distinct(self) -> Self {
+        count_distinct(self)
+    }
+
     pub fn sum(self) -> Self {
         sum(self)
     }
 
+    pub fn sum_distinct(self) -> Self {
+        sum_distinct(self)
+    }
+
     pub fn min(self) -> Self {
         min(self)
     }
 
+    pub fn min_distinct(self) -> Self {
+        min_distinct(self)
+    }
+
     pub fn max(self) -> Self {
         max(self)
     }
 
+    pub fn max_distinct(self) -> Self {
+        max_distinct(self)
+    }
+
     pub fn avg(self) -> Self {
         avg(self)
     }
 
+    pub fn avg_distinct(self) -> Self {
+        avg_distinct(self)
+    }
+
     pub fn variance(self) -> Self {
         variance(self)
     }
 
+    pub fn variance_distinct(self) -> Self {
+        variance_distinct(self)
+    }
+
     pub fn stdev(self) -> Self {
         stdev(self)
     }
+
+    pub fn stdev_distinct(self) -> Self {
+        stdev_distinct(self)
+    }
+}
+
+fn aggregate(func: AggregateFunctionNode<'_>, distinct: bool) -> ExprNode<'_> {
+    ExprNode::Aggregate(Box::new(AggregateNode { func, distinct }))
 }
 
 pub fn count<'a, T: Into<CountArgExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Count(expr.into())))
+    aggregate(AggregateFunctionNode::Count(expr.into()), false)
+}
+
+pub fn count_distinct<'a, T: Into<CountArgExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Count(expr.into()), true)
 }
 
 pub fn sum<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Sum(expr.into())))
+    aggregate(AggregateFunctionNode::Sum(expr.into()), false)
+}
+
+pub fn sum_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Sum(expr.into()), true)
 }
 
 pub fn min<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Min(expr.into())))
+    aggregate(AggregateFunctionNode::Min(expr.into()), false)
+}
+
+pub fn min_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Min(expr.into()), true)
 }
 
 pub fn max<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Max(expr.into())))
+    aggregate(AggregateFunctionNode::Max(expr.into()), false)
+}
+
+pub fn max_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Max(expr.into()), true)
 }
 
 pub fn avg<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Avg(expr.into())))
+    aggregate(AggregateFunctionNode::Avg(expr.into()), false)
+}
+
+pub fn avg_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Avg(expr.into()), true)
 }
 
 pub fn variance<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Variance(expr.into())))
+    aggregate(AggregateFunctionNode::Variance(expr.into()), false)
+}
+
+pub fn variance_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Variance(expr.into()), true)
 }
 
 pub fn stdev<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Stdev(expr.into())))
+    aggregate(AggregateFunctionNode::Stdev(expr.into()), false)
+}
+
+pub fn stdev_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    aggregate(AggregateFunctionNode::Stdev(expr.into()), true)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ast_builder::{avg, col, count, max, min, stdev, sum, test_expr, variance};
+    use crate::ast_builder::{
+        avg, avg_distinct, col, count, count_distinct, max, max_distinct, min, min_distinct, stdev,
+        stdev_distinct, sum, sum_distinct, test_expr, variance, variance_distinct,
+    };
 
     #[test]
     fn aggregate() {
@@ -193,5 +269,49 @@ mod tests {
         let actual = stdev("scatterplot");
         let expected = "STDEV(scatterplot)";
         test_expr(actual, expected);
+
+        let actual = col("id").count_distinct();
+        let expected = "COUNT(DISTINCT id)";
+        test_expr(actual, expected);
+
+        let actual = count_distinct("id");
+        let expected = "COUNT(DISTINCT id)";
+        test_expr(actual, expected);
+
+        let actual = col("amount").sum_distinct();
+        let expected = "SUM(DISTINCT amount)";
+        test_expr(actual, expected);
+
+        let actual = sum_distinct("amount");
+        let expected = "SUM(DISTINCT amount)";
+        test_expr(actual, expected);
+
+        let actual = min_distinct("budget");
+        let expected = "MIN(DISTINCT budget)";
+        test_expr(actual, expected);
+
+        let actual = col("score").max_distinct();
+        let expected = "MAX(DISTINCT score)";
+        test_expr(actual, expected);
+
+        let actual = max_distinct("score");
+        let expected = "MAX(DISTINCT score)";
+        test_expr(actual, expected);
+
+        let actual = avg_distinct("grade");
+        let expected = "AVG(DISTINCT grade)";
+        test_expr(actual, expected);
+
+        let actual = variance_distinct("statistic");
+        let expected = "VARIANCE(DISTINCT statistic)";
+        test_expr(actual, expected);
+
+        let actual = col("scatterplot").stdev_distinct();
+        let expected = "STDEV(DISTINCT scatterplot)";
+        test_expr(actual, expected);
+
+        let actual = stdev_distinct("scatterplot");
+        let expected = "STDEV(DISTINCT scatterplot)";
+        test_expr(actual, expected);
     }
 }
@@ -152,6 +152,7 @@ mod test {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -164,9 +165,11 @@ mod test {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             let query = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -11,6 +11,7 @@ pub mod between;
 pub mod function;
 pub mod in_list;
 pub mod numeric;
+pub mod window;
 
 pub use {
     case::case,
@@ -21,7 +22,9 @@ pub use {
 
 use {
     crate::{
-        ast::{Aggregate, AstLiteral, BinaryOperator, Expr, Function, Query, UnaryOperator},
+        ast::{
+            Aggregate, AstLiteral, BinaryOperator, Expr, Function, Query, UnaryOperator, Window,
+        },
         ast_builder::QueryNode,
         parse_sql::{parse_comma_separated_exprs, parse_expr, parse_query},
         prelude::DataType,
@@ -34,6 +37,7 @@ use {
     in_list::InListNode,
     numeric::NumericNode,
     std::borrow::Cow,
+    window::WindowNode,
 };
 
 #[derive(Clone, Debug)]
@@ -82,6 +86,7 @@ pub enum ExprNode<'a> {
     Nested(Box<ExprNode<'a>>),
     Function(Box<FunctionNode<'a>>),
     Aggregate(Box<AggregateNode<'a>>),
+    Window(Box<WindowNode<'a>>),
     Exists {
         subquery: Box<QueryNode<'a>>,
         negated: bool,
@@ -242,6 +247,7 @@ impl<'a> TryFrom<ExprNode<'a>> for Expr {
             ExprNode::Aggregate(aggr_expr) => Aggregate::try_from(*aggr_expr)
                 .map(Box::new)
                 .map(Expr::Aggregate),
+            ExprNode::Window(window) => Window::try_from(*window).map(Box::new).map(Expr::Window),
             ExprNode::Exists { subquery, negated } => Query::try_from(*subquery)
                 .map(Box::new)
                 .map(|subquery| Expr::Exists { subquery, negated }),
@@ -69,10 +69,12 @@ impl<'a> TryFrom<NumericNode<'a>> for AstLiteral {
             NumericNode::U16(v) => Ok(AstLiteral::Number(v.into())),
             NumericNode::U32(v) => Ok(AstLiteral::Number(v.into())),
             NumericNode::U64(v) => Ok(AstLiteral::Number(v.into())),
-            NumericNode::F32(v) => BigDecimal::try_from(v)
+            // parsed from the shortest representation, as the exact binary value of `4.56`
+            // carries far more digits than the literal it was written as
+            NumericNode::F32(v) => BigDecimal::from_str(&v.to_string())
                 .map_err(|_| AstBuilderError::FailedToParseNumeric(v.to_string()).into())
                 .map(AstLiteral::Number),
-            NumericNode::F64(v) => BigDecimal::try_from(v)
+            NumericNode::F64(v) => BigDecimal::from_str(&v.to_string())
                 .map_err(|_| AstBuilderError::FailedToParseNumeric(v.to_string()).into())
                 .map(AstLiteral::Number),
             NumericNode::Str(v) => BigDecimal::from_str(&v)
@@ -0,0 +1,339 @@
+use {
+    super::ExprNode,
+    crate::{
+        ast::{
+            Expr, ToSqlUnquoted, Window, WindowFrame, WindowFrameBound, WindowFrameUnits,
+            WindowFunction, WindowSpec,
+        },
+        ast_builder::{AstBuilderError, ExprList, OrderByExprList},
+        result::{Error, Result},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct WindowNode<'a> {
+    func: WindowFunctionNode<'a>,
+    over: WindowSpecNode<'a>,
+}
+
+#[derive(Clone, Debug)]
+pub enum WindowFunctionNode<'a> {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: ExprNode<'a>,
+        offset: Option<ExprNode<'a>>,
+        default: Option<ExprNode<'a>>,
+    },
+    Lead {
+        expr: ExprNode<'a>,
+        offset: Option<ExprNode<'a>>,
+        default: Option<ExprNode<'a>>,
+    },
+    FirstValue(ExprNode<'a>),
+    LastValue(ExprNode<'a>),
+    Aggregate(ExprNode<'a>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WindowSpecNode<'a> {
+    partition_by: Option<ExprList<'a>>,
+    order_by: Option<OrderByExprList<'a>>,
+    frame: Option<(
+        WindowFrameUnits,
+        WindowFrameBoundNode<'a>,
+        WindowFrameBoundNode<'a>,
+    )>,
+}
+
+#[derive(Clone, Debug)]
+pub enum WindowFrameBoundNode<'a> {
+    CurrentRow,
+    Preceding(Option<ExprNode<'a>>),
+    Following(Option<ExprNode<'a>>),
+}
+
+impl<'a> WindowFunctionNode<'a> {
+    pub fn over(self, spec: WindowSpecNode<'a>) -> ExprNode<'a> {
+        ExprNode::Window(Box::new(WindowNode {
+            func: self,
+            over: spec,
+        }))
+    }
+}
+
+impl<'a> ExprNode<'a> {
+    /// Computes the aggregate over a window rather than a group.
+    pub fn over(self, spec: WindowSpecNode<'a>) -> ExprNode<'a> {
+        WindowFunctionNode::Aggregate(self).over(spec)
+    }
+}
+
+impl<'a> WindowSpecNode<'a> {
+    pub fn partition_by<T: Into<ExprList<'a>>>(mut self, expr_list: T) -> Self {
+        self.partition_by = Some(expr_list.into());
+        self
+    }
+
+    pub fn order_by<T: Into<OrderByExprList<'a>>>(mut self, expr_list: T) -> Self {
+        self.order_by = Some(expr_list.into());
+        self
+    }
+
+    pub fn rows_between(
+        mut self,
+        start: WindowFrameBoundNode<'a>,
+        end: WindowFrameBoundNode<'a>,
+    ) -> Self {
+        self.frame = Some((WindowFrameUnits::Rows, start, end));
+        self
+    }
+
+    pub fn range_between(
+        mut self,
+        start: WindowFrameBoundNode<'a>,
+        end: WindowFrameBoundNode<'a>,
+    ) -> Self {
+        self.frame = Some((WindowFrameUnits::Range, start, end));
+        self
+    }
+}
+
+impl<'a> TryFrom<WindowFrameBoundNode<'a>> for WindowFrameBound {
+    type Error = Error;
+
+    fn try_from(node: WindowFrameBoundNode<'a>) -> Result<Self> {
+        let expr = |node: Option<ExprNode<'a>>| node.map(Expr::try_from).transpose();
+
+        match node {
+            WindowFrameBoundNode::CurrentRow => Ok(WindowFrameBound::CurrentRow),
+            WindowFrameBoundNode::Preceding(node) => expr(node).map(WindowFrameBound::Preceding),
+            WindowFrameBoundNode::Following(node) => expr(node).map(WindowFrameBound::Following),
+        }
+    }
+}
+
+impl<'a> TryFrom<WindowSpecNode<'a>> for WindowSpec {
+    type Error = Error;
+
+    fn try_from(node: WindowSpecNode<'a>) -> Result<Self> {
+        let WindowSpecNode {
+            partition_by,
+            order_by,
+            frame,
+        } = node;
+
+        let partition_by = partition_by
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let order_by = order_by
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let frame = frame
+            .map(|(units, start, end)| -> Result<_> {
+                Ok(WindowFrame {
+                    units,
+                    start: start.try_into()?,
+                    end: end.try_into()?,
+                })
+            })
+            .transpose()?;
+
+        Ok(WindowSpec {
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+}
+
+impl<'a> TryFrom<WindowNode<'a>> for Window {
+    type Error = Error;
+
+    fn try_from(node: WindowNode<'a>) -> Result<Self> {
+        let WindowNode { func, over } = node;
+        let expr = |node: Option<ExprNode<'a>>| node.map(Expr::try_from).transpose();
+
+        let func = match func {
+            WindowFunctionNode::RowNumber => WindowFunction::RowNumber,
+            WindowFunctionNode::Rank => WindowFunction::Rank,
+            WindowFunctionNode::DenseRank => WindowFunction::DenseRank,
+            WindowFunctionNode::Lag {
+                expr: node,
+                offset,
+                default,
+            } => WindowFunction::Lag {
+                expr: node.try_into()?,
+                offset: expr(offset)?,
+                default: expr(default)?,
+            },
+            WindowFunctionNode::Lead {
+                expr: node,
+                offset,
+                default,
+            } => WindowFunction::Lead {
+                expr: node.try_into()?,
+                offset: expr(offset)?,
+                default: expr(default)?,
+            },
+            WindowFunctionNode::FirstValue(node) => WindowFunction::FirstValue(node.try_into()?),
+            WindowFunctionNode::LastValue(node) => WindowFunction::LastValue(node.try_into()?),
+            WindowFunctionNode::Aggregate(node) => match Expr::try_from(node)? {
+                Expr::Aggregate(aggregate) => WindowFunction::Aggregate(*aggregate),
+                expr => {
+                    return Err(AstBuilderError::NonAggregateWindowFunction(
+                        expr.to_sql_unquoted(),
+                    )
+                    .into());
+                }
+            },
+        };
+
+        Ok(Window {
+            func,
+            over: over.try_into()?,
+        })
+    }
+}
+
+pub fn window<'a>() -> WindowSpecNode<'a> {
+    WindowSpecNode::default()
+}
+
+pub fn row_number<'a>() -> WindowFunctionNode<'a> {
+    WindowFunctionNode::RowNumber
+}
+
+pub fn rank<'a>() -> WindowFunctionNode<'a> {
+    WindowFunctionNode::Rank
+}
+
+pub fn dense_rank<'a>() -> WindowFunctionNode<'a> {
+    WindowFunctionNode::DenseRank
+}
+
+pub fn lag<'a, T: Into<ExprNode<'a>>>(
+    expr: T,
+    offset: Option<ExprNode<'a>>,
+    default: Option<ExprNode<'a>>,
+) -> WindowFunctionNode<'a> {
+    WindowFunctionNode::Lag {
+        expr: expr.into(),
+        offset,
+        default,
+    }
+}
+
+pub fn lead<'a, T: Into<ExprNode<'a>>>(
+    expr: T,
+    offset: Option<ExprNode<'a>>,
+    default: Option<ExprNode<'a>>,
+) -> WindowFunctionNode<'a> {
+    WindowFunctionNode::Lead {
+        expr: expr.into(),
+        offset,
+        default,
+    }
+}
+
+pub fn first_value<'a, T: Into<ExprNode<'a>>>(expr: T) -> WindowFunctionNode<'a> {
+    WindowFunctionNode::FirstValue(expr.into())
+}
+
+pub fn last_value<'a, T: Into<ExprNode<'a>>>(expr: T) -> WindowFunctionNode<'a> {
+    WindowFunctionNode::LastValue(expr.into())
+}
+
+pub fn current_row<'a>() -> WindowFrameBoundNode<'a> {
+    WindowFrameBoundNode::CurrentRow
+}
+
+pub fn preceding<'a, T: Into<ExprNode<'a>>>(offset: T) -> WindowFrameBoundNode<'a> {
+    WindowFrameBoundNode::Preceding(Some(offset.into()))
+}
+
+pub fn following<'a, T: Into<ExprNode<'a>>>(offset: T) -> WindowFrameBoundNode<'a> {
+    WindowFrameBoundNode::Following(Some(offset.into()))
+}
+
+pub fn unbounded_preceding<'a>() -> WindowFrameBoundNode<'a> {
+    WindowFrameBoundNode::Preceding(None)
+}
+
+pub fn unbounded_following<'a>() -> WindowFrameBoundNode<'a> {
+    WindowFrameBoundNode::Following(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::Expr,
+        ast_builder::{
+            col, current_row, dense_rank, first_value, following, lag, last_value, lead, num,
+            preceding, rank, row_number, sum, test_expr, text, unbounded_following,
+            unbounded_preceding, window, AstBuilderError,
+        },
+    };
+
+    #[test]
+    fn window_function() {
+        let actual = row_number().over(window());
+        let expected = "ROW_NUMBER() OVER ()";
+        test_expr(actual, expected);
+
+        let actual = rank().over(window().order_by("score DESC"));
+        let expected = "RANK() OVER (ORDER BY score DESC)";
+        test_expr(actual, expected);
+
+        let actual = dense_rank().over(window().partition_by("team").order_by(col("score")));
+        let expected = "DENSE_RANK() OVER (PARTITION BY team ORDER BY score)";
+        test_expr(actual, expected);
+
+        let actual = lag("name", None, None).over(window().order_by("id"));
+        let expected = "LAG(name) OVER (ORDER BY id)";
+        test_expr(actual, expected);
+
+        let actual = lead(col("name"), Some(num(2)), Some(text("none"))).over(window());
+        let expected = "LEAD(name, 2, 'none') OVER ()";
+        test_expr(actual, expected);
+
+        let actual = first_value("name").over(window().partition_by(vec!["team", "role"]));
+        let expected = "FIRST_VALUE(name) OVER (PARTITION BY team, role)";
+        test_expr(actual, expected);
+
+        let actual = last_value("name").over(
+            window()
+                .order_by("id")
+                .rows_between(unbounded_preceding(), unbounded_following()),
+        );
+        let expected = "LAST_VALUE(name) OVER (
+            ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+        )";
+        test_expr(actual, expected);
+
+        let actual = sum("amount").over(
+            window()
+                .order_by("id")
+                .rows_between(preceding(num(1)), following(num(1))),
+        );
+        let expected = "SUM(amount) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)";
+        test_expr(actual, expected);
+
+        let actual = col("amount").sum().over(
+            window()
+                .partition_by("team")
+                .range_between(unbounded_preceding(), current_row()),
+        );
+        let expected = "SUM(amount) OVER (
+            PARTITION BY team RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+        )";
+        test_expr(actual, expected);
+
+        let actual = Expr::try_from(col("amount").over(window()));
+        let expected = Err(AstBuilderError::NonAggregateWindowFunction("amount".to_owned()).into());
+        assert_eq!(actual, expected);
+    }
+}
@@ -3,21 +3,21 @@ use {
     crate::{ast::Statement, result::Result},
 };
 
-use super::OrderByExprNode;
+use super::OrderByExprList;
 
 #[derive(Clone, Debug)]
 pub struct CreateIndexNode<'a> {
     name: String,
     table_name: String,
-    column: OrderByExprNode<'a>,
+    columns: OrderByExprList<'a>,
 }
 
 impl<'a> CreateIndexNode<'a> {
-    pub fn new(table_name: String, name: String, column: OrderByExprNode<'a>) -> Self {
+    pub fn new(table_name: String, name: String, columns: OrderByExprList<'a>) -> Self {
         Self {
             table_name,
             name,
-            column,
+            columns,
         }
     }
 }
@@ -26,12 +26,12 @@ impl<'a> Build for CreateIndexNode<'a> {
     fn build(self) -> Result<Statement> {
         let table_name = self.table_name;
         let name = self.name;
-        let column = self.column.try_into()?;
+        let columns = self.columns.try_into()?;
 
         Ok(Statement::CreateIndex {
             name,
             table_name,
-            column,
+            columns,
         })
     }
 }
@@ -70,6 +70,12 @@ mod tests {
         let actual = table("Foo").create_index("nameIndex", "name desc").build();
         let expected = "CREATE INDEX nameIndex ON Foo (name Desc)";
         test(actual, expected);
+
+        let actual = table("Foo")
+            .create_index("tenantIndex", vec!["tenant_id", "created_at desc"])
+            .build();
+        let expected = "CREATE INDEX tenantIndex ON Foo (tenant_id, created_at DESC)";
+        test(actual, expected);
     }
 
     #[test]
@@ -1,5 +1,5 @@
 pub use {
-    super::{Build, ColumnList, ExprList, QueryNode, SelectNode},
+    super::{Build, ColumnList, ExprList, QueryNode, SelectItemList, SelectNode},
     crate::{
         ast::{Expr, Statement},
         result::Result,
@@ -31,6 +31,7 @@ impl InsertNode {
         InsertSourceNode {
             insert_node: self,
             source: QueryNode::Values(values),
+            returning: None,
         }
     }
 
@@ -38,6 +39,7 @@ impl InsertNode {
         InsertSourceNode {
             insert_node: self,
             source: query.into(),
+            returning: None,
         }
     }
 }
@@ -46,6 +48,14 @@ impl InsertNode {
 pub struct InsertSourceNode<'a> {
     insert_node: InsertNode,
     source: QueryNode<'a>,
+    returning: Option<SelectItemList<'a>>,
+}
+
+impl<'a> InsertSourceNode<'a> {
+    pub fn returning<T: Into<SelectItemList<'a>>>(mut self, select_items: T) -> Self {
+        self.returning = Some(select_items.into());
+        self
+    }
 }
 
 impl<'a> Build for InsertSourceNode<'a> {
@@ -54,11 +64,16 @@ impl<'a> Build for InsertSourceNode<'a> {
         let columns = self.insert_node.columns;
         let columns = columns.map_or_else(|| Ok(vec![]), |v| v.try_into())?;
         let source = self.source.try_into()?;
+        let returning = self
+            .returning
+            .map_or_else(|| Ok(vec![]), TryInto::try_into)?;
 
         Ok(Statement::Insert {
             table_name,
             columns,
             source,
+            on_conflict: None,
+            returning,
         })
     }
 }
@@ -95,5 +110,13 @@ mod tests {
             .build();
         let expected = r#"INSERT INTO Foo SELECT id, name FROM Bar LIMIT 10"#;
         test(actual, expected);
+
+        let actual = table("Foo")
+            .insert()
+            .values(vec!["1, 'a'"])
+            .returning("id, name AS label")
+            .build();
+        let expected = r#"INSERT INTO Foo VALUES (1, 'a') RETURNING id, name AS label"#;
+        test(actual, expected);
     }
 }
@@ -69,7 +69,10 @@ pub use {index::CreateIndexNode, index::DropIndexNode};
 
 /// Available aggregate or normal SQL functions
 pub use expr::{
-    aggregate::{avg, count, max, min, stdev, sum, variance, AggregateNode},
+    aggregate::{
+        avg, avg_distinct, count, count_distinct, max, max_distinct, min, min_distinct, stdev,
+        stdev_distinct, sum, sum_distinct, variance, variance_distinct, AggregateNode,
+    },
     function::{
         abs, acos, ascii, asin, atan, calc_distance, cast, ceil, chr, concat, concat_ws, cos,
         degrees, divide, exp, extract, find_idx, floor, format, gcd, generate_uuid, get_x, get_y,
@@ -80,6 +83,13 @@ pub use expr::{
     },
 };
 
+/// Available window functions
+pub use expr::window::{
+    current_row, dense_rank, first_value, following, lag, last_value, lead, preceding, rank,
+    row_number, unbounded_following, unbounded_preceding, window, WindowFrameBoundNode,
+    WindowFunctionNode, WindowNode, WindowSpecNode,
+};
+
 /// Functions for building transaction statements
 pub use transaction::{begin, commit, rollback};
 
@@ -38,7 +38,11 @@ impl<'a> TryFrom<OrderByExprNode<'a>> for OrderByExpr {
             OrderByExprNode::Expr(expr_node) => {
                 let expr = Expr::try_from(expr_node)?;
 
-                Ok(OrderByExpr { expr, asc: None })
+                Ok(OrderByExpr {
+                    expr,
+                    asc: None,
+                    nulls_first: None,
+                })
             }
         }
     }
@@ -70,5 +74,9 @@ mod tests {
         let actual = OrderByExprNode::Text("foo desc".into());
         let expected = "foo DESC";
         test(actual, expected);
+
+        let actual = OrderByExprNode::Text("foo desc nulls last".into());
+        let expected = "foo DESC NULLS LAST";
+        test(actual, expected);
     }
 }
@@ -95,6 +95,7 @@ impl<'a> TryFrom<QueryNode<'a>> for Query {
                     .collect::<Result<Vec<_>>>()?;
 
                 Ok(Query {
+                    with: None,
                     body: SetExpr::Values(Values(values)),
                     order_by: Vec::new(),
                     limit: None,
@@ -172,6 +173,7 @@ mod test {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -184,9 +186,11 @@ mod test {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
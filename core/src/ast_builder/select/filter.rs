@@ -214,6 +214,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -226,9 +227,11 @@ mod tests {
                 selection: Some(expr("PlayerItem.amount > 10").try_into().unwrap()),
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -200,6 +200,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -212,9 +213,11 @@ mod tests {
                 selection: None,
                 group_by: vec![col("PlayerItem.category").try_into().unwrap()],
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
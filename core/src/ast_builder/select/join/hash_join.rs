@@ -74,6 +74,45 @@ impl<'a> HashJoinNode<'a> {
         )
     }
 
+    pub fn right_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Right)
+    }
+
+    pub fn right_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Right,
+        )
+    }
+
+    pub fn full_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Full)
+    }
+
+    pub fn full_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Full,
+        )
+    }
+
+    pub fn cross_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Cross)
+    }
+
+    pub fn cross_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Cross,
+        )
+    }
+
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
         ProjectNode::new(self, select_items)
     }
@@ -173,6 +212,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -185,9 +225,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -222,6 +264,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -234,9 +277,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -270,6 +315,7 @@ mod tests {
             };
 
             let subquery = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -282,13 +328,16 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Derived {
                         subquery: Query {
+                            with: None,
                             body: SetExpr::Select(Box::new(subquery)),
                             order_by: Vec::new(),
                             limit: None,
@@ -304,9 +353,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -3,9 +3,9 @@ use {
     crate::{
         ast::{Join, JoinConstraint, JoinOperator, Select},
         ast_builder::{
-            select::Prebuild, ExprList, ExprNode, FilterNode, GroupByNode, HashJoinNode, JoinNode,
-            LimitNode, OffsetNode, OrderByExprList, OrderByNode, ProjectNode, QueryNode,
-            SelectItemList, TableFactorNode,
+            select::Prebuild, AstBuilderError, ExprList, ExprNode, FilterNode, GroupByNode,
+            HashJoinNode, JoinNode, LimitNode, OffsetNode, OrderByExprList, OrderByNode,
+            ProjectNode, QueryNode, SelectItemList, TableFactorNode,
         },
         result::Result,
     },
@@ -78,6 +78,45 @@ impl<'a> JoinConstraintNode<'a> {
         )
     }
 
+    pub fn right_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Right)
+    }
+
+    pub fn right_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Right,
+        )
+    }
+
+    pub fn full_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Full)
+    }
+
+    pub fn full_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Full,
+        )
+    }
+
+    pub fn cross_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Cross)
+    }
+
+    pub fn cross_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Cross,
+        )
+    }
+
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
         ProjectNode::new(self, select_items)
     }
@@ -125,6 +164,15 @@ impl<'a> Prebuild<Select> for JoinConstraintNode<'a> {
                 JoinOperatorType::Left => {
                     JoinOperator::LeftOuter(JoinConstraint::On(self.expr.try_into()?))
                 }
+                JoinOperatorType::Right => {
+                    JoinOperator::RightOuter(JoinConstraint::On(self.expr.try_into()?))
+                }
+                JoinOperatorType::Full => {
+                    JoinOperator::FullOuter(JoinConstraint::On(self.expr.try_into()?))
+                }
+                JoinOperatorType::Cross => {
+                    return Err(AstBuilderError::CrossJoinWithConstraint.into());
+                }
             },
             join_executor,
         });
@@ -140,7 +188,7 @@ mod tests {
             Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select, SetExpr, Statement,
             TableFactor, TableWithJoins,
         },
-        ast_builder::{col, table, test, Build, SelectItemList},
+        ast_builder::{col, table, test, AstBuilderError, Build, SelectItemList},
     };
 
     #[test]
@@ -181,6 +229,33 @@ mod tests {
         let expected = "SELECT * FROM Foo LEFT OUTER JOIN Bar b ON Foo.id = b.id";
         test(actual, expected);
 
+        // join node -> join constraint node -> build
+        let actual = table("Foo")
+            .select()
+            .right_join("Bar")
+            .on("Foo.id = Bar.id")
+            .build();
+        let expected = "SELECT * FROM Foo RIGHT OUTER JOIN Bar ON Foo.id = Bar.id";
+        test(actual, expected);
+
+        // join node -> join constraint node -> build
+        let actual = table("Foo")
+            .select()
+            .full_join_as("Bar", "b")
+            .on("Foo.id = b.id")
+            .build();
+        let expected = "SELECT * FROM Foo FULL OUTER JOIN Bar b ON Foo.id = b.id";
+        test(actual, expected);
+
+        // cross join node -> join constraint node -> build
+        let actual = table("Foo")
+            .select()
+            .cross_join("Bar")
+            .on("Foo.id = Bar.id")
+            .build();
+        let expected = Err(AstBuilderError::CrossJoinWithConstraint.into());
+        assert_eq!(actual, expected, "cross join with constraint");
+
         // hash join node -> join constraint node -> build
         let actual = table("Player")
             .select()
@@ -205,6 +280,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -217,9 +293,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -10,6 +10,9 @@ use crate::ast::{JoinConstraint, JoinExecutor, JoinOperator, Select, TableFactor
 pub enum JoinOperatorType {
     Inner,
     Left,
+    Right,
+    Full,
+    Cross,
 }
 
 impl From<JoinOperatorType> for JoinOperator {
@@ -17,6 +20,9 @@ impl From<JoinOperatorType> for JoinOperator {
         match join_operator_type {
             JoinOperatorType::Inner => JoinOperator::Inner(JoinConstraint::None),
             JoinOperatorType::Left => JoinOperator::LeftOuter(JoinConstraint::None),
+            JoinOperatorType::Right => JoinOperator::RightOuter(JoinConstraint::None),
+            JoinOperatorType::Full => JoinOperator::FullOuter(JoinConstraint::None),
+            JoinOperatorType::Cross => JoinOperator::CrossJoin,
         }
     }
 }
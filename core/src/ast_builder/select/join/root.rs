@@ -119,6 +119,45 @@ impl<'a> JoinNode<'a> {
         )
     }
 
+    pub fn right_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Right)
+    }
+
+    pub fn right_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Right,
+        )
+    }
+
+    pub fn full_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Full)
+    }
+
+    pub fn full_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Full,
+        )
+    }
+
+    pub fn cross_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Cross)
+    }
+
+    pub fn cross_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Cross,
+        )
+    }
+
     pub fn hash_executor<T: Into<ExprNode<'a>>, U: Into<ExprNode<'a>>>(
         self,
         key_expr: T,
@@ -363,6 +402,78 @@ mod tests {
         test(actual, expected);
     }
 
+    #[test]
+    fn right_join() {
+        // select node -> right join node -> join constraint node
+        let actual = table("player")
+            .select()
+            .right_join("item")
+            .on("player.id = item.id")
+            .project(vec!["player.id", "item.id"])
+            .build();
+        let expected = "
+            SELECT player.id, item.id
+            FROM player
+            RIGHT JOIN item
+            ON player.id = item.id
+        ";
+        test(actual, expected);
+
+        // select node -> left join node -> join constraint node -> right join node
+        let actual = table("Item")
+            .select()
+            .left_join("Player")
+            .on("Player.id = Item.player_id")
+            .right_join_as("Player", "p1")
+            .on("p1.id = Item.player_id")
+            .build();
+        let expected = "
+            SELECT * FROM Item
+            LEFT JOIN Player ON Player.id = Item.player_id
+            RIGHT JOIN Player p1 ON p1.id = Item.player_id
+        ";
+        test(actual, expected);
+    }
+
+    #[test]
+    fn full_join() {
+        // select node -> full join node -> join constraint node
+        let actual = table("player")
+            .select()
+            .full_join("item")
+            .on("player.id = item.id")
+            .build();
+        let expected = "SELECT * FROM player FULL OUTER JOIN item ON player.id = item.id";
+        test(actual, expected);
+
+        // select node -> join node -> full join node -> build
+        let actual = table("Foo")
+            .select()
+            .join("Bar")
+            .full_join_as("Baz", "B")
+            .build();
+        let expected = "SELECT * FROM Foo INNER JOIN Bar FULL JOIN Baz B";
+        test(actual, expected);
+    }
+
+    #[test]
+    fn cross_join() {
+        // select node -> cross join node -> build
+        let actual = table("Foo").select().cross_join("Bar").build();
+        let expected = "SELECT * FROM Foo CROSS JOIN Bar";
+        test(actual, expected);
+
+        // select node -> cross join node -> cross join node -> project node
+        let actual = table("Foo")
+            .select()
+            .cross_join_as("Bar", "B")
+            .cross_join_as("Baz", "Z")
+            .project(vec!["Foo.id", "B.id", "Z.id"])
+            .build();
+        let expected = "SELECT Foo.id, B.id, Z.id FROM Foo CROSS JOIN Bar B CROSS JOIN Baz Z";
+        test(actual, expected);
+    }
+
     #[test]
     fn join_join() {
         // join - join
@@ -587,6 +698,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -599,9 +711,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
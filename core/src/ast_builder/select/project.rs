@@ -1,11 +1,11 @@
 use {
     super::Prebuild,
     crate::{
-        ast::Select,
+        ast::{Distinct, Select},
         ast_builder::{
-            ExprNode, FilterNode, GroupByNode, HashJoinNode, HavingNode, JoinConstraintNode,
-            JoinNode, LimitNode, OffsetNode, OrderByExprList, OrderByNode, QueryNode,
-            SelectItemList, SelectNode, TableFactorNode,
+            ExprList, ExprNode, FilterNode, GroupByNode, HashJoinNode, HavingNode,
+            JoinConstraintNode, JoinNode, LimitNode, OffsetNode, OrderByExprList, OrderByNode,
+            QueryNode, SelectItemList, SelectNode, TableFactorNode,
         },
         result::Result,
     },
@@ -78,10 +78,17 @@ impl<'a> From<FilterNode<'a>> for PrevNode<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+enum DistinctNode<'a> {
+    Distinct,
+    On(ExprList<'a>),
+}
+
 #[derive(Clone, Debug)]
 pub struct ProjectNode<'a> {
     prev_node: PrevNode<'a>,
     select_items_list: Vec<SelectItemList<'a>>,
+    distinct: Option<DistinctNode<'a>>,
 }
 
 impl<'a> ProjectNode<'a> {
@@ -92,9 +99,22 @@ impl<'a> ProjectNode<'a> {
         Self {
             prev_node: prev_node.into(),
             select_items_list: vec![select_items.into()],
+            distinct: None,
         }
     }
 
+    pub fn distinct(mut self) -> Self {
+        self.distinct = Some(DistinctNode::Distinct);
+
+        self
+    }
+
+    pub fn distinct_on<T: Into<ExprList<'a>>>(mut self, expr_list: T) -> Self {
+        self.distinct = Some(DistinctNode::On(expr_list.into()));
+
+        self
+    }
+
     pub fn project<T: Into<SelectItemList<'a>>>(mut self, select_items: T) -> Self {
         self.select_items_list.push(select_items.into());
 
@@ -129,6 +149,11 @@ impl<'a> Prebuild<Select> for ProjectNode<'a> {
             .into_iter()
             .flatten()
             .collect::<Vec<_>>();
+        query.distinct = match self.distinct {
+            Some(DistinctNode::Distinct) => Some(Distinct::Distinct),
+            Some(DistinctNode::On(expr_list)) => Some(Distinct::On(expr_list.try_into()?)),
+            None => None,
+        };
 
         Ok(query)
     }
@@ -182,6 +207,25 @@ mod tests {
             .build();
         let expected = "SELECT 1 + 1 as col1, col2 FROM Aliased";
         test(actual, expected);
+
+        // select node -> project node -> distinct -> build
+        let actual = table("Foo").select().project("city").distinct().build();
+        let expected = "SELECT DISTINCT city FROM Foo";
+        test(actual, expected);
+
+        // select node -> project node -> distinct on -> build
+        let actual = table("Foo")
+            .select()
+            .project("city, name")
+            .distinct_on("city")
+            .order_by("city, name DESC")
+            .build();
+        let expected = "
+            SELECT DISTINCT ON (city) city, name
+            FROM Foo
+            ORDER BY city, name DESC
+        ";
+        test(actual, expected);
     }
 
     #[test]
@@ -245,6 +289,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("Player.name, PlayerItem.name")
                     .try_into()
                     .unwrap(),
@@ -259,9 +304,11 @@ mod tests {
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
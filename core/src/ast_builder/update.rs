@@ -1,7 +1,7 @@
 use {
-    super::{AssignmentNode, Build, ExprNode},
+    super::{AssignmentNode, Build, ExprNode, SelectItemList},
     crate::{
-        ast::{Assignment, Expr, Statement},
+        ast::{Assignment, Expr, JoinExecutor, Statement},
         result::Result,
     },
 };
@@ -11,6 +11,7 @@ pub struct UpdateNode<'a> {
     table_name: String,
     assignments: Vec<AssignmentNode<'a>>,
     selection: Option<ExprNode<'a>>,
+    returning: Option<SelectItemList<'a>>,
 }
 
 impl<'a> UpdateNode<'a> {
@@ -19,6 +20,7 @@ impl<'a> UpdateNode<'a> {
             table_name,
             assignments: Vec::new(),
             selection: None,
+            returning: None,
         }
     }
 
@@ -32,6 +34,11 @@ impl<'a> UpdateNode<'a> {
             .push(AssignmentNode::Expr(id.to_owned(), value.into()));
         self
     }
+
+    pub fn returning<T: Into<SelectItemList<'a>>>(mut self, select_items: T) -> Self {
+        self.returning = Some(select_items.into());
+        self
+    }
 }
 
 impl<'a> Build for UpdateNode<'a> {
@@ -43,10 +50,16 @@ impl<'a> Build for UpdateNode<'a> {
             .into_iter()
             .map(Assignment::try_from)
             .collect::<Result<Vec<_>>>()?;
+        let returning = self
+            .returning
+            .map_or_else(|| Ok(vec![]), TryInto::try_into)?;
         Ok(Statement::Update {
             table_name,
             assignments,
+            from: None,
+            join_executor: JoinExecutor::NestedLoop,
             selection,
+            returning,
         })
     }
 }
@@ -89,5 +102,14 @@ mod tests {
             .build();
         let expected = "UPDATE Foo SET id = 2, head_item = (SELECT id FROM head_item WHERE level = 3 LIMIT 1) WHERE body_item = 1";
         test(actual, expected);
+
+        let actual = table("Foo")
+            .update()
+            .set("id", "2")
+            .filter("name = 'a'")
+            .returning("*")
+            .build();
+        let expected = "UPDATE Foo SET id = 2 WHERE name = 'a' RETURNING *";
+        test(actual, expected);
     }
 }
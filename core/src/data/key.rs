@@ -50,6 +50,8 @@ pub enum Key {
     Interval(Interval),
     Uuid(u128),
     Inet(IpAddr),
+    /// Key of a composite primary key or unique constraint
+    Composite(Vec<Key>),
     None,
 }
 
@@ -78,6 +80,7 @@ impl Ord for Key {
             (Key::Interval(l), Key::Interval(r)) => l.cmp(r),
             (Key::Uuid(l), Key::Uuid(r)) => l.cmp(r),
             (Key::Inet(l), Key::Inet(r)) => l.cmp(r),
+            (Key::Composite(l), Key::Composite(r)) => l.cmp(r),
             (Key::None, Key::None) => Ordering::Equal,
             (Key::None, _) => Ordering::Greater,
             (_, Key::None) => Ordering::Less,
@@ -103,7 +106,8 @@ impl Ord for Key {
             | (Key::Time(_), _)
             | (Key::Interval(_), _)
             | (Key::Uuid(_), _)
-            | (Key::Inet(_), _) => Ordering::Greater,
+            | (Key::Inet(_), _)
+            | (Key::Composite(_), _) => Ordering::Greater,
         }
     }
 }
@@ -130,6 +134,7 @@ impl PartialOrd for Key {
             (Key::Time(l), Key::Time(r)) => Some(l.cmp(r)),
             (Key::Interval(l), Key::Interval(r)) => l.partial_cmp(r),
             (Key::Uuid(l), Key::Uuid(r)) => Some(l.cmp(r)),
+            (Key::Composite(l), Key::Composite(r)) => Some(l.cmp(r)),
             _ => None,
         };
         Some(self.cmp(other))
@@ -206,6 +211,7 @@ impl From<Key> for Value {
             Key::Time(v) => Value::Time(v),
             Key::Interval(v) => Value::Interval(v),
             Key::Uuid(v) => Value::Uuid(v),
+            Key::Composite(keys) => Value::List(keys.into_iter().map(Value::from).collect()),
             Key::None => Value::Null,
         }
     }
@@ -215,6 +221,29 @@ const VALUE: u8 = 0;
 const NONE: u8 = 1;
 
 impl Key {
+    /// Key of a row on its primary key or unique columns, a [`Key::Composite`] when there
+    /// are more than one of them
+    pub fn from_values<'a>(values: impl IntoIterator<Item = &'a Value>) -> Result<Self> {
+        let mut keys = values
+            .into_iter()
+            .map(Key::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        match keys.len() {
+            1 => Ok(keys.remove(0)),
+            _ => Ok(Key::Composite(keys)),
+        }
+    }
+
+    /// Whether the key is or has a `NULL`, which never conflicts with other keys
+    pub fn has_null(&self) -> bool {
+        match self {
+            Key::None => true,
+            Key::Composite(keys) => keys.iter().any(Key::has_null),
+            _ => false,
+        }
+    }
+
     /// Key to Big-Endian for comparison purpose
     pub fn to_cmp_be_bytes(&self) -> Result<Vec<u8>> {
         Ok(match self {
@@ -374,6 +403,22 @@ impl Key {
                 .chain(v.to_be_bytes().iter())
                 .copied()
                 .collect::<Vec<_>>(),
+            Key::Composite(keys) => {
+                // zero bytes are escaped and every component is terminated by `[0, 0]`, so
+                // that the bytes of shorter components never compare past their terminator
+                let mut bytes = Vec::new();
+                for key in keys {
+                    for byte in key.to_cmp_be_bytes()? {
+                        match byte {
+                            0 => bytes.extend([0, u8::MAX]),
+                            _ => bytes.push(byte),
+                        }
+                    }
+                    bytes.extend([0, 0]);
+                }
+
+                bytes
+            }
             Key::None => vec![NONE],
         })
     }
@@ -475,6 +520,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_values() {
+        let values = [Value::I64(1), Value::Str("a".to_owned()), Value::Null];
+
+        assert_eq!(Key::from_values(&values[..1]), Ok(Key::I64(1)));
+        assert_eq!(
+            Key::from_values(&values[..2]),
+            Ok(Key::Composite(vec![Key::I64(1), Key::Str("a".to_owned())]))
+        );
+
+        let key = Key::from_values(&values).unwrap();
+        assert!(key.has_null());
+        assert!(!Key::I64(1).has_null());
+    }
+
     #[test]
     fn cmp() {
         use {
@@ -565,6 +625,11 @@ mod tests {
 
         assert_eq!(Key::None.partial_cmp(&Key::None), Some(Ordering::Equal));
         assert!(Key::None > Key::I8(100));
+
+        let composite = |l, r| Key::Composite(vec![Key::I64(l), Key::Str(r)]);
+        assert!(composite(1, "b".to_owned()) > composite(1, "a".to_owned()));
+        assert!(composite(2, "a".to_owned()) > composite(1, "b".to_owned()));
+        assert!(Key::Composite(Vec::new()) < Key::None);
     }
 
     #[test]
@@ -827,6 +892,24 @@ mod tests {
         assert_eq!(cmp(&n2, &n1), Ordering::Greater);
         assert_eq!(cmp(&n1, &null), Ordering::Less);
 
+        let str = |v: &str| Str(v.to_owned());
+        let n1 = Composite(vec![str("a"), str("z")]).to_cmp_be_bytes();
+        let n2 = Composite(vec![str("ab"), str("a")]).to_cmp_be_bytes();
+        let n3 = Composite(vec![str("ab"), I64(0)]).to_cmp_be_bytes();
+        let n4 = Composite(vec![str("ab"), I64(1)]).to_cmp_be_bytes();
+        let n5 = Composite(vec![str("ab"), None]).to_cmp_be_bytes();
+        let n6 = Composite(vec![None, I64(0)]).to_cmp_be_bytes();
+
+        assert_eq!(cmp(&n1, &n1), Ordering::Equal);
+        assert_eq!(cmp(&n1, &n2), Ordering::Less);
+        assert_eq!(cmp(&n3, &n4), Ordering::Less);
+        assert_eq!(cmp(&n4, &n5), Ordering::Less);
+        assert_eq!(cmp(&n5, &n6), Ordering::Less);
+
+        let prefix = Composite(vec![str("ab")]).to_cmp_be_bytes().unwrap();
+        assert!(n3.unwrap().starts_with(&prefix));
+        assert!(!n1.unwrap().starts_with(&prefix));
+
         assert_eq!(
             F64(12.34.into()).to_cmp_be_bytes(),
             Err(KeyError::FloatToCmpBigEndianNotSupported.into())
@@ -895,6 +978,10 @@ mod tests {
                     .as_u128()
             )
         );
+        assert_eq!(
+            Value::from(Key::Composite(vec![Key::I64(1), Key::Bool(true)])),
+            Value::List(vec![Value::I64(1), Value::Bool(true)])
+        );
         matches!(Value::from(Key::None), Value::Null);
     }
 }
@@ -4,7 +4,7 @@ use {
         ast::AstLiteral,
         result::{Error, Result},
     },
-    bigdecimal::BigDecimal,
+    bigdecimal::{BigDecimal, Zero},
     serde::Serialize,
     std::{borrow::Cow, cmp::Ordering, convert::TryFrom, fmt::Debug},
     thiserror::Error,
@@ -157,7 +157,7 @@ impl<'a> Literal<'a> {
     pub fn divide(&self, other: &Literal<'a>) -> Result<Literal<'static>> {
         match (self, other) {
             (Number(l), Number(r)) => {
-                if *r.as_ref() == 0.into() {
+                if r.is_zero() {
                     Err(LiteralError::DivisorShouldNotBeZero.into())
                 } else {
                     Ok(Number(Cow::Owned(l.as_ref() / r.as_ref())))
@@ -175,7 +175,7 @@ impl<'a> Literal<'a> {
     pub fn modulo(&self, other: &Literal<'a>) -> Result<Literal<'static>> {
         match (self, other) {
             (Number(l), Number(r)) => {
-                if *r.as_ref() == 0.into() {
+                if r.is_zero() {
                     Err(LiteralError::DivisorShouldNotBeZero.into())
                 } else {
                     Ok(Number(Cow::Owned(l.as_ref() % r.as_ref())))
@@ -1,6 +1,9 @@
 use {
     crate::{
-        ast::{ColumnDef, Expr, OrderByExpr, Statement, ToSql},
+        ast::{
+            Check, ColumnDef, ColumnUniqueOption, Expr, ForeignKey, OrderByExpr, Query, Statement,
+            ToSql, UniqueConstraint,
+        },
         prelude::{parse, translate},
         result::Result,
     },
@@ -22,7 +25,8 @@ pub enum SchemaIndexOrd {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SchemaIndex {
     pub name: String,
-    pub expr: Expr,
+    /// Indexed expressions, the leading one first
+    pub exprs: Vec<Expr>,
     pub order: SchemaIndexOrd,
     pub created: NaiveDateTime,
 }
@@ -33,6 +37,12 @@ pub struct Schema {
     pub column_defs: Option<Vec<ColumnDef>>,
     pub indexes: Vec<SchemaIndex>,
     pub engine: Option<String>,
+    /// Defining query of a materialized view
+    pub view_query: Option<Query>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub checks: Vec<Check>,
+    /// Composite `PRIMARY KEY` and `UNIQUE` constraints
+    pub unique_constraints: Vec<UniqueConstraint>,
 }
 
 impl Schema {
@@ -42,22 +52,43 @@ impl Schema {
             column_defs,
             indexes,
             engine,
-            ..
+            view_query,
+            foreign_keys,
+            checks,
+            unique_constraints,
         } = self;
 
-        let create_table = Statement::CreateTable {
-            if_not_exists: false,
-            name: table_name.to_owned(),
-            columns: column_defs.to_owned(),
-            engine: engine.to_owned(),
-            source: None,
+        let create_table = match view_query {
+            Some(query) => Statement::CreateMaterializedView {
+                name: table_name.to_owned(),
+                columns: column_defs
+                    .iter()
+                    .flatten()
+                    .map(|column_def| column_def.name.to_owned())
+                    .collect(),
+                query: Box::new(query.to_owned()),
+            },
+            None => Statement::CreateTable {
+                if_not_exists: false,
+                name: table_name.to_owned(),
+                columns: column_defs.to_owned(),
+                engine: engine.to_owned(),
+                source: None,
+                foreign_keys: foreign_keys.to_owned(),
+                checks: checks.to_owned(),
+                unique_constraints: unique_constraints.to_owned(),
+            },
         }
         .to_sql();
 
-        let create_indexes = indexes.iter().map(|SchemaIndex { name, expr, .. }| {
-            let expr = expr.to_sql();
+        let create_indexes = indexes.iter().map(|SchemaIndex { name, exprs, .. }| {
+            let exprs = exprs
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
 
-            format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({expr});"#)
+            format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({exprs});"#)
         });
 
         iter::once(create_table)
@@ -76,18 +107,20 @@ impl Schema {
             .map(|create_index| {
                 let create_index = translate(create_index)?;
                 match create_index {
-                    Statement::CreateIndex {
-                        name,
-                        column: OrderByExpr { expr, asc },
-                        ..
-                    } => {
-                        let order = asc
+                    Statement::CreateIndex { name, columns, .. } => {
+                        let order = columns
+                            .first()
+                            .and_then(|OrderByExpr { asc, .. }| *asc)
                             .and_then(|bool| bool.then_some(SchemaIndexOrd::Asc))
                             .unwrap_or(SchemaIndexOrd::Both);
+                        let exprs = columns
+                            .into_iter()
+                            .map(|OrderByExpr { expr, .. }| expr)
+                            .collect();
 
                         let index = SchemaIndex {
                             name,
-                            expr,
+                            exprs,
                             order,
                             created,
                         };
@@ -107,16 +140,66 @@ impl Schema {
                 name,
                 columns,
                 engine,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } => Ok(Schema {
                 table_name: name,
                 column_defs: columns,
                 indexes,
                 engine,
+                view_query: None,
+                foreign_keys,
+                checks,
+                unique_constraints,
+            }),
+            Statement::CreateMaterializedView { name, query, .. } => Ok(Schema {
+                table_name: name,
+                column_defs: None,
+                indexes,
+                engine: None,
+                view_query: Some(*query),
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                unique_constraints: Vec::new(),
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
         }
     }
+
+    /// Column indexes of the primary key, a composite `PRIMARY KEY` has more than one.
+    pub fn primary_key_indexes(&self) -> Option<Vec<usize>> {
+        let column_defs = self.column_defs.as_deref()?;
+
+        primary_key_indexes(column_defs, &self.unique_constraints)
+    }
+}
+
+/// Column indexes of the primary key which is either declared on a column or as a
+/// composite `PRIMARY KEY` constraint.
+pub fn primary_key_indexes(
+    column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
+) -> Option<Vec<usize>> {
+    let column_index = |name: &String| {
+        column_defs
+            .iter()
+            .position(|column_def| &column_def.name == name)
+    };
+
+    match unique_constraints
+        .iter()
+        .find(|constraint| constraint.is_primary)
+    {
+        Some(UniqueConstraint { columns, .. }) => columns.iter().map(column_index).collect(),
+        None => column_defs
+            .iter()
+            .position(|column_def| {
+                column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+            })
+            .map(|i| vec![i]),
+    }
 }
 
 #[derive(ThisError, Debug, PartialEq, Serialize)]
@@ -130,11 +213,15 @@ mod tests {
     use {
         super::SchemaParseError,
         crate::{
-            ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr},
+            ast::{
+                AstLiteral, BinaryOperator, Check, ColumnDef, ColumnUniqueOption, Expr, ForeignKey,
+                ReferentialAction, Statement, UniqueConstraint,
+            },
             chrono::Utc,
             data::{Schema, SchemaIndex, SchemaIndexOrd},
-            prelude::DataType,
+            prelude::{parse, translate, DataType},
         },
+        bigdecimal::BigDecimal,
     };
 
     fn assert_schema(actual: Schema, expected: Schema) {
@@ -143,6 +230,9 @@ mod tests {
             column_defs,
             indexes,
             engine,
+            foreign_keys,
+            checks,
+            unique_constraints,
             ..
         } = actual;
 
@@ -151,12 +241,18 @@ mod tests {
             column_defs: column_defs_e,
             indexes: indexes_e,
             engine: engine_e,
+            foreign_keys: foreign_keys_e,
+            checks: checks_e,
+            unique_constraints: unique_constraints_e,
             ..
         } = expected;
 
         assert_eq!(table_name, table_name_e);
         assert_eq!(column_defs, column_defs_e);
         assert_eq!(engine, engine_e);
+        assert_eq!(foreign_keys, foreign_keys_e);
+        assert_eq!(checks, checks_e);
+        assert_eq!(unique_constraints, unique_constraints_e);
         indexes
             .into_iter()
             .zip(indexes_e)
@@ -165,17 +261,17 @@ mod tests {
 
     fn assert_index(actual: SchemaIndex, expected: SchemaIndex) {
         let SchemaIndex {
-            name, expr, order, ..
+            name, exprs, order, ..
         } = actual;
         let SchemaIndex {
             name: name_e,
-            expr: expr_e,
+            exprs: exprs_e,
             order: order_e,
             ..
         } = expected;
 
         assert_eq!(name, name_e);
-        assert_eq!(expr, expr_e);
+        assert_eq!(exprs, exprs_e);
         assert_eq!(order, order_e);
     }
 
@@ -190,6 +286,7 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    identity: None,
                 },
                 ColumnDef {
                     name: "name".to_owned(),
@@ -197,10 +294,15 @@ mod tests {
                     nullable: true,
                     default: Some(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
                     unique: None,
+                    identity: None,
                 },
             ]),
             indexes: Vec::new(),
             engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'glue');"#;
@@ -214,6 +316,10 @@ mod tests {
             column_defs: None,
             indexes: Vec::new(),
             engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
         };
         let ddl = r#"CREATE TABLE "Test";"#;
         assert_eq!(schema.to_ddl(), ddl);
@@ -232,9 +338,14 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                identity: None,
             }]),
             indexes: Vec::new(),
             engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL PRIMARY KEY);"#;
@@ -244,6 +355,116 @@ mod tests {
         assert_schema(actual, schema);
     }
 
+    #[test]
+    fn table_with_foreign_key() {
+        let schema = Schema {
+            table_name: "Child".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "parent_id".to_owned(),
+                data_type: DataType::Int,
+                nullable: true,
+                default: None,
+                unique: None,
+                identity: None,
+            }]),
+            indexes: Vec::new(),
+            engine: None,
+            view_query: None,
+            foreign_keys: vec![ForeignKey {
+                name: "fk_parent".to_owned(),
+                columns: vec!["parent_id".to_owned()],
+                referenced_table_name: "Parent".to_owned(),
+                referenced_columns: vec!["id".to_owned()],
+                on_delete: ReferentialAction::Cascade,
+                on_update: ReferentialAction::NoAction,
+            }],
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
+        };
+
+        let ddl = r#"CREATE TABLE "Child" ("parent_id" INT NULL, CONSTRAINT "fk_parent" FOREIGN KEY ("parent_id") REFERENCES "Parent" ("id") ON DELETE CASCADE);"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn table_with_check() {
+        let schema = Schema {
+            table_name: "Item".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "price".to_owned(),
+                data_type: DataType::Int,
+                nullable: true,
+                default: None,
+                unique: None,
+                identity: None,
+            }]),
+            indexes: Vec::new(),
+            engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: vec![Check {
+                name: "Item_price_check".to_owned(),
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("price".to_owned())),
+                    op: BinaryOperator::Gt,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(BigDecimal::from(0)))),
+                },
+            }],
+            unique_constraints: Vec::new(),
+        };
+
+        let ddl = r#"CREATE TABLE "Item" ("price" INT NULL, CONSTRAINT "Item_price_check" CHECK ("price" > 0));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn table_with_unique_constraint() {
+        let column_def = |name: &str, nullable| ColumnDef {
+            name: name.to_owned(),
+            data_type: DataType::Int,
+            nullable,
+            default: None,
+            unique: None,
+            identity: None,
+        };
+        let schema = Schema {
+            table_name: "Orders".to_owned(),
+            column_defs: Some(vec![
+                column_def("tenant_id", false),
+                column_def("id", false),
+                column_def("code", true),
+            ]),
+            indexes: Vec::new(),
+            engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: vec![
+                UniqueConstraint {
+                    columns: vec!["tenant_id".to_owned(), "id".to_owned()],
+                    is_primary: true,
+                },
+                UniqueConstraint {
+                    columns: vec!["tenant_id".to_owned(), "code".to_owned()],
+                    is_primary: false,
+                },
+            ],
+        };
+
+        let ddl = r#"CREATE TABLE "Orders" ("tenant_id" INT NOT NULL, "id" INT NOT NULL, "code" INT NULL, PRIMARY KEY ("tenant_id", "id"), UNIQUE ("tenant_id", "code"));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+        assert_eq!(schema.primary_key_indexes(), Some(vec![0, 1]));
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
     #[test]
     fn invalid_ddl() {
         // Only Statement::CreateTable is supported
@@ -263,6 +484,7 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    identity: None,
                 },
                 ColumnDef {
                     name: "name".to_owned(),
@@ -270,27 +492,42 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    identity: None,
                 },
             ]),
             indexes: vec![
                 SchemaIndex {
                     name: "User_id".to_owned(),
-                    expr: Expr::Identifier("id".to_owned()),
+                    exprs: vec![Expr::Identifier("id".to_owned())],
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
                 },
                 SchemaIndex {
                     name: "User_name".to_owned(),
-                    expr: Expr::Identifier("name".to_owned()),
+                    exprs: vec![Expr::Identifier("name".to_owned())],
+                    order: SchemaIndexOrd::Both,
+                    created: Utc::now().naive_utc(),
+                },
+                SchemaIndex {
+                    name: "User_name_id".to_owned(),
+                    exprs: vec![
+                        Expr::Identifier("name".to_owned()),
+                        Expr::Identifier("id".to_owned()),
+                    ],
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
                 },
             ],
             engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
 CREATE INDEX "User_id" ON "User" ("id");
-CREATE INDEX "User_name" ON "User" ("name");"#;
+CREATE INDEX "User_name" ON "User" ("name");
+CREATE INDEX "User_name_id" ON "User" ("name", "id");"#;
         assert_eq!(schema.to_ddl(), ddl);
 
         let actual = Schema::from_ddl(ddl).unwrap();
@@ -313,6 +550,7 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    identity: None,
                 },
                 ColumnDef {
                     name: ";".to_owned(),
@@ -320,15 +558,20 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    identity: None,
                 },
             ]),
             indexes: vec![SchemaIndex {
                 name: ".".to_owned(),
-                expr: Expr::Identifier(";".to_owned()),
+                exprs: vec![Expr::Identifier(";".to_owned())],
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
             }],
             engine: None,
+            view_query: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
 CREATE INDEX "." ON "1" (";");"#;
@@ -337,4 +580,37 @@ CREATE INDEX "." ON "1" (";");"#;
         let actual = Schema::from_ddl(ddl).unwrap();
         assert_schema(actual, schema);
     }
+
+    #[test]
+    fn materialized_view() {
+        let query = match translate(&parse("SELECT id FROM User").unwrap()[0]).unwrap() {
+            Statement::Query(query) => query,
+            _ => unreachable!(),
+        };
+        let schema = Schema {
+            table_name: "UserIds".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "id".to_owned(),
+                data_type: DataType::Int,
+                nullable: true,
+                default: None,
+                unique: None,
+                identity: None,
+            }]),
+            indexes: Vec::new(),
+            engine: None,
+            view_query: Some(query.clone()),
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            unique_constraints: Vec::new(),
+        };
+
+        let ddl =
+            r#"CREATE MATERIALIZED VIEW "UserIds" ("id") AS SELECT "id" AS "id" FROM "User";"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_eq!(actual.table_name, "UserIds");
+        assert_eq!(actual.view_query, Some(query));
+    }
 }
@@ -0,0 +1,267 @@
+use {
+    crate::{
+        ast::{SequenceOptions, Statement, ToSql},
+        result::Result,
+    },
+    serde::{Deserialize, Serialize},
+    std::fmt::Debug,
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum SequenceError {
+    #[error("sequence already exists: {0}")]
+    SequenceAlreadyExists(String),
+
+    #[error("sequence not found: {0}")]
+    SequenceNotFound(String),
+
+    #[error("cannot drop sequence {0} because the identity column of table {1} uses it")]
+    SequenceOwnedByTable(String, String),
+
+    #[error("INCREMENT must not be zero")]
+    ZeroIncrement,
+
+    #[error("MINVALUE ({0}) must be less than MAXVALUE ({1})")]
+    InvalidRange(i64, i64),
+
+    #[error("START value ({0}) cannot be outside of MINVALUE ({1}) and MAXVALUE ({2})")]
+    StartOutOfRange(i64, i64, i64),
+
+    #[error("reached maximum value of sequence: {0}")]
+    ReachedMaxValue(String),
+
+    #[error("reached minimum value of sequence: {0}")]
+    ReachedMinValue(String),
+
+    #[error("current value of sequence is not yet defined: {0}")]
+    CurrentValueNotDefined(String),
+
+    #[error("sequence name must be a string: {0}")]
+    SequenceNameNotString(String),
+
+    #[error("NEXTVAL is only supported in INSERT VALUES and SELECT without FROM")]
+    NextValNotSupported,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sequence {
+    pub name: String,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub start: i64,
+    pub cycle: bool,
+    /// Value returned by the latest `NEXTVAL`, `None` until it is called
+    pub last_value: Option<i64>,
+    /// Table whose identity column generates its values from this sequence
+    pub owned_by: Option<String>,
+}
+
+impl Sequence {
+    pub fn new(name: &str, options: &SequenceOptions, owned_by: Option<&str>) -> Result<Self> {
+        let SequenceOptions {
+            increment,
+            min_value,
+            max_value,
+            start,
+            cycle,
+        } = *options;
+
+        let increment = increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(SequenceError::ZeroIncrement.into());
+        }
+
+        let ascending = increment > 0;
+        let min_value = min_value.unwrap_or(if ascending { 1 } else { i64::MIN });
+        let max_value = max_value.unwrap_or(if ascending { i64::MAX } else { -1 });
+        if min_value >= max_value {
+            return Err(SequenceError::InvalidRange(min_value, max_value).into());
+        }
+
+        let start = start.unwrap_or(if ascending { min_value } else { max_value });
+        if !(min_value..=max_value).contains(&start) {
+            return Err(SequenceError::StartOutOfRange(start, min_value, max_value).into());
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            increment,
+            min_value,
+            max_value,
+            start,
+            cycle,
+            last_value: None,
+            owned_by: owned_by.map(ToOwned::to_owned),
+        })
+    }
+
+    /// Name of the sequence generating values of an identity column
+    pub fn identity_name(table_name: &str, column_name: &str) -> String {
+        format!("{table_name}_{column_name}_seq")
+    }
+
+    /// Advances the sequence and returns the new value.
+    pub fn next_value(&mut self) -> Result<i64> {
+        let value = match self.last_value {
+            None => self.start,
+            Some(last_value) => match last_value.checked_add(self.increment) {
+                Some(value) if (self.min_value..=self.max_value).contains(&value) => value,
+                _ if !self.cycle && self.increment > 0 => {
+                    return Err(SequenceError::ReachedMaxValue(self.name.to_owned()).into());
+                }
+                _ if !self.cycle => {
+                    return Err(SequenceError::ReachedMinValue(self.name.to_owned()).into());
+                }
+                _ if self.increment > 0 => self.min_value,
+                _ => self.max_value,
+            },
+        };
+
+        self.last_value = Some(value);
+
+        Ok(value)
+    }
+
+    pub fn current_value(&self) -> Result<i64> {
+        self.last_value
+            .ok_or_else(|| SequenceError::CurrentValueNotDefined(self.name.to_owned()).into())
+    }
+
+    pub fn to_ddl(&self) -> String {
+        Statement::CreateSequence {
+            if_not_exists: false,
+            name: self.name.to_owned(),
+            options: SequenceOptions {
+                increment: Some(self.increment),
+                min_value: Some(self.min_value),
+                max_value: Some(self.max_value),
+                start: Some(self.start),
+                cycle: self.cycle,
+            },
+        }
+        .to_sql()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Sequence, SequenceError},
+        crate::ast::SequenceOptions,
+    };
+
+    #[test]
+    fn new() {
+        let sequence = Sequence::new("Foo", &SequenceOptions::default(), None).unwrap();
+        assert_eq!((sequence.min_value, sequence.max_value), (1, i64::MAX));
+        assert_eq!(sequence.start, 1);
+
+        let options = SequenceOptions {
+            increment: Some(-2),
+            ..SequenceOptions::default()
+        };
+        let sequence = Sequence::new("Foo", &options, Some("Bar")).unwrap();
+        assert_eq!((sequence.min_value, sequence.max_value), (i64::MIN, -1));
+        assert_eq!(sequence.start, -1);
+        assert_eq!(sequence.owned_by.as_deref(), Some("Bar"));
+
+        let options = SequenceOptions {
+            increment: Some(0),
+            ..SequenceOptions::default()
+        };
+        assert_eq!(
+            Sequence::new("Foo", &options, None),
+            Err(SequenceError::ZeroIncrement.into())
+        );
+
+        let options = SequenceOptions {
+            min_value: Some(5),
+            max_value: Some(5),
+            ..SequenceOptions::default()
+        };
+        assert_eq!(
+            Sequence::new("Foo", &options, None),
+            Err(SequenceError::InvalidRange(5, 5).into())
+        );
+
+        let options = SequenceOptions {
+            min_value: Some(5),
+            start: Some(1),
+            ..SequenceOptions::default()
+        };
+        assert_eq!(
+            Sequence::new("Foo", &options, None),
+            Err(SequenceError::StartOutOfRange(1, 5, i64::MAX).into())
+        );
+    }
+
+    #[test]
+    fn next_value() {
+        let options = SequenceOptions {
+            increment: Some(2),
+            max_value: Some(5),
+            ..SequenceOptions::default()
+        };
+        let mut sequence = Sequence::new("Foo", &options, None).unwrap();
+        assert_eq!(
+            sequence.current_value(),
+            Err(SequenceError::CurrentValueNotDefined("Foo".to_owned()).into())
+        );
+        assert_eq!(sequence.next_value(), Ok(1));
+        assert_eq!(sequence.next_value(), Ok(3));
+        assert_eq!(sequence.next_value(), Ok(5));
+        assert_eq!(sequence.current_value(), Ok(5));
+        assert_eq!(
+            sequence.next_value(),
+            Err(SequenceError::ReachedMaxValue("Foo".to_owned()).into())
+        );
+
+        sequence.cycle = true;
+        assert_eq!(sequence.next_value(), Ok(1));
+
+        let options = SequenceOptions {
+            increment: Some(-1),
+            min_value: Some(-2),
+            start: Some(-1),
+            ..SequenceOptions::default()
+        };
+        let mut sequence = Sequence::new("Foo", &options, None).unwrap();
+        assert_eq!(sequence.next_value(), Ok(-1));
+        assert_eq!(sequence.next_value(), Ok(-2));
+        assert_eq!(
+            sequence.next_value(),
+            Err(SequenceError::ReachedMinValue("Foo".to_owned()).into())
+        );
+
+        sequence.cycle = true;
+        assert_eq!(sequence.next_value(), Ok(-1));
+
+        let options = SequenceOptions {
+            start: Some(i64::MAX),
+            ..SequenceOptions::default()
+        };
+        let mut sequence = Sequence::new("Foo", &options, None).unwrap();
+        assert_eq!(sequence.next_value(), Ok(i64::MAX));
+        assert_eq!(
+            sequence.next_value(),
+            Err(SequenceError::ReachedMaxValue("Foo".to_owned()).into())
+        );
+    }
+
+    #[test]
+    fn to_ddl() {
+        let options = SequenceOptions {
+            start: Some(3),
+            max_value: Some(10),
+            ..SequenceOptions::default()
+        };
+        let sequence = Sequence::new("Foo", &options, None).unwrap();
+
+        assert_eq!(
+            sequence.to_ddl(),
+            r#"CREATE SEQUENCE "Foo" INCREMENT BY 1 MINVALUE 1 MAXVALUE 10 START WITH 3;"#
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::data::Value,
+    serde::{Deserialize, Serialize},
+    std::fmt::Debug,
+};
+
+/// Statistics of a table collected by `ANALYZE`, the planner reads them as estimates
+/// so they are not updated by later writes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableStats {
+    pub table_name: String,
+    pub row_count: usize,
+    /// Columns in the order of the schema, empty for schemaless tables
+    pub columns: Vec<ColumnStats>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub name: String,
+    pub null_count: usize,
+    /// Number of distinct non-NULL values
+    pub distinct_count: usize,
+    /// Smallest non-NULL value, `None` if the column has no comparable values
+    pub min: Option<Value>,
+    /// Largest non-NULL value, `None` if the column has no comparable values
+    pub max: Option<Value>,
+}
+
+impl TableStats {
+    pub fn column(&self, name: &str) -> Option<&ColumnStats> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
@@ -0,0 +1,73 @@
+use {
+    super::SchemaParseError,
+    crate::{
+        ast::{Query, Statement, ToSql},
+        prelude::{parse, translate},
+        result::Result,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    pub view_name: String,
+    pub columns: Vec<String>,
+    pub query: Query,
+}
+
+impl View {
+    pub fn to_ddl(&self) -> String {
+        Statement::CreateView {
+            or_replace: false,
+            name: self.view_name.to_owned(),
+            columns: self.columns.to_owned(),
+            query: Box::new(self.query.to_owned()),
+        }
+        .to_sql()
+    }
+
+    pub fn from_ddl(ddl: &str) -> Result<View> {
+        let create_view = parse(ddl)?
+            .into_iter()
+            .next()
+            .ok_or(SchemaParseError::CannotParseDDL)?;
+
+        match translate(&create_view)? {
+            Statement::CreateView {
+                name,
+                columns,
+                query,
+                ..
+            } => Ok(View {
+                view_name: name,
+                columns,
+                query: *query,
+            }),
+            _ => Err(SchemaParseError::CannotParseDDL.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{SchemaParseError, View};
+
+    #[test]
+    fn view_ddl() {
+        let view =
+            View::from_ddl("CREATE VIEW Named (item_id) AS SELECT id FROM Item WHERE id > 1")
+                .unwrap();
+        assert_eq!(view.view_name, "Named");
+        assert_eq!(view.columns, vec!["item_id".to_owned()]);
+
+        let ddl =
+            r#"CREATE VIEW "Named" ("item_id") AS SELECT "id" AS "id" FROM "Item" WHERE "id" > 1;"#;
+        assert_eq!(view.to_ddl(), ddl);
+        assert_eq!(View::from_ddl(ddl), Ok(view));
+
+        assert_eq!(
+            View::from_ddl(r#"CREATE TABLE "Item" ("id" INT NOT NULL);"#),
+            Err(SchemaParseError::CannotParseDDL.into())
+        );
+    }
+}
@@ -19,7 +19,7 @@ use {
     std::{convert::identity, rc::Rc},
 };
 
-pub use error::AggregateError;
+pub use {error::AggregateError, state::FrameAggregate};
 
 pub struct Aggregator<'a, T: GStore> {
     storage: &'a T,
@@ -172,18 +172,24 @@ impl<'a, T: GStore> Aggregator<'a, T> {
     }
 
     fn check_aggregate(&self) -> bool {
-        if !self.group_by.is_empty() {
-            return true;
-        }
+        is_aggregate(self.fields, self.group_by)
+    }
+}
 
-        self.fields
-            .iter()
-            .map(|field| match field {
-                SelectItem::Expr { expr, .. } => check(expr),
-                _ => false,
-            })
-            .any(identity)
+/// Whether rows are aggregated, either grouped by `GROUP BY` or by aggregate functions in
+/// the projection.
+pub fn is_aggregate(fields: &[SelectItem], group_by: &[Expr]) -> bool {
+    if !group_by.is_empty() {
+        return true;
     }
+
+    fields
+        .iter()
+        .map(|field| match field {
+            SelectItem::Expr { expr, .. } => check(expr),
+            _ => false,
+        })
+        .any(identity)
 }
 
 #[async_recursion(?Send)]
@@ -236,6 +242,14 @@ async fn aggregate<'a, T: GStore>(
                 .await
         }
         Expr::Aggregate(aggr) => state.accumulate(filter_context, aggr.as_ref()).await,
+        Expr::Window(window) => {
+            stream::iter(window.as_exprs())
+                .fold(
+                    Ok(state),
+                    |state, expr| async move { aggr(state?, expr).await },
+                )
+                .await
+        }
         _ => Ok(state),
     }
 }
@@ -264,6 +278,7 @@ fn check(expr: &Expr) -> bool {
                     .unwrap_or(false)
         }
         Expr::Aggregate(_) => true,
+        Expr::Window(window) => window.as_exprs().any(check),
         _ => false,
     }
 }
@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::{Aggregate, CountArgExpr, DataType},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, DataType},
         data::{Key, Value},
         executor::{context::RowContext, evaluate::evaluate},
         result::Result,
@@ -17,6 +17,7 @@ type Group = Rc<Vec<Key>>;
 type ValuesMap<'a> = HashMap<&'a Aggregate, Value>;
 type Context<'a> = Rc<RowContext<'a>>;
 
+#[derive(Clone)]
 enum AggrValue {
     Count {
         wildcard: bool,
@@ -45,28 +46,28 @@ impl AggrValue {
     fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
         let value = value.clone();
 
-        Ok(match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => AggrValue::Count {
+        Ok(match &aggr.func {
+            AggregateFunction::Count(CountArgExpr::Wildcard) => AggrValue::Count {
                 wildcard: true,
                 count: 1,
             },
-            Aggregate::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
+            AggregateFunction::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
                 wildcard: false,
                 count: i64::from(!value.is_null()),
             },
-            Aggregate::Sum(_) => AggrValue::Sum(value),
-            Aggregate::Min(_) => AggrValue::Min(value),
-            Aggregate::Max(_) => AggrValue::Max(value),
-            Aggregate::Avg(_) => AggrValue::Avg {
+            AggregateFunction::Sum(_) => AggrValue::Sum(value),
+            AggregateFunction::Min(_) => AggrValue::Min(value),
+            AggregateFunction::Max(_) => AggrValue::Max(value),
+            AggregateFunction::Avg(_) => AggrValue::Avg {
                 sum: value,
                 count: 1,
             },
-            Aggregate::Variance(_) => AggrValue::Variance {
+            AggregateFunction::Variance(_) => AggrValue::Variance {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
             },
-            Aggregate::Stdev(_) => AggrValue::Stdev {
+            AggregateFunction::Stdev(_) => AggrValue::Stdev {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
@@ -154,11 +155,57 @@ impl AggrValue {
     }
 }
 
+/// Aggregates values one at a time, used for aggregate window functions which are
+/// evaluated over window frames rather than groups.
+pub struct FrameAggregate<'b> {
+    aggr: &'b Aggregate,
+    distinct_values: HashSet<Key>,
+    aggr_value: Option<AggrValue>,
+}
+
+impl<'b> FrameAggregate<'b> {
+    pub fn new(aggr: &'b Aggregate) -> Self {
+        Self {
+            aggr,
+            distinct_values: HashSet::new(),
+            aggr_value: None,
+        }
+    }
+
+    pub fn accumulate(&mut self, value: &Value) -> Result<()> {
+        if self.aggr.distinct {
+            let key = Key::try_from(value)?;
+
+            if self.distinct_values.contains(&key) {
+                return Ok(());
+            }
+
+            self.distinct_values.insert(key);
+        }
+
+        self.aggr_value = match self.aggr_value.take() {
+            Some(aggr_value) => Some(aggr_value.accumulate(value)?.unwrap_or(aggr_value)),
+            None => Some(AggrValue::new(self.aggr, value)?),
+        };
+
+        Ok(())
+    }
+
+    pub async fn export(&self) -> Result<Value> {
+        match (&self.aggr_value, &self.aggr.func) {
+            (Some(aggr_value), _) => aggr_value.clone().export().await,
+            (None, AggregateFunction::Count(_)) => Ok(Value::I64(0)),
+            (None, _) => Ok(Value::Null),
+        }
+    }
+}
+
 pub struct State<'a, T: GStore> {
     storage: &'a T,
     index: usize,
     group: Group,
     values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue)>,
+    distinct_values: HashMap<(Group, &'a Aggregate), HashSet<Key>>,
     groups: HashSet<Group>,
     contexts: Vector<Rc<RowContext<'a>>>,
 }
@@ -170,6 +217,7 @@ impl<'a, T: GStore> State<'a, T> {
             index: 0,
             group: Rc::new(vec![Key::None]),
             values: IndexMap::new(),
+            distinct_values: HashMap::new(),
             groups: HashSet::new(),
             contexts: Vector::new(),
         }
@@ -201,6 +249,28 @@ impl<'a, T: GStore> State<'a, T> {
         Self { values, ..self }
     }
 
+    fn insert_distinct(self, aggr: &'a Aggregate, value: &Value) -> Result<(Self, bool)> {
+        let key = (Rc::clone(&self.group), aggr);
+        let value = Key::try_from(value)?;
+        let distinct_values = self.distinct_values.get(&key).cloned().unwrap_or_default();
+
+        if distinct_values.contains(&value) {
+            return Ok((self, false));
+        }
+
+        let distinct_values = self
+            .distinct_values
+            .update(key, distinct_values.update(value));
+
+        Ok((
+            Self {
+                distinct_values,
+                ..self
+            },
+            true,
+        ))
+    }
+
     fn get(&self, aggr: &'a Aggregate) -> Option<&(usize, AggrValue)> {
         let group = Rc::clone(&self.group);
 
@@ -253,27 +323,39 @@ impl<'a, T: GStore> State<'a, T> {
         filter_context: Option<Rc<RowContext<'a>>>,
         aggr: &'a Aggregate,
     ) -> Result<State<'a, T>> {
-        let value = match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => Value::Null,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
+        let value = match &aggr.func {
+            AggregateFunction::Count(CountArgExpr::Wildcard) => Value::Null,
+            AggregateFunction::Count(CountArgExpr::Expr(expr))
+            | AggregateFunction::Sum(expr)
+            | AggregateFunction::Min(expr)
+            | AggregateFunction::Max(expr)
+            | AggregateFunction::Avg(expr)
+            | AggregateFunction::Variance(expr)
+            | AggregateFunction::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
                 .await?
                 .try_into()?,
         };
-        let aggr_value = match self.get(aggr) {
-            Some((index, _)) if self.index <= *index => None,
+
+        if matches!(self.get(aggr), Some((index, _)) if self.index <= *index) {
+            return Ok(self);
+        }
+
+        let state = match aggr.distinct {
+            true => match self.insert_distinct(aggr, &value)? {
+                (state, true) => state,
+                (state, false) => return Ok(state),
+            },
+            false => self,
+        };
+
+        let aggr_value = match state.get(aggr) {
             Some((_, aggr_value)) => aggr_value.accumulate(&value)?,
             None => Some(AggrValue::new(aggr, &value)?),
         };
 
         match aggr_value {
-            Some(aggr_value) => Ok(self.update(aggr, aggr_value)),
-            None => Ok(self),
+            Some(aggr_value) => Ok(state.update(aggr, aggr_value)),
+            None => Ok(state),
         }
     }
 }
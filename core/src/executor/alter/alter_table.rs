@@ -1,8 +1,10 @@
 use {
-    super::{validate, AlterError},
+    super::{drop_identity_sequence, rename_identity_sequence, validate, AlterError},
     crate::{
         ast::{AlterTableOperation, Expr, Function},
         data::{Schema, SchemaIndex},
+        executor::foreign_key::{fetch_referencing, ForeignKeyError},
+        plan::PlanExpr,
         result::Result,
         store::{GStore, GStoreMut},
     },
@@ -16,34 +18,98 @@ pub async fn alter_table<T: GStore + GStoreMut>(
     match operation {
         AlterTableOperation::RenameTable {
             table_name: new_table_name,
-        } => storage.rename_schema(table_name, new_table_name).await,
+        } => {
+            if let Some((schema, foreign_key)) = fetch_referencing(storage, table_name)
+                .await?
+                .into_iter()
+                .next()
+            {
+                return Err(ForeignKeyError::TableReferenced(
+                    table_name.to_owned(),
+                    foreign_key.name,
+                    schema.table_name,
+                )
+                .into());
+            }
+
+            let column_names = identity_columns(storage, table_name).await?;
+            storage.rename_schema(table_name, new_table_name).await?;
+            storage.delete_statistics(table_name).await?;
+
+            for column_name in column_names {
+                rename_identity_sequence(
+                    storage,
+                    (table_name, &column_name),
+                    (new_table_name, &column_name),
+                )
+                .await?;
+            }
+
+            Ok(())
+        }
         AlterTableOperation::RenameColumn {
             old_column_name,
             new_column_name,
         } => {
+            validate_unreferenced_column(storage, table_name, old_column_name).await?;
+            validate_unchecked_column(storage, table_name, old_column_name).await?;
+            validate_unconstrained_column(storage, table_name, old_column_name).await?;
+
+            let column_names = identity_columns(storage, table_name).await?;
             storage
                 .rename_column(table_name, old_column_name, new_column_name)
-                .await
+                .await?;
+
+            if column_names.contains(old_column_name) {
+                rename_identity_sequence(
+                    storage,
+                    (table_name, old_column_name),
+                    (table_name, new_column_name),
+                )
+                .await?;
+            }
+
+            Ok(())
         }
         AlterTableOperation::AddColumn { column_def } => {
             validate(column_def).await?;
 
+            if column_def.identity.is_some() {
+                return Err(
+                    AlterError::UnsupportedAddIdentityColumn(column_def.name.to_owned()).into(),
+                );
+            }
+
             storage.add_column(table_name, column_def).await
         }
         AlterTableOperation::DropColumn {
             column_name,
             if_exists,
         } => {
-            let indexes = match storage.fetch_schema(table_name).await? {
-                Some(Schema { indexes, .. }) => indexes,
+            validate_unreferenced_column(storage, table_name, column_name).await?;
+            validate_unchecked_column(storage, table_name, column_name).await?;
+            validate_unconstrained_column(storage, table_name, column_name).await?;
+
+            let (indexes, column_defs) = match storage.fetch_schema(table_name).await? {
+                Some(Schema {
+                    indexes,
+                    column_defs,
+                    ..
+                }) => (indexes, column_defs),
                 None => {
                     return Err(AlterError::TableNotFound(table_name.to_owned()).into());
                 }
             };
+            let identity = column_defs
+                .iter()
+                .flatten()
+                .any(|column_def| &column_def.name == column_name && column_def.identity.is_some());
 
             let indexes = indexes
                 .iter()
-                .filter(|SchemaIndex { expr, .. }| find_column(expr, column_name))
+                .filter(|SchemaIndex { exprs, .. }| {
+                    exprs.iter().any(|expr| find_column(expr, column_name))
+                })
                 .map(|SchemaIndex { name, .. }| name);
 
             for index_name in indexes {
@@ -52,8 +118,127 @@ pub async fn alter_table<T: GStore + GStoreMut>(
 
             storage
                 .drop_column(table_name, column_name, *if_exists)
-                .await
+                .await?;
+
+            if identity {
+                drop_identity_sequence(storage, table_name, column_name).await?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+async fn identity_columns<T: GStore>(storage: &T, table_name: &str) -> Result<Vec<String>> {
+    let column_defs = storage
+        .fetch_schema(table_name)
+        .await?
+        .and_then(|schema| schema.column_defs)
+        .unwrap_or_default();
+
+    Ok(column_defs
+        .into_iter()
+        .filter(|column_def| column_def.identity.is_some())
+        .map(|column_def| column_def.name)
+        .collect())
+}
+
+/// Columns used by foreign keys, on either side, cannot be renamed or dropped.
+async fn validate_unreferenced_column<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let referencing = storage
+        .fetch_schema(table_name)
+        .await?
+        .map(|schema| schema.foreign_keys)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|foreign_key| {
+            foreign_key
+                .columns
+                .iter()
+                .any(|column| column == column_name)
+        });
+    let referenced = fetch_referencing(storage, table_name)
+        .await?
+        .into_iter()
+        .map(|(_, foreign_key)| foreign_key)
+        .find(|foreign_key| {
+            foreign_key
+                .referenced_columns
+                .iter()
+                .any(|column| column == column_name)
+        });
+
+    match referencing.or(referenced) {
+        Some(foreign_key) => {
+            Err(ForeignKeyError::ColumnReferenced(column_name.to_owned(), foreign_key.name).into())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Columns used by `CHECK` constraints cannot be renamed or dropped.
+async fn validate_unchecked_column<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let check = storage
+        .fetch_schema(table_name)
+        .await?
+        .map(|schema| schema.checks)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|check| uses_column(&check.expr, column_name));
+
+    match check {
+        Some(check) => {
+            Err(AlterError::ColumnUsedByCheck(column_name.to_owned(), check.name).into())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Columns of composite `PRIMARY KEY` and `UNIQUE` constraints cannot be renamed or dropped.
+async fn validate_unconstrained_column<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let constrained = storage
+        .fetch_schema(table_name)
+        .await?
+        .map(|schema| schema.unique_constraints)
+        .unwrap_or_default()
+        .iter()
+        .any(|constraint| {
+            constraint
+                .columns
+                .iter()
+                .any(|column| column == column_name)
+        });
+
+    match constrained {
+        true => Err(AlterError::ColumnUsedByUniqueConstraint(column_name.to_owned()).into()),
+        false => Ok(()),
+    }
+}
+
+fn uses_column(expr: &Expr, column_name: &str) -> bool {
+    let uses = |expr| uses_column(expr, column_name);
+
+    match PlanExpr::from(expr) {
+        PlanExpr::None | PlanExpr::Query(_) => false,
+        PlanExpr::Identifier(ident) | PlanExpr::CompoundIdentifier { ident, .. } => {
+            ident == column_name
         }
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => uses(expr),
+        PlanExpr::TwoExprs(expr, expr2) => uses(expr) || uses(expr2),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => [expr, expr2, expr3].into_iter().any(uses),
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(uses),
     }
 }
 
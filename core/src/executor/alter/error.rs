@@ -11,6 +11,27 @@ pub enum AlterError {
     #[error("table already exists: {0}")]
     TableAlreadyExists(String),
 
+    // CREATE VIEW
+    #[error("view already exists: {0}")]
+    ViewAlreadyExists(String),
+
+    #[error("view does not exist: {0}")]
+    ViewNotFound(String),
+
+    // REFRESH MATERIALIZED VIEW
+    #[error("materialized view does not exist: {0}")]
+    MaterializedViewNotFound(String),
+
+    #[error("not a materialized view: {0}")]
+    NotMaterializedView(String),
+
+    #[error("columns of materialized view have changed: {0}")]
+    MaterializedViewColumnsChanged(String),
+
+    // CREATE MATERIALIZED VIEW
+    #[error("type of column '{1}' of materialized view '{0}' is unknown, CAST it to a type")]
+    MaterializedViewColumnTypeUnknown(String, String),
+
     #[error("function already exists: {0}")]
     FunctionAlreadyExists(String),
 
@@ -28,6 +49,23 @@ pub enum AlterError {
     #[error("column '{0}' of data type '{1:?}' is unsupported for unique constraint")]
     UnsupportedDataTypeForUniqueColumn(String, DataType),
 
+    #[error("column '{0}' of data type '{1:?}' is unsupported for identity column")]
+    UnsupportedDataTypeForIdentityColumn(String, DataType),
+
+    #[error("both default and identity specified for column: {0}")]
+    IdentityColumnWithDefault(String),
+
+    // ALTER TABLE ADD COLUMN
+    #[error("adding an identity column is not supported: {0}")]
+    UnsupportedAddIdentityColumn(String),
+
+    // ALTER TABLE RENAME COLUMN, DROP COLUMN
+    #[error("column '{0}' is used by check constraint '{1}'")]
+    ColumnUsedByCheck(String, String),
+
+    #[error("column '{0}' is used by unique constraint")]
+    ColumnUsedByUniqueConstraint(String),
+
     // validate index expr
     #[error("unsupported index expr: {0:#?}")]
     UnsupportedIndexExpr(Expr),
@@ -2,15 +2,22 @@ mod alter_table;
 mod error;
 mod function;
 mod index;
+mod sequence;
 mod table;
 mod validate;
+mod view;
 
-use validate::{validate, validate_arg_names, validate_column_names, validate_default_args};
+use {
+    sequence::{create_identity_sequences, drop_identity_sequence, rename_identity_sequence},
+    validate::{validate, validate_arg_names, validate_column_names, validate_default_args},
+};
 
 pub use {
     alter_table::alter_table,
     error::AlterError,
     function::{delete_function, insert_function},
     index::create_index,
+    sequence::{create_sequence, drop_sequence},
     table::{create_table, drop_table},
+    view::{create_materialized_view, create_view, drop_view, refresh_materialized_view},
 };
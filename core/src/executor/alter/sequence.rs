@@ -0,0 +1,116 @@
+use crate::{
+    ast::{ColumnDef, ColumnIdentity, SequenceOptions},
+    data::{Sequence, SequenceError},
+    result::Result,
+    store::{GStore, GStoreMut},
+};
+
+pub async fn create_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_name: &str,
+    options: &SequenceOptions,
+    if_not_exists: bool,
+) -> Result<()> {
+    let sequence = Sequence::new(sequence_name, options, None)?;
+
+    match storage.fetch_sequence(sequence_name).await? {
+        Some(_) if if_not_exists => Ok(()),
+        Some(_) => Err(SequenceError::SequenceAlreadyExists(sequence_name.to_owned()).into()),
+        None => storage.insert_sequence(sequence).await,
+    }
+}
+
+pub async fn drop_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for sequence_name in sequence_names {
+        match storage.fetch_sequence(sequence_name).await? {
+            Some(Sequence {
+                owned_by: Some(table_name),
+                ..
+            }) => {
+                return Err(SequenceError::SequenceOwnedByTable(
+                    sequence_name.to_owned(),
+                    table_name,
+                )
+                .into());
+            }
+            Some(_) => storage.delete_sequence(sequence_name).await?,
+            None if if_exists => {}
+            None => {
+                return Err(SequenceError::SequenceNotFound(sequence_name.to_owned()).into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the sequences backing the identity columns of a new table.
+pub async fn create_identity_sequences<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+) -> Result<()> {
+    let mut sequences = Vec::new();
+
+    for column_def in column_defs {
+        let options = match &column_def.identity {
+            Some(ColumnIdentity { options, .. }) => options,
+            None => continue,
+        };
+
+        let sequence_name = Sequence::identity_name(table_name, &column_def.name);
+        if storage.fetch_sequence(&sequence_name).await?.is_some() {
+            return Err(SequenceError::SequenceAlreadyExists(sequence_name).into());
+        }
+
+        sequences.push(Sequence::new(&sequence_name, options, Some(table_name))?);
+    }
+
+    for sequence in sequences {
+        storage.insert_sequence(sequence).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn drop_identity_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let sequence_name = Sequence::identity_name(table_name, column_name);
+
+    storage.delete_sequence(&sequence_name).await
+}
+
+/// Moves the sequence of an identity column after its table or the column is renamed.
+pub async fn rename_identity_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    (table_name, column_name): (&str, &str),
+    (new_table_name, new_column_name): (&str, &str),
+) -> Result<()> {
+    let sequence_name = Sequence::identity_name(table_name, column_name);
+    let new_sequence_name = Sequence::identity_name(new_table_name, new_column_name);
+
+    let sequence = match storage.fetch_sequence(&sequence_name).await? {
+        Some(sequence) => sequence,
+        None => return Ok(()),
+    };
+
+    if storage.fetch_sequence(&new_sequence_name).await?.is_some() {
+        return Err(SequenceError::SequenceAlreadyExists(new_sequence_name).into());
+    }
+
+    storage.delete_sequence(&sequence_name).await?;
+    storage
+        .insert_sequence(Sequence {
+            name: new_sequence_name,
+            owned_by: Some(new_table_name.to_owned()),
+            ..sequence
+        })
+        .await
+}
@@ -1,12 +1,24 @@
 use {
-    super::{validate, validate_column_names, AlterError},
+    super::{
+        create_identity_sequences, drop_identity_sequence, validate, validate_column_names,
+        AlterError,
+    },
     crate::{
-        ast::{ColumnDef, Query, SetExpr, TableFactor, Values},
+        ast::{
+            Check, ColumnDef, ForeignKey, Query, SetExpr, TableFactor, UniqueConstraint, Values,
+        },
         data::{Schema, TableError},
-        executor::{evaluate_stateless, select::select},
+        executor::{
+            evaluate_stateless,
+            foreign_key::{
+                check_references, fetch_referencing, resolve_foreign_keys, ForeignKeyError,
+            },
+            select::select,
+            validate::validate_checks,
+        },
         prelude::{DataType, Value},
         result::{Error, Result},
-        store::{GStore, GStoreMut},
+        store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::TryStreamExt,
 };
@@ -18,9 +30,10 @@ pub async fn create_table<T: GStore + GStoreMut>(
     if_not_exists: bool,
     source: &Option<Box<Query>>,
     engine: &Option<String>,
+    (foreign_keys, checks, unique_constraints): (&[ForeignKey], &[Check], &[UniqueConstraint]),
 ) -> Result<()> {
-    let target_columns_defs = match source.as_deref() {
-        Some(Query { body, .. }) => match body {
+    let target_columns_defs = match source.as_deref().map(get_source_body) {
+        Some(body) => match body {
             SetExpr::Select(select_query) => match &select_query.from.relation {
                 TableFactor::Table { name, .. } => {
                     let schema = storage.fetch_schema(name).await?;
@@ -31,7 +44,15 @@ pub async fn create_table<T: GStore + GStoreMut>(
                         AlterError::CtasSourceTableNotFound(name.to_owned()).into()
                     })?;
 
-                    source_column_defs
+                    source_column_defs.map(|column_defs| {
+                        column_defs
+                            .into_iter()
+                            .map(|column_def| ColumnDef {
+                                identity: None,
+                                ..column_def
+                            })
+                            .collect()
+                    })
                 }
                 TableFactor::Series { .. } => {
                     let column_def = ColumnDef {
@@ -40,6 +61,7 @@ pub async fn create_table<T: GStore + GStoreMut>(
                         nullable: false,
                         default: None,
                         unique: None,
+                        identity: None,
                     };
 
                     Some(vec![column_def])
@@ -82,11 +104,15 @@ pub async fn create_table<T: GStore + GStoreMut>(
                         nullable: true,
                         default: None,
                         unique: None,
+                        identity: None,
                     })
                     .collect::<Vec<_>>();
 
                 Some(column_defs)
             }
+            SetExpr::SetOperation { .. } => {
+                return Err(Error::Table(TableError::Unreachable));
+            }
         },
         None if column_defs.is_some() => column_defs.map(<[ColumnDef]>::to_vec),
         None => None,
@@ -98,29 +124,94 @@ pub async fn create_table<T: GStore + GStoreMut>(
         for column_def in column_defs {
             validate(column_def).await?;
         }
+
+        for ColumnDef {
+            name, data_type, ..
+        } in column_defs.iter().filter(|column_def| {
+            unique_constraints
+                .iter()
+                .any(|constraint| constraint.columns.contains(&column_def.name))
+        }) {
+            if matches!(data_type, DataType::Float | DataType::Map) {
+                return Err(AlterError::UnsupportedDataTypeForUniqueColumn(
+                    name.to_owned(),
+                    data_type.clone(),
+                )
+                .into());
+            }
+        }
     }
 
-    if storage.fetch_schema(target_table_name).await?.is_none() {
-        let schema = Schema {
-            table_name: target_table_name.to_owned(),
-            column_defs: target_columns_defs,
-            indexes: vec![],
-            engine: engine.clone(),
-        };
-
-        storage.insert_schema(&schema).await?;
-    } else if !if_not_exists {
-        return Err(AlterError::TableAlreadyExists(target_table_name.to_owned()).into());
+    if storage.fetch_view(target_table_name).await?.is_some() {
+        return Err(AlterError::ViewAlreadyExists(target_table_name.to_owned()).into());
     }
 
+    let column_names = target_columns_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Vec<_>>();
+    let (foreign_keys, checks) = match storage.fetch_schema(target_table_name).await? {
+        None => {
+            let foreign_keys = resolve_foreign_keys(
+                storage,
+                target_table_name,
+                target_columns_defs.as_deref(),
+                unique_constraints,
+                foreign_keys,
+            )
+            .await?;
+
+            if let Some(column_defs) = target_columns_defs.as_deref() {
+                create_identity_sequences(storage, target_table_name, column_defs).await?;
+            }
+
+            let schema = Schema {
+                table_name: target_table_name.to_owned(),
+                column_defs: target_columns_defs,
+                indexes: vec![],
+                engine: engine.clone(),
+                view_query: None,
+                foreign_keys,
+                checks: checks.to_vec(),
+                unique_constraints: unique_constraints.to_vec(),
+            };
+
+            storage.insert_schema(&schema).await?;
+
+            (schema.foreign_keys, schema.checks)
+        }
+        Some(_) if if_not_exists => (Vec::new(), Vec::new()),
+        Some(_) => {
+            return Err(AlterError::TableAlreadyExists(target_table_name.to_owned()).into());
+        }
+    };
+
     match source {
         Some(query) => {
             let rows = select(storage, query, None)
                 .await?
                 .map_ok(Into::into)
-                .try_collect()
+                .try_collect::<Vec<_>>()
                 .await?;
 
+            let values = rows
+                .iter()
+                .filter_map(|row| match row {
+                    DataRow::Vec(values) => Some(values.as_slice()),
+                    DataRow::Map(_) => None,
+                })
+                .collect::<Vec<_>>();
+            validate_checks(&checks, &column_names, &values).await?;
+            check_references(
+                storage,
+                target_table_name,
+                &column_names,
+                &foreign_keys,
+                &values,
+            )
+            .await?;
+
             storage
                 .append_data(target_table_name, rows)
                 .await
@@ -134,16 +225,51 @@ pub async fn drop_table<T: GStore + GStoreMut>(
     storage: &mut T,
     table_names: &[String],
     if_exists: bool,
+    cascade: bool,
 ) -> Result<()> {
     for table_name in table_names {
         let schema = storage.fetch_schema(table_name).await?;
 
         if !if_exists {
-            schema.ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+            schema
+                .as_ref()
+                .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+        }
+
+        for (referencing, foreign_key) in fetch_referencing(storage, table_name).await? {
+            if table_names.contains(&referencing.table_name) {
+                continue;
+            } else if !cascade {
+                return Err(ForeignKeyError::TableReferenced(
+                    table_name.to_owned(),
+                    foreign_key.name,
+                    referencing.table_name,
+                )
+                .into());
+            }
+
+            storage
+                .drop_foreign_key(&referencing.table_name, &foreign_key.name)
+                .await?;
         }
 
         storage.delete_schema(table_name).await?;
+        storage.delete_statistics(table_name).await?;
+
+        let column_defs = schema.and_then(|schema| schema.column_defs);
+        for column_def in column_defs.iter().flatten() {
+            if column_def.identity.is_some() {
+                drop_identity_sequence(storage, table_name, &column_def.name).await?;
+            }
+        }
     }
 
     Ok(())
 }
+
+fn get_source_body(query: &Query) -> &SetExpr {
+    match &query.body {
+        SetExpr::SetOperation { left, .. } => get_source_body(left),
+        body => body,
+    }
+}
@@ -12,6 +12,7 @@ pub async fn validate(column_def: &ColumnDef) -> Result<()> {
         data_type,
         default,
         unique,
+        identity,
         name,
         ..
     } = column_def;
@@ -27,6 +28,33 @@ pub async fn validate(column_def: &ColumnDef) -> Result<()> {
         .into());
     }
 
+    // identity + data type, default
+    if identity.is_some() {
+        if !matches!(
+            data_type,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int
+                | DataType::Int128
+                | DataType::Uint8
+                | DataType::Uint16
+                | DataType::Uint32
+                | DataType::Uint64
+                | DataType::Uint128
+        ) {
+            return Err(AlterError::UnsupportedDataTypeForIdentityColumn(
+                name.to_owned(),
+                data_type.clone(),
+            )
+            .into());
+        }
+
+        if default.is_some() {
+            return Err(AlterError::IdentityColumnWithDefault(name.to_owned()).into());
+        }
+    }
+
     if let Some(expr) = default {
         evaluate_stateless(None, expr).await?;
     }
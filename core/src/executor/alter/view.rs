@@ -0,0 +1,440 @@
+use {
+    super::{validate_column_names, AlterError},
+    crate::{
+        ast::{
+            AggregateFunction, AstLiteral, BinaryOperator, ColumnDef, Cte, DataType, Expr,
+            Function, Join, Query, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+            UnaryOperator, Values,
+        },
+        data::{Row, Schema, View},
+        executor::{fetch::FetchError, select::select_with_labels},
+        plan::plan,
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+        translate::alias_or_name,
+    },
+    async_recursion::async_recursion,
+    bigdecimal::ToPrimitive,
+    futures::stream::{self, StreamExt, TryStreamExt},
+    std::{collections::HashMap, iter},
+};
+
+pub async fn create_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+    columns: &[String],
+    query: &Query,
+    or_replace: bool,
+) -> Result<()> {
+    if storage.fetch_schema(view_name).await?.is_some() {
+        return Err(AlterError::TableAlreadyExists(view_name.to_owned()).into());
+    }
+
+    for (i, column) in columns.iter().enumerate() {
+        if columns[..i].contains(column) {
+            return Err(AlterError::DuplicateColumnName(column.to_owned()).into());
+        }
+    }
+
+    if storage.fetch_view(view_name).await?.is_none() || or_replace {
+        storage.delete_view(view_name).await?;
+        storage
+            .insert_view(View {
+                view_name: view_name.to_owned(),
+                columns: columns.to_owned(),
+                query: query.to_owned(),
+            })
+            .await
+    } else {
+        Err(AlterError::ViewAlreadyExists(view_name.to_owned()).into())
+    }
+}
+
+pub async fn drop_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for view_name in view_names {
+        let view = storage.fetch_view(view_name).await?;
+
+        if !if_exists {
+            view.ok_or_else(|| AlterError::ViewNotFound(view_name.to_owned()))?;
+        }
+
+        storage.delete_view(view_name).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn create_materialized_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+    columns: &[String],
+    query: &Query,
+) -> Result<()> {
+    if storage.fetch_view(view_name).await?.is_some() {
+        return Err(AlterError::ViewAlreadyExists(view_name.to_owned()).into());
+    }
+
+    if storage.fetch_schema(view_name).await?.is_some() {
+        return Err(AlterError::TableAlreadyExists(view_name.to_owned()).into());
+    }
+
+    let (column_defs, rows) = materialize(storage, view_name, columns, query).await?;
+    if let Some(column_defs) = column_defs.as_deref() {
+        validate_column_names(column_defs)?;
+    }
+
+    let schema = Schema {
+        table_name: view_name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        engine: None,
+        view_query: Some(query.to_owned()),
+        foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        unique_constraints: Vec::new(),
+    };
+
+    storage.insert_schema(&schema).await?;
+    storage.append_data(view_name, rows).await
+}
+
+pub async fn refresh_materialized_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+) -> Result<()> {
+    let Schema {
+        column_defs,
+        view_query,
+        ..
+    } = storage
+        .fetch_schema(view_name)
+        .await?
+        .ok_or_else(|| AlterError::MaterializedViewNotFound(view_name.to_owned()))?;
+    let query = view_query.ok_or_else(|| AlterError::NotMaterializedView(view_name.to_owned()))?;
+
+    let column_names = column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Vec<_>>();
+    let (refreshed_column_defs, rows) =
+        materialize(storage, view_name, &column_names, &query).await?;
+
+    if column_defs != refreshed_column_defs {
+        return Err(AlterError::MaterializedViewColumnsChanged(view_name.to_owned()).into());
+    }
+
+    let keys = storage
+        .scan_data(view_name)
+        .await?
+        .map(|result| result.map(|(key, _)| key))
+        .collect::<Result<Vec<_>>>()?;
+
+    storage.delete_data(view_name, keys).await?;
+    storage.append_data(view_name, rows).await
+}
+
+/// Runs the defining query of a materialized view, returning the rows to store along with
+/// column definitions whose types are the ones the query projects.
+async fn materialize<T: GStore>(
+    storage: &T,
+    view_name: &str,
+    columns: &[String],
+    query: &Query,
+) -> Result<(Option<Vec<ColumnDef>>, Vec<DataRow>)> {
+    let query = match plan(storage, Statement::Query(query.to_owned())).await? {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+
+    let (labels, rows) = select_with_labels(storage, &query, None).await?;
+    let rows = rows.try_collect::<Vec<Row>>().await?;
+
+    let column_defs = match labels {
+        Some(labels) if columns.len() > labels.len() => {
+            return Err(FetchError::TooManyColumnAliases(
+                view_name.to_owned(),
+                labels.len(),
+                columns.len(),
+            )
+            .into());
+        }
+        Some(labels) => {
+            let data_types = projected_columns(storage, &HashMap::new(), &query)
+                .await?
+                .into_iter()
+                .map(|(_, data_type)| data_type)
+                .chain(iter::repeat(None));
+
+            let column_defs = columns
+                .iter()
+                .chain(labels.iter().skip(columns.len()))
+                .zip(data_types)
+                .map(|(name, data_type)| {
+                    let data_type = data_type.ok_or_else(|| {
+                        AlterError::MaterializedViewColumnTypeUnknown(
+                            view_name.to_owned(),
+                            name.to_owned(),
+                        )
+                    })?;
+
+                    Ok(ColumnDef {
+                        name: name.to_owned(),
+                        data_type,
+                        nullable: true,
+                        default: None,
+                        unique: None,
+                        identity: None,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Some(column_defs)
+        }
+        None => None,
+    };
+
+    Ok((column_defs, rows.into_iter().map(Into::into).collect()))
+}
+
+/// Column names of a relation along with their types, `None` for a type which cannot be
+/// known without running the query.
+type Columns = Vec<(String, Option<DataType>)>;
+
+#[async_recursion(?Send)]
+async fn projected_columns<T: GStore>(
+    storage: &T,
+    ctes: &HashMap<String, Columns>,
+    query: &Query,
+) -> Result<Columns> {
+    let mut ctes = ctes.clone();
+    for Cte { alias, query } in query.with.iter().flat_map(|with| &with.cte_tables) {
+        let columns = projected_columns(storage, &ctes, query).await?;
+
+        ctes.insert(
+            alias.name.to_owned(),
+            rename_columns(columns, &alias.columns),
+        );
+    }
+
+    match &query.body {
+        SetExpr::Select(select) => {
+            let relations = stream::iter(
+                iter::once(&select.from.relation).chain(
+                    select
+                        .from
+                        .joins
+                        .iter()
+                        .map(|Join { relation, .. }| relation),
+                ),
+            )
+            .then(|relation| relation_columns(storage, &ctes, relation))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+            let columns = select
+                .projection
+                .iter()
+                .flat_map(|select_item| match select_item {
+                    SelectItem::Expr { expr, label } => {
+                        vec![(label.to_owned(), expr_type(&relations, expr))]
+                    }
+                    SelectItem::QualifiedWildcard(target) => relations
+                        .iter()
+                        .filter(|(alias, _)| alias == target)
+                        .flat_map(|(_, columns)| columns.clone())
+                        .collect(),
+                    SelectItem::Wildcard => relations
+                        .iter()
+                        .flat_map(|(_, columns)| columns.clone())
+                        .collect(),
+                })
+                .collect();
+
+            Ok(columns)
+        }
+        SetExpr::Values(Values(rows)) => {
+            let columns = rows
+                .first()
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .map(|(i, expr)| (format!("column{}", i + 1), expr_type(&[], expr)))
+                .collect();
+
+            Ok(columns)
+        }
+        SetExpr::SetOperation { left, .. } => projected_columns(storage, &ctes, left).await,
+    }
+}
+
+async fn relation_columns<T: GStore>(
+    storage: &T,
+    ctes: &HashMap<String, Columns>,
+    relation: &TableFactor,
+) -> Result<(String, Columns)> {
+    let (alias, columns) = match relation {
+        TableFactor::Table { name, alias, .. } => {
+            let columns = match ctes.get(name) {
+                Some(columns) => columns.clone(),
+                None => storage
+                    .fetch_schema(name)
+                    .await?
+                    .and_then(|schema| schema.column_defs)
+                    .into_iter()
+                    .flatten()
+                    .map(
+                        |ColumnDef {
+                             name, data_type, ..
+                         }| (name, Some(data_type)),
+                    )
+                    .collect(),
+            };
+
+            (alias_or_name(alias.clone(), name.to_owned()), columns)
+        }
+        TableFactor::Derived { subquery, alias } => {
+            let columns = projected_columns(storage, ctes, subquery).await?;
+
+            (alias.clone(), columns)
+        }
+        TableFactor::Series { alias, .. } => {
+            (alias.clone(), vec![("N".to_owned(), Some(DataType::Int))])
+        }
+        TableFactor::Dictionary { alias, .. } => (alias.clone(), Vec::new()),
+    };
+
+    let TableAlias {
+        name,
+        columns: aliases,
+    } = alias;
+
+    Ok((name, rename_columns(columns, &aliases)))
+}
+
+fn rename_columns(columns: Columns, aliases: &[String]) -> Columns {
+    columns
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, data_type))| match aliases.get(i) {
+            Some(alias) => (alias.to_owned(), data_type),
+            None => (name, data_type),
+        })
+        .collect()
+}
+
+fn expr_type(relations: &[(String, Columns)], expr: &Expr) -> Option<DataType> {
+    let column_type = |alias: Option<&str>, ident: &str| {
+        relations
+            .iter()
+            .filter(|(name, _)| alias.map(|alias| alias == name).unwrap_or(true))
+            .flat_map(|(_, columns)| columns)
+            .find(|(name, _)| name == ident)
+            .and_then(|(_, data_type)| data_type.clone())
+    };
+
+    match expr {
+        Expr::Identifier(ident) => column_type(None, ident),
+        Expr::CompoundIdentifier { alias, ident } => column_type(Some(alias), ident),
+        Expr::Nested(expr)
+        | Expr::UnaryOp {
+            op: UnaryOperator::Plus | UnaryOperator::Minus,
+            expr,
+        } => expr_type(relations, expr),
+        Expr::Literal(AstLiteral::Boolean(_)) => Some(DataType::Boolean),
+        Expr::Literal(AstLiteral::Number(v)) => match v.to_i64() {
+            Some(_) => Some(DataType::Int),
+            None => Some(DataType::Float),
+        },
+        Expr::Literal(AstLiteral::QuotedString(_)) => Some(DataType::Text),
+        Expr::Literal(AstLiteral::HexString(_)) => Some(DataType::Bytea),
+        Expr::Literal(AstLiteral::Null) => None,
+        Expr::TypedString { data_type, .. } => Some(data_type.clone()),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            ..
+        }
+        | Expr::IsNull(_)
+        | Expr::IsNotNull(_)
+        | Expr::InList { .. }
+        | Expr::InSubquery { .. }
+        | Expr::Between { .. }
+        | Expr::Like { .. }
+        | Expr::ILike { .. }
+        | Expr::Exists { .. } => Some(DataType::Boolean),
+        Expr::BinaryOp { left, op, right } => match op {
+            BinaryOperator::Plus
+            | BinaryOperator::Minus
+            | BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo
+            | BinaryOperator::StringConcat => {
+                let data_type = expr_type(relations, left)?;
+
+                (expr_type(relations, right)? == data_type).then_some(data_type)
+            }
+            BinaryOperator::Gt
+            | BinaryOperator::Lt
+            | BinaryOperator::GtEq
+            | BinaryOperator::LtEq
+            | BinaryOperator::Eq
+            | BinaryOperator::NotEq
+            | BinaryOperator::And
+            | BinaryOperator::Or
+            | BinaryOperator::Xor => Some(DataType::Boolean),
+        },
+        Expr::Case {
+            when_then,
+            else_result,
+            ..
+        } => {
+            let mut data_types = when_then
+                .iter()
+                .map(|(_, then)| then)
+                .chain(else_result.as_deref())
+                .filter(|expr| !matches!(expr, Expr::Literal(AstLiteral::Null)))
+                .map(|expr| expr_type(relations, expr));
+            let data_type = data_types.next()??;
+
+            data_types
+                .all(|other| other.as_ref() == Some(&data_type))
+                .then_some(data_type)
+        }
+        Expr::Function(function) => match function.as_ref() {
+            Function::Cast { data_type, .. } => Some(data_type.clone()),
+            Function::Lower(_)
+            | Function::Upper(_)
+            | Function::Initcap(_)
+            | Function::Left { .. }
+            | Function::Right { .. }
+            | Function::Lpad { .. }
+            | Function::Rpad { .. }
+            | Function::Replace { .. }
+            | Function::Concat(_)
+            | Function::ConcatWs { .. }
+            | Function::Trim { .. }
+            | Function::Ltrim { .. }
+            | Function::Rtrim { .. }
+            | Function::Reverse(_)
+            | Function::Repeat { .. }
+            | Function::Substr { .. } => Some(DataType::Text),
+            Function::Now() => Some(DataType::Timestamp),
+            Function::GenerateUuid() => Some(DataType::Uuid),
+            _ => None,
+        },
+        Expr::Aggregate(aggregate) => match &aggregate.func {
+            AggregateFunction::Count(_) => Some(DataType::Int),
+            AggregateFunction::Sum(expr)
+            | AggregateFunction::Max(expr)
+            | AggregateFunction::Min(expr) => expr_type(relations, expr),
+            AggregateFunction::Avg(_)
+            | AggregateFunction::Variance(_)
+            | AggregateFunction::Stdev(_) => Some(DataType::Float),
+        },
+        _ => None,
+    }
+}
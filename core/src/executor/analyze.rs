@@ -0,0 +1,126 @@
+use {
+    super::execute::ExecuteError,
+    crate::{
+        data::{ColumnStats, Key, Schema, TableStats, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    std::collections::HashSet,
+};
+
+/// Collects the statistics of the table, or all tables if no name is given, and stores them
+/// for the planner.
+pub async fn analyze<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: Option<&str>,
+) -> Result<()> {
+    let schemas = match table_name {
+        Some(table_name) => {
+            let schema = storage
+                .fetch_schema(table_name)
+                .await?
+                .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+
+            vec![schema]
+        }
+        None => storage.fetch_all_schemas().await?,
+    };
+
+    for schema in schemas {
+        let statistics = collect(storage, &schema).await?;
+
+        storage.insert_statistics(statistics).await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct ColumnCollector {
+    null_count: usize,
+    distinct: HashSet<Key>,
+    min: Option<Key>,
+    max: Option<Key>,
+}
+
+impl ColumnCollector {
+    fn add(&mut self, value: Option<&Value>) {
+        let value = match value {
+            None | Some(Value::Null) => {
+                self.null_count += 1;
+
+                return;
+            }
+            Some(value) => value,
+        };
+
+        // lists, maps and points have no key, they count towards neither distinct values nor
+        // the min and max
+        let key = match Key::try_from(value) {
+            Ok(key) => key,
+            Err(_) => return,
+        };
+
+        if !matches!(&self.min, Some(min) if min <= &key) {
+            self.min = Some(key.clone());
+        }
+
+        if !matches!(&self.max, Some(max) if max >= &key) {
+            self.max = Some(key.clone());
+        }
+
+        self.distinct.insert(key);
+    }
+
+    fn finish(self, name: String) -> ColumnStats {
+        ColumnStats {
+            name,
+            null_count: self.null_count,
+            distinct_count: self.distinct.len(),
+            min: self.min.map(Value::from),
+            max: self.max.map(Value::from),
+        }
+    }
+}
+
+async fn collect<T: GStore>(storage: &T, schema: &Schema) -> Result<TableStats> {
+    let column_names = schema
+        .column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Vec<_>>();
+    let mut columns = column_names
+        .iter()
+        .map(|_| ColumnCollector::default())
+        .collect::<Vec<_>>();
+    let mut row_count = 0;
+
+    for item in storage.scan_data(&schema.table_name).await? {
+        let (_, row) = item?;
+        row_count += 1;
+
+        match &row {
+            DataRow::Vec(values) => columns
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, column)| column.add(values.get(i))),
+            DataRow::Map(values) => columns
+                .iter_mut()
+                .zip(&column_names)
+                .for_each(|(column, name)| column.add(values.get(name))),
+        }
+    }
+
+    let columns = columns
+        .into_iter()
+        .zip(column_names)
+        .map(|(column, name)| column.finish(name))
+        .collect();
+
+    Ok(TableStats {
+        table_name: schema.table_name.to_owned(),
+        row_count,
+        columns,
+    })
+}
@@ -0,0 +1,24 @@
+use {crate::data::Row, im_rc::HashMap, std::rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct CteTable {
+    pub columns: Option<Rc<[String]>>,
+    pub rows: Rc<[Row]>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CteContext {
+    tables: HashMap<String, CteTable>,
+}
+
+impl CteContext {
+    pub fn get(&self, name: &str) -> Option<&CteTable> {
+        self.tables.get(name)
+    }
+
+    pub fn update(&self, name: String, table: CteTable) -> Self {
+        Self {
+            tables: self.tables.update(name, table),
+        }
+    }
+}
@@ -1,4 +1,9 @@
 mod aggregate_context;
+mod cte_context;
 mod row_context;
 
-pub use {aggregate_context::AggregateContext, row_context::RowContext};
+pub use {
+    aggregate_context::AggregateContext,
+    cte_context::{CteContext, CteTable},
+    row_context::RowContext,
+};
@@ -1,5 +1,8 @@
 use {
-    crate::data::{Row, Value},
+    crate::{
+        ast::Window,
+        data::{Row, Value},
+    },
     std::{borrow::Cow, collections::HashMap, fmt::Debug, rc::Rc},
 };
 
@@ -19,6 +22,15 @@ pub enum RowContext<'a> {
         left: Rc<RowContext<'a>>,
         right: Rc<RowContext<'a>>,
     },
+    Window {
+        values: HashMap<&'a Window, Value>,
+        next: Rc<RowContext<'a>>,
+    },
+    Using {
+        columns: Rc<[String]>,
+        values: Vec<Value>,
+        next: Rc<RowContext<'a>>,
+    },
 }
 
 impl<'a> RowContext<'a> {
@@ -34,6 +46,37 @@ impl<'a> RowContext<'a> {
         Self::Bridge { left, right }
     }
 
+    pub fn window(values: HashMap<&'a Window, Value>, next: Rc<RowContext<'a>>) -> Self {
+        Self::Window { values, next }
+    }
+
+    /// Merges the `USING` columns of a joined row into single values,
+    /// taking the left side value unless it is NULL.
+    pub fn using(columns: Rc<[String]>, next: Rc<RowContext<'a>>) -> Self {
+        let values = match next.as_ref() {
+            Self::Data {
+                row, next: left, ..
+            } => columns
+                .iter()
+                .map(|column| {
+                    left.as_ref()
+                        .and_then(|left| left.get_value(column))
+                        .filter(|value| !value.is_null())
+                        .or_else(|| row.get_value(column))
+                        .cloned()
+                        .unwrap_or(Value::Null)
+                })
+                .collect(),
+            _ => columns.iter().map(|_| Value::Null).collect(),
+        };
+
+        Self::Using {
+            columns,
+            values,
+            next,
+        }
+    }
+
     pub fn get_value(&'a self, target: &str) -> Option<&'a Value> {
         match self {
             Self::Data {
@@ -52,6 +95,32 @@ impl<'a> RowContext<'a> {
                 .position(|column| column == target)
                 .and_then(|index| values.get(index)),
             Self::RefMapData(values) => values.get(target),
+            Self::Window { next, .. } => next.get_value(target),
+            Self::Using {
+                columns,
+                values,
+                next,
+            } => columns
+                .iter()
+                .position(|column| column == target)
+                .and_then(|index| values.get(index))
+                .or_else(|| next.get_value(target)),
+        }
+    }
+
+    pub fn get_window_value(&'a self, target: &Window) -> Option<&'a Value> {
+        match self {
+            Self::Window { values, next } => {
+                values.get(target).or_else(|| next.get_window_value(target))
+            }
+            Self::Data {
+                next: Some(next), ..
+            }
+            | Self::Using { next, .. } => next.get_window_value(target),
+            Self::Bridge { left, right } => left
+                .get_window_value(target)
+                .or_else(|| right.get_window_value(target)),
+            _ => None,
         }
     }
 
@@ -78,6 +147,9 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_value(target_table_alias, target)
                 .or_else(|| right.get_alias_value(target_table_alias, target)),
+            Self::Window { next, .. } | Self::Using { next, .. } => {
+                next.get_alias_value(target_table_alias, target)
+            }
             _ => None,
         }
     }
@@ -94,6 +166,7 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_entries(alias)
                 .or_else(|| right.get_alias_entries(alias)),
+            Self::Window { next, .. } | Self::Using { next, .. } => next.get_alias_entries(alias),
             _ => None,
         }
     }
@@ -115,6 +188,20 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => {
                 [left.get_all_entries(), right.get_all_entries()].concat()
             }
+            Self::Window { next, .. } => next.get_all_entries(),
+            Self::Using {
+                columns,
+                values,
+                next,
+            } => columns
+                .iter()
+                .zip(values.iter().cloned())
+                .chain(
+                    next.get_all_entries()
+                        .into_iter()
+                        .filter(|(column, _)| !columns.contains(column)),
+                )
+                .collect(),
             _ => vec![],
         }
     }
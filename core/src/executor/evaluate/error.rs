@@ -11,6 +11,9 @@ pub enum EvaluateError {
     #[serde(serialize_with = "error_serialize")]
     FormatParseError(#[from] chrono::format::ParseError),
 
+    #[error("placeholder is not bound: ${0}")]
+    UnboundPlaceholder(usize),
+
     #[error("literal add on non-numeric")]
     LiteralAddOnNonNumeric,
 
@@ -74,6 +77,9 @@ pub enum EvaluateError {
     #[error("unreachable empty aggregate value: {0:?}")]
     UnreachableEmptyAggregateValue(Aggregate),
 
+    #[error("window function is not allowed here: {0}")]
+    WindowFunctionNotAllowed(String),
+
     #[error("the divisor should not be zero")]
     DivisorShouldNotBeZero,
 
@@ -6,8 +6,8 @@ mod function;
 use {
     super::{context::RowContext, select::select},
     crate::{
-        ast::{Aggregate, Expr, Function},
-        data::{CustomFunction, Interval, Literal, Row, Value},
+        ast::{Aggregate, Expr, Function, ToSql},
+        data::{CustomFunction, Interval, Literal, Row, SequenceError, Value},
         mock::MockStorage,
         result::{Error, Result},
         store::GStore,
@@ -63,6 +63,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
         Expr::TypedString { data_type, value } => {
             expr::typed_string(data_type, Cow::Borrowed(value))
         }
+        Expr::Placeholder(n) => Err(EvaluateError::UnboundPlaceholder(*n).into()),
         Expr::Identifier(ident) => {
             let context = context
                 .ok_or_else(|| EvaluateError::ContextRequiredForIdentEvaluation(expr.clone()))?;
@@ -143,6 +144,13 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             Some(value) => Ok(Evaluated::from(value.clone())),
             None => Err(EvaluateError::UnreachableEmptyAggregateValue(*aggr.clone()).into()),
         },
+        Expr::Window(window) => match context
+            .as_ref()
+            .and_then(|context| context.get_window_value(window))
+        {
+            Some(value) => Ok(Evaluated::from(value.clone())),
+            None => Err(EvaluateError::WindowFunctionNotAllowed(window.to_sql()).into()),
+        },
         Expr::Function(func) => {
             let context = context.as_ref().map(Rc::clone);
             let aggregated = aggregated.as_ref().map(Rc::clone);
@@ -331,6 +339,27 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             f::concat(exprs)
         }
+        // --- sequence ---
+        Function::NextVal(_) => Err(SequenceError::NextValNotSupported.into()),
+        Function::CurrVal(expr) => {
+            let sequence_name = eval(expr).await?;
+            let sequence_name = match Value::try_from(sequence_name)? {
+                Value::Str(sequence_name) => sequence_name,
+                value => {
+                    return Err(SequenceError::SequenceNameNotString(String::from(&value)).into())
+                }
+            };
+
+            storage
+                .ok_or_else(|| {
+                    EvaluateError::UnsupportedStatelessExpr(Expr::Function(Box::new(func.clone())))
+                })?
+                .fetch_sequence(&sequence_name)
+                .await?
+                .ok_or(SequenceError::SequenceNotFound(sequence_name))?
+                .current_value()
+                .map(|value| Evaluated::from(Value::I64(value)))
+        }
         Function::Custom { name, exprs } => {
             let CustomFunction {
                 func_name,
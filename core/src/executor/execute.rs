@@ -1,20 +1,30 @@
 use {
     super::{
         alter::{
-            alter_table, create_index, create_table, delete_function, drop_table, insert_function,
+            alter_table, create_index, create_materialized_view, create_sequence, create_table,
+            create_view, delete_function, drop_sequence, drop_table, drop_view, insert_function,
+            refresh_materialized_view,
         },
+        analyze::analyze,
+        explain::{explain, ExplainError},
         fetch::{fetch, fetch_columns},
+        foreign_key::{check_references, delete_rows, update_rows},
         insert::insert,
-        select::{select, select_with_labels},
+        join::JoinedRows,
+        profile::Profile,
+        returning::project_returning,
+        select::{select, select_with_labels, select_with_profile},
+        sequence::{bind_sequences, SequenceBinder},
         update::Update,
-        validate::{validate_unique, ColumnValidation},
+        validate::{validate_checks, validate_unique, ColumnValidation},
     },
     crate::{
         ast::{
             AstLiteral, BinaryOperator, DataType, Dictionary, Expr, Query, SelectItem, SetExpr,
-            Statement, TableAlias, TableFactor, TableWithJoins, Variable,
+            Statement, TableAlias, TableFactor, TableWithJoins, ToSql, Variable,
         },
         data::{Key, Row, Schema, Value},
+        plan::fetch_schema_map,
         result::Result,
         store::{GStore, GStoreMut},
     },
@@ -28,6 +38,9 @@ use {
 pub enum ExecuteError {
     #[error("table not found: {0}")]
     TableNotFound(String),
+
+    #[error("materialized view cannot be modified: {0}")]
+    MaterializedViewModified(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -44,9 +57,13 @@ pub enum Payload {
     Update(usize),
     DropTable,
     DropFunction,
+    DropView,
+    RefreshMaterializedView,
+    DropSequence,
     AlterTable,
     CreateIndex,
     DropIndex,
+    Analyze,
     StartTransaction,
     Commit,
     Rollback,
@@ -57,6 +74,7 @@ pub enum Payload {
 pub enum PayloadVariable {
     Tables(Vec<String>),
     Functions(Vec<String>),
+    Views(Vec<String>),
     Version(String),
 }
 
@@ -92,7 +110,16 @@ async fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
 ) -> Result<Payload> {
-    match statement {
+    if let Statement::Insert { table_name, .. }
+    | Statement::Update { table_name, .. }
+    | Statement::Delete { table_name, .. } = statement
+    {
+        check_modifiable(storage, table_name).await?;
+    }
+
+    let statement = bind_sequences(storage, statement).await?;
+
+    match statement.as_ref() {
         //- Modification
         //-- Tables
         Statement::CreateTable {
@@ -101,7 +128,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
             if_not_exists,
             source,
             engine,
-            ..
+            foreign_keys,
+            checks,
+            unique_constraints,
         } => create_table(
             storage,
             name,
@@ -109,12 +138,15 @@ async fn execute_inner<T: GStore + GStoreMut>(
             *if_not_exists,
             source,
             engine,
+            (foreign_keys, checks, unique_constraints),
         )
         .await
         .map(|_| Payload::Create),
         Statement::DropTable {
-            names, if_exists, ..
-        } => drop_table(storage, names, *if_exists)
+            names,
+            if_exists,
+            cascade,
+        } => drop_table(storage, names, *if_exists, *cascade)
             .await
             .map(|_| Payload::DropTable),
         Statement::AlterTable { name, operation } => alter_table(storage, name, operation)
@@ -123,14 +155,50 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::CreateIndex {
             name,
             table_name,
-            column,
-        } => create_index(storage, table_name, name, column)
+            columns,
+        } => create_index(storage, table_name, name, columns)
             .await
             .map(|_| Payload::CreateIndex),
         Statement::DropIndex { name, table_name } => storage
             .drop_index(table_name, name)
             .await
             .map(|_| Payload::DropIndex),
+        Statement::Analyze { table_name } => analyze(storage, table_name.as_deref())
+            .await
+            .map(|_| Payload::Analyze),
+        //-- Views
+        Statement::CreateView {
+            or_replace,
+            name,
+            columns,
+            query,
+        } => create_view(storage, name, columns, query, *or_replace)
+            .await
+            .map(|_| Payload::Create),
+        Statement::DropView { if_exists, names } => drop_view(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropView),
+        Statement::CreateMaterializedView {
+            name,
+            columns,
+            query,
+        } => create_materialized_view(storage, name, columns, query)
+            .await
+            .map(|_| Payload::Create),
+        Statement::RefreshMaterializedView { name } => refresh_materialized_view(storage, name)
+            .await
+            .map(|_| Payload::RefreshMaterializedView),
+        //-- Sequences
+        Statement::CreateSequence {
+            if_not_exists,
+            name,
+            options,
+        } => create_sequence(storage, name, options, *if_not_exists)
+            .await
+            .map(|_| Payload::Create),
+        Statement::DropSequence { if_exists, names } => drop_sequence(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropSequence),
         //- Transaction
         Statement::StartTransaction => storage
             .begin(false)
@@ -143,15 +211,34 @@ async fn execute_inner<T: GStore + GStoreMut>(
             table_name,
             columns,
             source,
-        } => insert(storage, table_name, columns, source)
+            on_conflict,
+            returning,
+        } => {
+            insert(
+                storage,
+                table_name,
+                columns,
+                source,
+                on_conflict.as_ref(),
+                returning,
+            )
             .await
-            .map(Payload::Insert),
+        }
         Statement::Update {
             table_name,
             selection,
             assignments,
+            from,
+            join_executor,
+            returning,
         } => {
-            let Schema { column_defs, .. } = storage
+            let Schema {
+                column_defs,
+                foreign_keys,
+                checks,
+                unique_constraints,
+                ..
+            } = storage
                 .fetch_schema(table_name)
                 .await?
                 .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
@@ -162,67 +249,171 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     .map(|col_def| col_def.name.to_owned())
                     .collect()
             });
-            let columns_to_update = assignments
+            let columns_to_update: Vec<String> = assignments
                 .iter()
                 .map(|assignment| assignment.id.to_owned())
                 .collect();
+            let foreign_keys = foreign_keys
+                .into_iter()
+                .filter(|foreign_key| {
+                    foreign_key
+                        .columns
+                        .iter()
+                        .any(|column| columns_to_update.contains(column))
+                })
+                .collect::<Vec<_>>();
 
-            let update = Update::new(storage, table_name, assignments, column_defs.as_deref())?;
+            let sequences = SequenceBinder::default();
+            let update = Update::new(
+                storage,
+                table_name,
+                assignments,
+                column_defs.as_deref(),
+                &unique_constraints,
+                Some(&sequences),
+            )?;
 
-            let rows = fetch(storage, table_name, all_columns, selection.as_ref())
-                .await?
-                .and_then(|item| {
-                    let update = &update;
-                    let (key, row) = item;
+            let rows = match from {
+                Some(from) => {
+                    let storage = &*storage;
+                    let joined_rows = JoinedRows::fetch(storage, from, join_executor).await?;
 
-                    async move {
-                        let row = update.apply(row).await?;
+                    fetch(storage, table_name, all_columns, None)
+                        .await?
+                        .try_filter_map(|(key, row)| {
+                            let update = &update;
+                            let joined_rows = &joined_rows;
 
-                        Ok((key, row))
-                    }
-                })
-                .try_collect::<Vec<(Key, Row)>>()
-                .await?;
+                            async move {
+                                let joined_row = joined_rows
+                                    .find(storage, table_name, &row, selection.as_ref())
+                                    .await?;
+
+                                match joined_row {
+                                    Some(joined_row) => {
+                                        let row = update.apply_with(row, Some(joined_row)).await?;
+
+                                        Ok(Some((key, row)))
+                                    }
+                                    None => Ok(None),
+                                }
+                            }
+                        })
+                        .try_collect::<Vec<(Key, Row)>>()
+                        .await?
+                }
+                None => {
+                    fetch(storage, table_name, all_columns, selection.as_ref())
+                        .await?
+                        .and_then(|item| {
+                            let update = &update;
+                            let (key, row) = item;
+
+                            async move {
+                                let row = update.apply(row).await?;
+
+                                Ok((key, row))
+                            }
+                        })
+                        .try_collect::<Vec<(Key, Row)>>()
+                        .await?
+                }
+            };
 
             if let Some(column_defs) = column_defs {
-                let column_validation =
-                    ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update);
-                let rows = rows.iter().filter_map(|(_, row)| match row {
-                    Row::Vec { values, .. } => Some(values.as_slice()),
-                    Row::Map(_) => None,
-                });
-
-                validate_unique(storage, table_name, column_validation, rows).await?;
+                let values = rows
+                    .iter()
+                    .filter_map(|(_, row)| match row {
+                        Row::Vec { values, .. } => Some(values.as_slice()),
+                        Row::Map(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                if !checks.is_empty() || !foreign_keys.is_empty() {
+                    let labels = column_defs
+                        .iter()
+                        .map(|column_def| column_def.name.to_owned())
+                        .collect::<Vec<_>>();
+
+                    validate_checks(&checks, &labels, &values).await?;
+                    check_references(storage, table_name, &labels, &foreign_keys, &values).await?;
+                }
+
+                let column_validation = ColumnValidation::SpecifiedColumns(
+                    &column_defs,
+                    &unique_constraints,
+                    columns_to_update.clone(),
+                );
+
+                validate_unique(storage, table_name, column_validation, values.into_iter()).await?;
             }
 
+            sequences.save(storage).await?;
+
             let num_rows = rows.len();
+            let returned = match returning.is_empty() {
+                true => None,
+                false => {
+                    let returned_rows = rows.iter().map(|(_, row)| row.clone()).collect();
+
+                    Some(project_returning(storage, table_name, returning, returned_rows).await?)
+                }
+            };
             let rows = rows
                 .into_iter()
                 .map(|(key, row)| (key, row.into()))
                 .collect();
 
-            storage
-                .insert_data(table_name, rows)
-                .await
-                .map(|_| Payload::Update(num_rows))
+            update_rows(storage, table_name, &columns_to_update, rows).await?;
+
+            Ok(returned.unwrap_or(Payload::Update(num_rows)))
         }
         Statement::Delete {
             table_name,
+            using,
+            join_executor,
             selection,
+            returning,
         } => {
             let columns = fetch_columns(storage, table_name).await?.map(Rc::from);
-            let keys = fetch(storage, table_name, columns, selection.as_ref())
-                .await?
-                .map_ok(|(key, _)| key)
-                .try_collect::<Vec<_>>()
-                .await?;
+            let rows = match using {
+                Some(using) => {
+                    let storage = &*storage;
+                    let joined_rows = JoinedRows::fetch(storage, using, join_executor).await?;
+
+                    fetch(storage, table_name, columns, None)
+                        .await?
+                        .try_filter_map(|(key, row)| {
+                            let joined_rows = &joined_rows;
 
+                            async move {
+                                joined_rows
+                                    .find(storage, table_name, &row, selection.as_ref())
+                                    .await
+                                    .map(|joined_row| joined_row.map(|_| (key, row)))
+                            }
+                        })
+                        .try_collect::<Vec<_>>()
+                        .await?
+                }
+                None => {
+                    fetch(storage, table_name, columns, selection.as_ref())
+                        .await?
+                        .try_collect::<Vec<_>>()
+                        .await?
+                }
+            };
+
+            let (keys, rows): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+            let returned = match returning.is_empty() {
+                true => None,
+                false => Some(project_returning(storage, table_name, returning, rows).await?),
+            };
             let num_keys = keys.len();
 
-            storage
-                .delete_data(table_name, keys)
-                .await
-                .map(|_| Payload::Delete(num_keys))
+            delete_rows(storage, table_name, keys).await?;
+
+            Ok(returned.unwrap_or(Payload::Delete(num_keys)))
         }
 
         //- Selection
@@ -256,9 +447,41 @@ async fn execute_inner<T: GStore + GStoreMut>(
 
             Ok(Payload::ShowColumns(output))
         }
+        Statement::Explain {
+            analyze: false,
+            statement,
+        } => {
+            let schema_map = fetch_schema_map(storage, statement).await?;
+
+            explain(&schema_map, statement, None)
+        }
+        Statement::Explain {
+            analyze: true,
+            statement,
+        } => {
+            let query = match statement.as_ref() {
+                Statement::Query(query) => query,
+                _ => {
+                    return Err(
+                        ExplainError::AnalyzeUnsupportedStatement(statement.to_sql()).into(),
+                    )
+                }
+            };
+
+            let schema_map = fetch_schema_map(storage, statement).await?;
+            let profile = Rc::new(Profile::default());
+            select_with_profile(storage, query, Rc::clone(&profile))
+                .await?
+                .try_for_each(|_| async { Ok(()) })
+                .await?;
+
+            explain(&schema_map, statement, Some(&profile))
+        }
         Statement::ShowIndexes(table_name) => {
             let query = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(crate::ast::Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Dictionary {
@@ -279,6 +502,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     }),
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 order_by: Vec::new(),
                 limit: None,
@@ -301,7 +525,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::ShowVariable(variable) => match variable {
             Variable::Tables => {
                 let query = Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(crate::ast::Select {
+                        distinct: None,
                         projection: vec![SelectItem::Expr {
                             expr: Expr::Identifier("TABLE_NAME".to_owned()),
                             label: "TABLE_NAME".to_owned(),
@@ -319,6 +545,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
+                        plan_id: None,
                     })),
                     order_by: Vec::new(),
                     limit: None,
@@ -348,6 +575,16 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     function_desc,
                 )))
             }
+            Variable::Views => {
+                let mut view_names: Vec<_> = storage
+                    .fetch_all_views()
+                    .await?
+                    .into_iter()
+                    .map(|view| view.view_name)
+                    .collect();
+                view_names.sort();
+                Ok(Payload::ShowVariable(PayloadVariable::Views(view_names)))
+            }
             Variable::Version => {
                 let version = var("CARGO_PKG_VERSION")
                     .unwrap_or_else(|_| env!("CARGO_PKG_VERSION").to_owned());
@@ -369,3 +606,18 @@ async fn execute_inner<T: GStore + GStoreMut>(
             .map(|_| Payload::DropFunction),
     }
 }
+
+/// Rows of a materialized view are only written by `REFRESH MATERIALIZED VIEW`, which would
+/// discard any other change.
+async fn check_modifiable<T: GStore>(storage: &T, table_name: &str) -> Result<()> {
+    let is_materialized_view = storage
+        .fetch_schema(table_name)
+        .await?
+        .map(|schema| schema.view_query.is_some())
+        .unwrap_or(false);
+
+    match is_materialized_view {
+        true => Err(ExecuteError::MaterializedViewModified(table_name.to_owned()).into()),
+        false => Ok(()),
+    }
+}
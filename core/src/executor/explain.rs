@@ -0,0 +1,673 @@
+use {
+    super::{
+        aggregate::is_aggregate,
+        execute::Payload,
+        profile::{Profile, Stage, StageStats},
+        window::collect_windows,
+    },
+    crate::{
+        ast::{
+            Assignment, Cte, Distinct, Expr, IndexCondition, IndexItem, Join, JoinConstraint,
+            JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
+            TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values, With,
+        },
+        data::{Schema, Value},
+        result::Result,
+    },
+    itertools::Itertools,
+    serde::Serialize,
+    std::{collections::HashMap, ops::Bound},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum ExplainError {
+    #[error("EXPLAIN is not supported for the statement: {0}")]
+    UnsupportedStatement(String),
+
+    #[error("EXPLAIN ANALYZE is only supported for queries: {0}")]
+    AnalyzeUnsupportedStatement(String),
+}
+
+/// A step of the plan, rendered as a row indented by its depth in the plan tree.
+struct PlanNode {
+    title: String,
+    /// Measured by `EXPLAIN ANALYZE`
+    stats: Option<StageStats>,
+    children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    fn new<S: Into<String>>(title: S) -> Self {
+        Self {
+            title: title.into(),
+            stats: None,
+            children: Vec::new(),
+        }
+    }
+
+    fn with_child(mut self, child: PlanNode) -> Self {
+        self.children.push(child);
+
+        self
+    }
+
+    fn with_stats(mut self, stats: Option<StageStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
+
+    /// Stats of the nearest measured step this one pulls its rows from, the first child
+    /// is the outer side of a join.
+    fn input_stats(&self) -> Option<StageStats> {
+        let child = self.children.first()?;
+
+        child.stats.or_else(|| child.input_stats())
+    }
+
+    fn render(self, depth: usize, analyze: bool, rows: &mut Vec<Vec<Value>>) {
+        let input_stats = self.input_stats();
+        let PlanNode {
+            title,
+            stats,
+            children,
+        } = self;
+
+        let line = match depth {
+            0 => title,
+            _ => format!("{}-> {}", "  ".repeat(depth), title),
+        };
+
+        let row = match (analyze, stats) {
+            (false, _) => vec![Value::Str(line)],
+            (true, None) => vec![Value::Str(line), Value::Null, Value::Null, Value::Null],
+            (true, Some(StageStats { rows, elapsed })) => {
+                let (rows_in, elapsed) = match input_stats {
+                    Some(input) => (Value::I64(input.rows as i64), elapsed - input.elapsed),
+                    None => (Value::Null, elapsed),
+                };
+                let elapsed = elapsed.num_microseconds().unwrap_or(i64::MAX).max(0) as f64;
+
+                vec![
+                    Value::Str(line),
+                    rows_in,
+                    Value::I64(rows as i64),
+                    Value::F64(elapsed / 1000.0),
+                ]
+            }
+        };
+
+        rows.push(row);
+
+        for child in children {
+            child.render(depth + 1, analyze, rows);
+        }
+    }
+}
+
+struct Explainer<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+    profile: Option<&'a Profile>,
+    /// Names of the CTEs in scope, which are scanned instead of the tables
+    ctes: Vec<&'a str>,
+}
+
+/// Shows the plan of a statement which is already planned, outer steps first: table access
+/// methods with the chosen indexes, join strategies and the filters applied at each step.
+///
+/// With the profile of running the same statement, the rows in and out and the time spent
+/// in each step are shown as well. The time of a step excludes the steps it pulls from.
+pub fn explain(
+    schema_map: &HashMap<String, Schema>,
+    statement: &Statement,
+    profile: Option<&Profile>,
+) -> Result<Payload> {
+    let explainer = Explainer {
+        schema_map,
+        profile,
+        ctes: Vec::new(),
+    };
+
+    let node = match statement {
+        Statement::Query(query) => explainer.explain_query(query),
+        Statement::Insert {
+            table_name, source, ..
+        } => PlanNode::new(format!("Insert on {table_name}"))
+            .with_child(explainer.explain_query(source)),
+        Statement::Update {
+            table_name,
+            assignments,
+            from,
+            join_executor,
+            selection,
+            ..
+        } => {
+            let assignments = assignments
+                .iter()
+                .map(|Assignment { id, value }| format!("{id} = {}", value.to_sql_unquoted()))
+                .join(", ");
+
+            PlanNode::new(format!("Update on {table_name}: {assignments}")).with_child(
+                explainer.explain_target(
+                    table_name,
+                    from.as_ref(),
+                    join_executor,
+                    selection.as_ref(),
+                ),
+            )
+        }
+        Statement::Delete {
+            table_name,
+            using,
+            join_executor,
+            selection,
+            ..
+        } => PlanNode::new(format!("Delete on {table_name}")).with_child(explainer.explain_target(
+            table_name,
+            using.as_ref(),
+            join_executor,
+            selection.as_ref(),
+        )),
+        _ => return Err(ExplainError::UnsupportedStatement(statement.to_sql()).into()),
+    };
+
+    let mut rows = Vec::new();
+    node.render(0, profile.is_some(), &mut rows);
+
+    let labels = match profile {
+        Some(_) => vec!["QUERY PLAN", "ROWS IN", "ROWS OUT", "ELAPSED MS"],
+        None => vec!["QUERY PLAN"],
+    };
+    let labels = labels.into_iter().map(ToOwned::to_owned).collect();
+
+    Ok(Payload::Select { labels, rows })
+}
+
+impl<'a> Explainer<'a> {
+    fn stats(&self, select: Option<&Select>, stage: Stage) -> Option<StageStats> {
+        self.profile?.get(select?, stage)
+    }
+
+    /// Rows of `UPDATE` and `DELETE` are scanned from the table, joined with the rows of
+    /// `FROM` or `USING` when given.
+    fn explain_target(
+        &self,
+        table_name: &str,
+        joined: Option<&'a TableWithJoins>,
+        join_executor: &JoinExecutor,
+        selection: Option<&Expr>,
+    ) -> PlanNode {
+        let scan = PlanNode::new(format!("Seq Scan on {table_name}"));
+        let table_with_joins = match joined {
+            Some(table_with_joins) => table_with_joins,
+            None => return explain_filter(selection, scan),
+        };
+        let joined = self.explain_table_with_joins(table_with_joins);
+
+        match join_executor {
+            JoinExecutor::NestedLoop => {
+                let title = match selection {
+                    Some(expr) => format!("Nested Loop Join: {}", expr.to_sql_unquoted()),
+                    None => "Nested Loop Join".to_owned(),
+                };
+
+                PlanNode::new(title).with_child(scan).with_child(joined)
+            }
+            JoinExecutor::Hash {
+                key_expr,
+                value_expr,
+                where_clause,
+            } => {
+                let hash_key = format!(
+                    "{} = {}",
+                    key_expr.to_sql_unquoted(),
+                    value_expr.to_sql_unquoted()
+                );
+                let title = match selection {
+                    Some(expr) => format!("Hash Join: {hash_key} AND {}", expr.to_sql_unquoted()),
+                    None => format!("Hash Join: {hash_key}"),
+                };
+
+                PlanNode::new(title)
+                    .with_child(scan)
+                    .with_child(explain_filter(where_clause.as_ref(), joined))
+            }
+        }
+    }
+
+    fn explain_query(&self, query: &'a Query) -> PlanNode {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let scoped;
+        let explainer = match with {
+            Some(With { cte_tables, .. }) => {
+                let ctes = cte_tables
+                    .iter()
+                    .map(|Cte { alias, .. }| alias.name.as_str());
+
+                scoped = Explainer {
+                    schema_map: self.schema_map,
+                    profile: self.profile,
+                    ctes: self.ctes.iter().copied().chain(ctes).collect(),
+                };
+
+                &scoped
+            }
+            None => self,
+        };
+
+        let select = match body {
+            SetExpr::Select(select) => Some(select.as_ref()),
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
+        };
+        let stats = |stage| explainer.stats(select, stage);
+
+        let (node, distinct) = match body {
+            SetExpr::Select(select) => (
+                explainer.explain_select(select, order_by),
+                select.distinct.as_ref(),
+            ),
+            SetExpr::Values(Values(values_list)) => (
+                PlanNode::new(format!("Values: {} rows", values_list.len())),
+                None,
+            ),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let all = all.then_some(" ALL").unwrap_or_default();
+                let node = PlanNode::new(format!("Set Operation: {op}{all}"))
+                    .with_child(explainer.explain_query(left))
+                    .with_child(explainer.explain_query(right));
+
+                (node, None)
+            }
+        };
+
+        let node = match order_by.is_empty() {
+            true => node,
+            false => {
+                let order_by = order_by
+                    .iter()
+                    .map(ToSqlUnquoted::to_sql_unquoted)
+                    .join(", ");
+
+                PlanNode::new(format!("Sort: {order_by}"))
+                    .with_stats(stats(Stage::Sort))
+                    .with_child(node)
+            }
+        };
+
+        let node = match distinct {
+            Some(Distinct::Distinct) => PlanNode::new("Distinct")
+                .with_stats(stats(Stage::Distinct))
+                .with_child(node),
+            Some(Distinct::On(exprs)) => {
+                let exprs = exprs.iter().map(ToSqlUnquoted::to_sql_unquoted).join(", ");
+
+                PlanNode::new(format!("Distinct On: {exprs}"))
+                    .with_stats(stats(Stage::Distinct))
+                    .with_child(node)
+            }
+            None => node,
+        };
+
+        let limit = match (limit, offset) {
+            (None, None) => None,
+            (Some(limit), None) => Some(limit.to_sql_unquoted()),
+            (None, Some(offset)) => Some(format!("OFFSET {}", offset.to_sql_unquoted())),
+            (Some(limit), Some(offset)) => Some(format!(
+                "{} OFFSET {}",
+                limit.to_sql_unquoted(),
+                offset.to_sql_unquoted()
+            )),
+        };
+        let node = match limit {
+            Some(limit) => PlanNode::new(format!("Limit: {limit}"))
+                .with_stats(stats(Stage::Limit))
+                .with_child(node),
+            None => node,
+        };
+
+        match with {
+            Some(With {
+                recursive,
+                cte_tables,
+            }) => {
+                let ctes = cte_tables.iter().map(|Cte { alias, query }| {
+                    let recursive = recursive.then_some("Recursive ").unwrap_or_default();
+
+                    PlanNode::new(format!("{recursive}CTE {}", alias.name))
+                        .with_child(explainer.explain_query(query))
+                });
+
+                PlanNode {
+                    children: ctes.chain(node.children).collect(),
+                    ..node
+                }
+            }
+            None => node,
+        }
+    }
+
+    /// Steps of `SELECT` up to the projection, in the order the executor streams rows through
+    /// them.
+    fn explain_select(&self, select: &'a Select, order_by: &[OrderByExpr]) -> PlanNode {
+        let Select {
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+            ..
+        } = select;
+        let stats = |stage| self.stats(Some(select), stage);
+
+        let TableWithJoins { relation, joins } = from;
+        let node = self
+            .explain_table_factor(relation)
+            .with_stats(stats(Stage::Fetch));
+        let node = match joins.is_empty() {
+            true => node,
+            false => joins
+                .iter()
+                .fold(node, |node, join| self.explain_join(node, join))
+                .with_stats(stats(Stage::Join)),
+        };
+        let node = match selection {
+            Some(expr) => explain_filter(Some(expr), node).with_stats(stats(Stage::Filter)),
+            None => node,
+        };
+
+        let node = match is_aggregate(projection, group_by) {
+            true => {
+                let group_by = match group_by.is_empty() {
+                    true => None,
+                    false => Some(format!(
+                        "GROUP BY {}",
+                        group_by
+                            .iter()
+                            .map(ToSqlUnquoted::to_sql_unquoted)
+                            .join(", ")
+                    )),
+                };
+                let having = having
+                    .as_ref()
+                    .map(|having| format!("HAVING {}", having.to_sql_unquoted()));
+                let title = match group_by.into_iter().chain(having).join(" ").as_str() {
+                    "" => "Aggregate".to_owned(),
+                    detail => format!("Aggregate: {detail}"),
+                };
+
+                PlanNode::new(title)
+                    .with_stats(stats(Stage::Aggregate))
+                    .with_child(node)
+            }
+            false => node,
+        };
+
+        let mut windows = Vec::new();
+        projection
+            .iter()
+            .filter_map(|item| match item {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            })
+            .chain(order_by.iter().map(|OrderByExpr { expr, .. }| expr))
+            .for_each(|expr| collect_windows(expr, &mut windows));
+
+        let node = match windows.is_empty() {
+            true => node,
+            false => {
+                let windows = windows.into_iter().map(ToSql::to_sql).join(", ");
+
+                PlanNode::new(format!("Window: {windows}"))
+                    .with_stats(stats(Stage::Window))
+                    .with_child(node)
+            }
+        };
+
+        let projection = projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::Expr { expr, label } if &expr.to_sql_unquoted() == label => {
+                    label.to_owned()
+                }
+                _ => item.to_sql_unquoted(),
+            })
+            .join(", ");
+
+        PlanNode::new(format!("Project: {projection}"))
+            .with_stats(stats(Stage::Project))
+            .with_child(node)
+    }
+
+    fn explain_table_with_joins(&self, table_with_joins: &'a TableWithJoins) -> PlanNode {
+        let TableWithJoins { relation, joins } = table_with_joins;
+
+        joins
+            .iter()
+            .fold(self.explain_table_factor(relation), |node, join| {
+                self.explain_join(node, join)
+            })
+    }
+
+    fn explain_join(&self, node: PlanNode, join: &'a Join) -> PlanNode {
+        let Join {
+            relation,
+            join_operator,
+            join_executor,
+        } = join;
+
+        let (join_operator, join_constraint) = match join_operator {
+            JoinOperator::Inner(join_constraint) => ("INNER", join_constraint),
+            JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER", join_constraint),
+            JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER", join_constraint),
+            JoinOperator::CrossJoin => ("CROSS", &JoinConstraint::None),
+        };
+        let join_constraint = match join_constraint {
+            JoinConstraint::On(expr) => Some(expr.to_sql_unquoted()),
+            JoinConstraint::Using(columns) => Some(format!("USING ({})", columns.join(", "))),
+            JoinConstraint::Natural => Some("NATURAL".to_owned()),
+            JoinConstraint::None => None,
+        };
+        let relation = self.explain_table_factor(relation);
+
+        match join_executor {
+            JoinExecutor::NestedLoop => {
+                let title = match join_constraint {
+                    Some(join_constraint) => {
+                        format!("Nested Loop Join: {join_operator} ON {join_constraint}")
+                    }
+                    None => format!("Nested Loop Join: {join_operator}"),
+                };
+
+                PlanNode::new(title).with_child(node).with_child(relation)
+            }
+            JoinExecutor::Hash {
+                key_expr,
+                value_expr,
+                where_clause,
+            } => {
+                let hash_key = format!(
+                    "{} = {}",
+                    key_expr.to_sql_unquoted(),
+                    value_expr.to_sql_unquoted()
+                );
+                let title = match join_constraint {
+                    Some(join_constraint) => {
+                        format!("Hash Join: {join_operator} ON {hash_key} AND {join_constraint}")
+                    }
+                    None => format!("Hash Join: {join_operator} ON {hash_key}"),
+                };
+
+                PlanNode::new(title)
+                    .with_child(node)
+                    .with_child(explain_filter(where_clause.as_ref(), relation))
+            }
+        }
+    }
+
+    fn explain_table_factor(&self, table_factor: &'a TableFactor) -> PlanNode {
+        let alias_of = |name: &str, alias: &TableAlias| match alias.name == name {
+            true => "".to_owned(),
+            false => format!(" AS {}", alias.name),
+        };
+
+        match table_factor {
+            TableFactor::Table { name, alias, index } => {
+                let alias = alias
+                    .as_ref()
+                    .map(|alias| alias_of(name, alias))
+                    .unwrap_or_default();
+                let schema = self.schema_map.get(name);
+                let primary_key = || {
+                    schema
+                        .and_then(|schema| {
+                            let column_defs = schema.column_defs.as_ref()?;
+
+                            match schema.primary_key_indexes()?.as_slice() {
+                                [i] => column_defs.get(*i).map(|column_def| &column_def.name),
+                                _ => None,
+                            }
+                        })
+                        .map(String::as_str)
+                        .unwrap_or("PRIMARY KEY")
+                };
+
+                match (index, schema) {
+                    (None, _) if self.ctes.contains(&name.as_str()) => {
+                        PlanNode::new(format!("CTE Scan on {name}{alias}"))
+                    }
+                    (None, _) => PlanNode::new(format!("Seq Scan on {name}{alias}")),
+                    (Some(IndexItem::PrimaryKey(expr)), _) => PlanNode::new(format!(
+                        "Primary Key Scan on {name}{alias}: {} = {}",
+                        primary_key(),
+                        expr.to_sql_unquoted()
+                    )),
+                    (Some(IndexItem::PrimaryKeyRange { lower, upper, asc }), _) => {
+                        let conditions = range_conditions(primary_key(), lower, upper);
+                        let order = match asc {
+                            true => "",
+                            false => " (DESC)",
+                        };
+                        let title = format!("Primary Key Scan on {name}{alias}");
+                        let title = match conditions.as_str() {
+                            "" => format!("{title}{order}"),
+                            conditions => format!("{title}: {conditions}{order}"),
+                        };
+
+                        PlanNode::new(title)
+                    }
+                    (
+                        Some(IndexItem::NonClustered {
+                            name: index_name,
+                            asc,
+                            prefix,
+                            condition,
+                        }),
+                        _,
+                    ) => {
+                        let index_exprs = schema
+                            .and_then(|schema| {
+                                schema
+                                    .indexes
+                                    .iter()
+                                    .find(|index| &index.name == index_name)
+                            })
+                            .map(|index| index.exprs.as_slice())
+                            .unwrap_or_default();
+                        let index_expr = |i: usize| {
+                            index_exprs
+                                .get(i)
+                                .map(ToSqlUnquoted::to_sql_unquoted)
+                                .unwrap_or_else(|| format!("({i})"))
+                        };
+
+                        let conditions = prefix
+                            .iter()
+                            .enumerate()
+                            .map(|(i, expr)| {
+                                format!("{} = {}", index_expr(i), expr.to_sql_unquoted())
+                            })
+                            .chain(condition.iter().map(|condition| {
+                                let index_expr = index_expr(prefix.len());
+
+                                match condition {
+                                    IndexCondition::Range {
+                                        lower: Bound::Included(lower),
+                                        upper: Bound::Included(upper),
+                                    } if lower == upper => {
+                                        format!("{index_expr} = {}", lower.to_sql_unquoted())
+                                    }
+                                    IndexCondition::Range { lower, upper } => {
+                                        range_conditions(&index_expr, lower, upper)
+                                    }
+                                    IndexCondition::InList(list) => format!(
+                                        "{index_expr} IN ({})",
+                                        list.iter().map(ToSqlUnquoted::to_sql_unquoted).join(", ")
+                                    ),
+                                }
+                            }))
+                            .join(" AND ");
+                        let order = match asc {
+                            Some(true) => " (ASC)",
+                            Some(false) => " (DESC)",
+                            None => "",
+                        };
+                        let title = format!("Index Scan using {index_name} on {name}{alias}");
+                        let title = match conditions.as_str() {
+                            "" => format!("{title}{order}"),
+                            conditions => format!("{title}: {conditions}{order}"),
+                        };
+
+                        PlanNode::new(title)
+                    }
+                }
+            }
+            TableFactor::Derived { subquery, alias } => {
+                PlanNode::new(format!("Subquery Scan on {}", alias.name))
+                    .with_child(self.explain_query(subquery))
+            }
+            TableFactor::Series { alias, size } => PlanNode::new(format!(
+                "Series Scan on {}: SERIES({})",
+                alias.name,
+                size.to_sql_unquoted()
+            )),
+            TableFactor::Dictionary { dict, alias } => {
+                let alias = alias_of(&dict.to_string(), alias);
+
+                PlanNode::new(format!("Dictionary Scan on {dict}{alias}"))
+            }
+        }
+    }
+}
+
+fn range_conditions(target: &str, lower: &Bound<Expr>, upper: &Bound<Expr>) -> String {
+    [(lower, ">", ">="), (upper, "<", "<=")]
+        .into_iter()
+        .filter_map(|(bound, excluded, included)| match bound {
+            Bound::Included(expr) => Some((included, expr)),
+            Bound::Excluded(expr) => Some((excluded, expr)),
+            Bound::Unbounded => None,
+        })
+        .map(|(op, expr)| format!("{target} {op} {}", expr.to_sql_unquoted()))
+        .join(" AND ")
+}
+
+fn explain_filter(selection: Option<&Expr>, node: PlanNode) -> PlanNode {
+    match selection {
+        Some(expr) => PlanNode::new(format!("Filter: {}", expr.to_sql_unquoted())).with_child(node),
+        None => node,
+    }
+}
@@ -1,16 +1,25 @@
 use {
-    super::{context::RowContext, evaluate::evaluate_stateless, filter::check_expr},
+    super::{
+        context::{CteContext, CteTable, RowContext},
+        evaluate::evaluate_stateless,
+        filter::check_expr,
+        join::fetch_merged_columns,
+    },
     crate::{
         ast::{
             ToSql,
             {
-                ColumnDef, ColumnUniqueOption, Dictionary, Expr, IndexItem, Join, Query, Select,
+                Cte, DataType, Dictionary, Expr, IndexCondition, IndexItem, Join, Query, Select,
                 SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, ToSqlUnquoted,
-                Values,
+                Values, With,
             },
         },
         data::{get_alias, get_index, Key, Row, Value},
-        executor::{evaluate::evaluate, select::select},
+        executor::{
+            evaluate::evaluate,
+            profile::Profile,
+            select::{select_with_ctes, split_recursive},
+        },
         result::Result,
         store::{DataRow, GStore},
     },
@@ -19,7 +28,7 @@ use {
     iter_enum::Iterator,
     itertools::Itertools,
     serde::Serialize,
-    std::{borrow::Cow, collections::HashMap, fmt::Debug, iter, rc::Rc},
+    std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Debug, iter, ops::Bound, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -78,20 +87,23 @@ pub async fn fetch<'a, T: GStore>(
 }
 
 #[derive(futures_enum::Stream)]
-pub enum Rows<I1, I2, I3, I4> {
+pub enum Rows<I1, I2, I3, I4, I5> {
     Derived(I1),
     Table(I2),
     Series(I3),
     Dictionary(I4),
+    Cte(I5),
 }
 
 pub async fn fetch_relation_rows<'a, T: GStore>(
     storage: &'a T,
     table_factor: &'a TableFactor,
     filter_context: &Option<Rc<RowContext<'a>>>,
+    cte_context: &CteContext,
+    profile: &Option<Rc<Profile>>,
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
     let columns = Rc::from(
-        fetch_relation_columns(storage, table_factor)
+        fetch_relation_columns(storage, table_factor, cte_context)
             .await?
             .unwrap_or_default(),
     );
@@ -99,46 +111,92 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
     match table_factor {
         TableFactor::Derived { subquery, .. } => {
             let filter_context = filter_context.as_ref().map(Rc::clone);
-            let rows =
-                select(storage, subquery, filter_context)
-                    .await?
-                    .map_ok(move |row| match row {
-                        Row::Vec { values, .. } => Row::Vec {
-                            columns: Rc::clone(&columns),
-                            values,
-                        },
-                        Row::Map(values) => Row::Map(values),
-                    });
+            let profile = profile.as_ref().map(Rc::clone);
+            let rows = select_with_ctes(
+                storage,
+                subquery,
+                filter_context,
+                cte_context.clone(),
+                profile,
+            )
+            .await?
+            .1
+            .map_ok(move |row| match row {
+                Row::Vec { values, .. } => Row::Vec {
+                    columns: Rc::clone(&columns),
+                    values,
+                },
+                Row::Map(values) => Row::Map(values),
+            });
 
             Ok(Rows::Derived(rows))
         }
         TableFactor::Table { name, .. } => {
+            if let Some(CteTable { rows, .. }) = cte_context.get(name) {
+                let rows = Rc::clone(rows);
+                let rows = (0..rows.len()).map(move |i| {
+                    let row = match &rows[i] {
+                        Row::Vec { values, .. } => Row::Vec {
+                            columns: Rc::clone(&columns),
+                            values: values.clone(),
+                        },
+                        Row::Map(values) => Row::Map(values.clone()),
+                    };
+
+                    Ok(row)
+                });
+
+                return Ok(Rows::Cte(stream::iter(rows)));
+            }
+
             let rows = {
                 #[derive(Iterator)]
-                enum Rows<I1, I2, I3> {
+                enum Rows<I1, I2, I3, I4> {
                     Indexed(I1),
                     PrimaryKey(I2),
-                    FullScan(I3),
+                    PrimaryKeyRange(I3),
+                    FullScan(I4),
                 }
 
                 match get_index(table_factor) {
                     Some(IndexItem::NonClustered {
                         name: index_name,
                         asc,
-                        cmp_expr,
+                        prefix,
+                        condition,
                     }) => {
-                        let cmp_value = match cmp_expr {
-                            Some((op, expr)) => {
-                                let evaluated = evaluate(storage, None, None, expr).await?;
+                        let evaluate_all = |exprs: &'a [Expr]| {
+                            stream::iter(exprs)
+                                .then(|expr| async move {
+                                    evaluate(storage, None, None, expr)
+                                        .await
+                                        .and_then(Value::try_from)
+                                })
+                                .try_collect::<Vec<_>>()
+                        };
+
+                        let prefix = evaluate_all(prefix).await?;
+                        let ranges = match condition {
+                            Some(IndexCondition::Range { lower, upper }) => {
+                                let lower = evaluate_bound(storage, filter_context, lower).await?;
+                                let upper = evaluate_bound(storage, filter_context, upper).await?;
 
-                                Some((op, evaluated.try_into()?))
+                                vec![(lower, upper)]
                             }
-                            None => None,
+                            Some(IndexCondition::InList(list)) => {
+                                in_list_ranges(evaluate_all(list).await?, *asc)?
+                            }
+                            None => vec![(Bound::Unbounded, Bound::Unbounded)],
                         };
 
-                        let rows = storage
-                            .scan_indexed_data(name, index_name, *asc, cmp_value)
+                        let rows = stream::iter(ranges)
+                            .then(|range| {
+                                storage.scan_indexed_data(name, index_name, *asc, &prefix, range)
+                            })
+                            .try_collect::<Vec<_>>()
                             .await?
+                            .into_iter()
+                            .flatten()
                             .map_ok(move |(_, data_row)| match data_row {
                                 DataRow::Vec(values) => Row::Vec {
                                     columns: Rc::clone(&columns),
@@ -150,18 +208,31 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                         Rows::Indexed(rows)
                     }
                     Some(IndexItem::PrimaryKey(expr)) => {
+                        let data_type = fetch_primary_key_type(storage, name).await?;
                         let filter_context = filter_context.as_ref().map(Rc::clone);
-                        let key = evaluate(storage, filter_context, None, expr)
+                        let value = evaluate(storage, filter_context, None, expr)
                             .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)?;
+                            .and_then(Value::try_from)?;
 
-                        let rows = storage
-                            .fetch_data(name, &key)
-                            .await
-                            .transpose()
-                            .map(|row| vec![row])
-                            .unwrap_or_else(Vec::new);
+                        // a value which the key type cannot hold exactly matches no key
+                        let key = match data_type.map(|data_type| value.cast(&data_type)) {
+                            Some(Ok(casted)) => match value.evaluate_cmp(&casted) {
+                                Some(Ordering::Less | Ordering::Greater) => None,
+                                _ => Some(casted),
+                            },
+                            Some(Err(_)) => None,
+                            None => Some(value),
+                        };
+
+                        let rows = match key.map(Key::try_from).transpose()? {
+                            Some(key) => storage
+                                .fetch_data(name, &key)
+                                .await
+                                .transpose()
+                                .map(|row| vec![row])
+                                .unwrap_or_else(Vec::new),
+                            None => Vec::new(),
+                        };
 
                         Rows::PrimaryKey(rows.into_iter().map_ok(move |data_row| match data_row {
                             DataRow::Vec(values) => Row::Vec {
@@ -171,6 +242,26 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                             DataRow::Map(values) => Row::Map(values),
                         }))
                     }
+                    Some(IndexItem::PrimaryKeyRange { lower, upper, asc }) => {
+                        let data_type = fetch_primary_key_type(storage, name).await?;
+                        let lower = evaluate_bound(storage, filter_context, lower).await?;
+                        let upper = evaluate_bound(storage, filter_context, upper).await?;
+                        let lower = key_bound(lower, data_type.as_ref(), true)?;
+                        let upper = key_bound(upper, data_type.as_ref(), false)?;
+
+                        let rows = match lower.zip(upper) {
+                            Some(range) => storage.scan_range_data(name, range, *asc).await?,
+                            None => Box::new(iter::empty()),
+                        };
+
+                        Rows::PrimaryKeyRange(rows.map_ok(move |(_, data_row)| match data_row {
+                            DataRow::Vec(values) => Row::Vec {
+                                columns: Rc::clone(&columns),
+                                values,
+                            },
+                            DataRow::Map(values) => Row::Map(values),
+                        }))
+                    }
                     _ => {
                         let rows = storage.scan_data(name).await?.map_ok(move |(_, data_row)| {
                             match data_row {
@@ -231,9 +322,13 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                 })
                                 .unwrap_or_default();
 
+                            let object_type = match schema.view_query {
+                                Some(_) => "MATERIALIZED VIEW",
+                                None => "TABLE",
+                            };
                             let table_rows = HashMap::from([
                                 ("OBJECT_NAME".to_owned(), Value::Str(schema.table_name)),
-                                ("OBJECT_TYPE".to_owned(), Value::Str("TABLE".to_owned())),
+                                ("OBJECT_TYPE".to_owned(), Value::Str(object_type.to_owned())),
                             ])
                             .into_iter()
                             .chain(meta)
@@ -250,8 +345,29 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                 .chain(index_rows)
                                 .map(|hash_map| Ok(Row::Map(hash_map)))
                         });
+                        let view_rows = storage.fetch_all_views().await?.into_iter().map(|view| {
+                            Ok(Row::Map(HashMap::from([
+                                ("OBJECT_NAME".to_owned(), Value::Str(view.view_name)),
+                                ("OBJECT_TYPE".to_owned(), Value::Str("VIEW".to_owned())),
+                            ])))
+                        });
+
+                        let sequence_rows =
+                            storage
+                                .fetch_all_sequences()
+                                .await?
+                                .into_iter()
+                                .map(|sequence| {
+                                    Ok(Row::Map(HashMap::from([
+                                        ("OBJECT_NAME".to_owned(), Value::Str(sequence.name)),
+                                        (
+                                            "OBJECT_TYPE".to_owned(),
+                                            Value::Str("SEQUENCE".to_owned()),
+                                        ),
+                                    ])))
+                                });
 
-                        Rows::Objects(rows)
+                        Rows::Objects(rows.chain(view_rows).chain(sequence_rows))
                     }
                     Dictionary::GlueTables => {
                         let schemas = storage.fetch_all_schemas().await?;
@@ -307,21 +423,23 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                     Dictionary::GlueIndexes => {
                         let schemas = storage.fetch_all_schemas().await?;
                         let rows = schemas.into_iter().flat_map(move |schema| {
-                            let column_defs = schema.column_defs.unwrap_or_default();
-                            let primary_column = column_defs.iter().find_map(|column_def| {
-                                let ColumnDef { name, unique, .. } = column_def;
-
-                                (unique == &Some(ColumnUniqueOption { is_primary: true }))
-                                    .then_some(name)
+                            let primary_columns = schema.primary_key_indexes().map(|indexes| {
+                                let column_defs = schema.column_defs.as_deref().unwrap_or(&[]);
+
+                                indexes
+                                    .into_iter()
+                                    .map(|i| column_defs[i].name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
                             });
 
-                            let clustered = match primary_column {
+                            let clustered = match primary_columns {
                                 Some(column_name) => {
                                     let values = vec![
                                         Value::Str(schema.table_name.clone()),
                                         Value::Str("PRIMARY".to_owned()),
                                         Value::Str("BOTH".to_owned()),
-                                        Value::Str(column_name.to_owned()),
+                                        Value::Str(column_name),
                                         Value::Bool(true),
                                     ];
 
@@ -337,11 +455,17 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
 
                             let columns = Rc::clone(&columns);
                             let non_clustered = schema.indexes.into_iter().map(move |index| {
+                                let exprs = index
+                                    .exprs
+                                    .iter()
+                                    .map(ToSqlUnquoted::to_sql_unquoted)
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
                                 let values = vec![
                                     Value::Str(schema.table_name.clone()),
                                     Value::Str(index.name),
                                     Value::Str(index.order.to_string()),
-                                    Value::Str(index.expr.to_sql_unquoted()),
+                                    Value::Str(exprs),
                                     Value::Bool(false),
                                 ];
 
@@ -364,6 +488,108 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
     }
 }
 
+/// Keys are stored in the type of the primary key column, so the values looked up or scanned
+/// by have to be cast into it first.
+async fn fetch_primary_key_type<T: GStore>(
+    storage: &T,
+    table_name: &str,
+) -> Result<Option<DataType>> {
+    let data_type = storage.fetch_schema(table_name).await?.and_then(|schema| {
+        let i = match schema.primary_key_indexes()?.as_slice() {
+            [i] => *i,
+            _ => return None,
+        };
+
+        schema
+            .column_defs?
+            .into_iter()
+            .nth(i)
+            .map(|column_def| column_def.data_type)
+    });
+
+    Ok(data_type)
+}
+
+async fn evaluate_bound<'a, T: GStore>(
+    storage: &'a T,
+    filter_context: &Option<Rc<RowContext<'a>>>,
+    bound: &'a Bound<Expr>,
+) -> Result<Bound<Value>> {
+    let filter_context = filter_context.as_ref().map(Rc::clone);
+    let evaluate = |expr| async move {
+        evaluate(storage, filter_context, None, expr)
+            .await
+            .and_then(Value::try_from)
+    };
+
+    match bound {
+        Bound::Included(expr) => evaluate(expr).await.map(Bound::Included),
+        Bound::Excluded(expr) => evaluate(expr).await.map(Bound::Excluded),
+        Bound::Unbounded => Ok(Bound::Unbounded),
+    }
+}
+
+/// Point ranges of the values of an `IN` list in index order, without duplicates and `NULL`s,
+/// which no index value equals.
+fn in_list_ranges(
+    values: Vec<Value>,
+    asc: Option<bool>,
+) -> Result<Vec<(Bound<Value>, Bound<Value>)>> {
+    let mut values = values
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .map(|value| Key::try_from(&value).map(|key| (key, value)))
+        .collect::<Result<Vec<_>>>()?;
+    values.sort_by(|(a, _), (b, _)| a.cmp(b));
+    values.dedup_by(|(a, _), (b, _)| a == b);
+
+    if asc == Some(false) {
+        values.reverse();
+    }
+
+    Ok(values
+        .into_iter()
+        .map(|(_, value)| (Bound::Included(value.clone()), Bound::Included(value)))
+        .collect())
+}
+
+/// Casts the bound of a primary key range into the type of the key, returns `None` for a
+/// `NULL` bound, which no key satisfies.
+fn key_bound(
+    bound: Bound<Value>,
+    data_type: Option<&DataType>,
+    is_lower: bool,
+) -> Result<Option<Bound<Key>>> {
+    let (value, inclusive) = match bound {
+        Bound::Included(value) => (value, true),
+        Bound::Excluded(value) => (value, false),
+        Bound::Unbounded => return Ok(Some(Bound::Unbounded)),
+    };
+
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let casted = match data_type {
+        Some(data_type) => value.cast(data_type)?,
+        None => value.clone(),
+    };
+
+    // a lossy cast moves the bound, e.g. `id >= 1.5` on an integer key is `id > 1`
+    let inclusive = match (is_lower, value.evaluate_cmp(&casted)) {
+        (true, Some(Ordering::Greater)) | (false, Some(Ordering::Less)) => false,
+        (true, Some(Ordering::Less)) | (false, Some(Ordering::Greater)) => true,
+        _ => inclusive,
+    };
+
+    let key = Key::try_from(casted)?;
+
+    Ok(Some(match inclusive {
+        true => Bound::Included(key),
+        false => Bound::Excluded(key),
+    }))
+}
+
 pub async fn fetch_columns<T: GStore>(
     storage: &T,
     table_name: &str,
@@ -387,10 +613,14 @@ pub async fn fetch_columns<T: GStore>(
 pub async fn fetch_relation_columns<T: GStore>(
     storage: &T,
     table_factor: &TableFactor,
+    cte_context: &CteContext,
 ) -> Result<Option<Vec<String>>> {
     match table_factor {
         TableFactor::Table { name, alias, .. } => {
-            let columns = fetch_columns(storage, name).await?;
+            let columns = match cte_context.get(name) {
+                Some(CteTable { columns, .. }) => columns.as_deref().map(<[String]>::to_vec),
+                None => fetch_columns(storage, name).await?,
+            };
             match (columns, alias) {
                 (columns, None) => Ok(columns),
                 (None, Some(_)) => Ok(None),
@@ -436,79 +666,107 @@ pub async fn fetch_relation_columns<T: GStore>(
                 "UNIQUENESS".to_owned(),
             ],
         })),
-        TableFactor::Derived {
-            subquery: Query { body, .. },
-            alias:
-                TableAlias {
-                    columns: alias_columns,
-                    name,
-                },
-        } => match body {
-            SetExpr::Select(statement) => {
-                let Select {
-                    from:
-                        TableWithJoins {
-                            relation, joins, ..
-                        },
-                    projection,
-                    ..
-                } = statement.as_ref();
+        TableFactor::Derived { subquery, alias } => {
+            let labels = fetch_query_labels(storage, subquery, cte_context).await?;
 
-                let labels = fetch_labels(storage, relation, joins, projection).await?;
-                match labels {
-                    None => Ok(None),
-                    Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
-                    Some(labels) if alias_columns.len() > labels.len() => {
-                        Err(FetchError::TooManyColumnAliases(
-                            name.to_string(),
-                            labels.len(),
-                            alias_columns.len(),
-                        )
-                        .into())
+            alias_labels(alias, labels)
+        }
+    }
+}
+
+pub fn alias_labels(
+    alias: &TableAlias,
+    labels: Option<Vec<String>>,
+) -> Result<Option<Vec<String>>> {
+    let TableAlias {
+        name,
+        columns: alias_columns,
+    } = alias;
+
+    match labels {
+        None => Ok(None),
+        Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
+        Some(labels) if alias_columns.len() > labels.len() => Err(
+            FetchError::TooManyColumnAliases(name.to_string(), labels.len(), alias_columns.len())
+                .into(),
+        ),
+        Some(labels) => Ok(Some(
+            alias_columns
+                .iter()
+                .cloned()
+                .chain(labels[alias_columns.len()..labels.len()].to_vec())
+                .collect(),
+        )),
+    }
+}
+
+#[async_recursion(?Send)]
+async fn fetch_query_labels<T: GStore>(
+    storage: &T,
+    query: &Query,
+    cte_context: &CteContext,
+) -> Result<Option<Vec<String>>> {
+    let cte_context = match &query.with {
+        Some(With {
+            recursive,
+            cte_tables,
+        }) => {
+            let mut cte_context = cte_context.clone();
+
+            for Cte { alias, query } in cte_tables {
+                let query = match recursive {
+                    true => {
+                        split_recursive(alias, query)?.map_or(query.as_ref(), |(anchor, ..)| anchor)
                     }
-                    Some(labels) => Ok(Some(
-                        alias_columns
-                            .iter()
-                            .cloned()
-                            .chain(labels[alias_columns.len()..labels.len()].to_vec())
-                            .collect(),
-                    )),
-                }
-            }
-            SetExpr::Values(Values(values_list)) => {
-                let total_len = values_list[0].len();
-                let alias_len = alias_columns.len();
-                if alias_len > total_len {
-                    return Err(FetchError::TooManyColumnAliases(
-                        name.into(),
-                        total_len,
-                        alias_len,
-                    )
-                    .into());
-                }
-                let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
-                let labels = alias_columns
-                    .iter()
-                    .cloned()
-                    .chain(labels)
-                    .collect::<Vec<_>>();
+                    false => query,
+                };
+                let labels = fetch_query_labels(storage, query, &cte_context).await?;
+                let columns = alias_labels(alias, labels)?.map(Rc::from);
+                let table = CteTable {
+                    columns,
+                    rows: Rc::from([]),
+                };
 
-                Ok(Some(labels))
+                cte_context = cte_context.update(alias.name.to_owned(), table);
             }
-        },
+
+            cte_context
+        }
+        None => cte_context.clone(),
+    };
+
+    match &query.body {
+        SetExpr::Select(statement) => {
+            let Select {
+                from: TableWithJoins { relation, joins },
+                projection,
+                ..
+            } = statement.as_ref();
+
+            fetch_labels(storage, relation, joins, projection, &cte_context).await
+        }
+        SetExpr::Values(Values(values_list)) => {
+            let labels = (1..=values_list[0].len())
+                .map(|i| format!("column{}", i))
+                .collect();
+
+            Ok(Some(labels))
+        }
+        SetExpr::SetOperation { left, .. } => fetch_query_labels(storage, left, &cte_context).await,
     }
 }
 
 async fn fetch_join_columns<'a, T: GStore>(
     storage: &T,
     joins: &'a [Join],
+    cte_context: &CteContext,
 ) -> Result<Option<Vec<(&'a String, Vec<String>)>>> {
     let columns = stream::iter(joins)
         .filter_map(|join| async {
             let relation = &join.relation;
             let alias = get_alias(relation);
 
-            fetch_relation_columns(storage, relation)
+            fetch_relation_columns(storage, relation, cte_context)
                 .await
                 .map(|columns| Some((alias, columns?)))
                 .transpose()
@@ -524,10 +782,11 @@ pub async fn fetch_labels<T: GStore>(
     relation: &TableFactor,
     joins: &[Join],
     projection: &[SelectItem],
+    cte_context: &CteContext,
 ) -> Result<Option<Vec<String>>> {
     let table_alias = get_alias(relation);
-    let columns = fetch_relation_columns(storage, relation).await?;
-    let join_columns = fetch_join_columns(storage, joins).await?;
+    let columns = fetch_relation_columns(storage, relation, cte_context).await?;
+    let join_columns = fetch_join_columns(storage, joins, cte_context).await?;
 
     if (columns.is_none() || join_columns.is_none())
         && projection.iter().any(|item| {
@@ -540,18 +799,24 @@ pub async fn fetch_labels<T: GStore>(
         return Ok(None);
     }
 
+    let merged_columns = if projection
+        .iter()
+        .any(|item| matches!(item, SelectItem::Wildcard))
+    {
+        let (merged_columns, _) =
+            fetch_merged_columns(storage, relation, joins, cte_context).await?;
+
+        merged_columns.unwrap_or_default()
+    } else {
+        Vec::new()
+    };
     let columns = columns.unwrap_or_default();
     let join_columns = join_columns.unwrap_or_default();
 
     projection
         .iter()
         .flat_map(|item| match item {
-            SelectItem::Wildcard => {
-                let columns = columns.iter().cloned();
-                let join_columns = join_columns.iter().flat_map(|(_, columns)| columns.clone());
-
-                columns.chain(join_columns).map(Ok).collect()
-            }
+            SelectItem::Wildcard => merged_columns.iter().cloned().map(Ok).collect(),
             SelectItem::QualifiedWildcard(target_table_alias) => {
                 if table_alias == target_table_alias {
                     return columns.iter().cloned().map(Ok).collect();
@@ -0,0 +1,797 @@
+use {
+    super::{
+        update::UpdateError,
+        validate::{validate_checks, validate_unique, ColumnValidation},
+    },
+    crate::{
+        ast::{ColumnDef, ForeignKey, ReferentialAction, UniqueConstraint},
+        data::{primary_key_indexes, Key, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    async_recursion::async_recursion,
+    serde::Serialize,
+    std::{
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+    },
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum ForeignKeyError {
+    #[error("duplicate foreign key name: {0}")]
+    DuplicateName(String),
+
+    #[error("referenced table does not exist: {0}")]
+    ReferencedTableNotFound(String),
+
+    #[error("referencing column does not exist: {0}")]
+    ReferencingColumnNotFound(String),
+
+    #[error("referenced column does not exist: {0}")]
+    ReferencedColumnNotFound(String),
+
+    #[error("referenced table has no primary key: {0}")]
+    ReferencedPrimaryKeyNotFound(String),
+
+    #[error("number of referencing and referenced columns does not match: {0}")]
+    ColumnCountMismatch(String),
+
+    #[error("referenced columns are not unique or primary key in table: {0}")]
+    ReferencedColumnNotUnique(String),
+
+    #[error("data types of referencing column '{0}' and referenced column '{1}' do not match")]
+    DataTypeMismatch(String, String),
+
+    #[error(r#"insert or update on table "{0}" violates foreign key "{1}""#)]
+    ReferencedRowNotFound(String, String),
+
+    #[error(r#"table "{0}" is referenced by foreign key "{1}" on table "{2}""#)]
+    TableReferenced(String, String, String),
+
+    #[error(r#"column "{0}" is used by foreign key "{1}""#)]
+    ColumnReferenced(String, String),
+}
+
+/// Validates foreign keys of a new table, referenced columns left out are filled with the
+/// primary key of the referenced table, which can be a composite one.
+pub async fn resolve_foreign_keys<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: Option<&[ColumnDef]>,
+    unique_constraints: &[UniqueConstraint],
+    foreign_keys: &[ForeignKey],
+) -> Result<Vec<ForeignKey>> {
+    let column_defs = column_defs.unwrap_or_default();
+    let mut names = HashSet::new();
+    let mut resolved = Vec::with_capacity(foreign_keys.len());
+
+    for foreign_key in foreign_keys {
+        let ForeignKey {
+            name,
+            columns,
+            referenced_table_name,
+            referenced_columns,
+            ..
+        } = foreign_key;
+
+        if !names.insert(name) {
+            return Err(ForeignKeyError::DuplicateName(name.to_owned()).into());
+        }
+
+        let referencing_column_defs = columns
+            .iter()
+            .map(|column| {
+                column_defs
+                    .iter()
+                    .find(|column_def| &column_def.name == column)
+                    .ok_or_else(|| ForeignKeyError::ReferencingColumnNotFound(column.to_owned()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (referenced_column_defs, referenced_unique_constraints) = match referenced_table_name
+            == table_name
+        {
+            true => (column_defs.to_vec(), unique_constraints.to_vec()),
+            false => {
+                let schema = storage
+                    .fetch_schema(referenced_table_name)
+                    .await?
+                    .filter(|schema| schema.view_query.is_none())
+                    .ok_or_else(|| {
+                        ForeignKeyError::ReferencedTableNotFound(referenced_table_name.to_owned())
+                    })?;
+
+                (
+                    schema.column_defs.unwrap_or_default(),
+                    schema.unique_constraints,
+                )
+            }
+        };
+        let referenced_names = get_names(&referenced_column_defs);
+
+        let referenced_columns = match referenced_columns.is_empty() {
+            true => primary_key_indexes(&referenced_column_defs, &referenced_unique_constraints)
+                .map(|indexes| {
+                    indexes
+                        .into_iter()
+                        .map(|i| referenced_names[i].to_owned())
+                        .collect::<Vec<_>>()
+                })
+                .ok_or_else(|| {
+                    ForeignKeyError::ReferencedPrimaryKeyNotFound(referenced_table_name.to_owned())
+                })?,
+            false => referenced_columns.to_owned(),
+        };
+
+        if referenced_columns.len() != columns.len() {
+            return Err(ForeignKeyError::ColumnCountMismatch(name.to_owned()).into());
+        }
+
+        let referenced_indexes = find_indexes(&referenced_names, &referenced_columns)
+            .map_err(ForeignKeyError::ReferencedColumnNotFound)?;
+
+        if !is_unique_key(
+            &referenced_column_defs,
+            &referenced_unique_constraints,
+            &referenced_indexes,
+        ) {
+            return Err(ForeignKeyError::ReferencedColumnNotUnique(
+                referenced_table_name.to_owned(),
+            )
+            .into());
+        }
+
+        let referenced = referenced_indexes
+            .iter()
+            .map(|i| &referenced_column_defs[*i]);
+        for (referencing, referenced) in referencing_column_defs.iter().zip(referenced) {
+            if referencing.data_type != referenced.data_type {
+                return Err(ForeignKeyError::DataTypeMismatch(
+                    referencing.name.to_owned(),
+                    referenced.name.to_owned(),
+                )
+                .into());
+            }
+        }
+
+        resolved.push(ForeignKey {
+            referenced_columns,
+            ..foreign_key.clone()
+        });
+    }
+
+    Ok(resolved)
+}
+
+/// Fetches the foreign keys of other tables, or the table itself, which reference `table_name`.
+pub async fn fetch_referencing<T: GStore>(
+    storage: &T,
+    table_name: &str,
+) -> Result<Vec<(Schema, ForeignKey)>> {
+    let referencing = storage
+        .fetch_all_schemas()
+        .await?
+        .into_iter()
+        .flat_map(|schema| {
+            schema
+                .foreign_keys
+                .iter()
+                .filter(|foreign_key| foreign_key.referenced_table_name == table_name)
+                .cloned()
+                .map(|foreign_key| (schema.clone(), foreign_key))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    Ok(referencing)
+}
+
+/// Checks that every foreign key value of the rows to be written exists in its referenced
+/// table, rows holding `NULL` in any of the referencing columns are not checked.
+pub async fn check_references<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    columns: &[String],
+    foreign_keys: &[ForeignKey],
+    rows: &[&[Value]],
+) -> Result<()> {
+    for foreign_key in foreign_keys {
+        let ForeignKey {
+            name,
+            referenced_table_name,
+            referenced_columns,
+            ..
+        } = foreign_key;
+
+        let indexes = find_indexes(columns, &foreign_key.columns)
+            .map_err(ForeignKeyError::ReferencingColumnNotFound)?;
+        let mut missing = rows
+            .iter()
+            .filter_map(|values| project(values, &indexes).transpose())
+            .collect::<Result<HashSet<_>>>()?;
+
+        if referenced_table_name == table_name {
+            let indexes = find_indexes(columns, referenced_columns)
+                .map_err(ForeignKeyError::ReferencedColumnNotFound)?;
+
+            for values in rows {
+                if let Some(key) = project(values, &indexes)? {
+                    missing.remove(&key);
+                }
+            }
+        }
+
+        if missing.is_empty() {
+            continue;
+        }
+
+        let schema = storage
+            .fetch_schema(referenced_table_name)
+            .await?
+            .filter(|schema| schema.column_defs.is_some())
+            .ok_or_else(|| {
+                ForeignKeyError::ReferencedTableNotFound(referenced_table_name.to_owned())
+            })?;
+        let column_names = get_names(schema.column_defs.as_deref().unwrap_or_default());
+        let referenced_indexes = find_indexes(&column_names, referenced_columns)
+            .map_err(ForeignKeyError::ReferencedColumnNotFound)?;
+
+        // positions of the primary key columns among the referenced ones
+        let primary_key = schema.primary_key_indexes().and_then(|primary_key| {
+            primary_key
+                .iter()
+                .map(|i| referenced_indexes.iter().position(|j| i == j))
+                .collect::<Option<Vec<_>>>()
+                .filter(|positions| positions.len() == referenced_indexes.len())
+        });
+
+        match primary_key {
+            Some(positions) => {
+                for key in missing.clone() {
+                    let primary_key = match positions.as_slice() {
+                        [i] => key[*i].clone(),
+                        _ => Key::Composite(positions.iter().map(|i| key[*i].clone()).collect()),
+                    };
+
+                    if storage
+                        .fetch_data(referenced_table_name, &primary_key)
+                        .await?
+                        .is_some()
+                    {
+                        missing.remove(&key);
+                    }
+                }
+            }
+            None => {
+                for item in storage.scan_data(referenced_table_name).await? {
+                    if let (_, DataRow::Vec(values)) = item? {
+                        if let Some(key) = project(&values, &referenced_indexes)? {
+                            missing.remove(&key);
+                        }
+                    }
+
+                    if missing.is_empty() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(ForeignKeyError::ReferencedRowNotFound(
+                table_name.to_owned(),
+                name.to_owned(),
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Deletes rows and applies `ON DELETE` actions of the foreign keys referencing them.
+pub async fn delete_rows<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    keys: Vec<Key>,
+) -> Result<()> {
+    if !is_referenceable(storage, table_name, None).await? {
+        return storage.delete_data(table_name, keys).await;
+    }
+
+    let schemas = storage.fetch_all_schemas().await?;
+    let mut cascade = Cascade::new(schemas);
+    if cascade.referencing(table_name).is_empty() {
+        return storage.delete_data(table_name, keys).await;
+    }
+
+    cascade.delete(storage, table_name, keys).await?;
+    cascade.apply(storage).await
+}
+
+/// Writes updated rows and applies `ON UPDATE` actions of the foreign keys referencing the
+/// changed values, `columns` are the columns the rows are updated on.
+pub async fn update_rows<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    columns: &[String],
+    rows: Vec<(Key, DataRow)>,
+) -> Result<()> {
+    if !is_referenceable(storage, table_name, Some(columns)).await? {
+        return storage.insert_data(table_name, rows).await;
+    }
+
+    let schemas = storage.fetch_all_schemas().await?;
+    let mut cascade = Cascade::new(schemas);
+    if cascade.referencing(table_name).is_empty() {
+        return storage.insert_data(table_name, rows).await;
+    }
+
+    let rows = rows
+        .into_iter()
+        .filter_map(|(key, row)| match row {
+            DataRow::Vec(values) => Some((key, values)),
+            DataRow::Map(_) => None,
+        })
+        .collect();
+
+    cascade.update(storage, table_name, rows).await?;
+    cascade.apply(storage).await
+}
+
+/// Only primary key and unique columns can be referenced, so the foreign keys of every table
+/// are looked up only when the table has them and they are among the written `columns`.
+async fn is_referenceable<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    columns: Option<&[String]>,
+) -> Result<bool> {
+    let schema = match storage.fetch_schema(table_name).await? {
+        Some(schema) => schema,
+        None => return Ok(false),
+    };
+    let written = |name: &String| columns.map_or(true, |columns| columns.contains(name));
+
+    let unique_column = schema
+        .column_defs
+        .unwrap_or_default()
+        .iter()
+        .any(|column_def| column_def.unique.is_some() && written(&column_def.name));
+
+    Ok(unique_column
+        || schema
+            .unique_constraints
+            .iter()
+            .any(|UniqueConstraint { columns, .. }| columns.iter().any(written)))
+}
+
+/// Row changes caused by referential actions, every change is collected before anything is
+/// written so that a violation found along the way leaves the storage untouched.
+struct Cascade {
+    schemas: Vec<Schema>,
+    deleted: HashMap<String, HashSet<Key>>,
+    updated: HashMap<String, HashMap<Key, Vec<Value>>>,
+}
+
+impl Cascade {
+    fn new(schemas: Vec<Schema>) -> Self {
+        Self {
+            schemas,
+            deleted: HashMap::new(),
+            updated: HashMap::new(),
+        }
+    }
+
+    fn referencing(&self, table_name: &str) -> Vec<(Schema, ForeignKey)> {
+        self.schemas
+            .iter()
+            .flat_map(|schema| {
+                schema
+                    .foreign_keys
+                    .iter()
+                    .filter(|foreign_key| foreign_key.referenced_table_name == table_name)
+                    .map(|foreign_key| (schema.clone(), foreign_key.clone()))
+            })
+            .collect()
+    }
+
+    fn column_names(&self, table_name: &str) -> Vec<String> {
+        self.schemas
+            .iter()
+            .find(|schema| schema.table_name == table_name)
+            .and_then(|schema| schema.column_defs.as_deref())
+            .map(get_names)
+            .unwrap_or_default()
+    }
+
+    fn is_deleted(&self, table_name: &str, key: &Key) -> bool {
+        self.deleted
+            .get(table_name)
+            .map(|keys| keys.contains(key))
+            .unwrap_or(false)
+    }
+
+    /// Current values of the row, including the changes collected so far.
+    async fn fetch_row<T: GStore>(
+        &self,
+        storage: &T,
+        table_name: &str,
+        key: &Key,
+    ) -> Result<Option<Vec<Value>>> {
+        if self.is_deleted(table_name, key) {
+            return Ok(None);
+        } else if let Some(values) = self.updated.get(table_name).and_then(|rows| rows.get(key)) {
+            return Ok(Some(values.clone()));
+        }
+
+        match storage.fetch_data(table_name, key).await? {
+            Some(DataRow::Vec(values)) => Ok(Some(values)),
+            _ => Ok(None),
+        }
+    }
+
+    #[async_recursion(?Send)]
+    async fn delete<T: GStore>(
+        &mut self,
+        storage: &T,
+        table_name: &str,
+        keys: Vec<Key>,
+    ) -> Result<()> {
+        let mut deleted = Vec::new();
+        for key in keys {
+            if let Some(values) = self.fetch_row(storage, table_name, &key).await? {
+                deleted.push(values);
+            }
+
+            self.deleted
+                .entry(table_name.to_owned())
+                .or_default()
+                .insert(key);
+        }
+
+        let columns = self.column_names(table_name);
+        for (schema, foreign_key) in self.referencing(table_name) {
+            let indexes = find_indexes(&columns, &foreign_key.referenced_columns)
+                .map_err(ForeignKeyError::ReferencedColumnNotFound)?;
+            let deleted_keys = deleted
+                .iter()
+                .filter_map(|values| project(values, &indexes).transpose())
+                .collect::<Result<HashSet<_>>>()?;
+
+            let rows = self
+                .fetch_referencing_rows(storage, &schema, &foreign_key, &deleted_keys)
+                .await?;
+            if rows.is_empty() {
+                continue;
+            }
+
+            match foreign_key.on_delete {
+                ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                    return Err(ForeignKeyError::TableReferenced(
+                        table_name.to_owned(),
+                        foreign_key.name,
+                        schema.table_name,
+                    )
+                    .into());
+                }
+                ReferentialAction::Cascade => {
+                    let keys = rows.into_iter().map(|(key, ..)| key).collect();
+
+                    self.delete(storage, &schema.table_name, keys).await?;
+                }
+                ReferentialAction::SetNull => {
+                    let rows =
+                        set_referencing_values(storage, &schema, &foreign_key, rows, |_| None)
+                            .await?;
+
+                    self.update(storage, &schema.table_name, rows).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_recursion(?Send)]
+    async fn update<T: GStore>(
+        &mut self,
+        storage: &T,
+        table_name: &str,
+        rows: Vec<(Key, Vec<Value>)>,
+    ) -> Result<()> {
+        let mut changed = Vec::new();
+        for (key, values) in rows {
+            if self.is_deleted(table_name, &key) {
+                continue;
+            }
+
+            if let Some(old_values) = self.fetch_row(storage, table_name, &key).await? {
+                changed.push((old_values, values.clone()));
+            }
+
+            self.updated
+                .entry(table_name.to_owned())
+                .or_default()
+                .insert(key, values);
+        }
+
+        let columns = self.column_names(table_name);
+        for (schema, foreign_key) in self.referencing(table_name) {
+            let indexes = find_indexes(&columns, &foreign_key.referenced_columns)
+                .map_err(ForeignKeyError::ReferencedColumnNotFound)?;
+
+            let mut changes = HashMap::new();
+            for (old_values, new_values) in changed.iter() {
+                let old_key = match project(old_values, &indexes)? {
+                    Some(old_key) => old_key,
+                    None => continue,
+                };
+
+                if project(new_values, &indexes)?.as_ref() != Some(&old_key) {
+                    let new_values = indexes
+                        .iter()
+                        .map(|i| new_values[*i].clone())
+                        .collect::<Vec<_>>();
+
+                    changes.insert(old_key, new_values);
+                }
+            }
+
+            if changes.is_empty() {
+                continue;
+            }
+
+            let old_keys = changes.keys().cloned().collect();
+            let rows = self
+                .fetch_referencing_rows(storage, &schema, &foreign_key, &old_keys)
+                .await?;
+            if rows.is_empty() {
+                continue;
+            }
+
+            let rows = match foreign_key.on_update {
+                ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                    return Err(ForeignKeyError::TableReferenced(
+                        table_name.to_owned(),
+                        foreign_key.name,
+                        schema.table_name,
+                    )
+                    .into());
+                }
+                ReferentialAction::Cascade => {
+                    set_referencing_values(storage, &schema, &foreign_key, rows, |old_key| {
+                        changes.get(old_key).cloned()
+                    })
+                    .await?
+                }
+                ReferentialAction::SetNull => {
+                    set_referencing_values(storage, &schema, &foreign_key, rows, |_| None).await?
+                }
+            };
+
+            self.update(storage, &schema.table_name, rows).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Rows of the referencing table whose foreign key values are among `referenced_keys`,
+    /// fetched by key when the foreign key columns are the primary key of the table.
+    async fn fetch_referencing_rows<T: GStore>(
+        &self,
+        storage: &T,
+        schema: &Schema,
+        foreign_key: &ForeignKey,
+        referenced_keys: &HashSet<Vec<Key>>,
+    ) -> Result<Vec<(Key, Vec<Value>, Vec<Key>)>> {
+        let columns = get_names(schema.column_defs.as_deref().unwrap_or_default());
+        let indexes = find_indexes(&columns, &foreign_key.columns)
+            .map_err(ForeignKeyError::ReferencingColumnNotFound)?;
+
+        // positions of the primary key columns among the foreign key ones
+        let primary_key = schema.primary_key_indexes().and_then(|primary_key| {
+            primary_key
+                .iter()
+                .map(|i| indexes.iter().position(|j| i == j))
+                .collect::<Option<Vec<_>>>()
+                .filter(|positions| positions.len() == indexes.len())
+        });
+
+        if let Some(positions) = primary_key {
+            let mut rows = Vec::new();
+            for referenced in referenced_keys {
+                let key = match positions.as_slice() {
+                    [i] => referenced[*i].clone(),
+                    _ => Key::Composite(positions.iter().map(|i| referenced[*i].clone()).collect()),
+                };
+
+                if let Some(values) = self.fetch_row(storage, &schema.table_name, &key).await? {
+                    if project(&values, &indexes)?.as_ref() == Some(referenced) {
+                        rows.push((key, values, referenced.clone()));
+                    }
+                }
+            }
+
+            return Ok(rows);
+        }
+
+        let updated = self.updated.get(&schema.table_name);
+
+        let mut rows = Vec::new();
+        for item in storage.scan_data(&schema.table_name).await? {
+            let (key, values) = match item? {
+                (key, DataRow::Vec(values)) => (key, values),
+                (_, DataRow::Map(_)) => continue,
+            };
+
+            if self.is_deleted(&schema.table_name, &key) {
+                continue;
+            }
+
+            let values = match updated.and_then(|rows| rows.get(&key)) {
+                Some(values) => values.clone(),
+                None => values,
+            };
+
+            match project(&values, &indexes)? {
+                Some(referenced) if referenced_keys.contains(&referenced) => {
+                    rows.push((key, values, referenced));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(rows)
+    }
+
+    async fn apply<T: GStore + GStoreMut>(self, storage: &mut T) -> Result<()> {
+        let Cascade {
+            deleted, updated, ..
+        } = self;
+
+        for (table_name, rows) in updated {
+            let rows = rows
+                .into_iter()
+                .filter(|(key, _)| {
+                    !deleted
+                        .get(&table_name)
+                        .map(|keys| keys.contains(key))
+                        .unwrap_or(false)
+                })
+                .map(|(key, values)| (key, DataRow::Vec(values)))
+                .collect::<Vec<_>>();
+
+            storage.insert_data(&table_name, rows).await?;
+        }
+
+        for (table_name, keys) in deleted {
+            storage
+                .delete_data(&table_name, keys.into_iter().collect())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces the foreign key values of referencing rows, `NULL` is set when `get_values`
+/// returns nothing. Rows are stored under their primary key, so like `UPDATE`, primary key
+/// columns cannot be changed.
+async fn set_referencing_values<T: GStore>(
+    storage: &T,
+    schema: &Schema,
+    foreign_key: &ForeignKey,
+    rows: Vec<(Key, Vec<Value>, Vec<Key>)>,
+    get_values: impl Fn(&Vec<Key>) -> Option<Vec<Value>>,
+) -> Result<Vec<(Key, Vec<Value>)>> {
+    let column_defs = schema.column_defs.as_deref().unwrap_or_default();
+    let columns = get_names(column_defs);
+    let indexes = find_indexes(&columns, &foreign_key.columns)
+        .map_err(ForeignKeyError::ReferencingColumnNotFound)?;
+
+    let primary_key = schema.primary_key_indexes().unwrap_or_default();
+    if let Some(i) = indexes.iter().find(|i| primary_key.contains(i)) {
+        return Err(UpdateError::UpdateOnPrimaryKeyNotSupported(columns[*i].to_owned()).into());
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|(key, mut values, referenced)| {
+            let new_values =
+                get_values(&referenced).unwrap_or_else(|| vec![Value::Null; indexes.len()]);
+
+            for (i, value) in indexes.iter().zip(new_values) {
+                value.validate_null(column_defs[*i].nullable)?;
+                values[*i] = value;
+            }
+
+            Ok((key, values))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = rows
+        .iter()
+        .map(|(_, values)| values.as_slice())
+        .collect::<Vec<_>>();
+    validate_checks(&schema.checks, &columns, &values).await?;
+    validate_unique(
+        storage,
+        &schema.table_name,
+        ColumnValidation::SpecifiedColumns(
+            column_defs,
+            &schema.unique_constraints,
+            foreign_key.columns.to_owned(),
+        ),
+        values.into_iter(),
+    )
+    .await?;
+
+    Ok(rows)
+}
+
+/// Keys of the values at `indexes`, nothing if any of them is `NULL`.
+fn project(values: &[Value], indexes: &[usize]) -> Result<Option<Vec<Key>>> {
+    let values = indexes
+        .iter()
+        .map(|i| values.get(*i).unwrap_or(&Value::Null))
+        .collect::<Vec<_>>();
+
+    if values.iter().any(|value| value.is_null()) {
+        return Ok(None);
+    }
+
+    values
+        .into_iter()
+        .map(Key::try_from)
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+fn find_indexes(columns: &[String], targets: &[String]) -> Result<Vec<usize>, String> {
+    targets
+        .iter()
+        .map(|target| {
+            columns
+                .iter()
+                .position(|column| column == target)
+                .ok_or_else(|| target.to_owned())
+        })
+        .collect()
+}
+
+fn get_names(column_defs: &[ColumnDef]) -> Vec<String> {
+    column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect()
+}
+
+/// Whether the columns at `indexes` are exactly the columns of the primary key, a `UNIQUE`
+/// column or a `UNIQUE` constraint, in any order.
+fn is_unique_key(
+    column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
+    indexes: &[usize],
+) -> bool {
+    let targets = indexes.iter().copied().collect::<HashSet<_>>();
+    if targets.len() != indexes.len() {
+        return false;
+    }
+
+    let names = get_names(column_defs);
+    let unique_column = match indexes {
+        [i] => column_defs[*i].unique.is_some(),
+        _ => false,
+    };
+
+    unique_column
+        || unique_constraints
+            .iter()
+            .any(|UniqueConstraint { columns, .. }| {
+                find_indexes(&names, columns)
+                    .map(|indexes| indexes.into_iter().collect::<HashSet<_>>() == targets)
+                    .unwrap_or(false)
+            })
+}
@@ -1,18 +1,34 @@
 use {
     super::{
+        context::RowContext,
+        execute::Payload,
+        filter::check_expr,
+        foreign_key::{check_references, update_rows},
+        returning::project_returning,
         select::select,
-        validate::{validate_unique, ColumnValidation},
+        update::Update,
+        validate::{
+            validate_checks, validate_unique, validate_unique_rows, ColumnValidation, ValidateError,
+        },
     },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, Query, SetExpr, Values},
-        data::{Key, Row, Schema, Value},
+        ast::{
+            ColumnDef, ColumnIdentity, Expr, IdentityGeneration, OnConflict, OnConflictAction,
+            Query, SelectItem, SetExpr, UniqueConstraint, Values,
+        },
+        data::{primary_key_indexes, Key, Row, Schema, Sequence, SequenceError, Value},
         executor::{evaluate::evaluate_stateless, limit::Limit},
         result::Result,
         store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{fmt::Debug, rc::Rc},
+    std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+        rc::Rc,
+    },
     thiserror::Error as ThisError,
 };
 
@@ -38,6 +54,18 @@ pub enum InsertError {
 
     #[error("map type required: {0}")]
     MapTypeValueRequired(String),
+
+    #[error("ON CONFLICT is not supported on schemaless table: {0}")]
+    OnConflictOnSchemalessTable(String),
+
+    #[error("there is no unique or primary key column matching the ON CONFLICT target: {0}")]
+    ConflictTargetNotUnique(String),
+
+    #[error("ON CONFLICT DO UPDATE cannot affect a row a second time")]
+    ConflictRowAffectedTwice,
+
+    #[error("cannot insert a value into column generated always as identity: {0}")]
+    IdentityColumnAlwaysGenerated(String),
 }
 
 enum RowsData {
@@ -50,19 +78,107 @@ pub async fn insert<T: GStore + GStoreMut>(
     table_name: &str,
     columns: &[String],
     source: &Query,
-) -> Result<usize> {
-    let Schema { column_defs, .. } = storage
+    on_conflict: Option<&OnConflict>,
+    returning: &[SelectItem],
+) -> Result<Payload> {
+    let Schema {
+        column_defs,
+        foreign_keys,
+        checks,
+        unique_constraints,
+        ..
+    } = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
+    let labels = column_defs.as_deref().map(get_labels);
+
+    let (updated, rows) = match (column_defs, on_conflict) {
+        (Some(column_defs), None) => {
+            let rows = fetch_vec_rows(
+                storage,
+                table_name,
+                (column_defs, &unique_constraints),
+                columns,
+                source,
+            )
+            .await?;
+
+            (Vec::new(), rows)
+        }
+        (Some(column_defs), Some(on_conflict)) => {
+            upsert(
+                storage,
+                table_name,
+                (column_defs, &unique_constraints),
+                columns,
+                source,
+                on_conflict,
+            )
+            .await?
+        }
+        (None, None) => {
+            let rows = fetch_map_rows(storage, source).await?;
+
+            (Vec::new(), RowsData::Append(rows))
+        }
+        (None, Some(_)) => {
+            return Err(InsertError::OnConflictOnSchemalessTable(table_name.to_owned()).into());
+        }
+    };
+
+    let constrained = !checks.is_empty() || !foreign_keys.is_empty();
+    if let (Some(labels), true) = (labels.as_ref(), constrained) {
+        let inserted = match &rows {
+            RowsData::Append(rows) => rows.iter().collect::<Vec<_>>(),
+            RowsData::Insert(rows) => rows.iter().map(|(_, row)| row).collect(),
+        };
+        let values = updated
+            .iter()
+            .map(|(_, row)| row)
+            .chain(inserted)
+            .filter_map(|row| match row {
+                DataRow::Vec(values) => Some(values.as_slice()),
+                DataRow::Map(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        validate_checks(&checks, labels, &values).await?;
+        check_references(storage, table_name, labels, &foreign_keys, &values).await?;
+    }
+
+    let returned = match returning.is_empty() {
+        true => None,
+        false => {
+            let returned_rows = get_returned_rows(labels.as_ref(), &updated, &rows);
 
-    let rows = match column_defs {
-        Some(column_defs) => {
-            fetch_vec_rows(storage, table_name, column_defs, columns, source).await
+            Some(project_returning(storage, table_name, returning, returned_rows).await?)
         }
-        None => fetch_map_rows(storage, source).await.map(RowsData::Append),
-    }?;
+    };
+    let num_updated = updated.len();
+
+    if let Some(OnConflict {
+        action: OnConflictAction::DoUpdate { assignments, .. },
+        ..
+    }) = on_conflict.filter(|_| !updated.is_empty())
+    {
+        let columns = assignments
+            .iter()
+            .map(|assignment| assignment.id.to_owned())
+            .collect::<Vec<_>>();
+
+        update_rows(storage, table_name, &columns, updated).await?;
+    }
+    let num_rows = num_updated + write_rows(storage, table_name, rows).await?;
+
+    Ok(returned.unwrap_or(Payload::Insert(num_rows)))
+}
 
+async fn write_rows<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    rows: RowsData,
+) -> Result<usize> {
     match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
@@ -83,21 +199,35 @@ pub async fn insert<T: GStore + GStoreMut>(
     }
 }
 
-async fn fetch_vec_rows<T: GStore>(
-    storage: &T,
+async fn fetch_vec_rows<T: GStore + GStoreMut>(
+    storage: &mut T,
     table_name: &str,
-    column_defs: Vec<ColumnDef>,
+    (column_defs, unique_constraints): (Vec<ColumnDef>, &[UniqueConstraint]),
     columns: &[String],
     source: &Query,
 ) -> Result<RowsData> {
-    let labels = Rc::from(
-        column_defs
-            .iter()
-            .map(|column_def| column_def.name.to_owned())
-            .collect::<Vec<_>>(),
-    );
-    let column_defs = Rc::from(column_defs);
-    let column_validation = ColumnValidation::All(&column_defs);
+    let column_defs: Rc<[ColumnDef]> = Rc::from(column_defs);
+    let mut rows = fetch_vec_values(storage, &column_defs, columns, source).await?;
+    generate_identity_values(storage, table_name, &column_defs, &mut rows).await?;
+
+    validate_unique(
+        storage,
+        table_name,
+        ColumnValidation::All(&column_defs, unique_constraints),
+        rows.iter().map(|values| values.as_slice()),
+    )
+    .await?;
+
+    into_rows_data(&column_defs, unique_constraints, rows)
+}
+
+async fn fetch_vec_values<T: GStore>(
+    storage: &T,
+    column_defs: &Rc<[ColumnDef]>,
+    columns: &[String],
+    source: &Query,
+) -> Result<Vec<Vec<Value>>> {
+    let labels = get_labels(column_defs);
 
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
@@ -105,11 +235,11 @@ async fn fetch_vec_rows<T: GStore>(
         Select(I2),
     }
 
-    let rows = match &source.body {
+    match &source.body {
         SetExpr::Values(Values(values_list)) => {
             let limit = Limit::new(source.limit.as_ref(), source.offset.as_ref()).await?;
             let rows = stream::iter(values_list).then(|values| {
-                let column_defs = Rc::clone(&column_defs);
+                let column_defs = Rc::clone(column_defs);
                 let labels = Rc::clone(&labels);
 
                 async move {
@@ -124,53 +254,97 @@ async fn fetch_vec_rows<T: GStore>(
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
-                let values = row?.try_into_vec()?;
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
+            let rows = select(storage, source, None).await?.and_then(|row| {
+                let column_defs = Rc::clone(column_defs);
 
-                column_defs
-                    .iter()
-                    .zip(values.iter())
-                    .try_for_each(|(column_def, value)| {
-                        let ColumnDef {
-                            data_type,
-                            nullable,
-                            ..
-                        } = column_def;
-
-                        value.validate_type(data_type)?;
-                        value.validate_null(*nullable)
-                    })?;
-
-                Ok(values)
+                async move {
+                    let values = row.try_into_vec()?;
+
+                    fill_selected_values(&column_defs, columns, values).await
+                }
             });
 
             Rows::Select(rows)
         }
     }
     .try_collect::<Vec<Vec<Value>>>()
-    .await?;
+    .await
+}
 
-    validate_unique(
-        storage,
-        table_name,
-        column_validation,
-        rows.iter().map(|values| values.as_slice()),
-    )
-    .await?;
+/// Fills identity columns left out of the inserted rows, which hold `NULL` at this point,
+/// with the next values of their sequences.
+async fn generate_identity_values<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    rows: &mut [Vec<Value>],
+) -> Result<()> {
+    for (i, column_def) in column_defs.iter().enumerate() {
+        if column_def.identity.is_none() || rows.iter().all(|values| !values[i].is_null()) {
+            continue;
+        }
 
-    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
-        unique == &Some(ColumnUniqueOption { is_primary: true })
-    });
+        let sequence_name = Sequence::identity_name(table_name, &column_def.name);
+        let mut sequence = storage
+            .fetch_sequence(&sequence_name)
+            .await?
+            .ok_or(SequenceError::SequenceNotFound(sequence_name))?;
+
+        for values in rows.iter_mut().filter(|values| values[i].is_null()) {
+            values[i] = Value::I64(sequence.next_value()?).cast(&column_def.data_type)?;
+        }
+
+        storage.insert_sequence(sequence).await?;
+    }
+
+    Ok(())
+}
+
+fn get_labels(column_defs: &[ColumnDef]) -> Rc<[String]> {
+    column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn get_returned_rows(
+    labels: Option<&Rc<[String]>>,
+    updated: &[(Key, DataRow)],
+    rows: &RowsData,
+) -> Vec<Row> {
+    let inserted = match rows {
+        RowsData::Append(rows) => rows.iter().collect::<Vec<_>>(),
+        RowsData::Insert(rows) => rows.iter().map(|(_, row)| row).collect(),
+    };
+
+    updated
+        .iter()
+        .map(|(_, row)| row)
+        .chain(inserted)
+        .map(|row| match row.clone() {
+            DataRow::Vec(values) => Row::Vec {
+                columns: labels.map(Rc::clone).unwrap_or_else(|| Rc::from([])),
+                values,
+            },
+            DataRow::Map(values) => Row::Map(values),
+        })
+        .collect()
+}
 
-    match primary_key {
-        Some(i) => rows
+fn into_rows_data(
+    column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
+    rows: Vec<Vec<Value>>,
+) -> Result<RowsData> {
+    match primary_key_indexes(column_defs, unique_constraints) {
+        Some(indexes) => rows
             .into_iter()
-            .filter_map(|values| {
-                values
-                    .get(i)
-                    .map(Key::try_from)
-                    .map(|result| result.map(|key| (key, values.into())))
+            .map(|values| {
+                let key = Key::from_values(indexes.iter().filter_map(|&i| values.get(i)))?;
+
+                Ok((key, values.into()))
             })
             .collect::<Result<Vec<_>>>()
             .map(RowsData::Insert),
@@ -178,6 +352,248 @@ async fn fetch_vec_rows<T: GStore>(
     }
 }
 
+#[derive(Clone, Copy)]
+enum Conflict {
+    /// index of the row stored in the table
+    Stored(usize),
+    /// row inserted by the same statement
+    Inserted,
+}
+
+async fn upsert<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    (column_defs, unique_constraints): (Vec<ColumnDef>, &[UniqueConstraint]),
+    columns: &[String],
+    source: &Query,
+    on_conflict: &OnConflict,
+) -> Result<(Vec<(Key, DataRow)>, RowsData)> {
+    let OnConflict {
+        columns: conflict_columns,
+        action,
+    } = on_conflict;
+    let column_defs: Rc<[ColumnDef]> = Rc::from(column_defs);
+    let labels = get_labels(&column_defs);
+
+    let column_index = |column: &String| {
+        column_defs
+            .iter()
+            .position(|ColumnDef { name, .. }| name == column)
+    };
+    let constraint_indexes = |UniqueConstraint { columns, .. }: &UniqueConstraint| {
+        columns.iter().map(column_index).collect::<Option<Vec<_>>>()
+    };
+    let conflict_target = unique_constraints.iter().find(|constraint| {
+        constraint.columns.len() == conflict_columns.len()
+            && constraint
+                .columns
+                .iter()
+                .all(|column| conflict_columns.contains(column))
+    });
+
+    let conflict_indexes = match (conflict_columns.is_empty(), conflict_target) {
+        (true, _) => column_defs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ColumnDef { unique, .. })| unique.map(|_| vec![i]))
+            .chain(unique_constraints.iter().filter_map(constraint_indexes))
+            .collect::<Vec<_>>(),
+        (false, Some(constraint)) => constraint_indexes(constraint).into_iter().collect(),
+        (false, None) => conflict_columns
+            .iter()
+            .map(|column| {
+                column_defs
+                    .iter()
+                    .position(|ColumnDef { name, unique, .. }| name == column && unique.is_some())
+                    .map(|i| vec![i])
+                    .ok_or_else(|| InsertError::ConflictTargetNotUnique(column.to_owned()).into())
+            })
+            .collect::<Result<Vec<_>>>()?,
+    };
+
+    let mut rows = fetch_vec_values(storage, &column_defs, columns, source).await?;
+    generate_identity_values(storage, table_name, &column_defs, &mut rows).await?;
+
+    let storage = &*storage;
+    let unique_count = column_defs
+        .iter()
+        .filter(|ColumnDef { unique, .. }| unique.is_some())
+        .count()
+        + unique_constraints.len();
+    let mut stored = match primary_key_indexes(&column_defs, unique_constraints) {
+        // the primary key is the only conflict target, and there is no other unique column
+        // which would need every stored row to be validated against
+        Some(primary_key) if unique_count == 1 => {
+            fetch_conflicting_rows(storage, table_name, &primary_key, &rows).await?
+        }
+        _ => storage
+            .scan_data(table_name)
+            .await?
+            .map(|item| {
+                let (key, row) = item?;
+
+                into_stored_values(row).map(|values| (key, values))
+            })
+            .collect::<Result<Vec<_>>>()?,
+    };
+
+    let mut conflicts = HashMap::new();
+    for (i, (_, values)) in stored.iter().enumerate() {
+        for key in conflict_keys(&conflict_indexes, values)? {
+            conflicts.insert(key, Conflict::Stored(i));
+        }
+    }
+
+    let update = match action {
+        OnConflictAction::DoNothing => None,
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => Some((
+            Update::new(
+                storage,
+                table_name,
+                assignments,
+                Some(&column_defs),
+                unique_constraints,
+                None,
+            )?,
+            selection.as_ref(),
+        )),
+    };
+
+    let mut inserted = Vec::new();
+    let mut affected = vec![false; stored.len()];
+
+    for values in rows {
+        let keys = conflict_keys(&conflict_indexes, &values)?;
+        let conflict = keys.iter().find_map(|key| conflicts.get(key).copied());
+
+        let (i, (update, selection)) = match (conflict, &update) {
+            (None, _) => {
+                for key in keys {
+                    conflicts.insert(key, Conflict::Inserted);
+                }
+
+                inserted.push(values);
+                continue;
+            }
+            (Some(_), None) => continue,
+            (Some(Conflict::Inserted), Some(_)) => {
+                return Err(InsertError::ConflictRowAffectedTwice.into());
+            }
+            (Some(Conflict::Stored(i)), Some(_)) if affected[i] => {
+                return Err(InsertError::ConflictRowAffectedTwice.into());
+            }
+            (Some(Conflict::Stored(i)), Some(update)) => (i, update),
+        };
+
+        let row = Row::Vec {
+            columns: Rc::clone(&labels),
+            values: stored[i].1.clone(),
+        };
+        let excluded = Row::Vec {
+            columns: Rc::clone(&labels),
+            values,
+        };
+        // EXCLUDED is accessible both in upper and lower case, as PostgreSQL folds it
+        let excluded = Rc::new(RowContext::new(
+            "excluded",
+            Cow::Borrowed(&excluded),
+            Some(Rc::new(RowContext::new(
+                "EXCLUDED",
+                Cow::Borrowed(&excluded),
+                None,
+            ))),
+        ));
+
+        if let Some(expr) = selection {
+            let context =
+                RowContext::new(table_name, Cow::Borrowed(&row), Some(Rc::clone(&excluded)));
+
+            if !check_expr(storage, Some(Rc::new(context)), None, expr).await? {
+                continue;
+            }
+        }
+
+        let values = update
+            .apply_with(row, Some(excluded))
+            .await?
+            .try_into_vec()?;
+        for key in conflict_keys(&conflict_indexes, &stored[i].1)? {
+            conflicts.remove(&key);
+        }
+        for key in conflict_keys(&conflict_indexes, &values)? {
+            conflicts.insert(key, Conflict::Stored(i));
+        }
+
+        stored[i].1 = values;
+        affected[i] = true;
+    }
+
+    validate_unique_rows(
+        &column_defs,
+        unique_constraints,
+        stored
+            .iter()
+            .map(|(_, values)| values.as_slice())
+            .chain(inserted.iter().map(Vec::as_slice)),
+    )?;
+
+    let updated = stored
+        .into_iter()
+        .zip(affected)
+        .filter_map(|((key, values), affected)| affected.then(|| (key, values.into())))
+        .collect::<Vec<_>>();
+
+    let rows = into_rows_data(&column_defs, unique_constraints, inserted)?;
+
+    Ok((updated, rows))
+}
+
+/// Fetches the stored rows sharing their primary key with any of `rows`
+async fn fetch_conflicting_rows<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    primary_key: &[usize],
+    rows: &[Vec<Value>],
+) -> Result<Vec<(Key, Vec<Value>)>> {
+    let mut keys = HashSet::new();
+    let mut stored = Vec::new();
+
+    for values in rows {
+        let key = Key::from_values(primary_key.iter().filter_map(|&i| values.get(i)))?;
+        if !keys.insert(key.clone()) {
+            continue;
+        }
+
+        if let Some(row) = storage.fetch_data(table_name, &key).await? {
+            stored.push((key, into_stored_values(row)?));
+        }
+    }
+
+    Ok(stored)
+}
+
+fn into_stored_values(row: DataRow) -> Result<Vec<Value>> {
+    match row {
+        DataRow::Vec(values) => Ok(values),
+        DataRow::Map(_) => Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into()),
+    }
+}
+
+/// Keys of `values` on each conflict target, paired with the position of the target
+fn conflict_keys(indexes: &[Vec<usize>], values: &[Value]) -> Result<Vec<(usize, Key)>> {
+    indexes
+        .iter()
+        .enumerate()
+        .map(|(target, indexes)| {
+            Key::from_values(indexes.iter().filter_map(|&i| values.get(i))).map(|key| (target, key))
+        })
+        .filter(|key| !matches!(key, Ok((_, key)) if key.has_null()))
+        .collect()
+}
+
 async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<DataRow>> {
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
@@ -203,7 +619,7 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
             let rows = select(storage, source, None).await?.map(|row| {
                 let row = row?;
 
@@ -232,33 +648,7 @@ async fn fill_values(
     columns: &[String],
     values: &[Expr],
 ) -> Result<Vec<Value>> {
-    if !columns.is_empty() && values.len() != columns.len() {
-        return Err(InsertError::ColumnAndValuesNotMatched.into());
-    } else if values.len() > column_defs.len() {
-        return Err(InsertError::TooManyValues.into());
-    }
-
-    if let Some(wrong_column_name) = columns.iter().find(|column_name| {
-        !column_defs
-            .iter()
-            .any(|column_def| &&column_def.name == column_name)
-    }) {
-        return Err(InsertError::WrongColumnName(wrong_column_name.to_owned()).into());
-    }
-
-    #[derive(iter_enum::Iterator)]
-    enum Columns<I1, I2> {
-        All(I1),
-        Specified(I2),
-    }
-
-    let columns = if columns.is_empty() {
-        Columns::All(column_defs.iter().map(|ColumnDef { name, .. }| name))
-    } else {
-        Columns::Specified(columns.iter())
-    };
-
-    let column_name_value_list = columns.zip(values.iter()).collect::<Vec<(_, _)>>();
+    let column_name_value_list = match_columns(column_defs, columns, values.iter())?;
 
     let values = stream::iter(column_defs)
         .then(|column_def| {
@@ -269,6 +659,7 @@ async fn fill_values(
                     name: def_name,
                     data_type,
                     nullable,
+                    identity,
                     ..
                 } = column_def;
 
@@ -277,6 +668,10 @@ async fn fill_values(
                     .find(|(name, _)| name == &def_name)
                     .map(|(_, value)| value);
 
+                if let Some(value) = fill_identity(def_name, identity, value.is_some())? {
+                    return Ok(value);
+                }
+
                 match (value, &column_def.default, nullable) {
                     (Some(&expr), _, _) | (None, Some(expr), _) => evaluate_stateless(None, expr)
                         .await?
@@ -293,3 +688,110 @@ async fn fill_values(
 
     Ok(values)
 }
+
+/// Fills the row of a table from the values `INSERT ... SELECT` selects, the same way
+/// `fill_values` does for the expressions of `INSERT ... VALUES`.
+async fn fill_selected_values(
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    values: Vec<Value>,
+) -> Result<Vec<Value>> {
+    let mut column_name_value_list = match_columns(column_defs, columns, values.into_iter())?;
+
+    let mut filled = Vec::with_capacity(column_defs.len());
+    for column_def in column_defs {
+        let ColumnDef {
+            name: def_name,
+            data_type,
+            nullable,
+            identity,
+            default,
+            ..
+        } = column_def;
+
+        let value = column_name_value_list
+            .iter()
+            .position(|(name, _)| name == &def_name)
+            .map(|i| column_name_value_list.swap_remove(i).1);
+
+        if let Some(value) = fill_identity(def_name, identity, value.is_some())? {
+            filled.push(value);
+            continue;
+        }
+
+        let value = match (value, default, nullable) {
+            (Some(value), _, _) => {
+                value.validate_type(data_type)?;
+                value.validate_null(*nullable)?;
+
+                value
+            }
+            (None, Some(expr), _) => evaluate_stateless(None, expr)
+                .await?
+                .try_into_value(data_type, *nullable)?,
+            (None, None, true) => Value::Null,
+            (None, None, false) => {
+                return Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into());
+            }
+        };
+
+        filled.push(value);
+    }
+
+    Ok(filled)
+}
+
+/// Pairs the inserted values with the names of the columns they are inserted into.
+fn match_columns<'a, V>(
+    column_defs: &'a [ColumnDef],
+    columns: &'a [String],
+    values: impl ExactSizeIterator<Item = V>,
+) -> Result<Vec<(&'a String, V)>> {
+    if !columns.is_empty() && values.len() != columns.len() {
+        return Err(InsertError::ColumnAndValuesNotMatched.into());
+    } else if values.len() > column_defs.len() {
+        return Err(InsertError::TooManyValues.into());
+    }
+
+    if let Some(wrong_column_name) = columns.iter().find(|column_name| {
+        !column_defs
+            .iter()
+            .any(|column_def| &&column_def.name == column_name)
+    }) {
+        return Err(InsertError::WrongColumnName(wrong_column_name.to_owned()).into());
+    }
+
+    #[derive(iter_enum::Iterator)]
+    enum Columns<I1, I2> {
+        All(I1),
+        Specified(I2),
+    }
+
+    let columns = if columns.is_empty() {
+        Columns::All(column_defs.iter().map(|ColumnDef { name, .. }| name))
+    } else {
+        Columns::Specified(columns.iter())
+    };
+
+    Ok(columns.zip(values).collect())
+}
+
+/// Identity columns left out of the inserted columns hold `NULL` until
+/// `generate_identity_values` fills them, and `GENERATED ALWAYS` ones cannot be written.
+fn fill_identity(
+    name: &str,
+    identity: &Option<ColumnIdentity>,
+    specified: bool,
+) -> Result<Option<Value>> {
+    match (specified, identity) {
+        (false, Some(_)) => Ok(Some(Value::Null)),
+        (
+            true,
+            Some(ColumnIdentity {
+                generation: IdentityGeneration::Always,
+                ..
+            }),
+        ) => Err(InsertError::IdentityColumnAlwaysGenerated(name.to_owned()).into()),
+        _ => Ok(None),
+    }
+}
@@ -3,11 +3,17 @@ use {
     crate::{
         ast::{
             Expr, Join as AstJoin, JoinConstraint, JoinExecutor as AstJoinExecutor,
-            JoinOperator as AstJoinOperator, TableFactor,
+            JoinOperator as AstJoinOperator, TableFactor, TableWithJoins,
         },
         data::{get_alias, Key, Row, Value},
-        executor::{context::RowContext, evaluate::evaluate, filter::check_expr},
-        result::Result,
+        executor::{
+            context::{CteContext, RowContext},
+            evaluate::evaluate,
+            filter::check_expr,
+            profile::Profile,
+            select::SelectError,
+        },
+        result::{Error, Result},
         store::GStore,
     },
     futures::{
@@ -15,14 +21,17 @@ use {
         stream::{self, empty, once, Stream, StreamExt, TryStreamExt},
     },
     itertools::Itertools,
-    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc},
+    std::{borrow::Cow, cell::RefCell, collections::HashMap, iter, pin::Pin, rc::Rc},
     utils::OrStream,
 };
 
 pub struct Join<'a, T: GStore> {
     storage: &'a T,
+    relation: &'a TableFactor,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Rc<RowContext<'a>>>,
+    cte_context: CteContext,
+    profile: Option<Rc<Profile>>,
 }
 
 type JoinItem<'a> = Rc<RowContext<'a>>;
@@ -31,13 +40,19 @@ type Joined<'a> = Pin<Box<dyn Stream<Item = Result<JoinItem<'a>>> + 'a>>;
 impl<'a, T: GStore> Join<'a, T> {
     pub fn new(
         storage: &'a T,
+        relation: &'a TableFactor,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Rc<RowContext<'a>>>,
+        cte_context: CteContext,
+        profile: Option<Rc<Profile>>,
     ) -> Self {
         Self {
             storage,
+            relation,
             join_clauses,
             filter_context,
+            cte_context,
+            profile,
         }
     }
 
@@ -46,176 +61,459 @@ impl<'a, T: GStore> Join<'a, T> {
         rows: impl Stream<Item = Result<RowContext<'a>>> + 'a,
     ) -> Result<Joined<'a>> {
         let init_rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
+        let (_, using_columns) = fetch_merged_columns(
+            self.storage,
+            self.relation,
+            self.join_clauses,
+            &self.cte_context,
+        )
+        .await?;
 
-        stream::iter(self.join_clauses)
+        stream::iter(self.join_clauses.iter().zip(using_columns).enumerate())
             .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
-                let filter_context = self.filter_context.as_ref().map(Rc::clone);
+            .try_fold(init_rows, |rows, (i, (join_clause, using_columns))| {
+                let left_relations = iter::once(self.relation)
+                    .chain(self.join_clauses[..i].iter().map(|join| &join.relation))
+                    .collect();
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
+                self.join(join_clause, using_columns, left_relations, rows)
             })
             .await
     }
-}
 
-async fn join<'a, T: GStore>(
-    storage: &'a T,
-    filter_context: Option<Rc<RowContext<'a>>>,
-    ast_join: &'a AstJoin,
-    left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
-) -> Result<Joined<'a>> {
-    let AstJoin {
-        relation,
-        join_operator,
-        join_executor,
-    } = ast_join;
-
-    let table_alias = get_alias(relation);
-    let join_executor = JoinExecutor::new(
-        storage,
-        relation,
-        filter_context.as_ref().map(Rc::clone),
-        join_executor,
-    )
-    .await
-    .map(Rc::new)?;
-
-    let (join_operator, where_clause) = match join_operator {
-        AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
-        AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::Inner, Some(where_clause))
-        }
-        AstJoinOperator::LeftOuter(JoinConstraint::None) => (JoinOperator::LeftOuter, None),
-        AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::LeftOuter, Some(where_clause))
-        }
-    };
-
-    let columns = fetch_relation_columns(storage, relation)
-        .await?
-        .map(Rc::from);
-    let rows = left_rows.and_then(move |project_context| {
-        let init_context = {
-            let init_row = match columns.as_ref() {
-                Some(columns) => Row::Vec {
-                    columns: Rc::clone(columns),
-                    values: columns.iter().map(|_| Value::Null).collect(),
-                },
-                None => Row::Map(HashMap::new()),
-            };
+    async fn join(
+        &self,
+        ast_join: &'a AstJoin,
+        using_columns: Option<Rc<[String]>>,
+        left_relations: Vec<&'a TableFactor>,
+        left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
+    ) -> Result<Joined<'a>> {
+        let storage = self.storage;
+        let filter_context = self.filter_context.as_ref().map(Rc::clone);
+        let cte_context = self.cte_context.clone();
+        let profile = self.profile.as_ref().map(Rc::clone);
 
-            Rc::new(RowContext::new(
-                table_alias,
-                Cow::Owned(init_row),
-                Some(Rc::clone(&project_context)),
-            ))
-        };
-        let filter_context = filter_context.as_ref().map(Rc::clone);
-        let join_executor = Rc::clone(&join_executor);
-
-        async move {
-            let filter_context = match filter_context {
-                Some(filter_context) => Rc::new(RowContext::concat(
-                    Rc::clone(&project_context),
-                    Rc::clone(&filter_context),
-                )),
-                None => Rc::clone(&project_context),
-            };
-            let filter_context = Some(filter_context);
+        let AstJoin {
+            relation,
+            join_operator,
+            join_executor,
+        } = ast_join;
 
-            #[derive(futures_enum::Stream)]
-            enum Rows<I1, I2, I3> {
-                NestedLoop(I1),
-                Hash(I2),
-                Empty(I3),
+        let (join_operator, join_constraint) = match join_operator {
+            AstJoinOperator::Inner(join_constraint) => (JoinOperator::Inner, Some(join_constraint)),
+            AstJoinOperator::LeftOuter(join_constraint) => {
+                (JoinOperator::LeftOuter, Some(join_constraint))
             }
-            let rows = match join_executor.as_ref() {
-                JoinExecutor::NestedLoop => {
-                    let rows = fetch_relation_rows(storage, relation, &filter_context)
-                        .await?
-                        .and_then(|row| future::ok(Cow::Owned(row)))
-                        .try_filter_map(move |row| {
-                            check_where_clause(
+            AstJoinOperator::RightOuter(join_constraint) => {
+                (JoinOperator::RightOuter, Some(join_constraint))
+            }
+            AstJoinOperator::FullOuter(join_constraint) => {
+                (JoinOperator::FullOuter, Some(join_constraint))
+            }
+            AstJoinOperator::CrossJoin => (JoinOperator::Inner, None),
+        };
+        let constraint = match (join_constraint, &using_columns) {
+            (_, Some(columns)) => Constraint::Using(Rc::clone(columns)),
+            (Some(JoinConstraint::On(expr)), None) => Constraint::On(expr),
+            _ => Constraint::None,
+        };
+
+        let table_alias = get_alias(relation);
+        let join_executor = JoinExecutor::new(
+            storage,
+            relation,
+            filter_context.as_ref().map(Rc::clone),
+            &cte_context,
+            &profile,
+            join_executor,
+            join_operator,
+        )
+        .await
+        .map(Rc::new)?;
+
+        let matched = matches!(
+            join_operator,
+            JoinOperator::RightOuter | JoinOperator::FullOuter
+        )
+        .then(|| Rc::new(RefCell::new(vec![false; join_executor.rows().len()])));
+
+        let columns = fetch_relation_columns(storage, relation, &cte_context)
+            .await?
+            .map(Rc::from);
+        let rows = left_rows.and_then({
+            let join_executor = Rc::clone(&join_executor);
+            let matched = matched.as_ref().map(Rc::clone);
+            let cte_context = cte_context.clone();
+            let profile = profile.as_ref().map(Rc::clone);
+            let constraint = constraint.clone();
+
+            move |project_context| {
+                let init_context = Rc::new(RowContext::new(
+                    table_alias,
+                    Cow::Owned(null_row(columns.as_ref())),
+                    Some(Rc::clone(&project_context)),
+                ));
+                let filter_context = filter_context.as_ref().map(Rc::clone);
+                let cte_context = cte_context.clone();
+                let profile = profile.as_ref().map(Rc::clone);
+                let join_executor = Rc::clone(&join_executor);
+                let matched = matched.as_ref().map(Rc::clone);
+                let constraint = constraint.clone();
+
+                async move {
+                    let filter_context = match filter_context {
+                        Some(filter_context) => Rc::new(RowContext::concat(
+                            Rc::clone(&project_context),
+                            Rc::clone(&filter_context),
+                        )),
+                        None => Rc::clone(&project_context),
+                    };
+                    let filter_context = Some(filter_context);
+
+                    #[derive(futures_enum::Stream)]
+                    enum Rows<I1, I2, I3> {
+                        NestedLoop(I1),
+                        Checked(I2),
+                        Empty(I3),
+                    }
+                    let rows = match join_executor.as_ref() {
+                        JoinExecutor::NestedLoop => {
+                            let rows = fetch_relation_rows(
+                                storage,
+                                relation,
+                                &filter_context,
+                                &cte_context,
+                                &profile,
+                            )
+                            .await?
+                            .and_then(|row| future::ok(Cow::Owned(row)))
+                            .try_filter_map(move |row| {
+                                check_where_clause(
+                                    storage,
+                                    table_alias,
+                                    filter_context.as_ref().map(Rc::clone),
+                                    Some(&project_context).map(Rc::clone),
+                                    constraint.clone(),
+                                    row,
+                                )
+                            });
+                            Rows::NestedLoop(rows)
+                        }
+                        JoinExecutor::Materialized(rows) => {
+                            let rows = check_rows(
                                 storage,
                                 table_alias,
+                                filter_context,
+                                project_context,
+                                constraint,
+                                rows.iter().enumerate(),
+                                matched.as_deref(),
+                            )
+                            .await?;
+
+                            Rows::Checked(stream::iter(rows))
+                        }
+                        JoinExecutor::Hash {
+                            rows,
+                            rows_map,
+                            value_expr,
+                        } => {
+                            let indices = evaluate(
+                                storage,
                                 filter_context.as_ref().map(Rc::clone),
-                                Some(&project_context).map(Rc::clone),
-                                where_clause,
-                                row,
+                                None,
+                                value_expr,
                             )
-                        });
-                    Rows::NestedLoop(rows)
+                            .await
+                            .map(Key::try_from)?
+                            .map(|hash_key| rows_map.get(&hash_key))?;
+
+                            match indices {
+                                None => Rows::Empty(empty()),
+                                Some(indices) => {
+                                    let rows = check_rows(
+                                        storage,
+                                        table_alias,
+                                        filter_context,
+                                        project_context,
+                                        constraint,
+                                        indices.iter().map(|&i| (i, &rows[i])),
+                                        matched.as_deref(),
+                                    )
+                                    .await?;
+
+                                    Rows::Checked(stream::iter(rows))
+                                }
+                            }
+                        }
+                    };
+
+                    let rows: Joined = match join_operator {
+                        JoinOperator::Inner | JoinOperator::RightOuter => Box::pin(rows),
+                        JoinOperator::LeftOuter | JoinOperator::FullOuter => {
+                            let init_rows = once(async { Ok(init_context) });
+
+                            Box::pin(OrStream::new(rows, init_rows))
+                        }
+                    };
+
+                    Ok(rows)
                 }
-                JoinExecutor::Hash {
-                    rows_map,
-                    value_expr,
-                } => {
-                    let rows = evaluate(
-                        storage,
-                        filter_context.as_ref().map(Rc::clone),
-                        None,
-                        value_expr,
+            }
+        });
+        let rows = rows.try_flatten();
+        let merge = move |context| merge_using_columns(using_columns.as_ref(), context);
+
+        let matched = match matched {
+            Some(matched) => matched,
+            None => return Ok(Box::pin(rows.map_ok(merge))),
+        };
+
+        let unmatched_rows = once(async move {
+            let left_context = null_context(storage, left_relations, &cte_context).await?;
+            let matched = matched.borrow();
+            let rows = join_executor
+                .rows()
+                .iter()
+                .zip(matched.iter())
+                .filter(|(_, matched)| !**matched)
+                .map(|(row, _)| {
+                    Ok(Rc::new(RowContext::new(
+                        table_alias,
+                        Cow::Owned(row.clone()),
+                        left_context.as_ref().map(Rc::clone),
+                    )))
+                })
+                .collect::<Vec<Result<_>>>();
+
+            Ok::<_, Error>(stream::iter(rows))
+        })
+        .try_flatten();
+
+        Ok(Box::pin(rows.chain(unmatched_rows).map_ok(merge)))
+    }
+}
+
+/// Fetches the column layout of the joined relations with the `USING` and
+/// `NATURAL` columns merged, together with the merged columns of each join.
+/// The layout is `None` when any of the relations is schemaless.
+pub async fn fetch_merged_columns<T: GStore>(
+    storage: &T,
+    relation: &TableFactor,
+    joins: &[AstJoin],
+    cte_context: &CteContext,
+) -> Result<(Option<Vec<String>>, Vec<Option<Rc<[String]>>>)> {
+    let mut columns = fetch_relation_columns(storage, relation, cte_context).await?;
+    let mut using_columns = Vec::with_capacity(joins.len());
+
+    for join in joins {
+        let join_columns = fetch_relation_columns(storage, &join.relation, cte_context).await?;
+        let join_constraint = match &join.join_operator {
+            AstJoinOperator::Inner(join_constraint)
+            | AstJoinOperator::LeftOuter(join_constraint)
+            | AstJoinOperator::RightOuter(join_constraint)
+            | AstJoinOperator::FullOuter(join_constraint) => Some(join_constraint),
+            AstJoinOperator::CrossJoin => None,
+        };
+
+        let using = match (join_constraint, &columns, &join_columns) {
+            (Some(JoinConstraint::Using(using)), _, _) => {
+                let not_found = using.iter().find(|column| {
+                    [&columns, &join_columns].into_iter().any(|columns| {
+                        columns
+                            .as_ref()
+                            .map(|columns| !columns.contains(column))
+                            .unwrap_or(false)
+                    })
+                });
+
+                if let Some(column) = not_found {
+                    return Err(SelectError::UsingColumnNotFound(column.to_owned()).into());
+                }
+
+                using.iter().unique().cloned().collect()
+            }
+            (Some(JoinConstraint::Natural), Some(columns), Some(join_columns)) => columns
+                .iter()
+                .filter(|column| join_columns.contains(column))
+                .unique()
+                .cloned()
+                .collect(),
+            (Some(JoinConstraint::Natural), _, _) => {
+                return Err(SelectError::NaturalJoinOnSchemaless.into());
+            }
+            _ => Vec::new(),
+        };
+
+        columns = match (columns, join_columns) {
+            (Some(columns), Some(join_columns)) => Some(
+                using
+                    .iter()
+                    .cloned()
+                    .chain(
+                        columns
+                            .into_iter()
+                            .chain(join_columns)
+                            .filter(|column| !using.contains(column)),
                     )
-                    .await
-                    .map(Key::try_from)?
-                    .map(|hash_key| rows_map.get(&hash_key))?;
-
-                    match rows {
-                        None => Rows::Empty(empty()),
-                        Some(rows) => {
-                            let rows = stream::iter(rows)
-                                .filter_map(|row| {
-                                    let filter_context = filter_context.as_ref().map(Rc::clone);
-                                    let project_context = Some(&project_context).map(Rc::clone);
-
-                                    async {
-                                        check_where_clause(
-                                            storage,
-                                            table_alias,
-                                            filter_context,
-                                            project_context,
-                                            where_clause,
-                                            Cow::Borrowed(row),
-                                        )
-                                        .await
-                                        .transpose()
-                                    }
-                                })
-                                .collect::<Vec<_>>()
-                                .await;
-
-                            Rows::Hash(stream::iter(rows))
-                        }
-                    }
+                    .collect(),
+            ),
+            _ => None,
+        };
+        using_columns.push((!using.is_empty()).then(|| Rc::from(using)));
+    }
+
+    Ok((columns, using_columns))
+}
+
+/// Rows of the joined relations in the `FROM` clause of `UPDATE` and the `USING` clause
+/// of `DELETE`, which the rows of the target table are matched with.
+pub struct JoinedRows<'a> {
+    rows: Vec<JoinItem<'a>>,
+    /// Positions of the rows by their hash key, with the expression evaluated on a row
+    /// of the target table to look them up
+    rows_map: Option<(HashMap<Key, Vec<usize>>, &'a Expr)>,
+}
+
+impl<'a> JoinedRows<'a> {
+    pub async fn fetch<T: GStore>(
+        storage: &'a T,
+        table_with_joins: &'a TableWithJoins,
+        join_executor: &'a AstJoinExecutor,
+    ) -> Result<JoinedRows<'a>> {
+        let TableWithJoins { relation, joins } = table_with_joins;
+        let cte_context = CteContext::default();
+        let rows = fetch_relation_rows(storage, relation, &None, &cte_context, &None)
+            .await?
+            .map_ok(|row| RowContext::new(get_alias(relation), Cow::Owned(row), None));
+        let rows = Join::new(storage, relation, joins, None, cte_context, None)
+            .apply(rows)
+            .await?;
+
+        let (key_expr, value_expr, where_clause) = match join_executor {
+            AstJoinExecutor::NestedLoop => {
+                let rows = rows.try_collect().await?;
+
+                return Ok(Self {
+                    rows,
+                    rows_map: None,
+                });
+            }
+            AstJoinExecutor::Hash {
+                key_expr,
+                value_expr,
+                where_clause,
+            } => (key_expr, value_expr, where_clause),
+        };
+
+        let mut hashed_rows = Vec::new();
+        let mut rows_map = HashMap::<Key, Vec<usize>>::new();
+
+        for row in rows.try_collect::<Vec<_>>().await? {
+            if let Some(expr) = where_clause {
+                if !check_expr(storage, Some(Rc::clone(&row)), None, expr).await? {
+                    continue;
                 }
-            };
+            }
 
-            let rows: Joined = match join_operator {
-                JoinOperator::Inner => Box::pin(rows),
-                JoinOperator::LeftOuter => {
-                    let init_rows = once(async { Ok(init_context) });
+            let hash_key: Key = evaluate(storage, Some(Rc::clone(&row)), None, key_expr)
+                .await?
+                .try_into()?;
+            if matches!(hash_key, Key::None) {
+                continue;
+            }
+
+            rows_map
+                .entry(hash_key)
+                .or_default()
+                .push(hashed_rows.len());
+            hashed_rows.push(row);
+        }
+
+        Ok(Self {
+            rows: hashed_rows,
+            rows_map: Some((rows_map, value_expr)),
+        })
+    }
+
+    /// Finds the first joined row which satisfies `selection` alongside `row` of the
+    /// target table.
+    pub async fn find<T: GStore>(
+        &self,
+        storage: &'a T,
+        table_name: &str,
+        row: &Row,
+        selection: Option<&Expr>,
+    ) -> Result<Option<JoinItem<'a>>> {
+        let candidates = match &self.rows_map {
+            Some((rows_map, value_expr)) => {
+                let context = RowContext::new(table_name, Cow::Borrowed(row), None);
+                let hash_key: Key = evaluate(storage, Some(Rc::new(context)), None, value_expr)
+                    .await?
+                    .try_into()?;
 
-                    Box::pin(OrStream::new(rows, init_rows))
+                match rows_map.get(&hash_key) {
+                    Some(indices) => indices.iter().map(|&i| &self.rows[i]).collect(),
+                    None => Vec::new(),
                 }
+            }
+            None => self.rows.iter().collect::<Vec<_>>(),
+        };
+
+        for joined_row in candidates {
+            let matched = match selection {
+                Some(expr) => {
+                    let context = RowContext::new(
+                        table_name,
+                        Cow::Borrowed(row),
+                        Some(Rc::clone(joined_row)),
+                    );
+
+                    check_expr(storage, Some(Rc::new(context)), None, expr).await?
+                }
+                None => true,
             };
 
-            Ok(rows)
+            if matched {
+                return Ok(Some(Rc::clone(joined_row)));
+            }
         }
-    });
 
-    Ok(Box::pin(rows.try_flatten()))
+        Ok(None)
+    }
+}
+
+fn merge_using_columns<'a>(
+    using_columns: Option<&Rc<[String]>>,
+    context: JoinItem<'a>,
+) -> JoinItem<'a> {
+    match using_columns {
+        Some(columns) => Rc::new(RowContext::using(Rc::clone(columns), context)),
+        None => context,
+    }
+}
+
+#[derive(Clone)]
+enum Constraint<'a> {
+    None,
+    On(&'a Expr),
+    Using(Rc<[String]>),
 }
 
 #[derive(Copy, Clone)]
 enum JoinOperator {
     Inner,
     LeftOuter,
+    RightOuter,
+    FullOuter,
 }
 
 enum JoinExecutor<'a> {
     NestedLoop,
+    Materialized(Vec<Row>),
     Hash {
-        rows_map: HashMap<Key, Vec<Row>>,
+        rows: Vec<Row>,
+        rows_map: HashMap<Key, Vec<usize>>,
         value_expr: &'a Expr,
     },
 }
@@ -225,26 +523,47 @@ impl<'a> JoinExecutor<'a> {
         storage: &'a T,
         relation: &TableFactor,
         filter_context: Option<Rc<RowContext<'a>>>,
+        cte_context: &CteContext,
+        profile: &Option<Rc<Profile>>,
         ast_join_executor: &'a AstJoinExecutor,
+        join_operator: JoinOperator,
     ) -> Result<JoinExecutor<'a>> {
-        let (key_expr, value_expr, where_clause) = match ast_join_executor {
-            AstJoinExecutor::NestedLoop => return Ok(Self::NestedLoop),
-            AstJoinExecutor::Hash {
-                key_expr,
-                value_expr,
-                where_clause,
-            } => (key_expr, value_expr, where_clause),
+        let (key_expr, value_expr, where_clause) = match (ast_join_executor, join_operator) {
+            (AstJoinExecutor::NestedLoop, JoinOperator::Inner | JoinOperator::LeftOuter) => {
+                return Ok(Self::NestedLoop);
+            }
+            (AstJoinExecutor::NestedLoop, JoinOperator::RightOuter | JoinOperator::FullOuter) => {
+                let rows =
+                    fetch_relation_rows(storage, relation, &filter_context, cte_context, profile)
+                        .await?
+                        .try_collect()
+                        .await?;
+
+                return Ok(Self::Materialized(rows));
+            }
+            (
+                AstJoinExecutor::Hash {
+                    key_expr,
+                    value_expr,
+                    where_clause,
+                },
+                _,
+            ) => (key_expr, value_expr, where_clause),
         };
 
-        let rows_map = fetch_relation_rows(storage, relation, &filter_context)
+        let rows = fetch_relation_rows(storage, relation, &filter_context, cte_context, profile)
             .await?
-            .try_filter_map(|row| {
+            .try_collect::<Vec<_>>()
+            .await?;
+        let rows_map = stream::iter(rows.iter().enumerate())
+            .map(Ok)
+            .try_filter_map(|(i, row)| {
                 let filter_context = filter_context.as_ref().map(Rc::clone);
 
                 async move {
                     let filter_context = Rc::new(RowContext::new(
                         get_alias(relation),
-                        Cow::Borrowed(&row),
+                        Cow::Borrowed(row),
                         filter_context,
                     ));
 
@@ -264,8 +583,8 @@ impl<'a> JoinExecutor<'a> {
                     match where_clause {
                         Some(expr) => check_expr(storage, Some(filter_context), None, expr)
                             .await
-                            .map(|pass| pass.then_some((hash_key, row))),
-                        None => Ok(Some((hash_key, row))),
+                            .map(|pass| pass.then_some((hash_key, i))),
+                        None => Ok(Some((hash_key, i))),
                     }
                 }
             })
@@ -273,11 +592,86 @@ impl<'a> JoinExecutor<'a> {
             .await?
             .into_iter()
             .into_group_map();
+
         Ok(Self::Hash {
+            rows,
             rows_map,
             value_expr,
         })
     }
+
+    fn rows(&self) -> &[Row] {
+        match self {
+            Self::NestedLoop => &[],
+            Self::Materialized(rows) | Self::Hash { rows, .. } => rows,
+        }
+    }
+}
+
+fn null_row(columns: Option<&Rc<[String]>>) -> Row {
+    match columns {
+        Some(columns) => Row::Vec {
+            columns: Rc::clone(columns),
+            values: columns.iter().map(|_| Value::Null).collect(),
+        },
+        None => Row::Map(HashMap::new()),
+    }
+}
+
+async fn null_context<'a, T: GStore>(
+    storage: &'a T,
+    relations: Vec<&'a TableFactor>,
+    cte_context: &CteContext,
+) -> Result<Option<Rc<RowContext<'a>>>> {
+    let mut context = None;
+
+    for relation in relations {
+        let columns = fetch_relation_columns(storage, relation, cte_context)
+            .await?
+            .map(Rc::from);
+
+        context = Some(Rc::new(RowContext::new(
+            get_alias(relation),
+            Cow::Owned(null_row(columns.as_ref())),
+            context,
+        )));
+    }
+
+    Ok(context)
+}
+
+async fn check_rows<'a, T: GStore>(
+    storage: &'a T,
+    table_alias: &'a str,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    project_context: Rc<RowContext<'a>>,
+    constraint: Constraint<'a>,
+    rows: impl Iterator<Item = (usize, &Row)>,
+    matched: Option<&RefCell<Vec<bool>>>,
+) -> Result<Vec<Result<JoinItem<'a>>>> {
+    let mut joined = Vec::new();
+
+    for (i, row) in rows {
+        let row = check_where_clause(
+            storage,
+            table_alias,
+            filter_context.as_ref().map(Rc::clone),
+            Some(Rc::clone(&project_context)),
+            constraint.clone(),
+            Cow::Borrowed(row),
+        )
+        .await?;
+
+        if let Some(row) = row {
+            if let Some(matched) = matched {
+                matched.borrow_mut()[i] = true;
+            }
+
+            joined.push(Ok(row));
+        }
+    }
+
+    Ok(joined)
 }
 
 async fn check_where_clause<'a, 'b, T: GStore>(
@@ -285,15 +679,26 @@ async fn check_where_clause<'a, 'b, T: GStore>(
     table_alias: &'a str,
     filter_context: Option<Rc<RowContext<'a>>>,
     project_context: Option<Rc<RowContext<'a>>>,
-    where_clause: Option<&'a Expr>,
+    constraint: Constraint<'a>,
     row: Cow<'b, Row>,
 ) -> Result<Option<Rc<RowContext<'a>>>> {
-    let filter_context = RowContext::new(table_alias, Cow::Borrowed(&row), filter_context);
-    let filter_context = Some(Rc::new(filter_context));
+    match constraint {
+        Constraint::On(expr) => {
+            let filter_context = RowContext::new(table_alias, Cow::Borrowed(&row), filter_context);
+
+            check_expr(storage, Some(Rc::new(filter_context)), None, expr).await?
+        }
+        Constraint::Using(columns) => columns.iter().all(|column| {
+            let left = project_context
+                .as_ref()
+                .and_then(|context| context.get_value(column));
 
-    match where_clause {
-        Some(expr) => check_expr(storage, filter_context, None, expr).await?,
-        None => true,
+            match (left, row.get_value(column)) {
+                (Some(left), Some(right)) => !left.is_null() && left.evaluate_eq(right),
+                _ => false,
+            }
+        }),
+        Constraint::None => true,
     }
     .then(|| RowContext::new(table_alias, Cow::Owned(row.into_owned()), project_context))
     .map(Rc::new)
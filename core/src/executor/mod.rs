@@ -1,17 +1,25 @@
 mod aggregate;
 mod alter;
+mod analyze;
 mod context;
 mod evaluate;
 mod execute;
+mod explain;
 mod fetch;
 mod filter;
+mod foreign_key;
 mod insert;
 mod join;
 mod limit;
+mod prepare;
+mod profile;
+mod returning;
 mod select;
+mod sequence;
 mod sort;
 mod update;
 mod validate;
+mod window;
 
 pub use {
     aggregate::AggregateError,
@@ -19,10 +27,14 @@ pub use {
     context::RowContext,
     evaluate::{evaluate_stateless, EvaluateError},
     execute::{execute, ExecuteError, Payload, PayloadVariable},
+    explain::ExplainError,
     fetch::FetchError,
+    foreign_key::ForeignKeyError,
     insert::InsertError,
+    prepare::{infer_param_types, PrepareError, PreparedStatement},
     select::SelectError,
     sort::SortError,
     update::UpdateError,
     validate::ValidateError,
+    window::WindowError,
 };
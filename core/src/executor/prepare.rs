@@ -0,0 +1,554 @@
+use {
+    crate::{
+        ast::{
+            Assignment, ColumnDef, DataType, Distinct, Expr, IndexCondition, IndexItem, Join,
+            JoinConstraint, JoinExecutor, JoinOperator, OnConflict, OnConflictAction, OrderByExpr,
+            Query, Select, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins, Values,
+        },
+        data::{Schema, Value},
+        result::Result,
+        store::GStore,
+    },
+    serde::Serialize,
+    std::{fmt::Debug, ops::Bound},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum PrepareError {
+    #[error("only a single statement can be prepared, found: {0}")]
+    SingleStatementRequired(usize),
+
+    #[error("params length not matching, expected: {expected}, found: {found}")]
+    ParamsLengthNotMatching { expected: usize, found: usize },
+}
+
+/// Statement parsed, translated and planned once, to be executed with bind parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreparedStatement {
+    statement: Statement,
+    param_types: Vec<Option<DataType>>,
+}
+
+impl PreparedStatement {
+    /// `param_types` are the types inferred by [`infer_param_types`] before planning.
+    pub fn new(mut statement: Statement, mut param_types: Vec<Option<DataType>>) -> Self {
+        let mut num_params = 0;
+        for_each_placeholder(&mut statement, &mut |expr| {
+            if let Expr::Placeholder(n) = expr {
+                num_params = num_params.max(*n);
+            }
+        });
+
+        param_types.resize(num_params, None);
+
+        Self {
+            statement,
+            param_types,
+        }
+    }
+
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Data types of `$1`, `$2`, ..., `None` if it cannot be inferred from the columns
+    pub fn param_types(&self) -> &[Option<DataType>] {
+        &self.param_types
+    }
+
+    /// Replaces the placeholders with `params`, each param is checked against the type of
+    /// its placeholder if there is one.
+    pub fn bind(&self, params: Vec<Value>) -> Result<Statement> {
+        if params.len() != self.param_types.len() {
+            return Err(PrepareError::ParamsLengthNotMatching {
+                expected: self.param_types.len(),
+                found: params.len(),
+            }
+            .into());
+        }
+
+        let params = params
+            .into_iter()
+            .zip(self.param_types.iter())
+            .map(|(value, data_type)| {
+                if let Some(data_type) = data_type {
+                    value.validate_type(data_type)?;
+                }
+
+                Expr::try_from(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut statement = self.statement.clone();
+        for_each_placeholder(&mut statement, &mut |expr| {
+            if let Expr::Placeholder(n) = expr {
+                *expr = params[*n - 1].clone();
+            }
+        });
+
+        Ok(statement)
+    }
+}
+
+/// Infers the types of placeholders assigned to columns, inserted into columns or
+/// compared with columns of the target table.
+pub async fn infer_param_types<T: GStore>(
+    storage: &T,
+    statement: &Statement,
+) -> Result<Vec<Option<DataType>>> {
+    let mut types = Vec::new();
+    let fetch_column_defs = |table_name| async move {
+        storage
+            .fetch_schema(table_name)
+            .await
+            .map(|schema| schema.and_then(|Schema { column_defs, .. }| column_defs))
+    };
+
+    let statement = match statement {
+        Statement::Explain { statement, .. } => statement.as_ref(),
+        statement => statement,
+    };
+
+    match statement {
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            on_conflict,
+            ..
+        } => {
+            let column_defs = fetch_column_defs(table_name).await?.unwrap_or_default();
+
+            if let SetExpr::Values(Values(values_list)) = &source.body {
+                let column_defs = match columns.is_empty() {
+                    true => column_defs.iter().collect::<Vec<_>>(),
+                    false => columns
+                        .iter()
+                        .filter_map(|column| find_column_def(&column_defs, column))
+                        .collect(),
+                };
+
+                for (column_def, expr) in values_list
+                    .iter()
+                    .flat_map(|values| column_defs.iter().zip(values))
+                {
+                    if let Expr::Placeholder(n) = expr {
+                        set_type(&mut types, *n, &column_def.data_type);
+                    }
+                }
+            }
+
+            if let Some(OnConflict {
+                action:
+                    OnConflictAction::DoUpdate {
+                        assignments,
+                        selection,
+                    },
+                ..
+            }) = on_conflict
+            {
+                infer_assignments(&mut types, &column_defs, assignments);
+                infer_condition(&mut types, &column_defs, selection.as_ref());
+            }
+        }
+        Statement::Update {
+            table_name,
+            assignments,
+            selection,
+            ..
+        } => {
+            let column_defs = fetch_column_defs(table_name).await?.unwrap_or_default();
+
+            infer_assignments(&mut types, &column_defs, assignments);
+            infer_condition(&mut types, &column_defs, selection.as_ref());
+        }
+        Statement::Delete {
+            table_name,
+            selection,
+            ..
+        } => {
+            let column_defs = fetch_column_defs(table_name).await?.unwrap_or_default();
+
+            infer_condition(&mut types, &column_defs, selection.as_ref());
+        }
+        Statement::Query(Query {
+            body: SetExpr::Select(select),
+            ..
+        }) => {
+            if let Select {
+                from:
+                    TableWithJoins {
+                        relation: TableFactor::Table { name, .. },
+                        joins,
+                    },
+                selection,
+                ..
+            } = select.as_ref()
+            {
+                if joins.is_empty() {
+                    let column_defs = fetch_column_defs(name).await?.unwrap_or_default();
+
+                    infer_condition(&mut types, &column_defs, selection.as_ref());
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(types)
+}
+
+fn set_type(types: &mut Vec<Option<DataType>>, n: usize, data_type: &DataType) {
+    if types.len() < n {
+        types.resize(n, None);
+    }
+
+    if types[n - 1].is_none() {
+        types[n - 1] = Some(data_type.clone());
+    }
+}
+
+fn find_column_def<'a>(column_defs: &'a [ColumnDef], column: &str) -> Option<&'a ColumnDef> {
+    column_defs
+        .iter()
+        .find(|column_def| column_def.name == column)
+}
+
+fn infer_assignments(
+    types: &mut Vec<Option<DataType>>,
+    column_defs: &[ColumnDef],
+    assignments: &[Assignment],
+) {
+    for Assignment { id, value } in assignments {
+        if let (Expr::Placeholder(n), Some(column_def)) = (value, find_column_def(column_defs, id))
+        {
+            set_type(types, *n, &column_def.data_type);
+        }
+    }
+}
+
+/// Placeholders compared with a column in `AND`, `OR` and `NOT` trees of the condition
+fn infer_condition(
+    types: &mut Vec<Option<DataType>>,
+    column_defs: &[ColumnDef],
+    expr: Option<&Expr>,
+) {
+    let column_def = |expr: &Expr| match expr {
+        Expr::Identifier(ident) | Expr::CompoundIdentifier { ident, .. } => {
+            find_column_def(column_defs, ident)
+        }
+        _ => None,
+    };
+    let infer = |types: &mut Vec<Option<DataType>>, column: &Expr, exprs: &[&Expr]| {
+        if let Some(ColumnDef { data_type, .. }) = column_def(column) {
+            for expr in exprs {
+                if let Expr::Placeholder(n) = expr {
+                    set_type(types, *n, data_type);
+                }
+            }
+        }
+    };
+
+    match expr {
+        Some(Expr::BinaryOp { left, right, .. }) => {
+            infer(types, left, &[right]);
+            infer(types, right, &[left]);
+            infer_condition(types, column_defs, Some(left));
+            infer_condition(types, column_defs, Some(right));
+        }
+        Some(Expr::Between {
+            expr, low, high, ..
+        }) => infer(types, expr, &[low, high]),
+        Some(Expr::InList { expr, list, .. }) => {
+            infer(types, expr, &list.iter().collect::<Vec<_>>());
+        }
+        Some(Expr::Nested(expr)) | Some(Expr::UnaryOp { expr, .. }) => {
+            infer_condition(types, column_defs, Some(expr));
+        }
+        _ => {}
+    }
+}
+
+/// Calls `f` on every placeholder of the statement, including the ones the planner
+/// moved into index items and join executors.
+fn for_each_placeholder<F: FnMut(&mut Expr)>(statement: &mut Statement, f: &mut F) {
+    match statement {
+        Statement::Query(query) => visit_query(query, f),
+        Statement::Insert {
+            source,
+            on_conflict,
+            returning,
+            ..
+        } => {
+            visit_query(source, f);
+
+            if let Some(OnConflict {
+                action:
+                    OnConflictAction::DoUpdate {
+                        assignments,
+                        selection,
+                    },
+                ..
+            }) = on_conflict
+            {
+                visit_assignments(assignments, f);
+                selection.iter_mut().for_each(|expr| visit_expr(expr, f));
+            }
+
+            visit_select_items(returning, f);
+        }
+        Statement::Update {
+            assignments,
+            from,
+            join_executor,
+            selection,
+            returning,
+            ..
+        } => {
+            visit_assignments(assignments, f);
+            from.iter_mut()
+                .for_each(|table_with_joins| visit_table_with_joins(table_with_joins, f));
+            visit_join_executor(join_executor, f);
+            selection.iter_mut().for_each(|expr| visit_expr(expr, f));
+            visit_select_items(returning, f);
+        }
+        Statement::Delete {
+            using,
+            join_executor,
+            selection,
+            returning,
+            ..
+        } => {
+            using
+                .iter_mut()
+                .for_each(|table_with_joins| visit_table_with_joins(table_with_joins, f));
+            visit_join_executor(join_executor, f);
+            selection.iter_mut().for_each(|expr| visit_expr(expr, f));
+            visit_select_items(returning, f);
+        }
+        Statement::CreateTable {
+            source: Some(query),
+            ..
+        } => visit_query(query, f),
+        Statement::Explain { statement, .. } => for_each_placeholder(statement, f),
+        _ => {}
+    }
+}
+
+fn visit_assignments<F: FnMut(&mut Expr)>(assignments: &mut [Assignment], f: &mut F) {
+    for Assignment { value, .. } in assignments {
+        visit_expr(value, f);
+    }
+}
+
+fn visit_select_items<F: FnMut(&mut Expr)>(items: &mut [SelectItem], f: &mut F) {
+    for item in items {
+        if let SelectItem::Expr { expr, .. } = item {
+            visit_expr(expr, f);
+        }
+    }
+}
+
+fn visit_query<F: FnMut(&mut Expr)>(query: &mut Query, f: &mut F) {
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+
+    for cte in with.iter_mut().flat_map(|with| with.cte_tables.iter_mut()) {
+        visit_query(&mut cte.query, f);
+    }
+
+    visit_set_expr(body, f);
+
+    for OrderByExpr { expr, .. } in order_by {
+        visit_expr(expr, f);
+    }
+
+    limit
+        .iter_mut()
+        .chain(offset.iter_mut())
+        .for_each(|expr| visit_expr(expr, f));
+}
+
+fn visit_set_expr<F: FnMut(&mut Expr)>(set_expr: &mut SetExpr, f: &mut F) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let Select {
+                distinct,
+                projection,
+                from,
+                selection,
+                group_by,
+                having,
+                plan_id: _,
+            } = select.as_mut();
+
+            if let Some(Distinct::On(exprs)) = distinct {
+                exprs.iter_mut().for_each(|expr| visit_expr(expr, f));
+            }
+
+            visit_select_items(projection, f);
+            visit_table_with_joins(from, f);
+            selection
+                .iter_mut()
+                .chain(group_by.iter_mut())
+                .chain(having.iter_mut())
+                .for_each(|expr| visit_expr(expr, f));
+        }
+        SetExpr::Values(Values(values_list)) => values_list
+            .iter_mut()
+            .flatten()
+            .for_each(|expr| visit_expr(expr, f)),
+        SetExpr::SetOperation { left, right, .. } => {
+            visit_query(left, f);
+            visit_query(right, f);
+        }
+    }
+}
+
+fn visit_table_with_joins<F: FnMut(&mut Expr)>(table_with_joins: &mut TableWithJoins, f: &mut F) {
+    let TableWithJoins { relation, joins } = table_with_joins;
+
+    visit_table_factor(relation, f);
+
+    for Join {
+        relation,
+        join_operator,
+        join_executor,
+    } in joins
+    {
+        visit_table_factor(relation, f);
+
+        if let JoinOperator::Inner(JoinConstraint::On(expr))
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) = join_operator
+        {
+            visit_expr(expr, f);
+        }
+
+        visit_join_executor(join_executor, f);
+    }
+}
+
+fn visit_join_executor<F: FnMut(&mut Expr)>(join_executor: &mut JoinExecutor, f: &mut F) {
+    if let JoinExecutor::Hash {
+        key_expr,
+        value_expr,
+        where_clause,
+    } = join_executor
+    {
+        visit_expr(key_expr, f);
+        visit_expr(value_expr, f);
+        where_clause.iter_mut().for_each(|expr| visit_expr(expr, f));
+    }
+}
+
+fn visit_table_factor<F: FnMut(&mut Expr)>(table_factor: &mut TableFactor, f: &mut F) {
+    match table_factor {
+        TableFactor::Table {
+            index: Some(IndexItem::PrimaryKey(expr)),
+            ..
+        }
+        | TableFactor::Series { size: expr, .. } => visit_expr(expr, f),
+        TableFactor::Table {
+            index:
+                Some(IndexItem::NonClustered {
+                    prefix, condition, ..
+                }),
+            ..
+        } => {
+            prefix.iter_mut().for_each(|expr| visit_expr(expr, f));
+
+            match condition {
+                Some(IndexCondition::Range { lower, upper }) => visit_bounds(lower, upper, f),
+                Some(IndexCondition::InList(list)) => {
+                    list.iter_mut().for_each(|expr| visit_expr(expr, f))
+                }
+                None => {}
+            }
+        }
+        TableFactor::Table {
+            index: Some(IndexItem::PrimaryKeyRange { lower, upper, .. }),
+            ..
+        } => visit_bounds(lower, upper, f),
+        TableFactor::Derived { subquery, .. } => visit_query(subquery, f),
+        TableFactor::Table { index: None, .. } | TableFactor::Dictionary { .. } => {}
+    }
+}
+
+fn visit_bounds<F: FnMut(&mut Expr)>(lower: &mut Bound<Expr>, upper: &mut Bound<Expr>, f: &mut F) {
+    [lower, upper].into_iter().for_each(|bound| match bound {
+        Bound::Included(expr) | Bound::Excluded(expr) => visit_expr(expr, f),
+        Bound::Unbounded => {}
+    })
+}
+
+fn visit_expr<F: FnMut(&mut Expr)>(expr: &mut Expr, f: &mut F) {
+    match expr {
+        Expr::Placeholder(_) => f(expr),
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => visit_expr(expr, f),
+        Expr::InList { expr, list, .. } => {
+            visit_expr(expr, f);
+            list.iter_mut().for_each(|expr| visit_expr(expr, f));
+        }
+        Expr::InSubquery { expr, subquery, .. } => {
+            visit_expr(expr, f);
+            visit_query(subquery, f);
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            visit_expr(expr, f);
+            visit_expr(low, f);
+            visit_expr(high, f);
+        }
+        Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+            visit_expr(expr, f);
+            visit_expr(pattern, f);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            visit_expr(left, f);
+            visit_expr(right, f);
+        }
+        Expr::Function(func) => func.as_exprs_mut().for_each(|expr| visit_expr(expr, f)),
+        Expr::Aggregate(aggregate) => aggregate
+            .as_expr_mut()
+            .into_iter()
+            .for_each(|expr| visit_expr(expr, f)),
+        Expr::Window(window) => window.as_exprs_mut().for_each(|expr| visit_expr(expr, f)),
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => visit_query(subquery, f),
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand
+                .iter_mut()
+                .chain(else_result.iter_mut())
+                .for_each(|expr| visit_expr(expr, f));
+
+            for (when, then) in when_then {
+                visit_expr(when, f);
+                visit_expr(then, f);
+            }
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            visit_expr(obj, f);
+            indexes.iter_mut().for_each(|expr| visit_expr(expr, f));
+        }
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. } => {}
+    }
+}
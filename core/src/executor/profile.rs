@@ -0,0 +1,136 @@
+use {
+    crate::{ast::Select, result::Result},
+    chrono::{Duration, Utc},
+    futures::{
+        stream::{Stream, StreamExt},
+        Future,
+    },
+    std::{
+        cell::RefCell,
+        collections::HashMap,
+        pin::Pin,
+        rc::Rc,
+        task::{Context, Poll},
+    },
+};
+
+/// Steps of the `SELECT` pipeline which `EXPLAIN ANALYZE` reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Fetch,
+    Join,
+    Filter,
+    Aggregate,
+    Window,
+    Project,
+    Sort,
+    Distinct,
+    Limit,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StageStats {
+    /// Rows passed to the next stage
+    pub rows: usize,
+    /// Time spent in the stage, including the stages it pulled the rows from
+    pub elapsed: Duration,
+}
+
+/// Stats of the stages measured while a query runs, keyed by the plan id the planner assigned
+/// to each [`Select`] so they can be matched with the plan of the same statement.
+#[derive(Debug, Default)]
+pub struct Profile {
+    stats: RefCell<HashMap<(usize, Stage), StageStats>>,
+}
+
+impl Profile {
+    pub fn get(&self, select: &Select, stage: Stage) -> Option<StageStats> {
+        let key = (select.plan_id?, stage);
+
+        self.stats.borrow().get(&key).copied()
+    }
+
+    fn record(&self, key: (usize, Stage), rows: usize, elapsed: Duration) {
+        let mut stats = self.stats.borrow_mut();
+        let stage_stats = stats.entry(key).or_insert(StageStats {
+            rows: 0,
+            elapsed: Duration::zero(),
+        });
+
+        stage_stats.rows += rows;
+        stage_stats.elapsed = stage_stats.elapsed + elapsed;
+    }
+}
+
+/// Measures the stages of a single `SELECT`, does nothing unless a [`Profile`] is given and
+/// the `SELECT` is planned with an id.
+pub struct Profiler {
+    profile: Option<(Rc<Profile>, usize)>,
+}
+
+impl Profiler {
+    pub fn new(profile: Option<Rc<Profile>>, select: &Select) -> Self {
+        Self {
+            profile: profile.zip(select.plan_id),
+        }
+    }
+
+    /// Measures building a stage, which may already pull all the rows of the stages before it.
+    pub async fn build<F: Future>(&self, stage: Stage, future: F) -> F::Output {
+        match &self.profile {
+            Some((profile, plan_id)) => {
+                let started = Utc::now();
+                let output = future.await;
+                profile.record((*plan_id, stage), 0, Utc::now() - started);
+
+                output
+            }
+            None => future.await,
+        }
+    }
+
+    /// Counts the rows yielded by a stage and measures the time spent to pull each of them.
+    pub fn stream<'a, T: 'a>(
+        &self,
+        stage: Stage,
+        rows: impl Stream<Item = Result<T>> + 'a,
+    ) -> impl Stream<Item = Result<T>> + 'a {
+        #[derive(futures_enum::Stream)]
+        enum Rows<S1, S2> {
+            Plain(S1),
+            Profiled(S2),
+        }
+
+        let (profile, plan_id) = match &self.profile {
+            Some((profile, plan_id)) => (Rc::clone(profile), *plan_id),
+            None => return Rows::Plain(rows),
+        };
+
+        Rows::Profiled(ProfiledRows {
+            rows: Box::pin(rows),
+            profile,
+            key: (plan_id, stage),
+        })
+    }
+}
+
+/// Boxes the rows of the stage, otherwise the stream types of the whole pipeline nest
+/// twice per stage.
+struct ProfiledRows<'a, T> {
+    rows: Pin<Box<dyn Stream<Item = Result<T>> + 'a>>,
+    profile: Rc<Profile>,
+    key: (usize, Stage),
+}
+
+impl<T> Stream for ProfiledRows<'_, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let started = Utc::now();
+        let poll = self.rows.poll_next_unpin(cx);
+        let pulled = matches!(poll, Poll::Ready(Some(Ok(_)))) as usize;
+        self.profile.record(self.key, pulled, Utc::now() - started);
+
+        poll
+    }
+}
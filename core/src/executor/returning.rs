@@ -0,0 +1,56 @@
+use {
+    super::{
+        context::{CteContext, RowContext},
+        execute::Payload,
+        fetch::fetch_labels,
+        select::Project,
+    },
+    crate::{
+        ast::{SelectItem, TableFactor},
+        data::Row,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, StreamExt, TryStreamExt},
+    std::{borrow::Cow, rc::Rc},
+};
+
+/// Projects the rows affected by `INSERT`, `UPDATE` or `DELETE` with the
+/// `RETURNING` select items.
+pub async fn project_returning<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    returning: &[SelectItem],
+    rows: Vec<Row>,
+) -> Result<Payload> {
+    let relation = TableFactor::Table {
+        name: table_name.to_owned(),
+        alias: None,
+        index: None,
+    };
+    let labels = fetch_labels(storage, &relation, &[], returning, &CteContext::default()).await?;
+    let columns = labels.clone().map(Rc::from);
+    let project = Project::new(storage, None, returning);
+
+    let rows = stream::iter(rows)
+        .then(|row| {
+            let context = RowContext::new(table_name, Cow::Owned(row), None);
+
+            project.apply(None, columns.clone(), Rc::new(context))
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    match labels {
+        Some(labels) => rows
+            .into_iter()
+            .map(Row::try_into_vec)
+            .collect::<Result<_>>()
+            .map(|rows| Payload::Select { labels, rows }),
+        None => rows
+            .into_iter()
+            .map(Row::try_into_map)
+            .collect::<Result<_>>()
+            .map(Payload::SelectMap),
+    }
+}
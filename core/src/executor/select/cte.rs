@@ -0,0 +1,128 @@
+use {
+    super::{distinct::row_keys, select_with_ctes, SelectError},
+    crate::{
+        ast::{Cte, Query, SetExpr, SetOperator, TableAlias, With},
+        data::{Key, Row},
+        executor::{
+            context::{CteContext, CteTable, RowContext},
+            fetch::alias_labels,
+            profile::Profile,
+        },
+        plan::references_cte,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::TryStreamExt,
+    std::{collections::HashSet, rc::Rc},
+};
+
+pub async fn materialize<'a, T: GStore>(
+    storage: &'a T,
+    with: &'a With,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    cte_context: CteContext,
+    profile: Option<Rc<Profile>>,
+) -> Result<CteContext> {
+    let With {
+        recursive,
+        cte_tables,
+    } = with;
+
+    let mut cte_context = cte_context;
+
+    for Cte { alias, query } in cte_tables {
+        let recursive_terms = match recursive {
+            true => split_recursive(alias, query)?,
+            false => None,
+        };
+
+        let table = match recursive_terms {
+            None => {
+                let filter_context = filter_context.as_ref().map(Rc::clone);
+                let profile = profile.as_ref().map(Rc::clone);
+                let (labels, rows) =
+                    select_with_ctes(storage, query, filter_context, cte_context.clone(), profile)
+                        .await?;
+                let rows = rows.try_collect::<Vec<_>>().await?;
+
+                CteTable {
+                    columns: alias_labels(alias, labels)?.map(Rc::from),
+                    rows: Rc::from(rows),
+                }
+            }
+            Some((anchor, step, all)) => {
+                let (labels, rows) = select_with_ctes(
+                    storage,
+                    anchor,
+                    filter_context.as_ref().map(Rc::clone),
+                    cte_context.clone(),
+                    profile.as_ref().map(Rc::clone),
+                )
+                .await?;
+                let columns = alias_labels(alias, labels)?.map(Rc::from);
+
+                let mut keys = HashSet::new();
+                let mut working = retain_new(rows.try_collect().await?, all, &mut keys)?;
+                let mut rows = working.clone();
+
+                while !working.is_empty() {
+                    let table = CteTable {
+                        columns: columns.as_ref().map(Rc::clone),
+                        rows: Rc::from(working),
+                    };
+                    let step_context = cte_context.update(alias.name.to_owned(), table);
+                    let filter_context = filter_context.as_ref().map(Rc::clone);
+                    let profile = profile.as_ref().map(Rc::clone);
+                    let (_, step_rows) =
+                        select_with_ctes(storage, step, filter_context, step_context, profile)
+                            .await?;
+
+                    working = retain_new(step_rows.try_collect().await?, all, &mut keys)?;
+                    rows.extend(working.iter().cloned());
+                }
+
+                CteTable {
+                    columns,
+                    rows: Rc::from(rows),
+                }
+            }
+        };
+
+        cte_context = cte_context.update(alias.name.to_owned(), table);
+    }
+
+    Ok(cte_context)
+}
+
+/// Splits the query of a CTE in `WITH RECURSIVE` into its non-recursive term, its recursive
+/// term and whether duplicate rows are kept.
+///
+/// Returns `None` when the CTE never refers to itself, so it is evaluated like a plain CTE.
+pub fn split_recursive<'a>(
+    alias: &TableAlias,
+    query: &'a Query,
+) -> Result<Option<(&'a Query, &'a Query, bool)>> {
+    match &query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all,
+            left,
+            right,
+        } if references_cte(&alias.name, right) => Ok(Some((left, right, *all))),
+        _ if references_cte(&alias.name, query) => {
+            Err(SelectError::RecursiveQueryWithoutUnion(alias.name.to_owned()).into())
+        }
+        _ => Ok(None),
+    }
+}
+
+fn retain_new(rows: Vec<Row>, all: bool, keys: &mut HashSet<Vec<Key>>) -> Result<Vec<Row>> {
+    if all {
+        return Ok(rows);
+    }
+
+    rows.into_iter()
+        .map(|row| row_keys(&row).map(|row_keys| keys.insert(row_keys).then_some(row)))
+        .filter_map(Result::transpose)
+        .collect()
+}
@@ -0,0 +1,138 @@
+use {
+    crate::{
+        ast::{Aggregate, Distinct as AstDistinct, Expr},
+        data::{Key, Row, Value},
+        executor::{context::RowContext, evaluate::evaluate},
+        result::Result,
+        store::GStore,
+    },
+    futures::{
+        future,
+        stream::{self, Stream, StreamExt, TryStreamExt},
+    },
+    im_rc::HashMap,
+    std::{borrow::Cow, collections::HashSet, rc::Rc},
+};
+
+pub struct Distinct<'a, T: GStore> {
+    storage: &'a T,
+    context: Option<Rc<RowContext<'a>>>,
+    distinct: Option<&'a AstDistinct>,
+}
+
+impl<'a, T: GStore> Distinct<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        context: Option<Rc<RowContext<'a>>>,
+        distinct: Option<&'a AstDistinct>,
+    ) -> Self {
+        Self {
+            storage,
+            context,
+            distinct,
+        }
+    }
+
+    pub fn apply(
+        self,
+        rows: impl Stream<
+                Item = Result<(
+                    Option<Rc<HashMap<&'a Aggregate, Value>>>,
+                    Rc<RowContext<'a>>,
+                    Row,
+                )>,
+            > + 'a,
+        table_alias: &'a str,
+    ) -> impl Stream<Item = Result<Row>> + 'a {
+        #[derive(futures_enum::Stream)]
+        enum Rows<S1, S2> {
+            NonDistinct(S1),
+            Distinct(S2),
+        }
+
+        let distinct = match self.distinct {
+            Some(distinct) => distinct,
+            None => return Rows::NonDistinct(rows.map_ok(|(.., row)| row)),
+        };
+
+        let storage = self.storage;
+        let context = self.context;
+        let rows = rows
+            .and_then(move |(aggregated, next, row)| {
+                let context = context.as_ref().map(Rc::clone);
+
+                async move {
+                    let keys = match distinct {
+                        AstDistinct::Distinct => row_keys(&row)?,
+                        AstDistinct::On(exprs) => {
+                            let filter_context = match context {
+                                Some(context) => Rc::new(RowContext::concat(next, context)),
+                                None => next,
+                            };
+                            let label_context =
+                                Rc::new(RowContext::new(table_alias, Cow::Borrowed(&row), None));
+                            let filter_context = Rc::new(RowContext::concat(
+                                filter_context,
+                                Rc::clone(&label_context),
+                            ));
+
+                            let keys =
+                                expr_keys(storage, &filter_context, aggregated, exprs).await?;
+
+                            drop(label_context);
+                            drop(filter_context);
+
+                            keys
+                        }
+                    };
+
+                    Ok((keys, row))
+                }
+            })
+            .scan(HashSet::new(), |keys_set, item| {
+                let item = item.map(|(keys, row)| keys_set.insert(keys).then_some(row));
+
+                future::ready(Some(item))
+            })
+            .try_filter_map(|row| async move { Ok(row) });
+
+        Rows::Distinct(Box::pin(rows))
+    }
+}
+
+pub(super) fn row_keys(row: &Row) -> Result<Vec<Key>> {
+    match row {
+        Row::Vec { values, .. } => values.iter().map(Key::try_from).collect(),
+        Row::Map(values) => {
+            let mut entries = values.iter().collect::<Vec<_>>();
+            entries.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+
+            entries
+                .into_iter()
+                .flat_map(|(name, value)| [Ok(Key::Str(name.clone())), Key::try_from(value)])
+                .collect()
+        }
+    }
+}
+
+async fn expr_keys<'a, 'b: 'a, 'c: 'a, T: GStore>(
+    storage: &'a T,
+    context: &Rc<RowContext<'b>>,
+    aggregated: Option<Rc<HashMap<&'c Aggregate, Value>>>,
+    exprs: &'a [Expr],
+) -> Result<Vec<Key>> {
+    stream::iter(exprs)
+        .then(|expr| {
+            let context = Some(Rc::clone(context));
+            let aggregated = aggregated.as_ref().map(Rc::clone);
+
+            async move {
+                evaluate(storage, context, aggregated, expr)
+                    .await
+                    .and_then(Value::try_from)
+                    .and_then(Key::try_from)
+            }
+        })
+        .try_collect()
+        .await
+}
@@ -4,4 +4,22 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error("each {op} query must have the same number of columns - left: {left}, right: {right}")]
+    SetOperationColumnCountMismatch {
+        op: String,
+        left: usize,
+        right: usize,
+    },
+
+    #[error(
+        "recursive query {0} must be of the form `non-recursive term UNION [ALL] recursive term`"
+    )]
+    RecursiveQueryWithoutUnion(String),
+
+    #[error("column {0} in USING clause does not exist in both sides of the join")]
+    UsingColumnNotFound(String),
+
+    #[error("NATURAL JOIN is not supported on schemaless tables")]
+    NaturalJoinOnSchemaless,
 }
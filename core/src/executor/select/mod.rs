@@ -1,19 +1,24 @@
+mod cte;
+mod distinct;
 mod error;
 mod project;
+mod set_operation;
 
-pub use error::SelectError;
+pub use {cte::split_recursive, error::SelectError, project::Project};
 
 use {
-    self::project::Project,
+    self::distinct::Distinct,
     super::{
         aggregate::Aggregator,
-        context::{AggregateContext, RowContext},
+        context::{AggregateContext, CteContext, RowContext},
         evaluate::evaluate_stateless,
         fetch::{fetch_labels, fetch_relation_rows},
         filter::Filter,
         join::Join,
         limit::Limit,
+        profile::{Profile, Profiler, Stage},
         sort::Sort,
+        window::Windowing,
     },
     crate::{
         ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
@@ -73,17 +78,23 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     let sorted = stream::iter(rows.into_iter())
         .then(|row| async move {
             stream::iter(order_by)
-                .then(|OrderByExpr { expr, asc }| {
-                    let row = Some(&row);
-
-                    async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)
-                            .map(|key| (key, *asc))
-                    }
-                })
+                .then(
+                    |OrderByExpr {
+                         expr,
+                         asc,
+                         nulls_first,
+                     }| {
+                        let row = Some(&row);
+
+                        async move {
+                            evaluate_stateless(row.map(Row::as_context), expr)
+                                .await
+                                .and_then(Value::try_from)
+                                .and_then(Key::try_from)
+                                .map(|key| (key, *asc, *nulls_first))
+                        }
+                    },
+                )
                 .try_collect::<Vec<_>>()
                 .await
                 .map(|keys| (keys, row))
@@ -100,24 +111,32 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
 }
 
 #[async_recursion(?Send)]
-pub async fn select_with_labels<'a, T: GStore>(
+pub async fn select_with_ctes<'a, T: GStore>(
     storage: &'a T,
     query: &'a Query,
     filter_context: Option<Rc<RowContext<'a>>>,
+    cte_context: CteContext,
+    profile: Option<Rc<Profile>>,
 ) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        SetOperation(S3),
     }
 
-    let Select {
-        from: table_with_joins,
-        selection: where_clause,
-        projection,
-        group_by,
-        having,
-    } = match &query.body {
+    let cte_context = match &query.with {
+        Some(with) => {
+            let filter_context = filter_context.as_ref().map(Rc::clone);
+
+            let profile = profile.as_ref().map(Rc::clone);
+
+            cte::materialize(storage, with, filter_context, cte_context, profile).await?
+        }
+        None => cte_context,
+    };
+
+    let select = match &query.body {
         SetExpr::Select(statement) => statement.as_ref(),
         SetExpr::Values(Values(values_list)) => {
             let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
@@ -128,10 +147,63 @@ pub async fn select_with_labels<'a, T: GStore>(
 
             return Ok((Some(labels), Row::Values(rows)));
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let (left_labels, left_rows) = select_with_ctes(
+                storage,
+                left,
+                filter_context.as_ref().map(Rc::clone),
+                cte_context.clone(),
+                profile.as_ref().map(Rc::clone),
+            )
+            .await?;
+            let (right_labels, right_rows) =
+                select_with_ctes(storage, right, filter_context, cte_context, profile).await?;
+            let (labels, rows) = set_operation::apply(
+                op,
+                *all,
+                (left_labels, Box::pin(left_rows)),
+                (right_labels, Box::pin(right_rows)),
+            )
+            .await?;
+
+            let rows = match query.order_by.is_empty() {
+                true => rows,
+                false => {
+                    let rows = rows.try_collect::<Vec<_>>().await?;
+                    let rows = sort_stateless(rows, &query.order_by).await?;
+
+                    Box::pin(stream::iter(rows.into_iter().map(Ok)))
+                }
+            };
+            let rows = limit.apply(rows);
+
+            return Ok((labels, Row::SetOperation(rows)));
+        }
     };
 
+    let Select {
+        distinct,
+        from: table_with_joins,
+        selection: where_clause,
+        projection,
+        group_by,
+        having,
+        plan_id: _,
+    } = select;
+    let profiler = Profiler::new(profile.as_ref().map(Rc::clone), select);
+
     let TableWithJoins { relation, joins } = &table_with_joins;
-    let rows = fetch_relation_rows(storage, relation, &None)
+    let rows = profiler
+        .build(
+            Stage::Fetch,
+            fetch_relation_rows(storage, relation, &None, &cte_context, &profile),
+        )
         .await?
         .map(move |row| {
             let row = row?;
@@ -139,8 +211,16 @@ pub async fn select_with_labels<'a, T: GStore>(
 
             Ok(RowContext::new(alias, Cow::Owned(row), None))
         });
+    let rows = profiler.stream(Stage::Fetch, rows);
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Rc::clone));
+    let join = Join::new(
+        storage,
+        relation,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+        cte_context.clone(),
+        profile,
+    );
     let aggregate = Aggregator::new(
         storage,
         projection,
@@ -155,13 +235,25 @@ pub async fn select_with_labels<'a, T: GStore>(
         None,
     ));
     let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+    let windowing = Windowing::new(
+        storage,
+        projection,
+        &query.order_by,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let sort = Sort::new(
         storage,
         filter_context.as_ref().map(Rc::clone),
         &query.order_by,
     );
+    let distinct = Distinct::new(
+        storage,
+        filter_context.as_ref().map(Rc::clone),
+        distinct.as_ref(),
+    );
 
-    let rows = join.apply(rows).await?;
+    let rows = profiler.build(Stage::Join, join.apply(rows)).await?;
+    let rows = profiler.stream(Stage::Join, rows);
     let rows = rows.try_filter_map(move |project_context| {
         let filter = Rc::clone(&filter);
 
@@ -172,10 +264,16 @@ pub async fn select_with_labels<'a, T: GStore>(
                 .map(|pass| pass.then_some(project_context))
         }
     });
+    let rows = profiler.stream(Stage::Filter, rows);
 
-    let rows = aggregate.apply(rows).await?;
+    let rows = profiler
+        .build(Stage::Aggregate, aggregate.apply(rows))
+        .await?;
+    let rows = profiler.stream(Stage::Aggregate, rows);
+    let rows = profiler.build(Stage::Window, windowing.apply(rows)).await?;
+    let rows = profiler.stream(Stage::Window, rows);
 
-    let labels = fetch_labels(storage, relation, joins, projection)
+    let labels = fetch_labels(storage, relation, joins, projection, &cte_context)
         .await?
         .map(Rc::from);
 
@@ -195,14 +293,38 @@ pub async fn select_with_labels<'a, T: GStore>(
             Ok((aggregated, next, row))
         }
     });
+    let rows = profiler.stream(Stage::Project, rows);
 
-    let rows = sort.apply(rows, get_alias(relation)).await?;
-    let rows = limit.apply(rows);
+    let rows = profiler
+        .build(Stage::Sort, sort.apply(rows, get_alias(relation)))
+        .await?;
+    let rows = profiler.stream(Stage::Sort, rows);
+    let rows = profiler.stream(Stage::Distinct, distinct.apply(rows, get_alias(relation)));
+    let rows = profiler.stream(Stage::Limit, limit.apply(rows));
     let labels = labels.map(|labels| labels.iter().cloned().collect());
 
     Ok((labels, Row::Select(rows)))
 }
 
+pub async fn select_with_labels<'a, T: GStore>(
+    storage: &'a T,
+    query: &'a Query,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
+    select_with_ctes(storage, query, filter_context, CteContext::default(), None).await
+}
+
+/// Runs the query while measuring the stages of each `SELECT` into the profile.
+pub async fn select_with_profile<'a, T: GStore>(
+    storage: &'a T,
+    query: &'a Query,
+    profile: Rc<Profile>,
+) -> Result<impl Stream<Item = Result<Row>> + 'a> {
+    select_with_ctes(storage, query, None, CteContext::default(), Some(profile))
+        .await
+        .map(|(_, rows)| rows)
+}
+
 pub async fn select<'a, T: GStore>(
     storage: &'a T,
     query: &'a Query,
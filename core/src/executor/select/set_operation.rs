@@ -0,0 +1,103 @@
+use {
+    super::{distinct::row_keys, SelectError},
+    crate::{
+        ast::SetOperator,
+        data::{Key, Row},
+        result::Result,
+    },
+    futures::{
+        future,
+        stream::{Stream, StreamExt, TryStreamExt},
+    },
+    std::{collections::HashMap, pin::Pin, rc::Rc},
+};
+
+type Rows<'a> = Pin<Box<dyn Stream<Item = Result<Row>> + 'a>>;
+type Counts = HashMap<Vec<Key>, usize>;
+
+pub async fn apply<'a>(
+    op: &SetOperator,
+    all: bool,
+    left: (Option<Vec<String>>, Rows<'a>),
+    right: (Option<Vec<String>>, Rows<'a>),
+) -> Result<(Option<Vec<String>>, Rows<'a>)> {
+    let (labels, left_rows) = left;
+    let (right_labels, right_rows) = right;
+
+    if let (Some(left), Some(right)) = (&labels, &right_labels) {
+        if left.len() != right.len() {
+            return Err(SelectError::SetOperationColumnCountMismatch {
+                op: op.to_string(),
+                left: left.len(),
+                right: right.len(),
+            }
+            .into());
+        }
+    }
+
+    let columns: Option<Rc<[String]>> = labels.as_ref().map(|labels| Rc::from(labels.clone()));
+    let right_rows = right_rows.map_ok(move |row| match (row, &columns) {
+        (Row::Vec { values, .. }, Some(columns)) => Row::Vec {
+            columns: Rc::clone(columns),
+            values,
+        },
+        (row, _) => row,
+    });
+
+    let retain: fn(&mut Counts, Vec<Key>) -> bool = match (op, all) {
+        (SetOperator::Union, true) => {
+            let rows = left_rows.chain(right_rows);
+
+            return Ok((labels, Box::pin(rows)));
+        }
+        (SetOperator::Union, false) => |counts, keys| counts.insert(keys, 1).is_none(),
+        (SetOperator::Intersect, true) => |counts, keys| match counts.get_mut(&keys) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+
+                true
+            }
+            _ => false,
+        },
+        (SetOperator::Intersect, false) => |counts, keys| counts.remove(&keys).is_some(),
+        (SetOperator::Except, true) => |counts, keys| match counts.get_mut(&keys) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+
+                false
+            }
+            _ => true,
+        },
+        (SetOperator::Except, false) => |counts, keys| counts.insert(keys, 0).is_none(),
+    };
+
+    let (rows, counts): (Rows<'a>, Counts) = match op {
+        SetOperator::Union => (Box::pin(left_rows.chain(right_rows)), HashMap::new()),
+        SetOperator::Intersect | SetOperator::Except => {
+            let counts = right_rows
+                .try_fold(HashMap::new(), |mut counts, row| {
+                    let counts = row_keys(&row).map(|keys| {
+                        *counts.entry(keys).or_insert(0) += 1;
+
+                        counts
+                    });
+
+                    future::ready(counts)
+                })
+                .await?;
+
+            (left_rows, counts)
+        }
+    };
+
+    let rows = rows
+        .and_then(|row| future::ready(row_keys(&row).map(|keys| (keys, row))))
+        .scan(counts, move |counts, item| {
+            let item = item.map(|(keys, row)| retain(counts, keys).then_some(row));
+
+            future::ready(Some(item))
+        })
+        .try_filter_map(|row| future::ready(Ok(row)));
+
+    Ok((labels, Box::pin(rows)))
+}
@@ -0,0 +1,286 @@
+use {
+    crate::{
+        ast::{
+            AstLiteral, Expr, Function, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
+            ToSql, Values,
+        },
+        data::{Sequence, SequenceError},
+        plan::PlanExpr,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    bigdecimal::BigDecimal,
+    std::{borrow::Cow, cell::RefCell, collections::HashMap},
+};
+
+/// Replaces `NEXTVAL` and `CURRVAL` calls with the values they produce.
+///
+/// Expressions are evaluated against immutable storage, so sequences are advanced
+/// here before execution instead, in the order the calls appear in the statement.
+/// Only `INSERT ... VALUES` rows and `SELECT` without `FROM` are bound, as each of
+/// their expressions is evaluated exactly once; `UPDATE` binds its assignments per
+/// row through `SequenceBinder`, and `NEXTVAL` left anywhere else fails on evaluation.
+pub async fn bind_sequences<'a, T: GStore + GStoreMut>(
+    storage: &mut T,
+    statement: &'a Statement,
+) -> Result<Cow<'a, Statement>> {
+    if !bindable_exprs(statement).any(contains_sequence_function) {
+        return Ok(Cow::Borrowed(statement));
+    }
+
+    let mut statement = statement.clone();
+    let sequences = SequenceBinder::default();
+    for expr in bindable_exprs_mut(&mut statement) {
+        sequences.bind(storage, expr).await?;
+    }
+
+    sequences.save(storage).await?;
+
+    Ok(Cow::Owned(statement))
+}
+
+/// Keeps the sequences `NEXTVAL` advances in memory until `save` writes them back,
+/// so expressions evaluated row by row can share them.
+#[derive(Default)]
+pub struct SequenceBinder {
+    sequences: RefCell<HashMap<String, (Sequence, bool)>>,
+}
+
+impl SequenceBinder {
+    /// Replaces the sequence function calls in `expr` with the values they produce,
+    /// in the order they appear.
+    pub async fn bind<T: GStore>(&self, storage: &T, expr: &mut Expr) -> Result<()> {
+        let mut calls = Vec::new();
+        collect_sequence_functions(expr, &mut calls);
+
+        for expr in calls {
+            let (name, next) = match expr {
+                Expr::Function(func) => match func.as_ref() {
+                    Function::NextVal(name) => (sequence_name(name)?, true),
+                    Function::CurrVal(name) => (sequence_name(name)?, false),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            if !self.sequences.borrow().contains_key(&name) {
+                let sequence = storage
+                    .fetch_sequence(&name)
+                    .await?
+                    .ok_or_else(|| SequenceError::SequenceNotFound(name.to_owned()))?;
+
+                self.sequences
+                    .borrow_mut()
+                    .insert(name.to_owned(), (sequence, false));
+            }
+
+            let mut sequences = self.sequences.borrow_mut();
+            let (sequence, advanced) = sequences
+                .get_mut(&name)
+                .ok_or_else(|| SequenceError::SequenceNotFound(name.to_owned()))?;
+            let value = match next {
+                true => {
+                    *advanced = true;
+                    sequence.next_value()?
+                }
+                false => sequence.current_value()?,
+            };
+
+            *expr = Expr::Literal(AstLiteral::Number(BigDecimal::from(value)));
+        }
+
+        Ok(())
+    }
+
+    pub async fn save<T: GStoreMut>(self, storage: &mut T) -> Result<()> {
+        for (sequence, advanced) in self.sequences.into_inner().into_values() {
+            if advanced {
+                storage.insert_sequence(sequence).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn sequence_name(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Literal(AstLiteral::QuotedString(name)) => Ok(name.to_owned()),
+        _ => Err(SequenceError::SequenceNameNotString(expr.to_sql()).into()),
+    }
+}
+
+fn bindable_exprs(statement: &Statement) -> Box<dyn Iterator<Item = &Expr> + '_> {
+    match statement {
+        Statement::Insert {
+            source:
+                Query {
+                    body: SetExpr::Values(Values(values_list)),
+                    ..
+                },
+            ..
+        }
+        | Statement::Query(Query {
+            body: SetExpr::Values(Values(values_list)),
+            ..
+        }) => Box::new(values_list.iter().flatten()),
+        Statement::Query(Query {
+            body: SetExpr::Select(select),
+            ..
+        }) if is_single_row(select) => {
+            Box::new(select.projection.iter().filter_map(|item| match item {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            }))
+        }
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn bindable_exprs_mut(statement: &mut Statement) -> Box<dyn Iterator<Item = &mut Expr> + '_> {
+    match statement {
+        Statement::Insert {
+            source:
+                Query {
+                    body: SetExpr::Values(Values(values_list)),
+                    ..
+                },
+            ..
+        }
+        | Statement::Query(Query {
+            body: SetExpr::Values(Values(values_list)),
+            ..
+        }) => Box::new(values_list.iter_mut().flatten()),
+        Statement::Query(Query {
+            body: SetExpr::Select(select),
+            ..
+        }) if is_single_row(select) => {
+            Box::new(select.projection.iter_mut().filter_map(|item| match item {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            }))
+        }
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+/// `SELECT` without `FROM` is translated into a single row `SERIES`.
+fn is_single_row(select: &Select) -> bool {
+    select.from.joins.is_empty()
+        && matches!(
+            &select.from.relation,
+            TableFactor::Series {
+                size: Expr::Literal(AstLiteral::Number(size)),
+                ..
+            } if size == &BigDecimal::from(1)
+        )
+}
+
+pub fn contains_sequence_function(expr: &Expr) -> bool {
+    if matches!(
+        expr,
+        Expr::Function(func) if matches!(func.as_ref(), Function::NextVal(_) | Function::CurrVal(_))
+    ) {
+        return true;
+    }
+
+    match PlanExpr::from(expr) {
+        PlanExpr::None
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. }
+        | PlanExpr::Query(_) => false,
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => {
+            contains_sequence_function(expr)
+        }
+        PlanExpr::TwoExprs(expr, expr2) => {
+            contains_sequence_function(expr) || contains_sequence_function(expr2)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => [expr, expr2, expr3]
+            .into_iter()
+            .any(contains_sequence_function),
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(contains_sequence_function),
+    }
+}
+
+/// Collects sequence function calls in the order they appear, descending into
+/// the same expressions `contains_sequence_function` does.
+fn collect_sequence_functions<'a>(expr: &'a mut Expr, calls: &mut Vec<&'a mut Expr>) {
+    match expr {
+        Expr::Function(func)
+            if matches!(func.as_ref(), Function::NextVal(_) | Function::CurrVal(_)) =>
+        {
+            calls.push(expr);
+        }
+        Expr::Function(func) => {
+            for expr in func.as_exprs_mut() {
+                collect_sequence_functions(expr, calls);
+            }
+        }
+        Expr::Aggregate(aggregate) => {
+            if let Some(expr) = aggregate.as_expr_mut() {
+                collect_sequence_functions(expr, calls);
+            }
+        }
+        Expr::Window(window) => {
+            for expr in window.as_exprs_mut() {
+                collect_sequence_functions(expr, calls);
+            }
+        }
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. }
+        | Expr::InSubquery { expr, .. } => collect_sequence_functions(expr, calls),
+        Expr::InList { expr, list, .. } => {
+            collect_sequence_functions(expr, calls);
+            for expr in list {
+                collect_sequence_functions(expr, calls);
+            }
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            collect_sequence_functions(expr, calls);
+            collect_sequence_functions(low, calls);
+            collect_sequence_functions(high, calls);
+        }
+        Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+            collect_sequence_functions(expr, calls);
+            collect_sequence_functions(pattern, calls);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            collect_sequence_functions(left, calls);
+            collect_sequence_functions(right, calls);
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                collect_sequence_functions(operand, calls);
+            }
+            for (when, then) in when_then {
+                collect_sequence_functions(when, calls);
+                collect_sequence_functions(then, calls);
+            }
+            if let Some(else_result) = else_result {
+                collect_sequence_functions(else_result, calls);
+            }
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            collect_sequence_functions(obj, calls);
+            for expr in indexes {
+                collect_sequence_functions(expr, calls);
+            }
+        }
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Placeholder(_)
+        | Expr::Subquery(_)
+        | Expr::Exists { .. } => {}
+    }
+}
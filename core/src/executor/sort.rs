@@ -52,7 +52,15 @@ impl<'a, T: GStore> Sort<'a, T> {
                 )>,
             > + 'a,
         table_alias: &'a str,
-    ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
+    ) -> Result<
+        impl Stream<
+                Item = Result<(
+                    Option<Rc<HashMap<&'a Aggregate, Value>>>,
+                    Rc<RowContext<'a>>,
+                    Row,
+                )>,
+            > + 'a,
+    > {
         #[derive(futures_enum::Stream)]
         enum Rows<I1, I2> {
             NonOrderBy(I1),
@@ -60,8 +68,6 @@ impl<'a, T: GStore> Sort<'a, T> {
         }
 
         if self.order_by.is_empty() {
-            let rows = rows.map_ok(|(.., row)| row);
-
             return Ok(Rows::NonOrderBy(Box::pin(rows)));
         }
 
@@ -75,36 +81,45 @@ impl<'a, T: GStore> Sort<'a, T> {
                 let order_by = self.order_by;
                 let order_by = order_by
                     .iter()
-                    .map(|OrderByExpr { expr, asc }| -> Result<_> {
-                        let big_decimal = match expr {
-                            Expr::Literal(AstLiteral::Number(n)) => Some(n),
-                            Expr::UnaryOp {
-                                op: UnaryOperator::Plus,
-                                expr,
-                            } => match expr.as_ref() {
+                    .map(
+                        |OrderByExpr {
+                             expr,
+                             asc,
+                             nulls_first,
+                         }|
+                         -> Result<_> {
+                            let big_decimal = match expr {
                                 Expr::Literal(AstLiteral::Number(n)) => Some(n),
+                                Expr::UnaryOp {
+                                    op: UnaryOperator::Plus,
+                                    expr,
+                                } => match expr.as_ref() {
+                                    Expr::Literal(AstLiteral::Number(n)) => Some(n),
+                                    _ => None,
+                                },
                                 _ => None,
-                            },
-                            _ => None,
-                        };
-
-                        match (big_decimal, &row) {
-                            (Some(n), Row::Vec { values, .. }) => {
-                                let index = n
-                                    .to_usize()
-                                    .ok_or_else(|| -> Error { SortError::Unreachable.into() })?;
-                                let zero_based = index.checked_sub(1).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-                                let value = values.get(zero_based).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-
-                                Ok((SortType::Value(value.clone()), *asc))
+                            };
+
+                            match (big_decimal, &row) {
+                                (Some(n), Row::Vec { values, .. }) => {
+                                    let index = n.to_usize().ok_or_else(|| -> Error {
+                                        SortError::Unreachable.into()
+                                    })?;
+                                    let zero_based =
+                                        index.checked_sub(1).ok_or_else(|| -> Error {
+                                            SortError::ColumnIndexOutOfRange(index).into()
+                                        })?;
+                                    let value =
+                                        values.get(zero_based).ok_or_else(|| -> Error {
+                                            SortError::ColumnIndexOutOfRange(index).into()
+                                        })?;
+
+                                    Ok((SortType::Value(value.clone()), *asc, *nulls_first))
+                                }
+                                _ => Ok((SortType::Expr(expr), *asc, *nulls_first)),
                             }
-                            _ => Ok((SortType::Expr(expr), *asc)),
-                        }
-                    })
+                        },
+                    )
                     .collect::<Result<Vec<_>>>();
 
                 let filter_context = match &self.context {
@@ -124,7 +139,7 @@ impl<'a, T: GStore> Sort<'a, T> {
 
                     let keys = order_by
                         .map(stream::iter)?
-                        .then(|(sort_type, asc)| {
+                        .then(|(sort_type, asc, nulls_first)| {
                             let context = Some(Rc::clone(&filter_context));
                             let aggregated = aggregated.as_ref().map(Rc::clone);
 
@@ -138,7 +153,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                                     }
                                 }
                                 .try_into()
-                                .map(|key| (key, asc))
+                                .map(|key| (key, asc, nulls_first))
                             }
                         })
                         .try_collect::<Vec<_>>()
@@ -147,29 +162,46 @@ impl<'a, T: GStore> Sort<'a, T> {
                     drop(label_context);
                     drop(filter_context);
 
-                    Ok((keys, row))
+                    Ok((keys, aggregated, next, row))
                 }
             })
-            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, Row)>>()
+            .try_collect::<Vec<_>>()
             .await
             .map(Vector::from)?
             .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
             .into_iter()
-            .map(|(.., row)| Ok(row));
+            .map(|(_, aggregated, next, row)| Ok((aggregated, next, row)));
 
         Ok(Rows::OrderBy(stream::iter(rows)))
     }
 }
 
-pub fn sort_by(keys_a: &[(Key, Option<bool>)], keys_b: &[(Key, Option<bool>)]) -> Ordering {
-    let pairs = keys_a
-        .iter()
-        .map(|(a, _)| a)
-        .zip(keys_b.iter())
-        .map(|(a, (b, asc))| (a, b, asc.unwrap_or(true)));
-
-    for (key_a, key_b, asc) in pairs {
-        match (key_a.cmp(key_b), asc) {
+/// Evaluated sort key with its `ASC | DESC` and `NULLS FIRST | LAST` options
+pub type SortKey = (Key, Option<bool>, Option<bool>);
+
+pub fn sort_by(keys_a: &[SortKey], keys_b: &[SortKey]) -> Ordering {
+    let pairs =
+        keys_a
+            .iter()
+            .map(|(a, ..)| a)
+            .zip(keys_b.iter())
+            .map(|(a, (b, asc, nulls_first))| {
+                let asc = asc.unwrap_or(true);
+
+                (a, b, asc, nulls_first.unwrap_or(!asc))
+            });
+
+    for (key_a, key_b, asc, nulls_first) in pairs {
+        let ord = match (key_a, key_b) {
+            (Key::None, Key::None) => continue,
+            (Key::None, _) if nulls_first => return Ordering::Less,
+            (Key::None, _) => return Ordering::Greater,
+            (_, Key::None) if nulls_first => return Ordering::Greater,
+            (_, Key::None) => return Ordering::Less,
+            _ => key_a.cmp(key_b),
+        };
+
+        match (ord, asc) {
             (Ordering::Equal, _) => continue,
             (ord, true) => return ord,
             (ord, false) => return ord.reverse(),
@@ -2,10 +2,11 @@ use {
     super::{
         context::RowContext,
         evaluate::{evaluate, Evaluated},
+        sequence::{contains_sequence_function, SequenceBinder},
     },
     crate::{
-        ast::{Assignment, ColumnDef, ColumnUniqueOption},
-        data::{Row, Value},
+        ast::{Assignment, ColumnDef, UniqueConstraint},
+        data::{primary_key_indexes, Row, Value},
         result::{Error, Result},
         store::GStore,
     },
@@ -33,6 +34,7 @@ pub struct Update<'a, T: GStore> {
     table_name: &'a str,
     fields: &'a [Assignment],
     column_defs: Option<&'a [ColumnDef]>,
+    sequences: Option<&'a SequenceBinder>,
 }
 
 impl<'a, T: GStore> Update<'a, T> {
@@ -41,16 +43,23 @@ impl<'a, T: GStore> Update<'a, T> {
         table_name: &'a str,
         fields: &'a [Assignment],
         column_defs: Option<&'a [ColumnDef]>,
+        unique_constraints: &[UniqueConstraint],
+        sequences: Option<&'a SequenceBinder>,
     ) -> Result<Self> {
         if let Some(column_defs) = column_defs {
+            let primary_key =
+                primary_key_indexes(column_defs, unique_constraints).unwrap_or_default();
+
             for assignment in fields.iter() {
                 let Assignment { id, .. } = assignment;
 
                 if column_defs.iter().all(|col_def| &col_def.name != id) {
                     return Err(UpdateError::ColumnNotFound(id.to_owned()).into());
-                } else if column_defs.iter().any(|ColumnDef { name, unique, .. }| {
-                    name == id && matches!(unique, Some(ColumnUniqueOption { is_primary: true }))
-                }) {
+                } else if column_defs
+                    .iter()
+                    .enumerate()
+                    .any(|(i, ColumnDef { name, .. })| name == id && primary_key.contains(&i))
+                {
                     return Err(UpdateError::UpdateOnPrimaryKeyNotSupported(id.to_owned()).into());
                 }
             }
@@ -61,14 +70,36 @@ impl<'a, T: GStore> Update<'a, T> {
             table_name,
             fields,
             column_defs,
+            sequences: sequences.filter(|_| {
+                fields
+                    .iter()
+                    .any(|Assignment { value, .. }| contains_sequence_function(value))
+            }),
         })
     }
 
     pub async fn apply(&self, row: Row) -> Result<Row> {
-        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), None);
+        self.apply_with(row, None).await
+    }
+
+    /// Applies the assignments with an extra `next` context to evaluate them against,
+    /// such as the `EXCLUDED` row of `INSERT ... ON CONFLICT DO UPDATE`.
+    pub async fn apply_with(&self, row: Row, next: Option<Rc<RowContext<'_>>>) -> Result<Row> {
+        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), next);
         let context = Some(Rc::new(context));
+        let fields = match self.sequences {
+            Some(sequences) => {
+                let mut fields = self.fields.to_vec();
+                for Assignment { value, .. } in fields.iter_mut() {
+                    sequences.bind(self.storage, value).await?;
+                }
+
+                Cow::Owned(fields)
+            }
+            None => Cow::Borrowed(self.fields),
+        };
 
-        let assignments = stream::iter(self.fields.iter())
+        let assignments = stream::iter(fields.iter())
             .then(|assignment| {
                 let Assignment {
                     id,
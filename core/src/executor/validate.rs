@@ -1,10 +1,15 @@
 use {
+    super::{
+        context::RowContext,
+        evaluate::{evaluate_stateless, EvaluateError},
+    },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption},
-        data::{Key, Value},
+        ast::{BinaryOperator, Check, ColumnDef, Expr, UnaryOperator, UniqueConstraint},
+        data::{primary_key_indexes, Key, Value},
         result::Result,
         store::{DataRow, Store},
     },
+    async_recursion::async_recursion,
     im_rc::HashSet,
     serde::Serialize,
     std::fmt::Debug,
@@ -25,58 +30,78 @@ pub enum ValidateError {
 
     #[error("duplicate entry '{0:?}' for primary_key field")]
     DuplicateEntryOnPrimaryKeyField(Key),
+
+    #[error("check constraint '{0}' is violated")]
+    CheckConstraintViolated(String),
 }
 
 pub enum ColumnValidation<'column_def> {
     /// `INSERT`
-    All(&'column_def [ColumnDef]),
+    All(&'column_def [ColumnDef], &'column_def [UniqueConstraint]),
     /// `UPDATE`
-    SpecifiedColumns(&'column_def [ColumnDef], Vec<String>),
+    SpecifiedColumns(
+        &'column_def [ColumnDef],
+        &'column_def [UniqueConstraint],
+        Vec<String>,
+    ),
 }
 
+/// Keys of a unique column, or of the columns of a composite unique constraint
 #[derive(Debug)]
-struct UniqueConstraint {
-    column_index: usize,
+struct UniqueKeys {
+    column_indexes: Vec<usize>,
     column_name: String,
     keys: HashSet<Key>,
 }
 
-impl UniqueConstraint {
-    fn new(column_index: usize, column_name: String) -> Self {
+impl UniqueKeys {
+    fn new(column_indexes: Vec<usize>, column_name: String) -> Self {
         Self {
-            column_index,
+            column_indexes,
             column_name,
             keys: HashSet::new(),
         }
     }
 
-    fn add(self, value: &Value) -> Result<Self> {
-        let new_key = self.check(value)?;
+    fn add(self, row: &[Value]) -> Result<Self> {
+        let new_key = self.check(row)?;
 
-        if matches!(new_key, Key::None) {
+        if new_key.has_null() {
             return Ok(self);
         }
 
         let keys = self.keys.update(new_key);
 
         Ok(Self {
-            column_index: self.column_index,
+            column_indexes: self.column_indexes,
             column_name: self.column_name,
             keys,
         })
     }
 
-    fn check(&self, value: &Value) -> Result<Key> {
-        let key = Key::try_from(value)?;
+    fn check(&self, row: &[Value]) -> Result<Key> {
+        let values = self
+            .column_indexes
+            .iter()
+            .map(|i| {
+                row.get(*i)
+                    .ok_or(ValidateError::ConflictOnStorageColumnIndex(*i))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let key = Key::from_values(values.iter().copied())?;
 
         if !self.keys.contains(&key) {
             Ok(key)
         } else {
-            Err(ValidateError::DuplicateEntryOnUniqueField(
-                value.clone(),
-                self.column_name.to_owned(),
+            let value = match values.as_slice() {
+                [value] => (*value).clone(),
+                _ => Value::List(values.into_iter().cloned().collect()),
+            };
+
+            Err(
+                ValidateError::DuplicateEntryOnUniqueField(value, self.column_name.to_owned())
+                    .into(),
             )
-            .into())
         }
     }
 }
@@ -88,44 +113,37 @@ pub async fn validate_unique<T: Store>(
     row_iter: impl Iterator<Item = &[Value]> + Clone,
 ) -> Result<()> {
     enum Columns {
-        /// key index
-        PrimaryKeyOnly(usize),
-        /// `[(key_index, table_name)]`
-        All(Vec<(usize, String)>),
+        /// key indexes
+        PrimaryKeyOnly(Vec<usize>),
+        /// `[(key_indexes, column_name)]`
+        All(Vec<(Vec<usize>, String)>),
     }
 
     let columns = match &column_validation {
-        ColumnValidation::All(column_defs) => {
-            let primary_key_index = column_defs
-                .iter()
-                .enumerate()
-                .find(|(_, ColumnDef { unique, .. })| {
-                    unique == &Some(ColumnUniqueOption { is_primary: true })
-                })
-                .map(|(i, _)| i);
-            let other_unique_column_def_count = column_defs
-                .iter()
-                .filter(|ColumnDef { unique, .. }| {
-                    unique == &Some(ColumnUniqueOption { is_primary: false })
-                })
-                .count();
-
-            match (primary_key_index, other_unique_column_def_count) {
-                (Some(primary_key_index), 0) => Columns::PrimaryKeyOnly(primary_key_index),
-                _ => Columns::All(fetch_all_unique_columns(column_defs)),
+        ColumnValidation::All(column_defs, unique_constraints) => {
+            let primary_key_indexes = primary_key_indexes(column_defs, unique_constraints);
+            let unique_columns = fetch_all_unique_columns(column_defs, unique_constraints);
+
+            match primary_key_indexes {
+                Some(primary_key_indexes) if unique_columns.len() == 1 => {
+                    Columns::PrimaryKeyOnly(primary_key_indexes)
+                }
+                _ => Columns::All(unique_columns),
             }
         }
-        ColumnValidation::SpecifiedColumns(column_defs, specified_columns) => Columns::All(
-            fetch_specified_unique_columns(column_defs, specified_columns),
-        ),
+        ColumnValidation::SpecifiedColumns(column_defs, unique_constraints, specified_columns) => {
+            Columns::All(fetch_specified_unique_columns(
+                column_defs,
+                unique_constraints,
+                specified_columns,
+            ))
+        }
     };
 
     match columns {
-        Columns::PrimaryKeyOnly(primary_key_index) => {
-            for primary_key in
-                row_iter.filter_map(|row| row.get(primary_key_index).map(Key::try_from))
-            {
-                let key = primary_key?;
+        Columns::PrimaryKeyOnly(primary_key_indexes) => {
+            for row in row_iter {
+                let key = Key::from_values(primary_key_indexes.iter().filter_map(|i| row.get(*i)))?;
 
                 if storage.fetch_data(table_name, &key).await?.is_some() {
                     return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
@@ -135,12 +153,12 @@ pub async fn validate_unique<T: Store>(
             Ok(())
         }
         Columns::All(columns) => {
-            let unique_constraints: Vec<_> = create_unique_constraints(columns, row_iter)?.into();
-            if unique_constraints.is_empty() {
+            let unique_keys: Vec<_> = create_unique_keys(columns, row_iter)?.into();
+            if unique_keys.is_empty() {
                 return Ok(());
             }
 
-            let unique_constraints = &unique_constraints;
+            let unique_keys = &unique_keys;
             storage.scan_data(table_name).await?.try_for_each(|result| {
                 let (_, data_row) = result?;
                 let values = match data_row {
@@ -150,62 +168,171 @@ pub async fn validate_unique<T: Store>(
                     }
                 };
 
-                unique_constraints.iter().try_for_each(|constraint| {
-                    let col_idx = constraint.column_index;
-                    let val = values
-                        .get(col_idx)
-                        .ok_or(ValidateError::ConflictOnStorageColumnIndex(col_idx))?;
+                unique_keys
+                    .iter()
+                    .try_for_each(|unique_keys| unique_keys.check(&values).map(|_| ()))
+            })
+        }
+    }
+}
+
+/// Validates `CHECK` constraints on each row, a constraint only fails when it evaluates to `FALSE`
+pub async fn validate_checks(
+    checks: &[Check],
+    columns: &[String],
+    rows: &[&[Value]],
+) -> Result<()> {
+    for values in rows {
+        for Check { name, expr } in checks {
+            if evaluate_check(columns, values, expr).await? == Some(false) {
+                return Err(ValidateError::CheckConstraintViolated(name.to_owned()).into());
+            }
+        }
+    }
 
-                    constraint.check(val)?;
+    Ok(())
+}
 
-                    Ok(())
-                })
-            })
+/// Evaluates a condition of `CHECK` in three-valued logic, `None` is `UNKNOWN`. Comparisons
+/// evaluate to `FALSE` on `NULL` elsewhere, so they are `UNKNOWN` here when an operand is `NULL`.
+#[async_recursion(?Send)]
+async fn evaluate_check(columns: &[String], values: &[Value], expr: &Expr) -> Result<Option<bool>> {
+    let evaluate = |expr| async move {
+        let context = RowContext::RefVecData { columns, values };
+
+        Value::try_from(evaluate_stateless(Some(context), expr).await?)
+    };
+    let is_null = |expr| async move { evaluate(expr).await.map(|value| value.is_null()) };
+
+    match expr {
+        Expr::Nested(expr) => evaluate_check(columns, values, expr).await,
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr,
+        } => Ok(evaluate_check(columns, values, expr).await?.map(|b| !b)),
+        Expr::BinaryOp {
+            left,
+            op: op @ (BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor),
+            right,
+        } => {
+            let left = evaluate_check(columns, values, left).await?;
+            let right = evaluate_check(columns, values, right).await?;
+
+            let value = match (op, left, right) {
+                (BinaryOperator::And, Some(false), _) | (BinaryOperator::And, _, Some(false)) => {
+                    Some(false)
+                }
+                (BinaryOperator::Or, Some(true), _) | (BinaryOperator::Or, _, Some(true)) => {
+                    Some(true)
+                }
+                (_, None, _) | (_, _, None) => None,
+                (BinaryOperator::Xor, Some(left), Some(right)) => Some(left ^ right),
+                (BinaryOperator::And, Some(_), Some(_)) => Some(true),
+                (_, Some(_), Some(_)) => Some(false),
+            };
+
+            Ok(value)
         }
+        Expr::BinaryOp {
+            left,
+            op:
+                BinaryOperator::Gt
+                | BinaryOperator::Lt
+                | BinaryOperator::GtEq
+                | BinaryOperator::LtEq
+                | BinaryOperator::Eq
+                | BinaryOperator::NotEq,
+            right,
+        } if is_null(left).await? || is_null(right).await? => Ok(None),
+        Expr::Between {
+            expr: target,
+            low,
+            high,
+            ..
+        } if is_null(target).await? || is_null(low).await? || is_null(high).await? => Ok(None),
+        Expr::InList { expr: target, .. } if is_null(target).await? => Ok(None),
+        _ => match evaluate(expr).await? {
+            Value::Bool(b) => Ok(Some(b)),
+            Value::Null => Ok(None),
+            value => Err(EvaluateError::BooleanTypeRequired(format!("{value:?}")).into()),
+        },
     }
 }
 
-fn create_unique_constraints<'a>(
-    unique_columns: Vec<(usize, String)>,
+/// Validates that `row_iter` has no duplicate values on any of the unique columns
+/// and composite unique constraints
+pub fn validate_unique_rows<'a>(
+    column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
+    row_iter: impl Iterator<Item = &'a [Value]> + Clone,
+) -> Result<()> {
+    create_unique_keys(
+        fetch_all_unique_columns(column_defs, unique_constraints),
+        row_iter,
+    )
+    .map(|_| ())
+}
+
+fn create_unique_keys<'a>(
+    unique_columns: Vec<(Vec<usize>, String)>,
     row_iter: impl Iterator<Item = &'a [Value]> + Clone,
-) -> Result<Vector<UniqueConstraint>> {
+) -> Result<Vector<UniqueKeys>> {
     unique_columns
         .into_iter()
-        .try_fold(Vector::new(), |constraints, col| {
-            let (col_idx, col_name) = col;
-            let new_constraint = UniqueConstraint::new(col_idx, col_name);
-            let new_constraint = row_iter
+        .try_fold(Vector::new(), |unique_keys, (col_indexes, col_name)| {
+            let new_keys = UniqueKeys::new(col_indexes, col_name);
+            let new_keys = row_iter
                 .clone()
-                .try_fold(new_constraint, |constraint, row| {
-                    let val = row
-                        .get(col_idx)
-                        .ok_or(ValidateError::ConflictOnStorageColumnIndex(col_idx))?;
-
-                    constraint.add(val)
-                })?;
-            Ok(constraints.push(new_constraint))
+                .try_fold(new_keys, |keys, row| keys.add(row))?;
+
+            Ok(unique_keys.push(new_keys))
         })
 }
 
-fn fetch_all_unique_columns(column_defs: &[ColumnDef]) -> Vec<(usize, String)> {
+fn fetch_all_unique_columns(
+    column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
+) -> Vec<(Vec<usize>, String)> {
     column_defs
         .iter()
         .enumerate()
-        .filter_map(|(i, table_col)| table_col.unique.map(|_| (i, table_col.name.to_owned())))
+        .filter_map(|(i, table_col)| {
+            table_col
+                .unique
+                .map(|_| (vec![i], table_col.name.to_owned()))
+        })
+        .chain(
+            unique_constraints
+                .iter()
+                .filter_map(|UniqueConstraint { columns, .. }| {
+                    let indexes = columns
+                        .iter()
+                        .map(|column| {
+                            column_defs
+                                .iter()
+                                .position(|column_def| &column_def.name == column)
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+
+                    Some((indexes, columns.join(", ")))
+                }),
+        )
         .collect()
 }
 
 fn fetch_specified_unique_columns(
     all_column_defs: &[ColumnDef],
+    unique_constraints: &[UniqueConstraint],
     specified_columns: &[String],
-) -> Vec<(usize, String)> {
-    all_column_defs
-        .iter()
-        .enumerate()
-        .filter_map(|(i, table_col)| {
-            (table_col.unique.is_some()
-                && specified_columns.iter().any(|col| col == &table_col.name))
-            .then_some((i, table_col.name.to_owned()))
+) -> Vec<(Vec<usize>, String)> {
+    fetch_all_unique_columns(all_column_defs, unique_constraints)
+        .into_iter()
+        .filter(|(col_indexes, _)| {
+            col_indexes.iter().any(|i| {
+                specified_columns
+                    .iter()
+                    .any(|col| col == &all_column_defs[*i].name)
+            })
         })
         .collect()
 }
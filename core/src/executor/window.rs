@@ -0,0 +1,503 @@
+use {
+    super::{
+        aggregate::FrameAggregate,
+        context::{AggregateContext, RowContext},
+        evaluate::{evaluate, evaluate_stateless},
+        sort::{sort_by, SortKey},
+    },
+    crate::{
+        ast::{
+            Aggregate, Expr, OrderByExpr, SelectItem, Window, WindowFrame, WindowFrameBound,
+            WindowFrameUnits, WindowFunction,
+        },
+        data::{Key, Value},
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, Stream, StreamExt, TryStreamExt},
+    serde::Serialize,
+    std::{cmp::Ordering, collections::HashMap, iter, rc::Rc},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum WindowError {
+    #[error("RANGE with offset PRECEDING or FOLLOWING is not supported")]
+    RangeOffsetNotSupported,
+
+    #[error("frame start cannot be UNBOUNDED FOLLOWING")]
+    FrameStartUnboundedFollowing,
+
+    #[error("frame end cannot be UNBOUNDED PRECEDING")]
+    FrameEndUnboundedPreceding,
+}
+
+type Aggregated<'a> = Option<Rc<im_rc::HashMap<&'a Aggregate, Value>>>;
+type Context<'a> = (Aggregated<'a>, Rc<RowContext<'a>>);
+
+/// Evaluates window functions found in the SELECT list and ORDER BY clause.
+///
+/// Window functions are computed after aggregation, over all the rows of a query at once.
+/// The computed values are attached to each row context so projection and sorting can look
+/// them up just like any other value.
+pub struct Windowing<'a, T: GStore> {
+    storage: &'a T,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    windows: Vec<&'a Window>,
+}
+
+impl<'a, T: GStore> Windowing<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        fields: &'a [SelectItem],
+        order_by: &'a [OrderByExpr],
+        filter_context: Option<Rc<RowContext<'a>>>,
+    ) -> Self {
+        let mut windows = Vec::new();
+        let exprs = fields
+            .iter()
+            .filter_map(|field| match field {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            })
+            .chain(order_by.iter().map(|OrderByExpr { expr, .. }| expr));
+
+        for expr in exprs {
+            collect_windows(expr, &mut windows);
+        }
+
+        Self {
+            storage,
+            filter_context,
+            windows,
+        }
+    }
+
+    pub async fn apply(
+        &self,
+        rows: impl Stream<Item = Result<AggregateContext<'a>>> + 'a,
+    ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>> + 'a> {
+        #[derive(futures_enum::Stream)]
+        enum Rows<I1, I2> {
+            NonWindow(I1),
+            Window(I2),
+        }
+
+        if self.windows.is_empty() {
+            return Ok(Rows::NonWindow(rows));
+        }
+
+        let rows = rows.try_collect::<Vec<_>>().await?;
+        let contexts = rows
+            .iter()
+            .map(|AggregateContext { aggregated, next }| {
+                let context = match &self.filter_context {
+                    Some(filter_context) => Rc::new(RowContext::concat(
+                        Rc::clone(next),
+                        Rc::clone(filter_context),
+                    )),
+                    None => Rc::clone(next),
+                };
+
+                (aggregated.clone().map(Rc::new), context)
+            })
+            .collect::<Vec<_>>();
+
+        let mut values = vec![HashMap::new(); rows.len()];
+        for window in &self.windows {
+            let evaluated = self.evaluate_window(window, &contexts).await?;
+
+            for (values, value) in values.iter_mut().zip(evaluated) {
+                values.insert(*window, value);
+            }
+        }
+
+        drop(contexts);
+
+        let rows =
+            rows.into_iter()
+                .zip(values)
+                .map(|(AggregateContext { aggregated, next }, values)| {
+                    Ok(AggregateContext {
+                        aggregated,
+                        next: Rc::new(RowContext::window(values, next)),
+                    })
+                });
+
+        Ok(Rows::Window(stream::iter(rows)))
+    }
+
+    async fn evaluate_window(
+        &self,
+        window: &'a Window,
+        contexts: &[Context<'a>],
+    ) -> Result<Vec<Value>> {
+        let Window { func, over } = window;
+
+        let mut partitions: HashMap<Vec<Key>, Vec<usize>> = HashMap::new();
+        let mut order_keys = Vec::with_capacity(contexts.len());
+
+        for (index, context) in contexts.iter().enumerate() {
+            let partition_key = stream::iter(&over.partition_by)
+                .then(|expr| self.evaluate(context, expr))
+                .and_then(|value| async move { Key::try_from(value) })
+                .try_collect::<Vec<_>>()
+                .await?;
+            let order_key = stream::iter(&over.order_by)
+                .then(
+                    |OrderByExpr {
+                         expr,
+                         asc,
+                         nulls_first,
+                     }| async move {
+                        let value = self.evaluate(context, expr).await?;
+
+                        Key::try_from(value).map(|key| (key, *asc, *nulls_first))
+                    },
+                )
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            partitions.entry(partition_key).or_default().push(index);
+            order_keys.push(order_key);
+        }
+
+        let frame = Frame::new(over.frame.as_ref(), !over.order_by.is_empty()).await?;
+        let mut values = vec![Value::Null; contexts.len()];
+
+        for mut indexes in partitions.into_values() {
+            indexes.sort_by(|a, b| sort_by(&order_keys[*a], &order_keys[*b]));
+
+            let peers = peer_groups(&indexes, &order_keys);
+            let partition = Partition {
+                indexes: &indexes,
+                peers: &peers,
+                contexts,
+            };
+            let partition_values = self.evaluate_partition(func, &frame, partition).await?;
+
+            for (index, value) in indexes.iter().zip(partition_values) {
+                values[*index] = value;
+            }
+        }
+
+        Ok(values)
+    }
+
+    async fn evaluate_partition(
+        &self,
+        func: &'a WindowFunction,
+        frame: &Frame,
+        partition: Partition<'_, 'a>,
+    ) -> Result<Vec<Value>> {
+        let Partition {
+            indexes,
+            peers,
+            contexts,
+        } = partition;
+        let size = indexes.len();
+        let context = |position: usize| &contexts[indexes[position]];
+
+        match func {
+            WindowFunction::RowNumber => Ok((1..=size).map(|n| Value::I64(n as i64)).collect()),
+            WindowFunction::Rank => Ok(peers
+                .iter()
+                .map(|(start, ..)| Value::I64(*start as i64 + 1))
+                .collect()),
+            WindowFunction::DenseRank => Ok(peers
+                .iter()
+                .map(|(.., dense_rank)| Value::I64(*dense_rank as i64))
+                .collect()),
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => {
+                let offset = match offset {
+                    Some(offset) => evaluate_offset(offset).await?,
+                    None => 1,
+                };
+                let lag = matches!(func, WindowFunction::Lag { .. });
+                let mut values = Vec::with_capacity(size);
+
+                for position in 0..size {
+                    let target = match lag {
+                        true => position.checked_sub(offset),
+                        false => position.checked_add(offset).filter(|target| *target < size),
+                    };
+
+                    let value = match (target, default) {
+                        (Some(target), _) => self.evaluate(context(target), expr).await?,
+                        (None, Some(default)) => self.evaluate(context(position), default).await?,
+                        (None, None) => Value::Null,
+                    };
+
+                    values.push(value);
+                }
+
+                Ok(values)
+            }
+            WindowFunction::FirstValue(expr) | WindowFunction::LastValue(expr) => {
+                let first = matches!(func, WindowFunction::FirstValue(_));
+                let mut values = Vec::with_capacity(size);
+
+                for (position, peer) in peers.iter().enumerate() {
+                    let (start, end) = frame.range(position, *peer, size);
+                    let target = match first {
+                        true => (start < end).then_some(start),
+                        false => end.checked_sub(1).filter(|target| *target >= start),
+                    };
+
+                    let value = match target {
+                        Some(target) => self.evaluate(context(target), expr).await?,
+                        None => Value::Null,
+                    };
+
+                    values.push(value);
+                }
+
+                Ok(values)
+            }
+            WindowFunction::Aggregate(aggregate) => {
+                let arguments = stream::iter(0..size)
+                    .then(|position| async move {
+                        match aggregate.as_expr() {
+                            Some(expr) => self.evaluate(context(position), expr).await,
+                            None => Ok(Value::Null),
+                        }
+                    })
+                    .try_collect::<Vec<_>>()
+                    .await?;
+
+                // The accumulated frame is only rebuilt when its start moves, so frames
+                // starting at UNBOUNDED PRECEDING are aggregated in a single pass.
+                let mut accumulated = FrameAggregate::new(aggregate);
+                let (mut accumulated_start, mut accumulated_end) = (0, 0);
+                let mut values = Vec::with_capacity(size);
+
+                for (position, peer) in peers.iter().enumerate() {
+                    let (start, end) = frame.range(position, *peer, size);
+
+                    if start != accumulated_start || end < accumulated_end {
+                        accumulated = FrameAggregate::new(aggregate);
+                        (accumulated_start, accumulated_end) = (start, start);
+                    }
+
+                    for argument in &arguments[accumulated_end..end] {
+                        accumulated.accumulate(argument)?;
+                    }
+
+                    accumulated_end = end;
+                    values.push(accumulated.export().await?);
+                }
+
+                Ok(values)
+            }
+        }
+    }
+
+    async fn evaluate<'b>(&'b self, context: &'b Context<'a>, expr: &'a Expr) -> Result<Value> {
+        let (aggregated, context) = context;
+
+        evaluate(
+            self.storage,
+            Some(Rc::clone(context)),
+            aggregated.as_ref().map(Rc::clone),
+            expr,
+        )
+        .await?
+        .try_into()
+    }
+}
+
+struct Partition<'b, 'a> {
+    indexes: &'b [usize],
+    /// `(start, end, dense_rank)` of the peer group each row belongs to
+    peers: &'b [(usize, usize, usize)],
+    contexts: &'b [Context<'a>],
+}
+
+fn peer_groups(indexes: &[usize], order_keys: &[Vec<SortKey>]) -> Vec<(usize, usize, usize)> {
+    let size = indexes.len();
+    let mut peers = Vec::with_capacity(size);
+    let mut start = 0;
+    let mut dense_rank = 0;
+
+    while start < size {
+        let keys = &order_keys[indexes[start]];
+        let end = (start + 1..size)
+            .find(|position| sort_by(keys, &order_keys[indexes[*position]]) != Ordering::Equal)
+            .unwrap_or(size);
+
+        dense_rank += 1;
+        peers.extend((start..end).map(|_| (start, end, dense_rank)));
+        start = end;
+    }
+
+    peers
+}
+
+enum FrameBound {
+    UnboundedPreceding,
+    Preceding(usize),
+    CurrentRow,
+    Following(usize),
+    UnboundedFollowing,
+}
+
+struct Frame {
+    rows: bool,
+    start: FrameBound,
+    end: FrameBound,
+}
+
+impl Frame {
+    /// Without an explicit frame, the frame covers the rows from the start of the partition
+    /// to the last peer of the current row, or the whole partition if there is no ORDER BY.
+    async fn new(frame: Option<&WindowFrame>, ordered: bool) -> Result<Self> {
+        let WindowFrame { units, start, end } = match frame {
+            Some(frame) => frame,
+            None => {
+                let end = match ordered {
+                    true => FrameBound::CurrentRow,
+                    false => FrameBound::UnboundedFollowing,
+                };
+
+                return Ok(Self {
+                    rows: false,
+                    start: FrameBound::UnboundedPreceding,
+                    end,
+                });
+            }
+        };
+
+        let rows = matches!(units, WindowFrameUnits::Rows);
+        let start = match frame_bound(start, rows).await? {
+            FrameBound::UnboundedFollowing => {
+                return Err(WindowError::FrameStartUnboundedFollowing.into());
+            }
+            start => start,
+        };
+        let end = match frame_bound(end, rows).await? {
+            FrameBound::UnboundedPreceding => {
+                return Err(WindowError::FrameEndUnboundedPreceding.into());
+            }
+            end => end,
+        };
+
+        Ok(Self { rows, start, end })
+    }
+
+    /// Returns the `[start, end)` positions of the frame for the row at `position`.
+    fn range(&self, position: usize, peer: (usize, usize, usize), size: usize) -> (usize, usize) {
+        let (peer_start, peer_end, _) = peer;
+
+        let end = match self.end {
+            FrameBound::UnboundedPreceding => 0,
+            FrameBound::Preceding(offset) => (position + 1).saturating_sub(offset),
+            FrameBound::CurrentRow if self.rows => position + 1,
+            FrameBound::CurrentRow => peer_end,
+            FrameBound::Following(offset) => position.saturating_add(offset).saturating_add(1),
+            FrameBound::UnboundedFollowing => size,
+        }
+        .min(size);
+        let start = match self.start {
+            FrameBound::UnboundedPreceding => 0,
+            FrameBound::Preceding(offset) => position.saturating_sub(offset),
+            FrameBound::CurrentRow if self.rows => position,
+            FrameBound::CurrentRow => peer_start,
+            FrameBound::Following(offset) => position.saturating_add(offset),
+            FrameBound::UnboundedFollowing => size,
+        }
+        .min(end);
+
+        (start, end)
+    }
+}
+
+async fn frame_bound(bound: &WindowFrameBound, rows: bool) -> Result<FrameBound> {
+    let bound = match bound {
+        WindowFrameBound::CurrentRow => FrameBound::CurrentRow,
+        WindowFrameBound::Preceding(None) => FrameBound::UnboundedPreceding,
+        WindowFrameBound::Following(None) => FrameBound::UnboundedFollowing,
+        WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_)) if !rows => {
+            return Err(WindowError::RangeOffsetNotSupported.into());
+        }
+        WindowFrameBound::Preceding(Some(offset)) => {
+            FrameBound::Preceding(evaluate_offset(offset).await?)
+        }
+        WindowFrameBound::Following(Some(offset)) => {
+            FrameBound::Following(evaluate_offset(offset).await?)
+        }
+    };
+
+    Ok(bound)
+}
+
+async fn evaluate_offset(expr: &Expr) -> Result<usize> {
+    let evaluated = evaluate_stateless(None, expr).await?;
+
+    Value::try_from(evaluated)?.try_into()
+}
+
+pub fn collect_windows<'a>(expr: &'a Expr, windows: &mut Vec<&'a Window>) {
+    let exprs: Vec<&Expr> = match expr {
+        Expr::Window(window) => {
+            if !windows.contains(&window.as_ref()) {
+                windows.push(window);
+            }
+
+            return;
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => vec![expr, low, high],
+        Expr::BinaryOp { left, right, .. }
+        | Expr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | Expr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        } => vec![left, right],
+        Expr::UnaryOp { expr, .. }
+        | Expr::Nested(expr)
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::InSubquery { expr, .. }
+        | Expr::Interval { expr, .. } => vec![expr],
+        Expr::InList { expr, list, .. } => iter::once(expr.as_ref()).chain(list).collect(),
+        Expr::ArrayIndex { obj, indexes } => iter::once(obj.as_ref()).chain(indexes).collect(),
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => operand
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(when_then.iter().flat_map(|(when, then)| [when, then]))
+            .chain(else_result.iter().map(AsRef::as_ref))
+            .collect(),
+        Expr::Function(func) => func.as_exprs().collect(),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Placeholder(_)
+        | Expr::Aggregate(_)
+        | Expr::Exists { .. }
+        | Expr::Subquery(_) => return,
+    };
+
+    for expr in exprs {
+        collect_windows(expr, windows);
+    }
+}
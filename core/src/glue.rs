@@ -1,7 +1,8 @@
 use {
     crate::{
         ast::Statement,
-        executor::{execute, Payload},
+        data::Value,
+        executor::{execute, infer_param_types, Payload, PrepareError, PreparedStatement},
         parse_sql::parse,
         plan::plan,
         result::Result,
@@ -33,6 +34,31 @@ impl<T: GStore + GStoreMut> Glue<T> {
             .await
     }
 
+    /// Parses, translates and plans a single statement which has `$1` or `?` placeholders,
+    /// to be executed by [`Glue::execute_prepared`] with different params.
+    pub async fn prepare<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<PreparedStatement> {
+        let parsed = parse(sql)?;
+        let statement = match parsed.as_slice() {
+            [statement] => translate(statement)?,
+            _ => return Err(PrepareError::SingleStatementRequired(parsed.len()).into()),
+        };
+
+        let param_types = infer_param_types(&self.storage, &statement).await?;
+        let statement = plan(&self.storage, statement).await?;
+
+        Ok(PreparedStatement::new(statement, param_types))
+    }
+
+    pub async fn execute_prepared(
+        &mut self,
+        statement: &PreparedStatement,
+        params: Vec<Value>,
+    ) -> Result<Payload> {
+        let statement = statement.bind(params)?;
+
+        self.execute_stmt(&statement).await
+    }
+
     pub async fn execute_stmt(&mut self, statement: &Statement) -> Result<Payload> {
         execute(&mut self.storage, statement).await
     }
@@ -4,7 +4,8 @@ use {
         result::{Error, Result},
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
-            RowIter, Store, StoreMut, Transaction,
+            RowIter, Sequence, SequenceMut, Statistics, StatisticsMut, Store, StoreMut,
+            Transaction, View, ViewMut,
         },
     },
     async_trait::async_trait,
@@ -41,6 +42,13 @@ impl CustomFunction for MockStorage {}
 #[async_trait(?Send)]
 impl CustomFunctionMut for MockStorage {}
 
+impl View for MockStorage {}
+impl ViewMut for MockStorage {}
+impl Sequence for MockStorage {}
+impl SequenceMut for MockStorage {}
+impl Statistics for MockStorage {}
+impl StatisticsMut for MockStorage {}
+
 #[async_trait(?Send)]
 impl Store for MockStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -126,6 +134,7 @@ mod tests {
             store::{Store, StoreMut},
         },
         futures::executor::block_on,
+        std::ops::Bound::Unbounded,
     };
 
     #[test]
@@ -152,23 +161,32 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: None,
+                identity: None,
             },
         ))
         .is_err());
         assert!(block_on(storage.drop_column("Foo", "col", false)).is_err());
 
         // Index & IndexMut
-        assert!(block_on(storage.scan_indexed_data("Foo", "idx_col", None, None)).is_err());
+        assert!(block_on(storage.scan_indexed_data(
+            "Foo",
+            "idx_col",
+            None,
+            &[],
+            (Unbounded, Unbounded)
+        ))
+        .is_err());
         assert!(block_on(storage.create_index(
             "Foo",
             "idx_col",
-            &OrderByExpr {
+            &[OrderByExpr {
                 expr: Expr::TypedString {
                     data_type: DataType::Boolean,
                     value: "true".to_owned(),
                 },
                 asc: None,
-            },
+                nulls_first: None,
+            }],
         ))
         .is_err());
         assert!(block_on(storage.drop_index("Foo", "idx_col")).is_err());
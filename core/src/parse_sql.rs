@@ -3,19 +3,127 @@ use {
     sqlparser::{
         ast::{
             Assignment as SqlAssignment, ColumnDef as SqlColumnDef, DataType as SqlDataType,
-            Expr as SqlExpr, Ident as SqlIdent, OrderByExpr as SqlOrderByExpr, Query as SqlQuery,
-            SelectItem as SqlSelectItem, Statement as SqlStatement,
+            Expr as SqlExpr, Ident as SqlIdent, ObjectName as SqlObjectName,
+            OrderByExpr as SqlOrderByExpr, Query as SqlQuery, SelectItem as SqlSelectItem,
+            Statement as SqlStatement,
         },
         dialect::PostgreSqlDialect,
-        parser::Parser,
-        tokenizer::Tokenizer,
+        keywords::Keyword,
+        parser::{Parser, ParserError},
+        tokenizer::{Token, TokenWithLocation, Tokenizer},
     },
 };
 
 const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 
-pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
-    Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
+/// Statement parsed by `parse`, the ones sqlparser does not know about come with their own variant.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsedStatement {
+    Sql(Box<SqlStatement>),
+    /// `REFRESH MATERIALIZED VIEW name`
+    RefreshMaterializedView {
+        name: SqlObjectName,
+    },
+}
+
+pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<ParsedStatement>> {
+    parse_statements(sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
+}
+
+/// Same as `Parser::parse_sql`, with the statements sqlparser does not know about.
+fn parse_statements(sql: &str) -> Result<Vec<ParsedStatement>, ParserError> {
+    let tokens = Tokenizer::new(&DIALECT, sql).tokenize_with_location()?;
+    let tokens = number_placeholders(tokens);
+    let mut parser = Parser::new(&DIALECT).with_tokens_with_locations(tokens);
+    let mut statements = Vec::new();
+    let mut expecting_statement_delimiter = false;
+
+    loop {
+        while parser.consume_token(&Token::SemiColon) {
+            expecting_statement_delimiter = false;
+        }
+
+        match parser.peek_token().token {
+            Token::EOF => break,
+            Token::Word(word) if word.keyword == Keyword::END => break,
+            _ => {}
+        }
+
+        if expecting_statement_delimiter {
+            return parser.expected("end of statement", parser.peek_token());
+        }
+
+        statements.push(parse_statement(&mut parser)?);
+        expecting_statement_delimiter = true;
+    }
+
+    Ok(statements)
+}
+
+/// Rewrites `?` placeholders into `$n` by the order they appear, and `?n` into `$n`.
+fn number_placeholders(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let mut n = 0;
+
+    tokens
+        .into_iter()
+        .map(|TokenWithLocation { token, location }| {
+            let token = match token {
+                Token::Placeholder(placeholder) if placeholder == "?" => {
+                    n += 1;
+
+                    Token::Placeholder(format!("${n}"))
+                }
+                Token::Placeholder(placeholder) if placeholder.starts_with('?') => {
+                    Token::Placeholder(placeholder.replacen('?', "$", 1))
+                }
+                token => token,
+            };
+
+            TokenWithLocation { token, location }
+        })
+        .collect()
+}
+
+/// sqlparser only knows the Hive `ANALYZE TABLE name`, so `ANALYZE [TABLE] [name]` is parsed
+/// here and an omitted table name is left empty for the translator.
+fn parse_statement(parser: &mut Parser) -> Result<ParsedStatement, ParserError> {
+    match parser.peek_token().token {
+        Token::Word(word) if word.keyword == Keyword::ANALYZE => {
+            parser.next_token();
+
+            let table_name = match parser.peek_token().token {
+                Token::EOF | Token::SemiColon => SqlObjectName(Vec::new()),
+                _ => {
+                    let _ = parser.parse_keyword(Keyword::TABLE);
+                    parser.parse_object_name()?
+                }
+            };
+
+            Ok(ParsedStatement::Sql(Box::new(SqlStatement::Analyze {
+                table_name,
+                partitions: None,
+                for_columns: false,
+                columns: Vec::new(),
+                cache_metadata: false,
+                noscan: false,
+                compute_statistics: false,
+            })))
+        }
+        Token::Word(word)
+            if word.quote_style.is_none() && word.value.eq_ignore_ascii_case("REFRESH") =>
+        {
+            parser.next_token();
+            parser.expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+
+            Ok(ParsedStatement::RefreshMaterializedView {
+                name: parser.parse_object_name()?,
+            })
+        }
+        _ => parser
+            .parse_statement()
+            .map(Box::new)
+            .map(ParsedStatement::Sql),
+    }
 }
 
 macro_rules! generate_parse_fn {
@@ -0,0 +1,708 @@
+use {
+    super::{context::Context, error::PlanError, planner::Planner},
+    crate::{
+        ast::{
+            Assignment, Cte, Distinct, IndexItem, Join, JoinConstraint, JoinExecutor, JoinOperator,
+            OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins, Values, With,
+        },
+        data::{Schema, View},
+        result::Result,
+    },
+    im_rc::HashMap,
+    std::{
+        cell::{Cell, RefCell},
+        collections::HashMap as StdHashMap,
+        rc::Rc,
+    },
+};
+
+/// Expands references to common table expressions.
+///
+/// Non-recursive CTEs are inlined as derived tables. A CTE which refers to itself
+/// in `WITH RECURSIVE` is kept as a single-CTE `WITH RECURSIVE` subquery so the executor
+/// can evaluate it iteratively.
+pub fn plan(statement: Statement) -> Statement {
+    CtePlanner::default().statement(statement)
+}
+
+/// Expands common table expressions and views.
+///
+/// A view is expanded into a derived table wherever a table reference is neither bound
+/// to a CTE nor shadowed by one. `CREATE VIEW` keeps its query unexpanded so later
+/// changes to the views it refers to are picked up, but it is checked for cycles.
+pub fn plan_with_views(views: StdHashMap<String, View>, statement: Statement) -> Result<Statement> {
+    let views = match &statement {
+        Statement::CreateView {
+            or_replace,
+            name,
+            columns,
+            query,
+        } if *or_replace || !views.contains_key(name) => {
+            let mut views = views;
+            views.insert(
+                name.to_owned(),
+                View {
+                    view_name: name.to_owned(),
+                    columns: columns.to_owned(),
+                    query: query.as_ref().clone(),
+                },
+            );
+
+            let planner = CtePlanner::new(views);
+            planner.table_factor(TableFactor::Table {
+                name: name.to_owned(),
+                alias: None,
+                index: None,
+            });
+
+            return planner.check_recursive_view().map(|_| statement);
+        }
+        _ => views,
+    };
+
+    let planner = CtePlanner::new(views);
+    let statement = planner.statement(statement);
+
+    planner.check_recursive_view().map(|_| statement)
+}
+
+/// Returns whether `query` refers to the common table expression `name` itself, with the
+/// same scoping rules that are applied when it is expanded.
+pub fn references_cte(name: &str, query: &Query) -> bool {
+    let referenced = Rc::new(Cell::new(false));
+
+    CtePlanner::default()
+        .bind(
+            name.to_owned(),
+            Binding::SelfReference(Rc::clone(&referenced)),
+        )
+        .query(None, query.clone());
+
+    referenced.get()
+}
+
+#[derive(Clone)]
+enum Binding {
+    Inline {
+        columns: Vec<String>,
+        query: Rc<Query>,
+    },
+    Recursive(Rc<Cte>),
+    SelfReference(Rc<Cell<bool>>),
+}
+
+#[derive(Default)]
+struct Views {
+    definitions: StdHashMap<String, View>,
+    /// Views currently being expanded, outermost first
+    expanding: RefCell<Vec<String>>,
+    recursive: RefCell<Option<String>>,
+}
+
+#[derive(Clone, Default)]
+struct CtePlanner {
+    bindings: HashMap<String, Binding>,
+    views: Rc<Views>,
+}
+
+impl<'a> Planner<'a> for CtePlanner {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let planner = match with {
+            Some(with) => self.with(with),
+            None => self.clone(),
+        };
+
+        let body = match body {
+            SetExpr::Select(select) => {
+                let select = planner.select(outer_context.as_ref().map(Rc::clone), *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(Values(values_list)) => {
+                let values_list = values_list
+                    .into_iter()
+                    .map(|exprs| {
+                        exprs
+                            .into_iter()
+                            .map(|expr| {
+                                planner.subquery_expr(outer_context.as_ref().map(Rc::clone), expr)
+                            })
+                            .collect()
+                    })
+                    .collect();
+
+                SetExpr::Values(Values(values_list))
+            }
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(planner.query(outer_context.as_ref().map(Rc::clone), *left)),
+                right: Box::new(planner.query(outer_context.as_ref().map(Rc::clone), *right)),
+            },
+        };
+
+        let order_by = order_by
+            .into_iter()
+            .map(
+                |OrderByExpr {
+                     expr,
+                     asc,
+                     nulls_first,
+                 }| OrderByExpr {
+                    expr: planner.subquery_expr(outer_context.as_ref().map(Rc::clone), expr),
+                    asc,
+                    nulls_first,
+                },
+            )
+            .collect();
+        let limit =
+            limit.map(|expr| planner.subquery_expr(outer_context.as_ref().map(Rc::clone), expr));
+        let offset = offset.map(|expr| planner.subquery_expr(outer_context, expr));
+
+        Query {
+            with: None,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, _: &str) -> Option<&'a Schema> {
+        None
+    }
+}
+
+impl CtePlanner {
+    fn new(views: StdHashMap<String, View>) -> Self {
+        Self {
+            bindings: HashMap::new(),
+            views: Rc::new(Views {
+                definitions: views,
+                ..Views::default()
+            }),
+        }
+    }
+
+    fn statement(&self, statement: Statement) -> Statement {
+        match statement {
+            Statement::Query(query) => Statement::Query(self.query(None, query)),
+            Statement::Insert {
+                table_name,
+                columns,
+                source,
+                on_conflict,
+                returning,
+            } => {
+                let source = self.query(None, source);
+
+                Statement::Insert {
+                    table_name,
+                    columns,
+                    source,
+                    on_conflict,
+                    returning,
+                }
+            }
+            Statement::CreateTable {
+                if_not_exists,
+                name,
+                columns,
+                source: Some(source),
+                engine,
+                foreign_keys,
+                checks,
+                unique_constraints,
+            } => {
+                let source = Some(Box::new(self.query(None, *source)));
+
+                Statement::CreateTable {
+                    if_not_exists,
+                    name,
+                    columns,
+                    source,
+                    engine,
+                    foreign_keys,
+                    checks,
+                    unique_constraints,
+                }
+            }
+            Statement::Update {
+                table_name,
+                assignments,
+                from,
+                join_executor,
+                selection,
+                returning,
+            } => {
+                let assignments = assignments
+                    .into_iter()
+                    .map(|Assignment { id, value }| Assignment {
+                        id,
+                        value: self.subquery_expr(None, value),
+                    })
+                    .collect();
+                let from = from.map(|from| self.table_with_joins(None, from));
+                let selection = selection.map(|expr| self.subquery_expr(None, expr));
+
+                Statement::Update {
+                    table_name,
+                    assignments,
+                    from,
+                    join_executor,
+                    selection,
+                    returning,
+                }
+            }
+            Statement::Delete {
+                table_name,
+                using,
+                join_executor,
+                selection,
+                returning,
+            } => {
+                let using = using.map(|using| self.table_with_joins(None, using));
+                let selection = selection.map(|expr| self.subquery_expr(None, expr));
+
+                Statement::Delete {
+                    table_name,
+                    using,
+                    join_executor,
+                    selection,
+                    returning,
+                }
+            }
+            _ => statement,
+        }
+    }
+
+    fn check_recursive_view(&self) -> Result<()> {
+        match self.views.recursive.borrow_mut().take() {
+            Some(name) => Err(PlanError::RecursiveView(name).into()),
+            None => Ok(()),
+        }
+    }
+
+    fn bind(&self, name: String, binding: Binding) -> Self {
+        Self {
+            bindings: self.bindings.update(name, binding),
+            views: Rc::clone(&self.views),
+        }
+    }
+
+    fn with(&self, with: With) -> Self {
+        let With {
+            recursive,
+            cte_tables,
+        } = with;
+
+        cte_tables.into_iter().fold(self.clone(), |planner, cte| {
+            let Cte { alias, query } = cte;
+            let referenced = Rc::new(Cell::new(false));
+            let query = match recursive {
+                true => planner
+                    .bind(
+                        alias.name.clone(),
+                        Binding::SelfReference(Rc::clone(&referenced)),
+                    )
+                    .query(None, *query),
+                false => planner.query(None, *query),
+            };
+
+            let name = alias.name.clone();
+            let binding = match referenced.get() {
+                true => Binding::Recursive(Rc::new(Cte {
+                    alias,
+                    query: Box::new(query),
+                })),
+                false => Binding::Inline {
+                    columns: alias.columns,
+                    query: Rc::new(query),
+                },
+            };
+
+            planner.bind(name, binding)
+        })
+    }
+
+    fn select(&self, outer_context: Option<Rc<Context<'_>>>, select: Select) -> Select {
+        let Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+            plan_id,
+        } = select;
+
+        let expr = |expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr);
+
+        let distinct = distinct.map(|distinct| match distinct {
+            Distinct::Distinct => Distinct::Distinct,
+            Distinct::On(exprs) => Distinct::On(exprs.into_iter().map(expr).collect()),
+        });
+        let projection = projection
+            .into_iter()
+            .map(|select_item| match select_item {
+                SelectItem::Expr { expr: item, label } => SelectItem::Expr {
+                    expr: expr(item),
+                    label,
+                },
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => select_item,
+            })
+            .collect();
+
+        Select {
+            distinct,
+            projection,
+            from: self.table_with_joins(outer_context.as_ref().map(Rc::clone), from),
+            selection: selection.map(expr),
+            group_by: group_by.into_iter().map(expr).collect(),
+            having: having.map(expr),
+            plan_id,
+        }
+    }
+
+    fn table_with_joins(
+        &self,
+        outer_context: Option<Rc<Context<'_>>>,
+        table_with_joins: TableWithJoins,
+    ) -> TableWithJoins {
+        let expr = |expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr);
+
+        let TableWithJoins { relation, joins } = table_with_joins;
+        let relation = self.table_factor(relation);
+        let joins = joins
+            .into_iter()
+            .map(|join| {
+                let Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                } = join;
+
+                let relation = self.table_factor(relation);
+                let join_operator = match join_operator {
+                    JoinOperator::Inner(JoinConstraint::On(on)) => {
+                        JoinOperator::Inner(JoinConstraint::On(expr(on)))
+                    }
+                    JoinOperator::LeftOuter(JoinConstraint::On(on)) => {
+                        JoinOperator::LeftOuter(JoinConstraint::On(expr(on)))
+                    }
+                    JoinOperator::RightOuter(JoinConstraint::On(on)) => {
+                        JoinOperator::RightOuter(JoinConstraint::On(expr(on)))
+                    }
+                    JoinOperator::FullOuter(JoinConstraint::On(on)) => {
+                        JoinOperator::FullOuter(JoinConstraint::On(expr(on)))
+                    }
+                    JoinOperator::Inner(_)
+                    | JoinOperator::LeftOuter(_)
+                    | JoinOperator::RightOuter(_)
+                    | JoinOperator::FullOuter(_)
+                    | JoinOperator::CrossJoin => join_operator,
+                };
+                let join_executor = match join_executor {
+                    JoinExecutor::NestedLoop => JoinExecutor::NestedLoop,
+                    JoinExecutor::Hash {
+                        key_expr,
+                        value_expr,
+                        where_clause,
+                    } => JoinExecutor::Hash {
+                        key_expr: expr(key_expr),
+                        value_expr: expr(value_expr),
+                        where_clause: where_clause.map(expr),
+                    },
+                };
+
+                Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                }
+            })
+            .collect();
+
+        TableWithJoins { relation, joins }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        match table_factor {
+            TableFactor::Table { name, alias, index } => {
+                let binding = match self.bindings.get(&name) {
+                    Some(binding) => binding,
+                    None => return self.view(name, alias, index),
+                };
+
+                let (alias_name, alias_columns) = match &alias {
+                    Some(TableAlias { name, columns }) => (name.clone(), columns.clone()),
+                    None => (name.clone(), Vec::new()),
+                };
+
+                let (subquery, columns) = match binding {
+                    Binding::SelfReference(referenced) => {
+                        referenced.set(true);
+
+                        return TableFactor::Table { name, alias, index };
+                    }
+                    Binding::Inline { columns, query } => {
+                        let columns = alias_columns
+                            .iter()
+                            .chain(columns.iter().skip(alias_columns.len()))
+                            .cloned()
+                            .collect();
+
+                        (query.as_ref().clone(), columns)
+                    }
+                    Binding::Recursive(cte) => {
+                        let subquery = Query {
+                            with: Some(With {
+                                recursive: true,
+                                cte_tables: vec![cte.as_ref().clone()],
+                            }),
+                            body: SetExpr::Select(Box::new(Select {
+                                distinct: None,
+                                projection: vec![SelectItem::Wildcard],
+                                from: TableWithJoins {
+                                    relation: TableFactor::Table {
+                                        name,
+                                        alias: None,
+                                        index: None,
+                                    },
+                                    joins: Vec::new(),
+                                },
+                                selection: None,
+                                group_by: Vec::new(),
+                                having: None,
+                                plan_id: None,
+                            })),
+                            order_by: Vec::new(),
+                            limit: None,
+                            offset: None,
+                        };
+
+                        (subquery, alias_columns)
+                    }
+                };
+
+                TableFactor::Derived {
+                    subquery,
+                    alias: TableAlias {
+                        name: alias_name,
+                        columns,
+                    },
+                }
+            }
+            TableFactor::Derived { subquery, alias } => TableFactor::Derived {
+                subquery: self.query(None, subquery),
+                alias,
+            },
+            TableFactor::Series { alias, size } => TableFactor::Series {
+                size: self.subquery_expr(None, size),
+                alias,
+            },
+            TableFactor::Dictionary { .. } => table_factor,
+        }
+    }
+
+    fn view(
+        &self,
+        name: String,
+        alias: Option<TableAlias>,
+        index: Option<IndexItem>,
+    ) -> TableFactor {
+        let view = match self.views.definitions.get(&name) {
+            Some(view) => view,
+            None => return TableFactor::Table { name, alias, index },
+        };
+
+        if self.views.expanding.borrow().contains(&name) {
+            self.views
+                .recursive
+                .borrow_mut()
+                .get_or_insert(name.clone());
+
+            return TableFactor::Table { name, alias, index };
+        }
+
+        self.views.expanding.borrow_mut().push(name.clone());
+        let subquery = CtePlanner {
+            bindings: HashMap::new(),
+            views: Rc::clone(&self.views),
+        }
+        .query(None, view.query.clone());
+        self.views.expanding.borrow_mut().pop();
+
+        let (alias_name, alias_columns) = match alias {
+            Some(TableAlias { name, columns }) => (name, columns),
+            None => (name, Vec::new()),
+        };
+        let columns = alias_columns
+            .iter()
+            .chain(view.columns.iter().skip(alias_columns.len()))
+            .cloned()
+            .collect();
+
+        TableFactor::Derived {
+            subquery,
+            alias: TableAlias {
+                name: alias_name,
+                columns,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{plan, plan_with_views},
+        crate::{
+            ast::Statement, data::View, parse_sql::parse, plan::PlanError, translate::translate,
+        },
+        std::collections::HashMap,
+    };
+
+    fn translate_sql(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).expect(sql)
+    }
+
+    macro_rules! test {
+        ($actual: literal, $expected: literal, $name: literal) => {
+            let actual = plan(translate_sql($actual));
+            let expected = translate_sql($expected);
+
+            assert_eq!(actual, expected, $name);
+        };
+    }
+
+    #[test]
+    fn inline() {
+        test!(
+            "WITH T AS (SELECT id FROM Foo) SELECT * FROM T",
+            "SELECT * FROM (SELECT id FROM Foo) AS T",
+            "single cte"
+        );
+        test!(
+            "WITH T (a, b) AS (SELECT id, name FROM Foo) SELECT * FROM T AS U (c)",
+            "SELECT * FROM (SELECT id, name FROM Foo) AS U (c, b)",
+            "column aliases"
+        );
+        test!(
+            "WITH T AS (SELECT id FROM Foo), U AS (SELECT * FROM T) SELECT * FROM U JOIN T",
+            "SELECT * FROM (SELECT * FROM (SELECT id FROM Foo) AS T) AS U
+            JOIN (SELECT id FROM Foo) AS T",
+            "reference to a preceding cte"
+        );
+        test!(
+            "WITH T AS (SELECT id FROM Foo) SELECT * FROM Bar WHERE id IN (SELECT id FROM T)",
+            "SELECT * FROM Bar WHERE id IN (SELECT id FROM (SELECT id FROM Foo) AS T)",
+            "subquery"
+        );
+        test!(
+            "WITH Foo AS (SELECT id FROM Foo) SELECT * FROM Foo",
+            "SELECT * FROM (SELECT id FROM Foo) AS Foo",
+            "cte shadowing a table"
+        );
+        test!(
+            "WITH RECURSIVE T AS (SELECT id FROM Foo) SELECT * FROM T",
+            "SELECT * FROM (SELECT id FROM Foo) AS T",
+            "recursive without self reference"
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        test!(
+            "WITH RECURSIVE T (n) AS (SELECT 1 UNION SELECT n + 1 FROM T WHERE n < 3)
+            SELECT * FROM T AS X",
+            "SELECT * FROM (
+                WITH RECURSIVE T (n) AS (SELECT 1 UNION SELECT n + 1 FROM T WHERE n < 3)
+                SELECT * FROM T
+            ) AS X",
+            "self reference"
+        );
+    }
+
+    #[test]
+    fn view() {
+        let views = || {
+            let view = |name: &str, columns: Vec<&str>, sql: &str| {
+                let query = match translate_sql(sql) {
+                    Statement::Query(query) => query,
+                    _ => unreachable!(),
+                };
+                let view = View {
+                    view_name: name.to_owned(),
+                    columns: columns.into_iter().map(ToOwned::to_owned).collect(),
+                    query,
+                };
+
+                (name.to_owned(), view)
+            };
+
+            HashMap::from([
+                view("V", vec!["a"], "SELECT id, name FROM Foo"),
+                view("W", vec![], "SELECT * FROM V WHERE a > 1"),
+            ])
+        };
+        let test = |actual, expected, name| {
+            let actual = plan_with_views(views(), translate_sql(actual));
+
+            assert_eq!(actual, Ok(translate_sql(expected)), "{name}");
+        };
+
+        test(
+            "SELECT * FROM V",
+            "SELECT * FROM (SELECT id, name FROM Foo) AS V (a)",
+            "single view",
+        );
+        test(
+            "SELECT * FROM W AS X",
+            "SELECT * FROM (
+                SELECT * FROM (SELECT id, name FROM Foo) AS V (a) WHERE a > 1
+            ) AS X",
+            "nested view",
+        );
+        test(
+            "WITH V AS (SELECT 1) SELECT * FROM V",
+            "SELECT * FROM (SELECT 1) AS V",
+            "cte shadowing a view",
+        );
+        test(
+            "DELETE FROM Foo WHERE id IN (SELECT a FROM V)",
+            "DELETE FROM Foo WHERE id IN (SELECT a FROM (SELECT id, name FROM Foo) AS V (a))",
+            "subquery in delete",
+        );
+        test(
+            "CREATE VIEW X AS SELECT * FROM W",
+            "CREATE VIEW X AS SELECT * FROM W",
+            "create view is not expanded",
+        );
+
+        assert_eq!(
+            plan_with_views(
+                views(),
+                translate_sql("CREATE OR REPLACE VIEW V AS SELECT * FROM W")
+            ),
+            Err(PlanError::RecursiveView("V".to_owned()).into()),
+            "recursive view"
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use {
     super::{context::Context, expr::PlanExpr},
     crate::ast::{
-        Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr, TableAlias,
-        TableFactor, TableWithJoins, Values,
+        Cte, Distinct, Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem,
+        SetExpr, TableAlias, TableFactor, TableWithJoins, Values, With,
     },
     std::{convert::identity, rc::Rc},
 };
@@ -40,12 +40,23 @@ pub fn check_expr(context: Option<Rc<Context<'_>>>, expr: &Expr) -> bool {
 
 fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
     let Query {
+        with,
         body,
         order_by,
         limit,
         offset,
     } = query;
 
+    if let Some(With { cte_tables, .. }) = with {
+        if !cte_tables
+            .iter()
+            .map(|Cte { query, .. }| check_query(context.as_ref().map(Rc::clone), query))
+            .all(identity)
+        {
+            return false;
+        }
+    }
+
     let body = match body {
         SetExpr::Select(select) => check_select(context.as_ref().map(Rc::clone), select),
         SetExpr::Values(Values(rows)) => rows
@@ -53,6 +64,10 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
             .flatten()
             .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
             .all(identity),
+        SetExpr::SetOperation { left, right, .. } => {
+            check_query(context.as_ref().map(Rc::clone), left)
+                && check_query(context.as_ref().map(Rc::clone), right)
+        }
     };
 
     if !body {
@@ -77,11 +92,13 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
 
 fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
     let Select {
+        distinct,
         projection,
         from,
         selection,
         group_by,
         having,
+        plan_id: _,
     } = select;
 
     if !projection
@@ -95,6 +112,16 @@ fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
         return false;
     }
 
+    if let Some(Distinct::On(exprs)) = distinct {
+        if !exprs
+            .iter()
+            .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
+            .all(identity)
+        {
+            return false;
+        }
+    }
+
     let TableWithJoins { relation, joins } = from;
 
     if !check_table_factor(context.as_ref().map(Rc::clone), relation) {
@@ -116,11 +143,16 @@ fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
 
             match join_operator {
                 JoinOperator::Inner(JoinConstraint::On(expr))
-                | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => {
                     check_expr(context.as_ref().map(Rc::clone), expr)
                 }
-                JoinOperator::Inner(JoinConstraint::None)
-                | JoinOperator::LeftOuter(JoinConstraint::None) => true,
+                JoinOperator::Inner(_)
+                | JoinOperator::LeftOuter(_)
+                | JoinOperator::RightOuter(_)
+                | JoinOperator::FullOuter(_)
+                | JoinOperator::CrossJoin => true,
             }
         })
         .all(identity)
@@ -45,6 +45,8 @@ impl Function {
             | Self::Ascii(expr)
             | Self::Chr(expr)
             | Self::Md5(expr)
+            | Self::NextVal(expr)
+            | Self::CurrVal(expr)
             | Self::Ltrim { expr, chars: None }
             | Self::Rtrim { expr, chars: None }
             | Self::Trim {
@@ -185,6 +187,191 @@ impl Function {
             }
         }
     }
+
+    /// Mutable counterpart of [`Function::as_exprs`]
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        #[derive(iter_enum::Iterator)]
+        enum Exprs<I0, I1, I2, I3, I4, I5> {
+            Empty(I0),
+            Single(I1),
+            Double(I2),
+            Triple(I3),
+            VariableArgs(I4),
+            VariableArgsWithSingle(I5),
+        }
+
+        match self {
+            Self::Now() | Function::Pi() | Function::GenerateUuid() | Self::Rand(None) => {
+                Exprs::Empty(empty())
+            }
+            Self::Lower(expr)
+            | Self::Length(expr)
+            | Self::Initcap(expr)
+            | Self::Upper(expr)
+            | Self::Sin(expr)
+            | Self::Cos(expr)
+            | Self::Tan(expr)
+            | Self::Asin(expr)
+            | Self::Acos(expr)
+            | Self::Atan(expr)
+            | Self::Radians(expr)
+            | Self::Degrees(expr)
+            | Self::Ceil(expr)
+            | Self::Rand(Some(expr))
+            | Self::Round(expr)
+            | Self::Floor(expr)
+            | Self::Exp(expr)
+            | Self::Ln(expr)
+            | Self::Log2(expr)
+            | Self::Log10(expr)
+            | Self::Sqrt(expr)
+            | Self::Abs(expr)
+            | Self::Sign(expr)
+            | Self::Ascii(expr)
+            | Self::Chr(expr)
+            | Self::Md5(expr)
+            | Self::NextVal(expr)
+            | Self::CurrVal(expr)
+            | Self::Ltrim { expr, chars: None }
+            | Self::Rtrim { expr, chars: None }
+            | Self::Trim {
+                expr,
+                filter_chars: None,
+                ..
+            }
+            | Self::Reverse(expr)
+            | Self::Cast { expr, .. }
+            | Self::Extract { expr, .. }
+            | Self::GetX(expr)
+            | Self::GetY(expr)
+            | Self::IsEmpty(expr)
+            | Self::Sort { expr, order: None } => Exprs::Single([expr].into_iter()),
+            Self::Left { expr, size: expr2 }
+            | Self::Right { expr, size: expr2 }
+            | Self::Lpad {
+                expr,
+                size: expr2,
+                fill: None,
+            }
+            | Self::Rpad {
+                expr,
+                size: expr2,
+                fill: None,
+            }
+            | Self::Trim {
+                expr,
+                filter_chars: Some(expr2),
+                ..
+            }
+            | Self::Log {
+                antilog: expr,
+                base: expr2,
+            }
+            | Self::Div {
+                dividend: expr,
+                divisor: expr2,
+            }
+            | Self::Mod {
+                dividend: expr,
+                divisor: expr2,
+            }
+            | Self::Gcd {
+                left: expr,
+                right: expr2,
+            }
+            | Self::Lcm {
+                left: expr,
+                right: expr2,
+            }
+            | Self::Format {
+                expr,
+                format: expr2,
+            }
+            | Self::ToDate {
+                expr,
+                format: expr2,
+            }
+            | Self::ToTimestamp {
+                expr,
+                format: expr2,
+            }
+            | Self::ToTime {
+                expr,
+                format: expr2,
+            }
+            | Self::Power { expr, power: expr2 }
+            | Self::Ltrim {
+                expr,
+                chars: Some(expr2),
+            }
+            | Self::Rtrim {
+                expr,
+                chars: Some(expr2),
+            }
+            | Self::Repeat { expr, num: expr2 }
+            | Self::Substr {
+                expr,
+                start: expr2,
+                count: None,
+            }
+            | Self::IfNull { expr, then: expr2 }
+            | Self::Unwrap {
+                expr,
+                selector: expr2,
+            }
+            | Self::Position {
+                from_expr: expr2,
+                sub_expr: expr,
+            }
+            | Self::FindIdx {
+                from_expr: expr,
+                sub_expr: expr2,
+                start: None,
+            }
+            | Self::Append { expr, value: expr2 }
+            | Self::Prepend { expr, value: expr2 }
+            | Self::Sort {
+                expr,
+                order: Some(expr2),
+            }
+            | Self::Take { expr, size: expr2 }
+            | Self::Point { x: expr, y: expr2 }
+            | Self::CalcDistance {
+                geometry1: expr,
+                geometry2: expr2,
+            } => Exprs::Double([expr, expr2].into_iter()),
+            Self::Lpad {
+                expr,
+                size: expr2,
+                fill: Some(expr3),
+            }
+            | Self::Rpad {
+                expr,
+                size: expr2,
+                fill: Some(expr3),
+            }
+            | Self::Substr {
+                expr,
+                start: expr2,
+                count: Some(expr3),
+            }
+            | Self::Replace {
+                expr,
+                old: expr2,
+                new: expr3,
+            }
+            | Self::FindIdx {
+                from_expr: expr,
+                sub_expr: expr2,
+                start: Some(expr3),
+            } => Exprs::Triple([expr, expr2, expr3].into_iter()),
+            Self::Custom { name: _, exprs } => Exprs::VariableArgs(exprs.iter_mut()),
+            Self::Concat(exprs) => Exprs::VariableArgs(exprs.iter_mut()),
+            Self::ConcatWs { separator, exprs } => {
+                Exprs::VariableArgsWithSingle(once(separator).chain(exprs.iter_mut()))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 mod aggregate;
 mod function;
+mod window;
 
 use {
     crate::ast::{Expr, Query},
@@ -22,7 +23,7 @@ pub enum PlanExpr<'a> {
 impl<'a> From<&'a Expr> for PlanExpr<'a> {
     fn from(expr: &'a Expr) -> Self {
         match expr {
-            Expr::Literal(_) | Expr::TypedString { .. } => PlanExpr::None,
+            Expr::Literal(_) | Expr::TypedString { .. } | Expr::Placeholder(_) => PlanExpr::None,
             Expr::Identifier(ident) => PlanExpr::Identifier(ident),
             Expr::CompoundIdentifier { alias, ident } => {
                 PlanExpr::CompoundIdentifier { alias, ident }
@@ -70,6 +71,7 @@ impl<'a> From<&'a Expr> for PlanExpr<'a> {
                 PlanExpr::MultiExprs(exprs)
             }
             Expr::Function(function) => PlanExpr::MultiExprs(function.as_exprs().collect()),
+            Expr::Window(window) => PlanExpr::MultiExprs(window.as_exprs().collect()),
             Expr::Subquery(subquery) | Expr::Exists { subquery, .. } => PlanExpr::Query(subquery),
             Expr::InSubquery {
                 expr,
@@ -230,6 +232,14 @@ mod tests {
         let expected = PlanExpr::MultiExprs(expected.iter().collect());
         test!(actual, expected);
 
+        let actual = expr("SUM(amount) OVER (PARTITION BY category ORDER BY id)");
+        let expected = ["amount", "category", "id"]
+            .into_iter()
+            .map(expr)
+            .collect::<Vec<_>>();
+        let expected = PlanExpr::MultiExprs(expected.iter().collect());
+        test!(actual, expected);
+
         let actual = Expr::Subquery(Box::new(query("SELECT id FROM Foo")));
         let expected = query("SELECT id FROM Foo");
         let expected = PlanExpr::Query(&expected);
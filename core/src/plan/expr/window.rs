@@ -0,0 +1,142 @@
+use {
+    crate::ast::{Expr, OrderByExpr, Window, WindowFrame, WindowFrameBound, WindowFunction},
+    std::iter::once,
+};
+
+impl Window {
+    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
+        let func_exprs: Vec<&Expr> = match &self.func {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                Vec::new()
+            }
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => once(expr).chain(offset).chain(default).collect(),
+            WindowFunction::FirstValue(expr) | WindowFunction::LastValue(expr) => vec![expr],
+            WindowFunction::Aggregate(aggregate) => aggregate.as_expr().into_iter().collect(),
+        };
+
+        let partition_by = self.over.partition_by.iter();
+        let order_by = self
+            .over
+            .order_by
+            .iter()
+            .map(|OrderByExpr { expr, .. }| expr);
+        let frame = self
+            .over
+            .frame
+            .iter()
+            .flat_map(|WindowFrame { start, end, .. }| [start, end])
+            .filter_map(|bound| match bound {
+                WindowFrameBound::Preceding(expr) | WindowFrameBound::Following(expr) => {
+                    expr.as_ref()
+                }
+                WindowFrameBound::CurrentRow => None,
+            });
+
+        func_exprs
+            .into_iter()
+            .chain(partition_by)
+            .chain(order_by)
+            .chain(frame)
+    }
+
+    /// Mutable counterpart of [`Window::as_exprs`]
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        let func_exprs: Vec<&mut Expr> = match &mut self.func {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                Vec::new()
+            }
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => once(expr).chain(offset).chain(default).collect(),
+            WindowFunction::FirstValue(expr) | WindowFunction::LastValue(expr) => vec![expr],
+            WindowFunction::Aggregate(aggregate) => aggregate.as_expr_mut().into_iter().collect(),
+        };
+
+        let partition_by = self.over.partition_by.iter_mut();
+        let order_by = self
+            .over
+            .order_by
+            .iter_mut()
+            .map(|OrderByExpr { expr, .. }| expr);
+        let frame = self
+            .over
+            .frame
+            .iter_mut()
+            .flat_map(|WindowFrame { start, end, .. }| [start, end])
+            .filter_map(|bound| match bound {
+                WindowFrameBound::Preceding(expr) | WindowFrameBound::Following(expr) => {
+                    expr.as_mut()
+                }
+                WindowFrameBound::CurrentRow => None,
+            });
+
+        func_exprs
+            .into_iter()
+            .chain(partition_by)
+            .chain(order_by)
+            .chain(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::Expr, parse_sql::parse_expr, translate::translate_expr};
+
+    fn expr(sql: &str) -> Expr {
+        let parsed = parse_expr(sql).expect(sql);
+
+        translate_expr(&parsed).expect(sql)
+    }
+
+    fn test(sql: &str, expected: &[&str]) {
+        let window = match expr(sql) {
+            Expr::Window(window) => *window,
+            _ => unreachable!("only for window tests"),
+        };
+        let actual = window.as_exprs().collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), expected.len(), "{sql}");
+
+        for (expected, actual) in expected.iter().zip(actual.into_iter()) {
+            assert_eq!(actual, &expr(expected), "{sql}");
+        }
+    }
+
+    #[test]
+    fn as_exprs() {
+        test("ROW_NUMBER() OVER ()", &[]);
+        test("RANK() OVER (ORDER BY id)", &["id"]);
+        test(
+            "DENSE_RANK() OVER (PARTITION BY a, b ORDER BY c DESC)",
+            &["a", "b", "c"],
+        );
+        test("LAG(name) OVER (ORDER BY id)", &["name", "id"]);
+        test("LEAD(name, 2, 'x') OVER ()", &["name", "2", "'x'"]);
+        test("FIRST_VALUE(name) OVER (PARTITION BY a)", &["name", "a"]);
+        test("COUNT(*) OVER (PARTITION BY a)", &["a"]);
+        test(
+            "SUM(amount) OVER (ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING)",
+            &["amount", "id", "2", "1"],
+        );
+        test(
+            "LAST_VALUE(name) OVER (ORDER BY id ROWS UNBOUNDED PRECEDING)",
+            &["name", "id"],
+        );
+    }
+}
@@ -1,19 +1,29 @@
 use {
+    super::statistics::{condition_selectivity, selectivity},
     crate::{
         ast::{
-            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, OrderByExpr,
-            Query, Select, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            AstLiteral, BinaryOperator, Expr, Function, IndexCondition, IndexItem, IndexOperator,
+            Join, JoinOperator, OrderByExpr, Query, Select, SetExpr, Statement, TableAlias,
+            TableFactor, TableWithJoins,
         },
-        data::{Schema, SchemaIndex, SchemaIndexOrd, TableError},
+        data::{Schema, SchemaIndex, SchemaIndexOrd, TableError, TableStats},
         result::{Error, Result},
     },
-    std::collections::HashMap,
+    std::{collections::HashMap, ops::Bound, slice},
     utils::Vector,
 };
 
-pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Result<Statement> {
+/// An index scan looks up every matched row by its key, so once it is expected to match more
+/// than this fraction of the table a sequential scan is cheaper.
+const MAX_INDEX_SELECTIVITY: f64 = 0.3;
+
+pub fn plan(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    statement: Statement,
+) -> Result<Statement> {
     match statement {
-        Statement::Query(query) => plan_query(schema_map, query).map(Statement::Query),
+        Statement::Query(query) => plan_query(schema_map, statistics, query).map(Statement::Query),
         _ => Ok(statement),
     }
 }
@@ -21,18 +31,17 @@ pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Resul
 struct Indexes(Vec<SchemaIndex>);
 
 impl Indexes {
-    fn find(&self, target: &Expr) -> Option<String> {
-        self.0
-            .iter()
-            .find(|SchemaIndex { expr, .. }| expr == target)
-            .map(|SchemaIndex { name, .. }| name.to_owned())
-    }
-
     fn find_ordered(&self, target: &OrderByExpr) -> Option<String> {
         self.0
             .iter()
-            .find(|SchemaIndex { expr, order, .. }| {
-                if expr != &target.expr {
+            .find(|SchemaIndex { exprs, order, .. }| {
+                if exprs.first() != Some(&target.expr) {
+                    return false;
+                }
+
+                // index scan places NULLs last in ascending order and first in descending order
+                let asc = target.asc.unwrap_or(true);
+                if matches!(target.nulls_first, Some(nulls_first) if nulls_first == asc) {
                     return false;
                 }
 
@@ -48,8 +57,13 @@ impl Indexes {
     }
 }
 
-fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Query> {
+fn plan_query(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    query: Query,
+) -> Result<Query> {
     let Query {
+        with,
         body,
         order_by,
         limit,
@@ -60,6 +74,28 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         SetExpr::Select(select) => select,
         SetExpr::Values(_) => {
             return Ok(Query {
+                with,
+                body,
+                order_by,
+                limit,
+                offset,
+            });
+        }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let body = SetExpr::SetOperation {
+                op,
+                all,
+                left: plan_query(schema_map, statistics, *left).map(Box::new)?,
+                right: plan_query(schema_map, statistics, *right).map(Box::new)?,
+            };
+
+            return Ok(Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -68,11 +104,52 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         }
     };
 
-    let TableWithJoins { relation, .. } = &select.from;
+    let TableWithJoins { relation, joins } = &select.from;
+
+    // rows of the first table can be padded with NULLs by RIGHT or FULL joins,
+    // so an index scan on it would drop rows that the join still has to emit
+    let null_padded = joins.iter().any(|Join { join_operator, .. }| {
+        matches!(
+            join_operator,
+            JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+        )
+    });
+    if null_padded {
+        return Ok(Query {
+            with,
+            body: SetExpr::Select(select),
+            order_by,
+            limit,
+            offset,
+        });
+    }
+
     let table_name = match relation {
-        TableFactor::Table { name, .. } => name,
+        TableFactor::Table {
+            index: None, name, ..
+        } => name,
+        // planned onto the primary key, which already took its conditions out of the selection
+        TableFactor::Table { index: Some(_), .. } => {
+            let selection = select
+                .selection
+                .map(|expr| plan_subquery(schema_map, statistics, expr))
+                .transpose()?;
+            let select = Select {
+                selection,
+                ..*select
+            };
+
+            return Ok(Query {
+                with,
+                body: SetExpr::Select(Box::new(select)),
+                order_by,
+                limit,
+                offset,
+            });
+        }
         TableFactor::Derived { .. } => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -93,6 +170,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         Some(Schema { indexes, .. }) => Indexes(indexes.clone()),
         None => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -107,18 +185,21 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             .map(|name| IndexItem::NonClustered {
                 name,
                 asc: value_expr.asc,
-                cmp_expr: None,
+                prefix: Vec::new(),
+                condition: None,
             })
     });
 
     match index {
         index if index.is_some() => {
             let Select {
+                distinct,
                 projection,
                 from,
                 selection,
                 group_by,
                 having,
+                plan_id,
             } = *select;
 
             let TableWithJoins { relation, joins } = from;
@@ -137,14 +218,17 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             let select = Select {
+                distinct,
                 projection,
                 from,
                 selection,
                 group_by,
                 having,
+                plan_id,
             };
 
             Ok(Query {
+                with,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vector::from(order_by).pop().0.into(),
                 limit,
@@ -152,9 +236,11 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             })
         }
         _ => {
-            let select = plan_select(schema_map, &indexes, *select)?;
+            let table_stats = statistics.get(table_name);
+            let select = plan_select(schema_map, statistics, &indexes, table_stats, *select)?;
             let body = SetExpr::Select(Box::new(select));
             let query = Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -168,42 +254,55 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
 
 fn plan_select(
     schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
     indexes: &Indexes,
+    table_stats: Option<&TableStats>,
     select: Select,
 ) -> Result<Select> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
         group_by,
         having,
+        plan_id,
     } = select;
 
     let selection = match selection {
         Some(expr) => expr,
         None => {
             return Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
                 group_by,
                 having,
+                plan_id,
             });
         }
     };
 
-    match plan_index(schema_map, indexes, selection)? {
+    let planned = match table_stats {
+        Some(table_stats) => plan_by_cost(schema_map, statistics, indexes, table_stats, selection)?,
+        None => plan_composite_index(schema_map, statistics, indexes, selection)?,
+    };
+
+    match planned {
         Planned::Expr(selection) => Ok(Select {
+            distinct,
             projection,
             from,
             selection: Some(selection),
             group_by,
             having,
+            plan_id,
         }),
         Planned::IndexedExpr {
             index_name,
-            index_op,
-            index_value_expr,
+            prefix,
+            condition,
             selection,
         } => {
             let TableWithJoins { relation, joins } = from;
@@ -219,7 +318,8 @@ fn plan_select(
             let index = Some(IndexItem::NonClustered {
                 name: index_name,
                 asc: None,
-                cmp_expr: Some((index_op, index_value_expr)),
+                prefix,
+                condition: Some(condition),
             });
             let from = TableWithJoins {
                 relation: TableFactor::Table { name, alias, index },
@@ -227,11 +327,13 @@ fn plan_select(
             };
 
             Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
                 group_by,
                 having,
+                plan_id,
             })
         }
     }
@@ -240,204 +342,468 @@ fn plan_select(
 enum Planned {
     IndexedExpr {
         index_name: String,
-        index_op: IndexOperator,
-        index_value_expr: Expr,
+        prefix: Vec<Expr>,
+        condition: IndexCondition,
         selection: Option<Expr>,
     },
     Expr(Expr),
 }
 
-fn plan_index(
+struct CompositeScan {
+    /// Positions of the conditions consumed by the index scan
+    used: Vec<usize>,
+    prefix: Vec<Expr>,
+    condition: IndexCondition,
+}
+
+/// Scans a composite index when equality conditions on its leading columns, optionally
+/// followed by a range condition on the next column, cover more than one of its columns.
+/// Otherwise falls back to [`plan_index`].
+fn plan_composite_index(
     schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
     indexes: &Indexes,
     selection: Expr,
 ) -> Result<Planned> {
-    match selection {
-        Expr::Nested(expr) => plan_index(schema_map, indexes, *expr),
-        Expr::IsNull(expr) => Ok(search_is_null(indexes, true, expr)),
-        Expr::IsNotNull(expr) => Ok(search_is_null(indexes, false, expr)),
-        Expr::Subquery(query) => plan_query(schema_map, *query)
-            .map(Box::new)
-            .map(Expr::Subquery)
-            .map(Planned::Expr),
-        Expr::Exists { subquery, negated } => plan_query(schema_map, *subquery)
-            .map(Box::new)
-            .map(|subquery| Expr::Exists { subquery, negated })
-            .map(Planned::Expr),
-        Expr::InSubquery {
-            expr,
-            subquery,
-            negated,
-        } => plan_query(schema_map, *subquery)
-            .map(Box::new)
-            .map(|subquery| Expr::InSubquery {
-                expr,
-                subquery,
-                negated,
-            })
-            .map(Planned::Expr),
-        Expr::BinaryOp {
-            left,
-            op: BinaryOperator::And,
-            right,
-        } => {
-            let left = match plan_index(schema_map, indexes, *left)? {
-                Planned::Expr(selection) => selection,
-                Planned::IndexedExpr {
-                    index_name,
-                    index_value_expr,
-                    index_op,
-                    selection,
-                } => {
-                    let selection = match selection {
-                        Some(expr) => Expr::BinaryOp {
-                            left: Box::new(expr),
-                            op: BinaryOperator::And,
-                            right,
-                        },
-                        None => *right,
-                    };
+    let mut conditions = Vec::new();
+    collect_conditions(&selection, &mut conditions);
 
-                    return Ok(Planned::IndexedExpr {
-                        index_name,
-                        index_op,
-                        index_value_expr,
-                        selection: Some(selection),
-                    });
-                }
-            };
+    let mut planned: Option<(&str, CompositeScan)> = None;
+    for SchemaIndex { name, exprs, .. } in indexes.0.iter().filter(|index| index.exprs.len() > 1) {
+        let scan = match search_composite(&conditions, exprs) {
+            Some(scan) => scan,
+            None => continue,
+        };
+
+        if !matches!(&planned, Some((_, planned)) if planned.used.len() >= scan.used.len()) {
+            planned = Some((name, scan));
+        }
+    }
+
+    let (
+        index_name,
+        CompositeScan {
+            used,
+            prefix,
+            condition,
+        },
+    ) = match planned {
+        Some(planned) => planned,
+        None => return plan_index(schema_map, statistics, indexes, selection),
+    };
 
-            match plan_index(schema_map, indexes, *right)? {
-                Planned::Expr(expr) => Ok(Planned::Expr(Expr::BinaryOp {
-                    left: Box::new(left),
-                    op: BinaryOperator::And,
-                    right: Box::new(expr),
-                })),
-                Planned::IndexedExpr {
-                    index_name,
-                    index_op,
-                    index_value_expr,
-                    selection,
-                } => {
-                    let selection = match selection {
-                        Some(expr) => Expr::BinaryOp {
-                            left: Box::new(left),
-                            op: BinaryOperator::And,
-                            right: Box::new(expr),
-                        },
-                        None => left,
+    let selection = remaining_conditions(schema_map, statistics, conditions, &used)?;
+
+    Ok(Planned::IndexedExpr {
+        index_name: index_name.to_owned(),
+        prefix,
+        condition,
+        selection,
+    })
+}
+
+/// With the statistics of the table, scans the index expected to match the fewest rows
+/// among the single column and composite scans the conditions allow, or no index at all
+/// when even that one matches a large part of the table.
+fn plan_by_cost(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    indexes: &Indexes,
+    table_stats: &TableStats,
+    selection: Expr,
+) -> Result<Planned> {
+    let mut conditions = Vec::new();
+    collect_conditions(&selection, &mut conditions);
+
+    let estimate = |exprs: &[Expr], scan: &CompositeScan| {
+        let prefix = exprs.iter().zip(&scan.prefix).map(|(expr, value_expr)| {
+            selectivity(table_stats, expr, &IndexOperator::Eq, value_expr)
+        });
+        let last = exprs
+            .get(scan.prefix.len())
+            .map(|expr| condition_selectivity(table_stats, expr, &scan.condition));
+
+        prefix.chain(last).product::<f64>()
+    };
+
+    let composite_scans = indexes
+        .0
+        .iter()
+        .filter(|index| index.exprs.len() > 1)
+        .filter_map(|SchemaIndex { name, exprs, .. }| {
+            search_composite(&conditions, exprs).map(|scan| (name, estimate(exprs, &scan), scan))
+        });
+    let single_scans = indexes
+        .0
+        .iter()
+        .flat_map(|SchemaIndex { name, exprs, .. }| {
+            let (conditions, estimate) = (&conditions, &estimate);
+
+            conditions
+                .iter()
+                .enumerate()
+                .filter_map(move |(i, condition)| {
+                    let target = exprs.first()?;
+                    let mut used = vec![i];
+                    let condition = search_condition(condition, target)?;
+                    let condition = complete_range(conditions, &mut used, target, condition);
+                    let scan = CompositeScan {
+                        used,
+                        prefix: Vec::new(),
+                        condition,
                     };
 
-                    Ok(Planned::IndexedExpr {
-                        index_name,
-                        index_value_expr,
-                        index_op,
-                        selection: Some(selection),
-                    })
-                }
-            }
+                    Some((name, estimate(slice::from_ref(target), &scan), scan))
+                })
+        });
+
+    let cheapest = composite_scans
+        .chain(single_scans)
+        .min_by(|(_, a, _), (_, b, _)| a.total_cmp(b))
+        .filter(|(_, selectivity, _)| *selectivity <= MAX_INDEX_SELECTIVITY);
+
+    let (
+        index_name,
+        _,
+        CompositeScan {
+            used,
+            prefix,
+            condition,
+        },
+    ) = match cheapest {
+        Some(cheapest) => cheapest,
+        None => {
+            return plan_conditions(schema_map, statistics, selection).map(Planned::Expr);
         }
+    };
+
+    let selection = remaining_conditions(schema_map, statistics, conditions, &used)?;
+
+    Ok(Planned::IndexedExpr {
+        index_name: index_name.to_owned(),
+        prefix,
+        condition,
+        selection,
+    })
+}
+
+/// Joins the conditions not consumed by the index scan back with `AND`.
+fn remaining_conditions(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    conditions: Vec<&Expr>,
+    used: &[usize],
+) -> Result<Option<Expr>> {
+    let conditions = conditions
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !used.contains(i))
+        .map(|(_, condition)| plan_subquery(schema_map, statistics, condition.clone()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(conditions.into_iter().reduce(|left, right| Expr::BinaryOp {
+        left: Box::new(left),
+        op: BinaryOperator::And,
+        right: Box::new(right),
+    }))
+}
+
+/// Plans the subqueries among the conditions of a selection which no index scan consumes.
+fn plan_conditions(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    selection: Expr,
+) -> Result<Expr> {
+    match selection {
+        Expr::Nested(expr) => plan_conditions(schema_map, statistics, *expr)
+            .map(Box::new)
+            .map(Expr::Nested),
         Expr::BinaryOp {
             left,
-            op: BinaryOperator::Gt,
-            right,
-        } => Ok(search_index_op(indexes, IndexOperator::Gt, left, right)),
-        Expr::BinaryOp {
-            left,
-            op: BinaryOperator::Lt,
-            right,
-        } => Ok(search_index_op(indexes, IndexOperator::Lt, left, right)),
-        Expr::BinaryOp {
-            left,
-            op: BinaryOperator::GtEq,
-            right,
-        } => Ok(search_index_op(indexes, IndexOperator::GtEq, left, right)),
-        Expr::BinaryOp {
-            left,
-            op: BinaryOperator::LtEq,
+            op: BinaryOperator::And,
             right,
-        } => Ok(search_index_op(indexes, IndexOperator::LtEq, left, right)),
+        } => Ok(Expr::BinaryOp {
+            left: plan_conditions(schema_map, statistics, *left).map(Box::new)?,
+            op: BinaryOperator::And,
+            right: plan_conditions(schema_map, statistics, *right).map(Box::new)?,
+        }),
+        _ => plan_subquery(schema_map, statistics, selection),
+    }
+}
+
+fn collect_conditions<'a>(expr: &'a Expr, conditions: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::Nested(expr) => collect_conditions(expr, conditions),
         Expr::BinaryOp {
             left,
-            op: BinaryOperator::Eq,
+            op: BinaryOperator::And,
             right,
-        } => Ok(search_index_op(indexes, IndexOperator::Eq, left, right)),
-        _ => Ok(Planned::Expr(selection)),
+        } => {
+            collect_conditions(left, conditions);
+            collect_conditions(right, conditions);
+        }
+        _ => conditions.push(expr),
     }
 }
 
-fn search_is_null(indexes: &Indexes, null: bool, expr: Box<Expr>) -> Planned {
-    match indexes.find(expr.as_ref()) {
-        Some(index_name) => {
-            let index_op = if null {
-                IndexOperator::Eq
-            } else {
-                IndexOperator::Lt
-            };
+fn search_composite(conditions: &[&Expr], exprs: &[Expr]) -> Option<CompositeScan> {
+    let mut used = Vec::new();
+    let mut prefix = Vec::new();
+    for expr in exprs {
+        let found = search_unused(conditions, &used, expr)
+            .find_map(|(i, condition)| eq_value(&condition).cloned().map(|value| (i, value)));
 
-            Planned::IndexedExpr {
-                index_name,
-                index_op,
-                index_value_expr: Expr::Literal(AstLiteral::Null),
-                selection: None,
+        match found {
+            Some((i, value_expr)) => {
+                used.push(i);
+                prefix.push(value_expr);
             }
+            None => break,
         }
-        None => {
-            let expr = if null {
-                Expr::IsNull(expr)
-            } else {
-                Expr::IsNotNull(expr)
-            };
+    }
+
+    let range = exprs.get(prefix.len()).and_then(|target| {
+        search_unused(conditions, &used, target)
+            .find(|(_, condition)| eq_value(condition).is_none())
+            .map(|(i, condition)| (target, i, condition))
+    });
+    let condition = match range {
+        Some((target, i, condition)) => {
+            used.push(i);
 
-            Planned::Expr(expr)
+            complete_range(conditions, &mut used, target, condition)
         }
+        None => IndexCondition::from((IndexOperator::Eq, prefix.pop()?)),
+    };
+
+    (!prefix.is_empty()).then_some(CompositeScan {
+        used,
+        prefix,
+        condition,
+    })
+}
+
+/// Narrows a range bounded on one side only with the first unused condition which bounds
+/// `target` on the other side. Ranges bounded by `NULL`, which stand for `IS NULL` and
+/// `IS NOT NULL`, are left as they are.
+fn complete_range(
+    conditions: &[&Expr],
+    used: &mut Vec<usize>,
+    target: &Expr,
+    condition: IndexCondition,
+) -> IndexCondition {
+    let (lower, upper) = match condition {
+        IndexCondition::Range { lower, upper } => (lower, upper),
+        IndexCondition::InList(_) => return condition,
+    };
+
+    let is_null = |bound: &Bound<Expr>| {
+        matches!(
+            bound,
+            Bound::Included(Expr::Literal(AstLiteral::Null))
+                | Bound::Excluded(Expr::Literal(AstLiteral::Null))
+        )
+    };
+    let missing_lower = match (&lower, &upper) {
+        (Bound::Unbounded, Bound::Included(_) | Bound::Excluded(_)) if !is_null(&upper) => true,
+        (Bound::Included(_) | Bound::Excluded(_), Bound::Unbounded) if !is_null(&lower) => false,
+        _ => return IndexCondition::Range { lower, upper },
+    };
+
+    let found = search_unused(conditions, used, target).find_map(|(i, condition)| {
+        let bound = match (condition, missing_lower) {
+            (
+                IndexCondition::Range {
+                    lower,
+                    upper: Bound::Unbounded,
+                },
+                true,
+            ) => lower,
+            (
+                IndexCondition::Range {
+                    lower: Bound::Unbounded,
+                    upper,
+                },
+                false,
+            ) => upper,
+            _ => return None,
+        };
+
+        (!is_null(&bound)).then_some((i, bound))
+    });
+
+    match found {
+        Some((i, bound)) if missing_lower => {
+            used.push(i);
+
+            IndexCondition::Range {
+                lower: bound,
+                upper,
+            }
+        }
+        Some((i, bound)) => {
+            used.push(i);
+
+            IndexCondition::Range {
+                lower,
+                upper: bound,
+            }
+        }
+        None => IndexCondition::Range { lower, upper },
     }
 }
 
-fn search_index_op(
-    indexes: &Indexes,
-    index_op: IndexOperator,
-    left: Box<Expr>,
-    right: Box<Expr>,
-) -> Planned {
-    if let Some(index_name) = indexes
-        .find(left.as_ref())
-        .and_then(|index_name| is_stateless(right.as_ref()).then_some(index_name))
-    {
-        Planned::IndexedExpr {
-            index_name,
-            index_op,
-            index_value_expr: *right,
-            selection: None,
+fn search_unused<'a>(
+    conditions: &'a [&'a Expr],
+    used: &'a [usize],
+    target: &'a Expr,
+) -> impl Iterator<Item = (usize, IndexCondition)> + 'a {
+    conditions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !used.contains(i))
+        .filter_map(|(i, condition)| search_condition(condition, target).map(|found| (i, found)))
+}
+
+fn eq_value(condition: &IndexCondition) -> Option<&Expr> {
+    match condition {
+        IndexCondition::Range {
+            lower: Bound::Included(lower),
+            upper: Bound::Included(upper),
+        } if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+fn search_condition(condition: &Expr, target: &Expr) -> Option<IndexCondition> {
+    let is_target = |expr: &Expr| match expr {
+        Expr::Nested(expr) => expr.as_ref() == target,
+        expr => expr == target,
+    };
+
+    match condition {
+        Expr::Nested(expr) => search_condition(expr, target),
+        Expr::IsNull(expr) if is_target(expr) => Some(IndexCondition::from((
+            IndexOperator::Eq,
+            Expr::Literal(AstLiteral::Null),
+        ))),
+        Expr::IsNotNull(expr) if is_target(expr) => Some(IndexCondition::from((
+            IndexOperator::Lt,
+            Expr::Literal(AstLiteral::Null),
+        ))),
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if is_target(expr) && is_stateless(low) && is_stateless(high) => {
+            Some(IndexCondition::Range {
+                lower: Bound::Included(low.as_ref().clone()),
+                upper: Bound::Included(high.as_ref().clone()),
+            })
         }
-    } else if let Some(index_name) = indexes
-        .find(right.as_ref())
-        .and_then(|index_name| is_stateless(left.as_ref()).then_some(index_name))
-    {
-        Planned::IndexedExpr {
-            index_name,
-            index_op: index_op.reverse(),
-            index_value_expr: *left,
-            selection: None,
+        Expr::InList {
+            expr,
+            list,
+            negated: false,
+        } if is_target(expr) && !list.is_empty() && list.iter().all(is_stateless) => {
+            Some(IndexCondition::InList(list.clone()))
         }
-    } else if let Expr::Nested(left) = *left {
-        search_index_op(indexes, index_op, left, right)
-    } else if let Expr::Nested(right) = *right {
-        search_index_op(indexes, index_op, left, right)
-    } else {
-        Planned::Expr(Expr::BinaryOp {
-            left,
-            op: index_op.into(),
-            right,
-        })
+        Expr::BinaryOp { left, op, right } => {
+            let index_op = match op {
+                BinaryOperator::Eq => IndexOperator::Eq,
+                BinaryOperator::Gt => IndexOperator::Gt,
+                BinaryOperator::GtEq => IndexOperator::GtEq,
+                BinaryOperator::Lt => IndexOperator::Lt,
+                BinaryOperator::LtEq => IndexOperator::LtEq,
+                _ => return None,
+            };
+
+            if is_target(left) && is_stateless(right) {
+                Some(IndexCondition::from((index_op, right.as_ref().clone())))
+            } else if is_target(right) && is_stateless(left) {
+                Some(IndexCondition::from((
+                    index_op.reverse(),
+                    left.as_ref().clone(),
+                )))
+            } else {
+                None
+            }
+        }
+        _ => None,
     }
 }
 
+fn plan_subquery(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    expr: Expr,
+) -> Result<Expr> {
+    match expr {
+        Expr::Subquery(query) => plan_query(schema_map, statistics, *query)
+            .map(Box::new)
+            .map(Expr::Subquery),
+        Expr::Exists { subquery, negated } => plan_query(schema_map, statistics, *subquery)
+            .map(Box::new)
+            .map(|subquery| Expr::Exists { subquery, negated }),
+        Expr::InSubquery {
+            expr,
+            subquery,
+            negated,
+        } => plan_query(schema_map, statistics, *subquery)
+            .map(Box::new)
+            .map(|subquery| Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            }),
+        _ => Ok(expr),
+    }
+}
+
+/// Without statistics, scans the index on the first condition an index can take, narrowed
+/// by the condition on the other side of its range if there is one.
+fn plan_index(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    indexes: &Indexes,
+    selection: Expr,
+) -> Result<Planned> {
+    let mut conditions = Vec::new();
+    collect_conditions(&selection, &mut conditions);
+
+    let found = conditions.iter().enumerate().find_map(|(i, condition)| {
+        indexes
+            .0
+            .iter()
+            .find_map(|SchemaIndex { name, exprs, .. }| {
+                let target = exprs.first()?;
+
+                search_condition(condition, target).map(|found| (i, name, target, found))
+            })
+    });
+    let (i, index_name, target, condition) = match found {
+        Some(found) => found,
+        None => return plan_conditions(schema_map, statistics, selection).map(Planned::Expr),
+    };
+
+    let mut used = vec![i];
+    let condition = complete_range(&conditions, &mut used, target, condition);
+    let selection = remaining_conditions(schema_map, statistics, conditions, &used)?;
+
+    Ok(Planned::IndexedExpr {
+        index_name: index_name.to_owned(),
+        prefix: Vec::new(),
+        condition,
+        selection,
+    })
+}
+
 fn is_stateless(expr: &Expr) -> bool {
     match expr {
         Expr::Literal(AstLiteral::Null) => false,
         Expr::Literal(_) => true,
-        Expr::TypedString { .. } => true,
+        Expr::TypedString { .. } | Expr::Placeholder(_) => true,
         Expr::IsNull(expr)
         | Expr::IsNotNull(expr)
         | Expr::UnaryOp { expr, .. }
@@ -3,16 +3,23 @@ use {
     crate::{
         ast::{
             BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
-            SetExpr, Statement, TableWithJoins,
+            SelectItem, SetExpr, Statement, TableFactor, TableWithJoins,
         },
-        data::Schema,
+        data::{Schema, TableStats},
     },
     std::{collections::HashMap, rc::Rc},
     utils::Vector,
 };
 
-pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
-    let planner = JoinPlanner { schema_map };
+pub fn plan(
+    schema_map: &HashMap<String, Schema>,
+    statistics: &HashMap<String, TableStats>,
+    statement: Statement,
+) -> Statement {
+    let planner = JoinPlanner {
+        schema_map,
+        statistics,
+    };
 
     match statement {
         Statement::Query(query) => {
@@ -20,17 +27,57 @@ pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> State
 
             Statement::Query(query)
         }
+        Statement::Update {
+            table_name,
+            assignments,
+            from: Some(from),
+            join_executor: JoinExecutor::NestedLoop,
+            selection: Some(selection),
+            returning,
+        } => {
+            let (join_executor, selection) =
+                planner.target_hash_join(&table_name, &from, selection);
+
+            Statement::Update {
+                table_name,
+                assignments,
+                from: Some(from),
+                join_executor,
+                selection,
+                returning,
+            }
+        }
+        Statement::Delete {
+            table_name,
+            using: Some(using),
+            join_executor: JoinExecutor::NestedLoop,
+            selection: Some(selection),
+            returning,
+        } => {
+            let (join_executor, selection) =
+                planner.target_hash_join(&table_name, &using, selection);
+
+            Statement::Delete {
+                table_name,
+                using: Some(using),
+                join_executor,
+                selection,
+                returning,
+            }
+        }
         _ => statement,
     }
 }
 
 struct JoinPlanner<'a> {
     schema_map: &'a HashMap<String, Schema>,
+    statistics: &'a HashMap<String, TableStats>,
 }
 
 impl<'a> Planner<'a> for JoinPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
         let Query {
+            with,
             body,
             order_by,
             limit,
@@ -44,9 +91,21 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
                 SetExpr::Select(Box::new(select))
             }
             SetExpr::Values(_) => body,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.query(outer_context.as_ref().map(Rc::clone), *left)),
+                right: Box::new(self.query(outer_context, *right)),
+            },
         };
 
         Query {
+            with,
             body,
             order_by,
             limit,
@@ -62,25 +121,280 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 impl<'a> JoinPlanner<'a> {
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
         let Select {
+            distinct,
             projection,
             from,
             selection,
             group_by,
             having,
+            plan_id,
         } = select;
 
+        let from = self.order_tables(from, &projection);
+        let (from, selection) = match selection {
+            Some(expr) => {
+                self.selection_hash_join(outer_context.as_ref().map(Rc::clone), from, expr)
+            }
+            None => (from, None),
+        };
         let (outer_context, from) = self.table_with_joins(outer_context, from);
         let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
 
         Select {
+            distinct,
             projection,
             from,
             selection,
             group_by,
             having,
+            plan_id,
         }
     }
 
+    /// Puts the larger of the first two tables of an inner join first. Rows of the first table
+    /// are streamed while the joined table is hashed once or read again for every row, so the
+    /// smaller table should be joined.
+    ///
+    /// Only tables with statistics and without common column names are swapped, as the swap
+    /// would change which table an unqualified column refers to, and `SELECT *` keeps the
+    /// column order as written.
+    fn order_tables(
+        &self,
+        table_with_joins: TableWithJoins,
+        projection: &[SelectItem],
+    ) -> TableWithJoins {
+        let TableWithJoins {
+            mut relation,
+            mut joins,
+        } = table_with_joins;
+
+        let row_count = |relation: &TableFactor| match relation {
+            TableFactor::Table {
+                name, index: None, ..
+            } => self
+                .statistics
+                .get(name)
+                .map(|table_stats| table_stats.row_count),
+            _ => None,
+        };
+        let column_names = |relation: &TableFactor| match relation {
+            TableFactor::Table { name, .. } => self
+                .schema_map
+                .get(name)
+                .and_then(|schema| schema.column_defs.as_ref()),
+            _ => None,
+        };
+
+        let swap = match joins.first() {
+            Some(Join {
+                relation: join_relation,
+                join_operator: JoinOperator::Inner(_) | JoinOperator::CrossJoin,
+                join_executor: JoinExecutor::NestedLoop,
+            }) => {
+                let smaller = matches!(
+                    (row_count(&relation), row_count(join_relation)),
+                    (Some(rows), Some(join_rows)) if rows < join_rows
+                );
+                let distinct = match (column_names(&relation), column_names(join_relation)) {
+                    (Some(column_defs), Some(join_column_defs)) => {
+                        column_defs.iter().all(|column_def| {
+                            join_column_defs
+                                .iter()
+                                .all(|join_column_def| join_column_def.name != column_def.name)
+                        })
+                    }
+                    _ => false,
+                };
+                let wildcard = projection
+                    .iter()
+                    .any(|item| matches!(item, SelectItem::Wildcard));
+
+                smaller && distinct && !wildcard
+            }
+            _ => false,
+        };
+
+        if swap {
+            std::mem::swap(&mut relation, &mut joins[0].relation);
+        }
+
+        TableWithJoins { relation, joins }
+    }
+
+    /// Moves an equality in WHERE into the hash key of an inner or cross join
+    /// which has no join condition of its own, e.g. `FROM a, b WHERE a.id = b.a_id`.
+    fn selection_hash_join(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        table_with_joins: TableWithJoins,
+        selection: Expr,
+    ) -> (TableWithJoins, Option<Expr>) {
+        let TableWithJoins { relation, joins } = table_with_joins;
+
+        if joins.is_empty() {
+            return (TableWithJoins { relation, joins }, Some(selection));
+        }
+
+        // filtering rows before a RIGHT or FULL join changes which rows it pads with NULLs
+        let first_candidate = joins
+            .iter()
+            .rposition(|Join { join_operator, .. }| {
+                matches!(
+                    join_operator,
+                    JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+                )
+            })
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        let mut exprs = split_and(selection.clone());
+        let mut moved = false;
+        let mut inner_context = self.update_context(None, &relation);
+        let mut planned_joins = Vec::with_capacity(joins.len());
+
+        for (i, join) in joins.into_iter().enumerate() {
+            let Join {
+                relation: join_relation,
+                join_operator,
+                join_executor,
+            } = join;
+
+            let candidate = i >= first_candidate
+                && matches!(join_executor, JoinExecutor::NestedLoop)
+                && matches!(
+                    join_operator,
+                    JoinOperator::Inner(JoinConstraint::None) | JoinOperator::CrossJoin
+                );
+            let current_context = self.update_context(None, &join_relation);
+            let hash_join = candidate
+                .then(|| {
+                    exprs.iter().enumerate().find_map(|(position, expr)| {
+                        if !matches!(
+                            expr,
+                            Expr::BinaryOp {
+                                op: BinaryOperator::Eq,
+                                ..
+                            }
+                        ) {
+                            return None;
+                        }
+
+                        match self.join_expr(
+                            outer_context.as_ref().map(Rc::clone),
+                            inner_context.as_ref().map(Rc::clone),
+                            current_context.as_ref().map(Rc::clone),
+                            expr.clone(),
+                        ) {
+                            (join_executor @ JoinExecutor::Hash { .. }, None) => {
+                                Some((position, join_executor))
+                            }
+                            _ => None,
+                        }
+                    })
+                })
+                .flatten();
+
+            let join = match hash_join {
+                Some((position, join_executor)) => {
+                    exprs.remove(position);
+                    moved = true;
+
+                    Join {
+                        relation: join_relation,
+                        join_operator: JoinOperator::Inner(JoinConstraint::None),
+                        join_executor,
+                    }
+                }
+                None => Join {
+                    relation: join_relation,
+                    join_operator,
+                    join_executor,
+                },
+            };
+
+            inner_context = self.update_context(inner_context, &join.relation);
+            planned_joins.push(join);
+        }
+
+        let selection = match moved {
+            true => exprs.into_iter().reduce(|left, right| Expr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            }),
+            false => Some(selection),
+        };
+        let table_with_joins = TableWithJoins {
+            relation,
+            joins: planned_joins,
+        };
+
+        (table_with_joins, selection)
+    }
+
+    /// Moves an equality in WHERE of `UPDATE ... FROM` or `DELETE ... USING` between a column
+    /// of the table and the joined relations into the hash key the joined rows are looked up
+    /// by, e.g. `UPDATE a SET ... FROM b WHERE a.id = b.a_id`. Conditions only on the joined
+    /// relations are moved along to filter the rows once before they are hashed.
+    fn target_hash_join(
+        &self,
+        table_name: &str,
+        table_with_joins: &TableWithJoins,
+        selection: Expr,
+    ) -> (JoinExecutor, Option<Expr>) {
+        let target = TableFactor::Table {
+            name: table_name.to_owned(),
+            alias: None,
+            index: None,
+        };
+        let target_context = self.update_context(None, &target);
+        let TableWithJoins { relation, joins } = table_with_joins;
+        let joined_context = joins
+            .iter()
+            .fold(self.update_context(None, relation), |context, join| {
+                self.update_context(context, &join.relation)
+            });
+
+        // an unqualified column which both have is read from the table
+        let is_joined = |expr: &Expr| {
+            check_evaluable(joined_context.as_ref().map(Rc::clone), expr)
+                && !check_evaluable(target_context.as_ref().map(Rc::clone), expr)
+        };
+        let is_target = |expr: &Expr| {
+            check_evaluable(target_context.as_ref().map(Rc::clone), expr)
+                && !check_evaluable(None, expr)
+        };
+
+        let mut exprs = split_and(selection.clone());
+        let hash_key = exprs.iter().position(|expr| match expr {
+            Expr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } => (is_joined(left) && is_target(right)) || (is_joined(right) && is_target(left)),
+            _ => false,
+        });
+        let (key_expr, value_expr) = match hash_key.map(|position| exprs.remove(position)) {
+            Some(Expr::BinaryOp { left, right, .. }) if is_joined(&left) => (*left, *right),
+            Some(Expr::BinaryOp { left, right, .. }) => (*right, *left),
+            _ => return (JoinExecutor::NestedLoop, Some(selection)),
+        };
+
+        let (where_clause, exprs): (Vec<_>, Vec<_>) = exprs.into_iter().partition(is_joined);
+        let and = |left, right| Expr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::And,
+            right: Box::new(right),
+        };
+        let join_executor = JoinExecutor::Hash {
+            key_expr,
+            value_expr,
+            where_clause: where_clause.into_iter().reduce(and),
+        };
+
+        (join_executor, exprs.into_iter().reduce(and))
+    }
+
     fn table_with_joins(
         &self,
         outer_context: Option<Rc<Context<'a>>>,
@@ -130,13 +444,20 @@ impl<'a> JoinPlanner<'a> {
         enum JoinOp {
             Inner,
             LeftOuter,
+            RightOuter,
+            FullOuter,
         }
 
         let (join_op, expr) = match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr)) => (JoinOp::Inner, expr),
             JoinOperator::LeftOuter(JoinConstraint::On(expr)) => (JoinOp::LeftOuter, expr),
-            JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => {
+            JoinOperator::RightOuter(JoinConstraint::On(expr)) => (JoinOp::RightOuter, expr),
+            JoinOperator::FullOuter(JoinConstraint::On(expr)) => (JoinOp::FullOuter, expr),
+            JoinOperator::Inner(_)
+            | JoinOperator::LeftOuter(_)
+            | JoinOperator::RightOuter(_)
+            | JoinOperator::FullOuter(_)
+            | JoinOperator::CrossJoin => {
                 let context = self.update_context(inner_context, &relation);
                 let join = Join {
                     relation,
@@ -161,6 +482,10 @@ impl<'a> JoinPlanner<'a> {
             (JoinOp::Inner, None) => JoinOperator::Inner(JoinConstraint::None),
             (JoinOp::LeftOuter, Some(expr)) => JoinOperator::LeftOuter(JoinConstraint::On(expr)),
             (JoinOp::LeftOuter, None) => JoinOperator::LeftOuter(JoinConstraint::None),
+            (JoinOp::RightOuter, Some(expr)) => JoinOperator::RightOuter(JoinConstraint::On(expr)),
+            (JoinOp::RightOuter, None) => JoinOperator::RightOuter(JoinConstraint::None),
+            (JoinOp::FullOuter, Some(expr)) => JoinOperator::FullOuter(JoinConstraint::On(expr)),
+            (JoinOp::FullOuter, None) => JoinOperator::FullOuter(JoinConstraint::None),
         };
 
         let context = self.update_context(inner_context, &relation);
@@ -384,6 +709,22 @@ impl<'a> JoinPlanner<'a> {
     }
 }
 
+fn split_and(expr: Expr) -> Vec<Expr> {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut exprs = split_and(*left);
+            exprs.extend(split_and(*right));
+
+            exprs
+        }
+        _ => vec![expr],
+    }
+}
+
 type EvaluableExpr = Option<Expr>;
 type RemainderExpr = Option<Expr>;
 
@@ -422,14 +763,16 @@ mod tests {
     use {
         super::plan,
         crate::{
-            ast::{DateTimeField, Statement},
+            ast::{DateTimeField, JoinExecutor, Statement},
             ast_builder::{col, exists, num, subquery, table, Build, QueryNode},
+            data::TableStats,
             mock::{run, MockStorage},
-            parse_sql::parse,
+            parse_sql::{parse, parse_expr},
             plan::fetch_schema_map,
-            translate::translate,
+            translate::{translate, translate_expr},
         },
         futures::executor::block_on,
+        std::collections::HashMap,
     };
 
     fn plan_join(storage: &MockStorage, sql: &str) -> Statement {
@@ -437,7 +780,7 @@ mod tests {
         let statement = translate(&parsed).unwrap();
         let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
 
-        plan(&schema_map, statement)
+        plan(&schema_map, &HashMap::new(), statement)
     }
 
     macro_rules! test {
@@ -530,7 +873,7 @@ mod tests {
         let actual = {
             let schema_map = block_on(fetch_schema_map(&storage, &actual)).unwrap();
 
-            plan(&schema_map, actual)
+            plan(&schema_map, &HashMap::new(), actual)
         };
         let expected = table("Player")
             .select()
@@ -554,6 +897,152 @@ mod tests {
         test!(actual, expected, "subquery in join_constraint:\n{sql}");
     }
 
+    #[test]
+    fn selection_hash_join() {
+        let storage = run("
+            CREATE TABLE Player (
+                id INTEGER,
+                name TEXT
+            );
+            CREATE TABLE PlayerItem (
+                user_id INTEGER,
+                item_id INTEGER,
+                amount INTEGER
+            );
+            CREATE TABLE Item (
+                id INTEGER,
+                name TEXT
+            );
+        ");
+
+        let sql = "SELECT * FROM Player, PlayerItem WHERE Player.id = PlayerItem.user_id";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .join("PlayerItem")
+            .hash_executor("PlayerItem.user_id", "Player.id");
+        test!(actual, expected, "comma join:\n{sql}");
+
+        let sql = "
+            SELECT * FROM Player
+            CROSS JOIN PlayerItem
+            JOIN Item
+            WHERE
+                PlayerItem.amount > 10 AND
+                Item.id = PlayerItem.item_id AND
+                Player.id = PlayerItem.user_id AND
+                Player.name = Item.name
+        ";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .join("PlayerItem")
+            .hash_executor("PlayerItem.user_id", "Player.id")
+            .join("Item")
+            .hash_executor("Item.id", "PlayerItem.item_id")
+            .filter("PlayerItem.amount > 10 AND Player.name = Item.name");
+        test!(actual, expected, "equalities spread over joins:\n{sql}");
+
+        let sql = "SELECT * FROM Player, PlayerItem WHERE Player.id = 1 OR PlayerItem.user_id = 1";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .cross_join("PlayerItem")
+            .filter("Player.id = 1 OR PlayerItem.user_id = 1");
+        test!(actual, expected, "no equality between tables:\n{sql}");
+
+        let sql = "
+            SELECT * FROM Player, PlayerItem
+            RIGHT JOIN Item ON Item.id = PlayerItem.item_id
+            WHERE Player.id = PlayerItem.user_id
+        ";
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        assert!(
+            translate(&parsed).is_err(),
+            "outer join after comma:\n{sql}"
+        );
+
+        let sql = "
+            SELECT * FROM Player
+            CROSS JOIN PlayerItem
+            RIGHT JOIN Item ON Item.id = PlayerItem.item_id
+            WHERE Player.id = PlayerItem.user_id
+        ";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .cross_join("PlayerItem")
+            .right_join("Item")
+            .hash_executor("Item.id", "PlayerItem.item_id")
+            .filter("Player.id = PlayerItem.user_id");
+        test!(actual, expected, "cross join before right join:\n{sql}");
+    }
+
+    #[test]
+    fn order_tables() {
+        let storage = run("
+            CREATE TABLE Player (pid INTEGER, name TEXT);
+            CREATE TABLE PlayerItem (user_id INTEGER, amount INTEGER);
+            CREATE TABLE Item (id INTEGER, name TEXT);
+        ");
+        let table_stats = |table_name: &str, row_count| {
+            let table_stats = TableStats {
+                table_name: table_name.to_owned(),
+                row_count,
+                columns: Vec::new(),
+            };
+
+            (table_name.to_owned(), table_stats)
+        };
+        let statistics = HashMap::from([
+            table_stats("Player", 10),
+            table_stats("PlayerItem", 1000),
+            table_stats("Item", 100),
+        ]);
+        let plan_join = |sql: &str| {
+            let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+            let statement = translate(&parsed).unwrap();
+            let schema_map = block_on(fetch_schema_map(&storage, &statement)).unwrap();
+
+            plan(&schema_map, &statistics, statement)
+        };
+
+        let sql = "SELECT name, amount FROM Player JOIN PlayerItem ON pid = user_id";
+        let actual = plan_join(sql);
+        let expected = table("PlayerItem")
+            .select()
+            .join("Player")
+            .hash_executor("pid", "user_id")
+            .project("name, amount");
+        test!(actual, expected, "smaller table joined:\n{sql}");
+
+        let sql = "SELECT * FROM Player JOIN PlayerItem ON pid = user_id";
+        let actual = plan_join(sql);
+        let expected = table("Player")
+            .select()
+            .join("PlayerItem")
+            .hash_executor("user_id", "pid");
+        test!(actual, expected, "wildcard keeps the order:\n{sql}");
+
+        let sql = "SELECT Player.name FROM Player JOIN Item ON Player.name = Item.name";
+        let actual = plan_join(sql);
+        let expected = table("Player")
+            .select()
+            .join("Item")
+            .hash_executor("Item.name", "Player.name")
+            .project("Player.name");
+        test!(actual, expected, "common column names:\n{sql}");
+
+        let sql = "SELECT name, amount FROM PlayerItem LEFT JOIN Player ON pid = user_id";
+        let actual = plan_join(sql);
+        let expected = table("PlayerItem")
+            .select()
+            .left_join("Player")
+            .hash_executor("pid", "user_id")
+            .project("name, amount");
+        test!(actual, expected, "outer join:\n{sql}");
+    }
+
     #[test]
     fn hash_join() {
         let storage = run("
@@ -644,6 +1133,41 @@ mod tests {
             .filter(true);
         test!(actual, expected, "hash_join expr AND where_clause:\n{sql}");
 
+        let sql = "
+            SELECT *
+            FROM Player
+            RIGHT JOIN PlayerItem ON
+                PlayerItem.user_id = Player.id AND
+                PlayerItem.amount > 10
+        ";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .right_join("PlayerItem")
+            .hash_executor("PlayerItem.user_id", "Player.id")
+            .hash_filter("PlayerItem.amount > 10");
+        test!(actual, expected, "right join hash_join expr:\n{sql}");
+
+        let sql = "
+            SELECT *
+            FROM Player
+            FULL JOIN PlayerItem ON
+                PlayerItem.user_id = Player.id AND
+                PlayerItem.amount > Player.id
+        ";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .full_join("PlayerItem")
+            .hash_executor("PlayerItem.user_id", "Player.id")
+            .on("PlayerItem.amount > Player.id");
+        test!(actual, expected, "full join hash_join expr:\n{sql}");
+
+        let sql = "SELECT * FROM Player CROSS JOIN PlayerItem";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player").select().cross_join("PlayerItem");
+        test!(actual, expected, "cross join:\n{sql}");
+
         let sql = "
             SELECT *
             FROM Player u1
@@ -946,4 +1470,63 @@ mod tests {
         );
         test!(actual, expected, "case expr:\n{sql}");
     }
+
+    #[test]
+    fn target_hash_join() {
+        let storage = run("
+            CREATE TABLE Player (id INTEGER, name TEXT);
+            CREATE TABLE PlayerItem (user_id INTEGER, amount INTEGER);
+        ");
+        let expr = |sql: &str| translate_expr(&parse_expr(sql).unwrap()).unwrap();
+        let plan_target_join = |sql: &str| match plan_join(&storage, sql) {
+            Statement::Update {
+                join_executor,
+                selection,
+                ..
+            }
+            | Statement::Delete {
+                join_executor,
+                selection,
+                ..
+            } => (join_executor, selection),
+            _ => unreachable!(),
+        };
+
+        let sql = "UPDATE Player SET name = 'x' FROM PlayerItem WHERE id = user_id AND amount > 1";
+        let expected = JoinExecutor::Hash {
+            key_expr: expr("user_id"),
+            value_expr: expr("id"),
+            where_clause: Some(expr("amount > 1")),
+        };
+        assert_eq!(
+            plan_target_join(sql),
+            (expected, None),
+            "update from:\n{sql}"
+        );
+
+        let sql = "
+            DELETE FROM Player USING PlayerItem
+            WHERE Player.name = 'a' AND PlayerItem.user_id = Player.id
+        ";
+        let expected = JoinExecutor::Hash {
+            key_expr: expr("PlayerItem.user_id"),
+            value_expr: expr("Player.id"),
+            where_clause: None,
+        };
+        assert_eq!(
+            plan_target_join(sql),
+            (expected, Some(expr("Player.name = 'a'"))),
+            "delete using:\n{sql}"
+        );
+
+        let sql = "DELETE FROM Player USING PlayerItem WHERE user_id = 1 OR amount = id";
+        assert_eq!(
+            plan_target_join(sql),
+            (
+                JoinExecutor::NestedLoop,
+                Some(expr("user_id = 1 OR amount = id"))
+            ),
+            "no equality with the table:\n{sql}"
+        );
+    }
 }
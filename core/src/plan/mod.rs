@@ -1,27 +1,73 @@
 mod context;
+mod cte;
 mod error;
 mod evaluable;
 mod expr;
 mod index;
 mod join;
+mod plan_id;
 mod planner;
 mod primary_key;
 mod schema;
+mod statistics;
 mod validate;
 
-use crate::{ast::Statement, result::Result, store::Store};
+use {
+    crate::{
+        ast::Statement,
+        result::Result,
+        store::{Statistics, Store, View},
+    },
+    std::collections::HashMap,
+};
 
 pub use {
-    self::validate::validate, error::*, index::plan as plan_index, join::plan as plan_join,
-    primary_key::plan as plan_primary_key, schema::fetch_schema_map,
+    self::validate::validate,
+    cte::{plan as plan_cte, plan_with_views},
+    error::*,
+    index::plan as plan_index,
+    join::plan as plan_join,
+    primary_key::plan as plan_primary_key,
+    schema::fetch_schema_map,
+    statistics::fetch_statistics_map,
 };
 
-pub async fn plan<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
+pub(crate) use {cte::references_cte, expr::PlanExpr};
+
+pub async fn plan<T: Store + View + Statistics>(
+    storage: &T,
+    statement: Statement,
+) -> Result<Statement> {
+    match statement {
+        Statement::Explain { analyze, statement } => plan_statement(storage, *statement)
+            .await
+            .map(plan_id::plan)
+            .map(|statement| Statement::Explain {
+                analyze,
+                statement: Box::new(statement),
+            }),
+        Statement::StartTransaction | Statement::Commit | Statement::Rollback => Ok(statement),
+        statement => plan_statement(storage, statement).await,
+    }
+}
+
+async fn plan_statement<T: Store + View + Statistics>(
+    storage: &T,
+    statement: Statement,
+) -> Result<Statement> {
+    let views = storage
+        .fetch_all_views()
+        .await?
+        .into_iter()
+        .map(|view| (view.view_name.to_owned(), view))
+        .collect::<HashMap<_, _>>();
+    let statement = plan_with_views(views, statement)?;
     let schema_map = fetch_schema_map(storage, &statement).await?;
     validate(&schema_map, &statement)?;
+    let statistics = fetch_statistics_map(storage, &schema_map).await?;
     let statement = plan_primary_key(&schema_map, statement);
-    let statement = plan_index(&schema_map, statement)?;
-    let statement = plan_join(&schema_map, statement);
+    let statement = plan_index(&schema_map, &statistics, statement)?;
+    let statement = plan_join(&schema_map, &statistics, statement);
 
     Ok(statement)
 }
@@ -0,0 +1,46 @@
+use {
+    crate::ast::{Cte, Join, Query, SetExpr, Statement, TableFactor, TableWithJoins, With},
+    std::iter,
+};
+
+/// Numbers the `SELECT`s the executor measures for `EXPLAIN ANALYZE`, which are the ones of
+/// the query itself, its set operations, its CTEs and its derived tables.
+pub fn plan(statement: Statement) -> Statement {
+    let mut statement = statement;
+
+    if let Statement::Query(query) = &mut statement {
+        plan_query(&mut 0, query);
+    }
+
+    statement
+}
+
+fn plan_query(next_id: &mut usize, query: &mut Query) {
+    if let Some(With { cte_tables, .. }) = &mut query.with {
+        for Cte { query, .. } in cte_tables {
+            plan_query(next_id, query);
+        }
+    }
+
+    match &mut query.body {
+        SetExpr::Select(select) => {
+            select.plan_id = Some(*next_id);
+            *next_id += 1;
+
+            let TableWithJoins { relation, joins } = &mut select.from;
+            let relations =
+                iter::once(relation).chain(joins.iter_mut().map(|Join { relation, .. }| relation));
+
+            for relation in relations {
+                if let TableFactor::Derived { subquery, .. } = relation {
+                    plan_query(next_id, subquery);
+                }
+            }
+        }
+        SetExpr::Values(_) => {}
+        SetExpr::SetOperation { left, right, .. } => {
+            plan_query(next_id, left);
+            plan_query(next_id, right);
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use {
     super::context::Context,
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, Function, Query, TableAlias, TableFactor},
+        ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr, Query, TableAlias, TableFactor},
         data::Schema,
     },
-    std::rc::Rc,
+    std::{mem, rc::Rc},
 };
 
 pub trait Planner<'a> {
@@ -17,7 +17,8 @@ pub trait Planner<'a> {
             Expr::Identifier(_)
             | Expr::CompoundIdentifier { .. }
             | Expr::Literal(_)
-            | Expr::TypedString { .. } => expr,
+            | Expr::TypedString { .. }
+            | Expr::Placeholder(_) => expr,
             Expr::IsNull(expr) => Expr::IsNull(Box::new(self.subquery_expr(outer_context, *expr))),
             Expr::IsNotNull(expr) => {
                 Expr::IsNotNull(Box::new(self.subquery_expr(outer_context, *expr)))
@@ -162,21 +163,37 @@ pub trait Planner<'a> {
                 leading_field,
                 last_field,
             },
-            Expr::Function(func) => match *func {
-                Function::Cast { expr, data_type } => Expr::Function(Box::new(Function::Cast {
-                    expr: self.subquery_expr(outer_context, expr),
-                    data_type,
-                })),
-                Function::Extract { field, expr } => Expr::Function(Box::new(Function::Extract {
-                    field,
-                    expr: self.subquery_expr(outer_context, expr),
-                })),
-                _ => Expr::Function(func),
-            },
-            Expr::Aggregate(_) => expr,
+            Expr::Function(mut func) => {
+                func.as_exprs_mut().for_each(|expr| {
+                    self.subquery_expr_mut(outer_context.as_ref().map(Rc::clone), expr)
+                });
+
+                Expr::Function(func)
+            }
+            Expr::Aggregate(mut aggregate) => {
+                if let Some(expr) = aggregate.as_expr_mut() {
+                    self.subquery_expr_mut(outer_context, expr);
+                }
+
+                Expr::Aggregate(aggregate)
+            }
+            Expr::Window(mut window) => {
+                window.as_exprs_mut().for_each(|expr| {
+                    self.subquery_expr_mut(outer_context.as_ref().map(Rc::clone), expr)
+                });
+
+                Expr::Window(window)
+            }
         }
     }
 
+    /// [`Planner::subquery_expr`] on an argument of a function, which is only lent in place.
+    fn subquery_expr_mut(&self, outer_context: Option<Rc<Context<'a>>>, expr: &mut Expr) {
+        let taken = mem::replace(expr, Expr::Literal(AstLiteral::Null));
+
+        *expr = self.subquery_expr(outer_context, taken);
+    }
+
     fn update_context(
         &self,
         next: Option<Rc<Context<'a>>>,
@@ -2,12 +2,12 @@ use {
     super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
     crate::{
         ast::{
-            BinaryOperator, Expr, IndexItem, Query, Select, SetExpr, Statement, TableFactor,
-            TableWithJoins,
+            BinaryOperator, Expr, IndexItem, IndexOperator, Join, JoinOperator, OrderByExpr, Query,
+            Select, SetExpr, Statement, TableFactor, TableWithJoins,
         },
         data::Schema,
     },
-    std::{collections::HashMap, rc::Rc},
+    std::{collections::HashMap, ops::Bound, rc::Rc},
 };
 
 pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
@@ -29,16 +29,35 @@ struct PrimaryKeyPlanner<'a> {
 
 impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
-        let body = match query.body {
+        let (body, order_by) = match query.body {
             SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
+                let (select, order_by) = self.select(outer_context, *select, query.order_by);
 
-                SetExpr::Select(Box::new(select))
+                (SetExpr::Select(Box::new(select)), order_by)
+            }
+            SetExpr::Values(_) => (query.body, query.order_by),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let body = SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(self.query(outer_context.as_ref().map(Rc::clone), *left)),
+                    right: Box::new(self.query(outer_context, *right)),
+                };
+
+                (body, query.order_by)
             }
-            SetExpr::Values(_) => query.body,
         };
 
-        Query { body, ..query }
+        Query {
+            body,
+            order_by,
+            ..query
+        }
     }
 
     fn get_schema(&self, name: &str) -> Option<&'a Schema> {
@@ -46,6 +65,123 @@ impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
     }
 }
 
+fn is_primary_key(relation_context: Option<&Rc<Context<'_>>>, expr: &Expr) -> bool {
+    let context = match relation_context {
+        Some(context) => context,
+        None => return false,
+    };
+
+    match expr {
+        Expr::Identifier(ident) => context.contains_primary_key(ident),
+        Expr::CompoundIdentifier { alias, ident } => {
+            context.contains_alias(alias) && context.contains_primary_key(ident)
+        }
+        _ => false,
+    }
+}
+
+/// Moves comparisons of the primary key of the first table with evaluable values into the
+/// key range, and returns the rest of the condition. Only the first lower and the first
+/// upper bound are taken, as the values cannot be compared before execution.
+fn take_range(
+    relation_context: Option<&Rc<Context<'_>>>,
+    range: &mut (Bound<Expr>, Bound<Expr>),
+    expr: Expr,
+) -> Option<Expr> {
+    let is_primary_key = |expr: &Expr| is_primary_key(relation_context, expr);
+
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let left = take_range(relation_context, range, *left);
+            let right = take_range(relation_context, range, *right);
+
+            match (left, right) {
+                (Some(left), Some(right)) => Some(Expr::BinaryOp {
+                    left: Box::new(left),
+                    op: BinaryOperator::And,
+                    right: Box::new(right),
+                }),
+                (expr @ Some(_), None) | (None, expr) => expr,
+            }
+        }
+        Expr::BinaryOp { left, op, right } => {
+            let index_op = match op {
+                BinaryOperator::Gt => IndexOperator::Gt,
+                BinaryOperator::GtEq => IndexOperator::GtEq,
+                BinaryOperator::Lt => IndexOperator::Lt,
+                BinaryOperator::LtEq => IndexOperator::LtEq,
+                _ => return Some(Expr::BinaryOp { left, op, right }),
+            };
+            let (index_op, value) = match (is_primary_key(&left), is_primary_key(&right)) {
+                (true, false) => (index_op, &right),
+                (false, true) => (index_op.reverse(), &left),
+                _ => return Some(Expr::BinaryOp { left, op, right }),
+            };
+
+            let bound = match index_op {
+                IndexOperator::Gt | IndexOperator::GtEq => &mut range.0,
+                _ => &mut range.1,
+            };
+            if !matches!(bound, Bound::Unbounded) || !check_evaluable(None, value) {
+                return Some(Expr::BinaryOp { left, op, right });
+            }
+
+            let value = value.as_ref().clone();
+            *bound = match index_op {
+                IndexOperator::GtEq | IndexOperator::LtEq => Bound::Included(value),
+                _ => Bound::Excluded(value),
+            };
+
+            None
+        }
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if matches!(range, (Bound::Unbounded, Bound::Unbounded))
+            && is_primary_key(&expr)
+            && check_evaluable(None, &low)
+            && check_evaluable(None, &high) =>
+        {
+            *range = (Bound::Included(*low), Bound::Included(*high));
+
+            None
+        }
+        Expr::Nested(expr) => take_range(relation_context, range, *expr)
+            .map(Box::new)
+            .map(Expr::Nested),
+        _ => Some(expr),
+    }
+}
+
+/// Returns the direction of a sort by the primary key of the only table, which the range
+/// scan can produce in place of the sort.
+fn key_order(
+    relation_context: Option<&Rc<Context<'_>>>,
+    select: &Select,
+    order_by: &[OrderByExpr],
+) -> Option<bool> {
+    let OrderByExpr { expr, asc, .. } = match order_by {
+        [order_by_expr] => order_by_expr,
+        _ => return None,
+    };
+
+    let plain = select.from.joins.is_empty()
+        && select.group_by.is_empty()
+        && select.having.is_none()
+        && select.distinct.is_none();
+    if !plain || !is_primary_key(relation_context, expr) {
+        return None;
+    }
+
+    Some(asc.unwrap_or(true))
+}
+
 enum PrimaryKey {
     Found {
         index_item: IndexItem,
@@ -55,16 +191,42 @@ enum PrimaryKey {
 }
 
 impl<'a> PrimaryKeyPlanner<'a> {
-    fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
-        let current_context = self.update_context(None, &select.from.relation);
+    fn select(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        select: Select,
+        order_by: Vec<OrderByExpr>,
+    ) -> (Select, Vec<OrderByExpr>) {
+        let relation_context = self.update_context(None, &select.from.relation);
         let current_context = select
             .from
             .joins
             .iter()
-            .fold(current_context, |context, join| {
+            .fold(relation_context.as_ref().map(Rc::clone), |context, join| {
                 self.update_context(context, &join.relation)
             });
 
+        let null_padded = select.from.joins.iter().any(|Join { join_operator, .. }| {
+            matches!(
+                join_operator,
+                JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+            )
+        });
+        if null_padded {
+            let context = Context::concat(current_context, outer_context);
+            let selection = select
+                .selection
+                .map(|expr| self.subquery_expr(context, expr));
+
+            let select = Select {
+                selection,
+                ..select
+            };
+
+            return (select, order_by);
+        }
+
+        let asc = key_order(relation_context.as_ref(), &select, &order_by);
         let (index, selection) = select
             .selection
             .map(|expr| self.expr(outer_context, current_context, expr))
@@ -74,7 +236,31 @@ impl<'a> PrimaryKeyPlanner<'a> {
             })
             .unwrap_or((None, None));
 
-        if let TableFactor::Table {
+        let (index, selection, order_by) = match index {
+            Some(index) => (Some(index), selection, order_by),
+            None => {
+                let mut range = (Bound::Unbounded, Bound::Unbounded);
+                let selection = selection
+                    .and_then(|expr| take_range(relation_context.as_ref(), &mut range, expr));
+
+                let index = match (range, asc) {
+                    ((Bound::Unbounded, Bound::Unbounded), None) => None,
+                    ((lower, upper), asc) => Some(IndexItem::PrimaryKeyRange {
+                        lower,
+                        upper,
+                        asc: asc.unwrap_or(true),
+                    }),
+                };
+                let order_by = match asc {
+                    Some(_) => Vec::new(),
+                    None => order_by,
+                };
+
+                (index, selection, order_by)
+            }
+        };
+
+        let select = if let TableFactor::Table {
             name,
             alias,
             index: None,
@@ -95,7 +281,9 @@ impl<'a> PrimaryKeyPlanner<'a> {
                 selection,
                 ..select
             }
-        }
+        };
+
+        (select, order_by)
     }
 
     fn expr(
@@ -220,8 +408,8 @@ mod tests {
         crate::{
             ast::{
                 AstLiteral, BinaryOperator, Expr, IndexItem, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
-                TableWithJoins, Values,
+                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
+                TableFactor, TableWithJoins, Values,
             },
             mock::{run, MockStorage},
             parse_sql::{parse, parse_expr},
@@ -229,6 +417,7 @@ mod tests {
             translate::{translate, translate_expr},
         },
         futures::executor::block_on,
+        std::ops::Bound,
     };
 
     fn plan(storage: &MockStorage, sql: &str) -> Statement {
@@ -241,6 +430,7 @@ mod tests {
 
     fn select(select: Select) -> Statement {
         Statement::Query(Query {
+            with: None,
             body: SetExpr::Select(Box::new(select)),
             limit: None,
             offset: None,
@@ -266,6 +456,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE id = 1;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -278,12 +469,14 @@ mod tests {
             selection: None,
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "primary key in lhs:\n{sql}");
 
         let sql = "SELECT * FROM Player WHERE 1 = id;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -296,12 +489,14 @@ mod tests {
             selection: None,
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "primary key in rhs:\n{sql}");
 
         let sql = "SELECT * FROM Player WHERE id = 1 AND True;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -314,6 +509,7 @@ mod tests {
             selection: Some(expr("True")),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "AND binary op:\n{sql}");
 
@@ -326,6 +522,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -338,6 +535,7 @@ mod tests {
             selection: Some(expr("name IS NOT NULL AND True")),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "AND binary op 2:\n{sql}");
 
@@ -359,6 +557,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -371,6 +570,7 @@ mod tests {
             selection: Some(expr("name IS NOT NULL AND (True)")),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "AND binary op 3:\n{sql}");
     }
@@ -391,6 +591,7 @@ mod tests {
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = 1";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -411,12 +612,45 @@ mod tests {
             selection: None,
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "basic inner join:\n{sql}");
 
+        let sql = "SELECT * FROM Player RIGHT JOIN Badge WHERE Player.id = 1";
+        let actual = plan(&storage, sql);
+        let expected = select(Select {
+            distinct: None,
+            projection: vec![SelectItem::Wildcard],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "Player".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: vec![Join {
+                    relation: TableFactor::Table {
+                        name: "Badge".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    join_operator: JoinOperator::RightOuter(JoinConstraint::None),
+                    join_executor: JoinExecutor::NestedLoop,
+                }],
+            },
+            selection: Some(expr("Player.id = 1")),
+            group_by: Vec::new(),
+            having: None,
+            plan_id: None,
+        });
+        assert_eq!(
+            actual, expected,
+            "right join keeps primary key filter:\n{sql}"
+        );
+
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = Badge.user_id";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -437,6 +671,7 @@ mod tests {
             selection: Some(expr("Player.id = Badge.user_id")),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "join but no primary key:\n{sql}");
 
@@ -448,7 +683,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -461,6 +698,7 @@ mod tests {
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 limit: None,
                 offset: None,
@@ -468,6 +706,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -484,6 +723,7 @@ mod tests {
                 }),
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             })
         };
         assert_eq!(actual, expected, "nested select:\n{sql}");
@@ -502,7 +742,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("name".to_owned()),
                         label: "name".to_owned(),
@@ -518,6 +760,7 @@ mod tests {
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 limit: Some(expr("1")),
                 offset: None,
@@ -525,6 +768,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -541,6 +785,7 @@ mod tests {
                 }),
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             })
         };
         assert_eq!(actual, expected, "name is not primary key:\n{sql}");
@@ -553,7 +798,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("id".to_owned()),
                         label: "id".to_owned(),
@@ -569,6 +816,7 @@ mod tests {
                     selection: Some(expr("id = id")),
                     group_by: Vec::new(),
                     having: None,
+                    plan_id: None,
                 })),
                 limit: None,
                 offset: None,
@@ -576,6 +824,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -592,6 +841,7 @@ mod tests {
                 }),
                 group_by: Vec::new(),
                 having: None,
+                plan_id: None,
             })
         };
         assert_eq!(actual, expected, "ambiguous nested contexts:\n{sql}");
@@ -600,17 +850,21 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = Statement::Delete {
             table_name: "Player".to_owned(),
+            using: None,
+            join_executor: JoinExecutor::NestedLoop,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("id".to_owned())),
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::Number(1.into()))),
             }),
+            returning: Vec::new(),
         };
         assert_eq!(actual, expected, "delete statement:\n{sql}");
 
         let sql = "VALUES (1), (2);";
         let actual = plan(&storage, sql);
         let expected = Statement::Query(Query {
+            with: None,
             body: SetExpr::Values(Values(vec![
                 vec![Expr::Literal(AstLiteral::Number(1.into()))],
                 vec![Expr::Literal(AstLiteral::Number(2.into()))],
@@ -624,6 +878,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE (name);";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -636,7 +891,104 @@ mod tests {
             selection: Some(Expr::Nested(Box::new(expr("name")))),
             group_by: Vec::new(),
             having: None,
+            plan_id: None,
         });
         assert_eq!(actual, expected, "nested:\n{sql}");
     }
+
+    #[test]
+    fn range() {
+        let storage = run("
+            CREATE TABLE Player (
+                id INTEGER PRIMARY KEY,
+                name TEXT
+            );
+        ");
+        let query = |index, selection: Option<&str>, order_by| {
+            Statement::Query(Query {
+                with: None,
+                body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
+                    projection: vec![SelectItem::Wildcard],
+                    from: TableWithJoins {
+                        relation: TableFactor::Table {
+                            name: "Player".to_owned(),
+                            alias: None,
+                            index,
+                        },
+                        joins: Vec::new(),
+                    },
+                    selection: selection.map(expr),
+                    group_by: Vec::new(),
+                    having: None,
+                    plan_id: None,
+                })),
+                limit: None,
+                offset: None,
+                order_by,
+            })
+        };
+        let range = |lower, upper, asc| Some(IndexItem::PrimaryKeyRange { lower, upper, asc });
+
+        let sql = "SELECT * FROM Player WHERE id BETWEEN 1 AND 3";
+        let expected = query(
+            range(Bound::Included(expr("1")), Bound::Included(expr("3")), true),
+            None,
+            Vec::new(),
+        );
+        assert_eq!(plan(&storage, sql), expected, "between:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE name = 'a' AND 5 > id AND id >= 2";
+        let expected = query(
+            range(Bound::Included(expr("2")), Bound::Excluded(expr("5")), true),
+            Some("name = 'a'"),
+            Vec::new(),
+        );
+        assert_eq!(
+            plan(&storage, sql),
+            expected,
+            "lower and upper bound:\n{sql}"
+        );
+
+        let sql = "SELECT * FROM Player WHERE id > 1 AND id > name AND id > 2";
+        let expected = query(
+            range(Bound::Excluded(expr("1")), Bound::Unbounded, true),
+            Some("id > name AND id > 2"),
+            Vec::new(),
+        );
+        assert_eq!(plan(&storage, sql), expected, "first bound only:\n{sql}");
+
+        let sql = "SELECT * FROM Player ORDER BY id DESC";
+        let expected = query(
+            range(Bound::Unbounded, Bound::Unbounded, false),
+            None,
+            Vec::new(),
+        );
+        assert_eq!(
+            plan(&storage, sql),
+            expected,
+            "order by primary key:\n{sql}"
+        );
+
+        let sql = "SELECT * FROM Player WHERE id < 3 ORDER BY name";
+        let order_by = vec![OrderByExpr {
+            expr: expr("name"),
+            asc: None,
+            nulls_first: None,
+        }];
+        let expected = query(
+            range(Bound::Unbounded, Bound::Excluded(expr("3")), true),
+            None,
+            order_by,
+        );
+        assert_eq!(
+            plan(&storage, sql),
+            expected,
+            "order by other column:\n{sql}"
+        );
+
+        let sql = "SELECT * FROM Player WHERE id > 1 OR id < 0";
+        let expected = query(None, Some("id > 1 OR id < 0"), Vec::new());
+        assert_eq!(plan(&storage, sql), expected, "OR binary op:\n{sql}");
+    }
 }
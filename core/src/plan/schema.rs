@@ -2,8 +2,8 @@ use {
     super::expr::PlanExpr,
     crate::{
         ast::{
-            Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr,
-            Statement, TableFactor, TableWithJoins,
+            Cte, Distinct, Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem,
+            SetExpr, Statement, TableFactor, TableWithJoins, With,
         },
         data::Schema,
         result::Result,
@@ -47,6 +47,26 @@ pub async fn fetch_schema_map<T: Store>(
 
             Ok(schema_list)
         }
+        Statement::Update {
+            table_name,
+            from: Some(table_with_joins),
+            ..
+        }
+        | Statement::Delete {
+            table_name,
+            using: Some(table_with_joins),
+            ..
+        } => {
+            let table_schema = storage
+                .fetch_schema(table_name)
+                .await?
+                .map(|schema| HashMap::from([(table_name.to_owned(), schema)]))
+                .unwrap_or_else(HashMap::new);
+            let joined_schema_list = scan_table_with_joins(storage, table_with_joins).await?;
+            let schema_list = table_schema.into_iter().chain(joined_schema_list).collect();
+
+            Ok(schema_list)
+        }
         Statement::DropTable { names, .. } => {
             stream::iter(names)
                 .filter_map(|table_name| async {
@@ -63,8 +83,10 @@ pub async fn fetch_schema_map<T: Store>(
     }
 }
 
+#[async_recursion(?Send)]
 async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<String, Schema>> {
     let Query {
+        with,
         body,
         limit,
         offset,
@@ -74,6 +96,33 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
     let schema_list = match body {
         SetExpr::Select(select) => scan_select(storage, select).await?,
         SetExpr::Values(_) => HashMap::new(),
+        SetExpr::SetOperation { left, right, .. } => scan_query(storage, left)
+            .await?
+            .into_iter()
+            .chain(scan_query(storage, right).await?)
+            .collect(),
+    };
+
+    let schema_list = match with {
+        Some(With { cte_tables, .. }) => {
+            let cte_schema_list = stream::iter(cte_tables)
+                .then(|Cte { query, .. }| scan_query(storage, query))
+                .try_collect::<Vec<_>>()
+                .await?
+                .into_iter()
+                .flatten();
+
+            schema_list
+                .into_iter()
+                .filter(|(name, _)| {
+                    !cte_tables
+                        .iter()
+                        .any(|Cte { alias, .. }| &alias.name == name)
+                })
+                .chain(cte_schema_list)
+                .collect()
+        }
+        None => schema_list,
     };
 
     let schema_list = match (limit, offset) {
@@ -94,11 +143,13 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
 
 async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<String, Schema>> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
         group_by,
         having,
+        plan_id: _,
     } = select;
 
     let projection = stream::iter(projection)
@@ -115,7 +166,15 @@ async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<S
 
     let from = scan_table_with_joins(storage, from).await?;
 
-    let exprs = selection.iter().chain(group_by.iter()).chain(having.iter());
+    let distinct = match distinct {
+        Some(Distinct::On(exprs)) => exprs.as_slice(),
+        Some(Distinct::Distinct) | None => &[],
+    };
+    let exprs = distinct
+        .iter()
+        .chain(selection.iter())
+        .chain(group_by.iter())
+        .chain(having.iter());
 
     Ok(stream::iter(exprs)
         .then(|expr| scan_expr(storage, expr))
@@ -155,13 +214,18 @@ async fn scan_join<T: Store>(storage: &T, join: &Join) -> Result<HashMap<String,
     let schema_list = scan_table_factor(storage, relation).await?;
     let schema_list = match join_operator {
         JoinOperator::Inner(JoinConstraint::On(expr))
-        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
             .await?
             .into_iter()
             .chain(schema_list)
             .collect(),
-        JoinOperator::Inner(JoinConstraint::None)
-        | JoinOperator::LeftOuter(JoinConstraint::None) => schema_list,
+        JoinOperator::Inner(_)
+        | JoinOperator::LeftOuter(_)
+        | JoinOperator::RightOuter(_)
+        | JoinOperator::FullOuter(_)
+        | JoinOperator::CrossJoin => schema_list,
     };
 
     Ok(schema_list)
@@ -0,0 +1,232 @@
+use {
+    crate::{
+        ast::{AstLiteral, Expr, IndexCondition, IndexOperator, UnaryOperator},
+        data::{ColumnStats, Schema, TableStats},
+        result::Result,
+        store::Statistics,
+    },
+    bigdecimal::ToPrimitive,
+    std::{collections::HashMap, ops::Bound},
+};
+
+/// Fraction of rows assumed to match an equality on a column without statistics
+const DEFAULT_EQ_SELECTIVITY: f64 = 0.1;
+
+/// Fraction of rows assumed to match a range condition which cannot be interpolated
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Fetches the statistics collected by `ANALYZE` for the tables the statement reads.
+pub async fn fetch_statistics_map<T: Statistics>(
+    storage: &T,
+    schema_map: &HashMap<String, Schema>,
+) -> Result<HashMap<String, TableStats>> {
+    let mut statistics = HashMap::new();
+
+    for table_name in schema_map.keys() {
+        if let Some(table_stats) = storage.fetch_statistics(table_name).await? {
+            statistics.insert(table_name.to_owned(), table_stats);
+        }
+    }
+
+    Ok(statistics)
+}
+
+/// Estimates the fraction of rows an index scan on `target` with the given operator and
+/// value returns, `NULL` values stand for `IS NULL` and `IS NOT NULL` like in index scans.
+pub fn selectivity(
+    table_stats: &TableStats,
+    target: &Expr,
+    index_op: &IndexOperator,
+    value: &Expr,
+) -> f64 {
+    let column = match column_stats(table_stats, target) {
+        Some(column) => column,
+        None => {
+            return match index_op {
+                IndexOperator::Eq => DEFAULT_EQ_SELECTIVITY,
+                _ => DEFAULT_RANGE_SELECTIVITY,
+            };
+        }
+    };
+
+    let row_count = table_stats.row_count as f64;
+    let null_fraction = column.null_count as f64 / row_count;
+    let non_null_fraction = 1.0 - null_fraction;
+
+    match (index_op, value) {
+        (IndexOperator::Eq, Expr::Literal(AstLiteral::Null)) => null_fraction,
+        (_, Expr::Literal(AstLiteral::Null)) => non_null_fraction,
+        (IndexOperator::Eq, _) => non_null_fraction / column.distinct_count.max(1) as f64,
+        (_, value) => non_null_fraction * range_fraction(column, index_op, value),
+    }
+}
+
+/// Estimates the fraction of rows an index scan on `target` with the condition returns.
+pub fn condition_selectivity(
+    table_stats: &TableStats,
+    target: &Expr,
+    condition: &IndexCondition,
+) -> f64 {
+    let (lower, upper) = match condition {
+        IndexCondition::Range { lower, upper } => (lower, upper),
+        IndexCondition::InList(list) => {
+            return list
+                .iter()
+                .map(|value| selectivity(table_stats, target, &IndexOperator::Eq, value))
+                .sum::<f64>()
+                .min(1.0);
+        }
+    };
+
+    let selectivity = |index_op, value| selectivity(table_stats, target, &index_op, value);
+
+    match (lower, upper) {
+        (Bound::Included(lower), Bound::Included(upper)) if lower == upper => {
+            selectivity(IndexOperator::Eq, lower)
+        }
+        (Bound::Included(value), Bound::Unbounded) => selectivity(IndexOperator::GtEq, value),
+        (Bound::Excluded(value), Bound::Unbounded) => selectivity(IndexOperator::Gt, value),
+        (Bound::Unbounded, Bound::Included(value)) => selectivity(IndexOperator::LtEq, value),
+        (Bound::Unbounded, Bound::Excluded(value)) => selectivity(IndexOperator::Lt, value),
+        (Bound::Unbounded, Bound::Unbounded) => 1.0,
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => between_selectivity(table_stats, target, lower, upper),
+    }
+}
+
+fn between_selectivity(table_stats: &TableStats, target: &Expr, lower: &Expr, upper: &Expr) -> f64 {
+    let default = DEFAULT_RANGE_SELECTIVITY * DEFAULT_RANGE_SELECTIVITY;
+    let column = match column_stats(table_stats, target) {
+        Some(column) => column,
+        None => return default,
+    };
+
+    let non_null_fraction = 1.0 - column.null_count as f64 / table_stats.row_count as f64;
+
+    match (position(column, lower), position(column, upper)) {
+        (Some(lower), Some(upper)) => non_null_fraction * (upper - lower).max(0.0),
+        _ => non_null_fraction * default,
+    }
+}
+
+fn column_stats<'a>(table_stats: &'a TableStats, target: &Expr) -> Option<&'a ColumnStats> {
+    let column = match target {
+        Expr::Identifier(name) => table_stats.column(name),
+        Expr::CompoundIdentifier { ident, .. } => table_stats.column(ident),
+        _ => None,
+    };
+
+    column.filter(|_| table_stats.row_count > 0)
+}
+
+fn range_fraction(column: &ColumnStats, index_op: &IndexOperator, value: &Expr) -> f64 {
+    let below = match position(column, value) {
+        Some(below) => below,
+        None => return DEFAULT_RANGE_SELECTIVITY,
+    };
+
+    match index_op {
+        IndexOperator::Lt | IndexOperator::LtEq => below,
+        IndexOperator::Gt | IndexOperator::GtEq | IndexOperator::Eq => 1.0 - below,
+    }
+}
+
+/// Interpolates the position of a numeric value between the min and max of the column.
+fn position(column: &ColumnStats, value: &Expr) -> Option<f64> {
+    let min = f64::try_from(column.min.as_ref()?).ok()?;
+    let max = f64::try_from(column.max.as_ref()?).ok()?;
+    let value = literal_to_f64(value)?;
+
+    Some(match max > min {
+        true => ((value - min) / (max - min)).clamp(0.0, 1.0),
+        false if value > min => 1.0,
+        false if value < min => 0.0,
+        false => 0.5,
+    })
+}
+
+fn literal_to_f64(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Literal(AstLiteral::Number(value)) => value.to_f64(),
+        Expr::Nested(expr) => literal_to_f64(expr),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => literal_to_f64(expr).map(|value| -value),
+        Expr::UnaryOp {
+            op: UnaryOperator::Plus,
+            expr,
+        } => literal_to_f64(expr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{condition_selectivity, selectivity},
+        crate::{
+            ast::{AstLiteral, Expr, IndexCondition, IndexOperator},
+            data::{ColumnStats, TableStats, Value},
+            parse_sql::parse_expr,
+            translate::translate_expr,
+        },
+        std::ops::Bound::{Excluded, Included},
+    };
+
+    fn expr(sql: &str) -> Expr {
+        translate_expr(&parse_expr(sql).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn estimate() {
+        let table_stats = TableStats {
+            table_name: "Item".to_owned(),
+            row_count: 100,
+            columns: vec![ColumnStats {
+                name: "price".to_owned(),
+                null_count: 20,
+                distinct_count: 8,
+                min: Some(Value::I64(0)),
+                max: Some(Value::I64(40)),
+            }],
+        };
+        let price = expr("price");
+        let null = Expr::Literal(AstLiteral::Null);
+
+        let test = |target: &Expr, index_op, value: &Expr, expected: f64| {
+            let actual = selectivity(&table_stats, target, &index_op, value);
+
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        };
+
+        test(&price, IndexOperator::Eq, &expr("3"), 0.1);
+        test(&price, IndexOperator::Eq, &null, 0.2);
+        test(&price, IndexOperator::Lt, &null, 0.8);
+        test(&price, IndexOperator::Lt, &expr("10"), 0.2);
+        test(&price, IndexOperator::GtEq, &expr("10"), 0.6);
+        test(&price, IndexOperator::Gt, &expr("-5"), 0.8);
+        test(&price, IndexOperator::Gt, &expr("'a'"), 0.8 / 3.0);
+        test(&expr("Item.price"), IndexOperator::Eq, &expr("3"), 0.1);
+        test(&expr("name"), IndexOperator::Eq, &expr("1"), 0.1);
+
+        let test = |condition: IndexCondition, expected: f64| {
+            let actual = condition_selectivity(&table_stats, &price, &condition);
+
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        };
+
+        let range = |lower, upper| IndexCondition::Range { lower, upper };
+        test(IndexCondition::from((IndexOperator::Eq, expr("3"))), 0.1);
+        test(IndexCondition::from((IndexOperator::Lt, expr("10"))), 0.2);
+        test(range(Included(expr("10")), Excluded(expr("30"))), 0.4);
+        test(range(Excluded(expr("30")), Included(expr("10"))), 0.0);
+        test(
+            range(Included(expr("'a'")), Included(expr("'b'"))),
+            0.8 / 9.0,
+        );
+        test(IndexCondition::InList(vec![expr("1"), expr("2")]), 0.2);
+    }
+}
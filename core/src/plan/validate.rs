@@ -1,7 +1,10 @@
 use {
     super::PlanError,
     crate::{
-        ast::{Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins},
+        ast::{
+            Expr, Join, JoinConstraint, JoinOperator, Query, SelectItem, SetExpr, Statement,
+            TableFactor, TableWithJoins,
+        },
         data::Schema,
         result::Result,
     },
@@ -18,27 +21,39 @@ pub fn validate(schema_map: &SchemaMap, statement: &Statement) -> Result<()> {
         _ => None,
     };
 
-    if let Some(query) = query {
-        if let Query {
-            body: SetExpr::Select(select),
-            ..
-        } = query
-        {
+    match query {
+        Some(query) => validate_query(schema_map, query),
+        None => Ok(()),
+    }
+}
+
+fn validate_query(schema_map: &SchemaMap, query: &Query) -> Result<()> {
+    match &query.body {
+        SetExpr::Select(select) => {
             for select_item in &select.projection {
                 if let SelectItem::Expr {
                     expr: Expr::Identifier(ident),
                     ..
                 } = select_item
                 {
+                    if is_merged_column(schema_map, &select.from.joins, ident) {
+                        continue;
+                    }
+
                     if let Some(context) = contextualize_query(schema_map, query) {
                         context.validate_duplicated(ident)?;
                     }
                 }
             }
+
+            Ok(())
         }
+        SetExpr::SetOperation { left, right, .. } => {
+            validate_query(schema_map, left)?;
+            validate_query(schema_map, right)
+        }
+        SetExpr::Values(_) => Ok(()),
     }
-
-    Ok(())
 }
 
 enum Context<'a> {
@@ -98,6 +113,45 @@ impl<'a> Context<'a> {
 
         validate(self, column_name).map(|_| ())
     }
+
+    fn contains(&self, column_name: &str) -> bool {
+        match self {
+            Context::Data { labels, next } => {
+                labels
+                    .as_ref()
+                    .map(|labels| labels.contains(&column_name))
+                    .unwrap_or(false)
+                    || next
+                        .as_ref()
+                        .map(|next| next.contains(column_name))
+                        .unwrap_or(false)
+            }
+            Context::Bridge { left, right } => {
+                left.contains(column_name) || right.contains(column_name)
+            }
+        }
+    }
+}
+
+/// Columns joined by `USING` or `NATURAL` are merged into one, so they are not ambiguous
+fn is_merged_column(schema_map: &SchemaMap, joins: &[Join], column_name: &str) -> bool {
+    joins.iter().any(|join| {
+        let join_constraint = match &join.join_operator {
+            JoinOperator::Inner(join_constraint)
+            | JoinOperator::LeftOuter(join_constraint)
+            | JoinOperator::RightOuter(join_constraint)
+            | JoinOperator::FullOuter(join_constraint) => join_constraint,
+            JoinOperator::CrossJoin => return false,
+        };
+
+        match join_constraint {
+            JoinConstraint::Using(columns) => columns.iter().any(|column| column == column_name),
+            JoinConstraint::Natural => contextualize_table_factor(schema_map, &join.relation)
+                .map(|context| context.contains(column_name))
+                .unwrap_or(false),
+            JoinConstraint::On(_) | JoinConstraint::None => false,
+        }
+    })
 }
 
 fn get_labels(schema: &Schema) -> Option<Vec<&str>> {
@@ -122,6 +176,7 @@ fn contextualize_query<'a>(schema_map: &'a SchemaMap, query: &'a Query) -> Optio
 
             Context::concat(by_table, by_joins)
         }
+        SetExpr::SetOperation { left, .. } => contextualize_query(schema_map, left),
         SetExpr::Values(_) => None,
     }
 }
@@ -165,6 +220,9 @@ mod tests {
             ("SELECT * FROM (SELECT * FROM Users) AS Sub", true),
             ("SELECT * FROM SERIES(3)", true),
             ("SELECT id FROM Users A JOIN Users B on A.id = B.id", false),
+            ("SELECT id FROM Users A JOIN Users B USING (id)", true),
+            ("SELECT name FROM Users A JOIN Users B USING (id)", false),
+            ("SELECT id, name FROM Users A NATURAL JOIN Users B", true),
             (
                 "INSERT INTO Users SELECT id FROM Users A JOIN Users B on A.id = B.id",
                 false,
@@ -3,12 +3,13 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error as ThisError};
 pub use crate::{
     ast_builder::AstBuilderError,
     data::{
-        IntervalError, KeyError, LiteralError, RowError, SchemaParseError, StringExtError,
-        TableError, ValueError,
+        IntervalError, KeyError, LiteralError, RowError, SchemaParseError, SequenceError,
+        StringExtError, TableError, ValueError,
     },
     executor::{
-        AggregateError, AlterError, EvaluateError, ExecuteError, FetchError, InsertError,
-        SelectError, SortError, UpdateError, ValidateError,
+        AggregateError, AlterError, EvaluateError, ExecuteError, ExplainError, FetchError,
+        ForeignKeyError, InsertError, PrepareError, SelectError, SortError, UpdateError,
+        ValidateError, WindowError,
     },
     plan::PlanError,
     store::{AlterTableError, IndexError},
@@ -35,6 +36,8 @@ pub enum Error {
     Index(#[from] IndexError),
     #[error("execute: {0}")]
     Execute(#[from] ExecuteError),
+    #[error("explain: {0}")]
+    Explain(#[from] ExplainError),
     #[error("alter: {0}")]
     Alter(#[from] AlterError),
     #[error("fetch: {0}")]
@@ -47,6 +50,8 @@ pub enum Error {
     Aggregate(#[from] AggregateError),
     #[error("sort: {0}")]
     Sort(#[from] SortError),
+    #[error("window: {0}")]
+    Window(#[from] WindowError),
     #[error("insert: {0}")]
     Insert(#[from] InsertError),
     #[error("update: {0}")]
@@ -55,6 +60,8 @@ pub enum Error {
     Table(#[from] TableError),
     #[error("validate: {0}")]
     Validate(#[from] ValidateError),
+    #[error("foreign-key: {0}")]
+    ForeignKey(#[from] ForeignKeyError),
     #[error("row: {0}")]
     Row(#[from] RowError),
     #[error("key: {0}")]
@@ -71,6 +78,10 @@ pub enum Error {
     Plan(#[from] PlanError),
     #[error("schema-parse: {0}")]
     Schema(#[from] SchemaParseError),
+    #[error("sequence: {0}")]
+    Sequence(#[from] SequenceError),
+    #[error("prepare: {0}")]
+    Prepare(#[from] PrepareError),
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -65,4 +65,10 @@ pub trait AlterTable {
 
         Err(Error::StorageMsg(msg))
     }
+
+    async fn drop_foreign_key(&mut self, _table_name: &str, _name: &str) -> Result<()> {
+        let msg = "[Storage] AlterTable::drop_foreign_key is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
 }
@@ -1,13 +1,13 @@
 use {
     super::RowIter,
     crate::{
-        ast::{IndexOperator, OrderByExpr},
+        ast::OrderByExpr,
         data::Value,
         result::{Error, Result},
     },
     async_trait::async_trait,
     serde::Serialize,
-    std::fmt::Debug,
+    std::{fmt::Debug, ops::Bound},
     thiserror::Error as ThisError,
 };
 
@@ -40,12 +40,15 @@ pub enum IndexError {
 
 #[async_trait(?Send)]
 pub trait Index {
+    /// Scans the rows whose index values start with `prefix` and have the value of the
+    /// next indexed column in `range`.
     async fn scan_indexed_data(
         &self,
         _table_name: &str,
         _index_name: &str,
         _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        _prefix: &[Value],
+        _range: (Bound<Value>, Bound<Value>),
     ) -> Result<RowIter> {
         Err(Error::StorageMsg(
             "[Storage] Index::scan_indexed_data is not supported".to_owned(),
@@ -59,7 +62,7 @@ pub trait IndexMut {
         &mut self,
         _table_name: &str,
         _index_name: &str,
-        _column: &OrderByExpr,
+        _columns: &[OrderByExpr],
     ) -> Result<()> {
         let msg = "[Storage] Index::create_index is not supported".to_owned();
 
@@ -3,17 +3,37 @@ mod data_row;
 mod function;
 mod index;
 mod metadata;
+mod sequence;
+mod statistics;
 mod transaction;
+mod view;
 
-pub trait GStore: Store + Index + Metadata + CustomFunction {}
-impl<S: Store + Index + Metadata + CustomFunction> GStore for S {}
+pub trait GStore: Store + Index + Metadata + CustomFunction + View + Sequence + Statistics {}
+impl<S: Store + Index + Metadata + CustomFunction + View + Sequence + Statistics> GStore for S {}
 
 pub trait GStoreMut:
-    StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut
+    StoreMut
+    + IndexMut
+    + AlterTable
+    + Transaction
+    + CustomFunction
+    + CustomFunctionMut
+    + ViewMut
+    + SequenceMut
+    + StatisticsMut
 {
 }
-impl<S: StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut>
-    GStoreMut for S
+impl<
+        S: StoreMut
+            + IndexMut
+            + AlterTable
+            + Transaction
+            + CustomFunction
+            + CustomFunctionMut
+            + ViewMut
+            + SequenceMut
+            + StatisticsMut,
+    > GStoreMut for S
 {
 }
 
@@ -23,7 +43,10 @@ pub use {
     function::{CustomFunction, CustomFunctionMut},
     index::{Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
+    sequence::{Sequence, SequenceMut},
+    statistics::{Statistics, StatisticsMut},
     transaction::Transaction,
+    view::{View, ViewMut},
 };
 
 use {
@@ -32,6 +55,7 @@ use {
         result::Result,
     },
     async_trait::async_trait,
+    std::ops::{Bound, RangeBounds},
 };
 
 pub type RowIter = Box<dyn Iterator<Item = Result<(Key, DataRow)>>>;
@@ -46,6 +70,33 @@ pub trait Store {
     async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>>;
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter>;
+
+    /// Scans the rows whose keys fall in the range, ordered by key.
+    ///
+    /// The default implementation filters and sorts a full scan, storages which keep their rows
+    /// sorted by key should override it.
+    async fn scan_range_data(
+        &self,
+        table_name: &str,
+        range: (Bound<Key>, Bound<Key>),
+        asc: bool,
+    ) -> Result<RowIter> {
+        let mut rows = self
+            .scan_data(table_name)
+            .await?
+            .filter(|item| match item {
+                Ok((key, _)) => range.contains(key),
+                Err(_) => true,
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+        if !asc {
+            rows.reverse();
+        }
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
 }
 
 /// By implementing `StoreMut` trait,
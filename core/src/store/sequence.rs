@@ -0,0 +1,35 @@
+use {
+    crate::{
+        data::Sequence as StructSequence,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+#[async_trait(?Send)]
+pub trait Sequence {
+    async fn fetch_sequence(&self, _sequence_name: &str) -> Result<Option<StructSequence>> {
+        Ok(None)
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Advancing a sequence writes the whole sequence back through `insert_sequence`,
+/// so storages supporting transactions roll its counter back along with the data.
+#[async_trait(?Send)]
+pub trait SequenceMut {
+    async fn insert_sequence(&mut self, _sequence: StructSequence) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    async fn delete_sequence(&mut self, _sequence_name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+}
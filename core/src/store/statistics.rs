@@ -0,0 +1,30 @@
+use {
+    crate::{
+        data::TableStats,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+/// Storages without statistics leave the planner to its rule based choices, so the fetch
+/// method defaults to no statistics rather than an error.
+#[async_trait(?Send)]
+pub trait Statistics {
+    async fn fetch_statistics(&self, _table_name: &str) -> Result<Option<TableStats>> {
+        Ok(None)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait StatisticsMut {
+    async fn insert_statistics(&mut self, _statistics: TableStats) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Statistics is not supported".to_owned(),
+        ))
+    }
+
+    /// Called when a table is dropped, storages without statistics have nothing to delete.
+    async fn delete_statistics(&mut self, _table_name: &str) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{
+        data::View as StructView,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+/// Storages without view support have no views to expand, so the fetch methods
+/// default to empty results rather than errors.
+#[async_trait(?Send)]
+pub trait View {
+    async fn fetch_view(&self, _view_name: &str) -> Result<Option<StructView>> {
+        Ok(None)
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        Ok(Vec::new())
+    }
+}
+
+#[async_trait(?Send)]
+pub trait ViewMut {
+    async fn insert_view(&mut self, _view: StructView) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] View is not supported".to_owned(),
+        ))
+    }
+
+    async fn delete_view(&mut self, _view_name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] View is not supported".to_owned(),
+        ))
+    }
+}
@@ -3,13 +3,22 @@ use {
         data_type::translate_data_type, expr::translate_expr, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, OperateFunctionArg},
+        ast::{
+            AlterTableOperation, Check, ColumnDef, ColumnIdentity, ColumnUniqueOption, DataType,
+            ForeignKey, IdentityGeneration, OperateFunctionArg, ReferentialAction, SequenceOptions,
+            UniqueConstraint,
+        },
+        data::BigDecimalExt,
         result::Result,
     },
     sqlparser::ast::{
         AlterTableOperation as SqlAlterTableOperation, ColumnDef as SqlColumnDef,
         ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef,
-        OperateFunctionArg as SqlOperateFunctionArg,
+        DataType as SqlDataType, Expr as SqlExpr, GeneratedAs as SqlGeneratedAs, Ident as SqlIdent,
+        MinMaxValue as SqlMinMaxValue, ObjectName as SqlObjectName,
+        OperateFunctionArg as SqlOperateFunctionArg, ReferentialAction as SqlReferentialAction,
+        SequenceOptions as SqlSequenceOptions, TableConstraint as SqlTableConstraint,
+        Value as SqlValue,
     },
 };
 
@@ -57,16 +66,272 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
         ..
     } = sql_column_def;
 
-    let (nullable, default, unique) = options.iter().try_fold(
-        (true, None, None),
-        |(nullable, default, unique), SqlColumnOptionDef { option, .. }| -> Result<_> {
+    translate_column(name, data_type, options)
+}
+
+type TableElements = (
+    Vec<ColumnDef>,
+    Vec<ForeignKey>,
+    Vec<Check>,
+    Vec<UniqueConstraint>,
+);
+
+/// Translates the columns and constraints of `CREATE TABLE`, column level `REFERENCES`
+/// and `CHECK` options are collected along with table level constraints.
+///
+/// Table level `PRIMARY KEY` and `UNIQUE` constraints on a single column are folded into
+/// the [`ColumnDef`], only the ones on several columns are returned as [`UniqueConstraint`].
+pub fn translate_table_elements(
+    table_name: &str,
+    sql_column_defs: &[SqlColumnDef],
+    sql_constraints: &[SqlTableConstraint],
+) -> Result<TableElements> {
+    let mut foreign_keys = Vec::new();
+    let mut checks = Vec::new();
+    let mut unique_constraints = Vec::new();
+    let mut column_defs = Vec::with_capacity(sql_column_defs.len());
+
+    for SqlColumnDef {
+        name,
+        data_type,
+        options,
+        ..
+    } in sql_column_defs
+    {
+        let (constraints, options): (Vec<_>, Vec<_>) = options.iter().partition(|option_def| {
+            matches!(
+                option_def.option,
+                SqlColumnOption::ForeignKey { .. } | SqlColumnOption::Check(_)
+            )
+        });
+
+        for SqlColumnOptionDef {
+            name: constraint_name,
+            option,
+        } in constraints
+        {
             match option {
-                SqlColumnOption::Null => Ok((nullable, default, unique)),
-                SqlColumnOption::NotNull => Ok((false, default, unique)),
+                SqlColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                } => {
+                    let foreign_key = translate_foreign_key(
+                        table_name,
+                        constraint_name.as_ref(),
+                        &[name.to_owned()],
+                        foreign_table,
+                        referred_columns,
+                        (on_delete.as_ref(), on_update.as_ref()),
+                    )?;
+
+                    foreign_keys.push(foreign_key);
+                }
+                SqlColumnOption::Check(expr) => {
+                    let check_name = match constraint_name {
+                        Some(constraint_name) => constraint_name.value.to_owned(),
+                        None => generate_check_name(
+                            &checks,
+                            format!("{table_name}_{}_check", name.value),
+                        ),
+                    };
+
+                    checks.push(Check {
+                        name: check_name,
+                        expr: translate_expr(expr)?,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        column_defs.push(translate_column(name, data_type, options)?);
+    }
+
+    for sql_constraint in sql_constraints {
+        match sql_constraint {
+            SqlTableConstraint::ForeignKey {
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+                on_delete,
+                on_update,
+            } => {
+                let foreign_key = translate_foreign_key(
+                    table_name,
+                    name.as_ref(),
+                    columns,
+                    foreign_table,
+                    referred_columns,
+                    (on_delete.as_ref(), on_update.as_ref()),
+                )?;
+
+                foreign_keys.push(foreign_key);
+            }
+            SqlTableConstraint::Check { name, expr } => {
+                let name = match name {
+                    Some(name) => name.value.to_owned(),
+                    None => generate_check_name(&checks, format!("{table_name}_check")),
+                };
+
+                checks.push(Check {
+                    name,
+                    expr: translate_expr(expr)?,
+                });
+            }
+            SqlTableConstraint::Unique {
+                columns,
+                is_primary,
+                ..
+            } => {
+                let is_primary = *is_primary;
+                let columns = columns
+                    .iter()
+                    .map(|column| column.value.to_owned())
+                    .collect::<Vec<_>>();
+
+                for column in &columns {
+                    let column_def = column_defs
+                        .iter_mut()
+                        .find(|column_def| &column_def.name == column)
+                        .ok_or_else(|| {
+                            TranslateError::UniqueConstraintColumnNotFound(column.to_owned())
+                        })?;
+
+                    if is_primary {
+                        column_def.nullable = false;
+                    }
+
+                    if let [_] = columns.as_slice() {
+                        column_def.unique = Some(ColumnUniqueOption { is_primary });
+                    }
+                }
+
+                if columns.len() > 1 {
+                    unique_constraints.push(UniqueConstraint {
+                        columns,
+                        is_primary,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let num_primary_keys = column_defs
+        .iter()
+        .filter(|column_def| {
+            matches!(
+                column_def.unique,
+                Some(ColumnUniqueOption { is_primary: true })
+            )
+        })
+        .count()
+        + unique_constraints
+            .iter()
+            .filter(|constraint| constraint.is_primary)
+            .count();
+
+    if num_primary_keys > 1 {
+        return Err(TranslateError::MultiplePrimaryKeysNotAllowed.into());
+    }
+
+    Ok((column_defs, foreign_keys, checks, unique_constraints))
+}
+
+/// Generated check names are numbered when taken, as in `Item_check1`.
+fn generate_check_name(checks: &[Check], name: String) -> String {
+    let taken = |name: &str| checks.iter().any(|check| check.name == name);
+
+    match taken(&name) {
+        false => name,
+        true => (1..)
+            .map(|i| format!("{name}{i}"))
+            .find(|name| !taken(name))
+            .unwrap_or(name),
+    }
+}
+
+fn translate_foreign_key(
+    table_name: &str,
+    name: Option<&SqlIdent>,
+    columns: &[SqlIdent],
+    foreign_table: &SqlObjectName,
+    referred_columns: &[SqlIdent],
+    (on_delete, on_update): (Option<&SqlReferentialAction>, Option<&SqlReferentialAction>),
+) -> Result<ForeignKey> {
+    let columns = columns
+        .iter()
+        .map(|column| column.value.to_owned())
+        .collect::<Vec<_>>();
+    let name = match name {
+        Some(name) => name.value.to_owned(),
+        None => format!("{table_name}_{}_fkey", columns.join("_")),
+    };
+
+    Ok(ForeignKey {
+        name,
+        columns,
+        referenced_table_name: translate_object_name(foreign_table)?,
+        referenced_columns: referred_columns
+            .iter()
+            .map(|column| column.value.to_owned())
+            .collect(),
+        on_delete: on_delete
+            .map(translate_referential_action)
+            .transpose()?
+            .unwrap_or_default(),
+        on_update: on_update
+            .map(translate_referential_action)
+            .transpose()?
+            .unwrap_or_default(),
+    })
+}
+
+fn translate_referential_action(
+    sql_referential_action: &SqlReferentialAction,
+) -> Result<ReferentialAction> {
+    match sql_referential_action {
+        SqlReferentialAction::NoAction => Ok(ReferentialAction::NoAction),
+        SqlReferentialAction::Restrict => Ok(ReferentialAction::Restrict),
+        SqlReferentialAction::Cascade => Ok(ReferentialAction::Cascade),
+        SqlReferentialAction::SetNull => Ok(ReferentialAction::SetNull),
+        SqlReferentialAction::SetDefault => Err(TranslateError::UnsupportedReferentialAction(
+            sql_referential_action.to_string(),
+        )
+        .into()),
+    }
+}
+
+fn translate_column<'a>(
+    name: &SqlIdent,
+    data_type: &SqlDataType,
+    options: impl IntoIterator<Item = &'a SqlColumnOptionDef>,
+) -> Result<ColumnDef> {
+    let (data_type, identity) = match translate_serial_type(data_type) {
+        Some(data_type) => {
+            let identity = ColumnIdentity {
+                generation: IdentityGeneration::ByDefault,
+                options: SequenceOptions::default(),
+            };
+
+            (data_type, Some(identity))
+        }
+        None => (translate_data_type(data_type)?, None),
+    };
+
+    let (nullable, default, unique, identity) = options.into_iter().try_fold(
+        (identity.is_none(), None, None, identity),
+        |(nullable, default, unique, identity), SqlColumnOptionDef { option, .. }| -> Result<_> {
+            match option {
+                SqlColumnOption::Null => Ok((nullable, default, unique, identity)),
+                SqlColumnOption::NotNull => Ok((false, default, unique, identity)),
                 SqlColumnOption::Default(default) => {
                     let default = translate_expr(default).map(Some)?;
 
-                    Ok((nullable, default, unique))
+                    Ok((nullable, default, unique, identity))
                 }
                 SqlColumnOption::Unique { is_primary } => {
                     let nullable = if *is_primary { false } else { nullable };
@@ -74,7 +339,29 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
                         is_primary: *is_primary,
                     });
 
-                    Ok((nullable, default, unique))
+                    Ok((nullable, default, unique, identity))
+                }
+                SqlColumnOption::Generated {
+                    generated_as:
+                        generated_as @ (SqlGeneratedAs::Always | SqlGeneratedAs::ByDefault),
+                    sequence_options,
+                    generation_expr: None,
+                } if identity.is_none() => {
+                    let generation = match generated_as {
+                        SqlGeneratedAs::Always => IdentityGeneration::Always,
+                        _ => IdentityGeneration::ByDefault,
+                    };
+                    let options = sequence_options
+                        .as_deref()
+                        .map(translate_sequence_options)
+                        .transpose()?
+                        .unwrap_or_default();
+                    let identity = Some(ColumnIdentity {
+                        generation,
+                        options,
+                    });
+
+                    Ok((false, default, unique, identity))
                 }
                 _ => Err(TranslateError::UnsupportedColumnOption(option.to_string()).into()),
             }
@@ -83,13 +370,84 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
 
     Ok(ColumnDef {
         name: name.value.to_owned(),
-        data_type: translate_data_type(data_type)?,
+        data_type,
         nullable,
         default,
         unique,
+        identity,
     })
 }
 
+/// `SMALLSERIAL`, `SERIAL` and `BIGSERIAL` are shorthands for integer columns
+/// generated by default as identity.
+fn translate_serial_type(sql_data_type: &SqlDataType) -> Option<DataType> {
+    let name = match sql_data_type {
+        SqlDataType::Custom(name, idents) if idents.is_empty() => name.0.get(0)?,
+        _ => return None,
+    };
+
+    match name.value.to_uppercase().as_str() {
+        "SMALLSERIAL" => Some(DataType::Int16),
+        "SERIAL" => Some(DataType::Int32),
+        "BIGSERIAL" => Some(DataType::Int),
+        _ => None,
+    }
+}
+
+pub fn translate_sequence_options(
+    sql_sequence_options: &[SqlSequenceOptions],
+) -> Result<SequenceOptions> {
+    let translate_value = |expr: &SqlExpr| -> Result<i64> {
+        match expr {
+            SqlExpr::Value(SqlValue::Number(value, _)) => value.to_i64(),
+            _ => None,
+        }
+        .ok_or_else(|| TranslateError::InvalidSequenceOptionValue(expr.to_string()).into())
+    };
+
+    sql_sequence_options.iter().try_fold(
+        SequenceOptions::default(),
+        |options, sql_sequence_option| match sql_sequence_option {
+            SqlSequenceOptions::IncrementBy(expr, _) => Ok(SequenceOptions {
+                increment: Some(translate_value(expr)?),
+                ..options
+            }),
+            SqlSequenceOptions::MinValue(SqlMinMaxValue::Some(expr)) => Ok(SequenceOptions {
+                min_value: Some(translate_value(expr)?),
+                ..options
+            }),
+            SqlSequenceOptions::MaxValue(SqlMinMaxValue::Some(expr)) => Ok(SequenceOptions {
+                max_value: Some(translate_value(expr)?),
+                ..options
+            }),
+            SqlSequenceOptions::MinValue(SqlMinMaxValue::Empty | SqlMinMaxValue::None) => {
+                Ok(SequenceOptions {
+                    min_value: None,
+                    ..options
+                })
+            }
+            SqlSequenceOptions::MaxValue(SqlMinMaxValue::Empty | SqlMinMaxValue::None) => {
+                Ok(SequenceOptions {
+                    max_value: None,
+                    ..options
+                })
+            }
+            SqlSequenceOptions::StartWith(expr, _) => Ok(SequenceOptions {
+                start: Some(translate_value(expr)?),
+                ..options
+            }),
+            SqlSequenceOptions::Cycle(no_cycle) => Ok(SequenceOptions {
+                cycle: !no_cycle,
+                ..options
+            }),
+            SqlSequenceOptions::Cache(_) => Err(TranslateError::UnsupportedSequenceOption(
+                sql_sequence_option.to_string().trim().to_owned(),
+            )
+            .into()),
+        },
+    )
+}
+
 pub fn translate_operate_function_arg(arg: &SqlOperateFunctionArg) -> Result<OperateFunctionArg> {
     let name = arg
         .name
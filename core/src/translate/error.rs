@@ -2,14 +2,8 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum TranslateError {
-    #[error("unimplemented - select on two or more than tables are not supported")]
-    TooManyTables,
-
-    #[error("unimplemented - select distinct is not supported")]
-    SelectDistinctNotSupported,
-
-    #[error("unimplemented - composite index is not supported")]
-    CompositeIndexNotSupported,
+    #[error("unimplemented - RIGHT or FULL JOIN after a comma separated table is not supported")]
+    OuterJoinAfterCommaNotSupported,
 
     #[error("unimplemented - join on update not supported")]
     JoinOnUpdateNotSupported,
@@ -60,8 +54,26 @@ pub enum TranslateError {
     #[error("qualified wildcard is not supported - COUNT({0})")]
     QualifiedWildcardInCountNotSupported(String),
 
-    #[error("order by - NULLS (FIRST | LAST) is not supported")]
-    OrderByNullsFirstOrLastNotSupported,
+    #[error("wildcard is not supported in COUNT(DISTINCT ...)")]
+    WildcardInCountDistinctNotSupported,
+
+    #[error("DISTINCT is only supported in aggregate functions, but found in {0}")]
+    DistinctInNonAggregateFunction(String),
+
+    #[error("window function {0} requires an OVER clause")]
+    WindowFunctionWithoutOver(String),
+
+    #[error("unsupported window function: {0}")]
+    UnsupportedWindowFunction(String),
+
+    #[error("named window is not supported: {0}")]
+    NamedWindowNotSupported(String),
+
+    #[error("unsupported window frame units: {0}")]
+    UnsupportedWindowFrameUnits(String),
+
+    #[error("unimplemented - NULLS (FIRST | LAST) on index is not supported")]
+    IndexNullsFirstOrLastNotSupported,
 
     #[error("unsupported SHOW VARIABLE keyword: {0}")]
     UnsupportedShowVariableKeyword(String),
@@ -96,11 +108,14 @@ pub enum TranslateError {
     #[error("unsupported query set expr: {0}")]
     UnsupportedQuerySetExpr(String),
 
+    #[error("unsupported set quantifier: {0}")]
+    UnsupportedSetQuantifier(String),
+
     #[error("unsupported query table factor: {0}")]
     UnsupportedQueryTableFactor(String),
 
-    #[error("unsupported join constraint: {0}")]
-    UnsupportedJoinConstraint(String),
+    #[error("unsupported on insert clause: {0}")]
+    UnsupportedOnInsert(String),
 
     #[error("unsupported join operator: {0}")]
     UnsupportedJoinOperator(String),
@@ -108,6 +123,15 @@ pub enum TranslateError {
     #[error("unsupported column option: {0}")]
     UnsupportedColumnOption(String),
 
+    #[error("unsupported referential action: {0}")]
+    UnsupportedReferentialAction(String),
+
+    #[error("unsupported sequence option: {0}")]
+    UnsupportedSequenceOption(String),
+
+    #[error("sequence option value must be an integer: {0}")]
+    InvalidSequenceOptionValue(String),
+
     #[error("unsupported alter table operation: {0}")]
     UnsupportedAlterTableOperation(String),
 
@@ -134,4 +158,13 @@ pub enum TranslateError {
 
     #[error("cannot drop primary index")]
     CannotDropPrimary,
+
+    #[error("column in unique constraint not found: {0}")]
+    UniqueConstraintColumnNotFound(String),
+
+    #[error("multiple primary keys are not allowed")]
+    MultiplePrimaryKeysNotAllowed,
+
+    #[error("invalid placeholder: {0}")]
+    InvalidPlaceholder(String),
 }
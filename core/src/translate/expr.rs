@@ -16,7 +16,7 @@ use {
     },
     sqlparser::ast::{
         DateTimeField as SqlDateTimeField, Expr as SqlExpr, Interval as SqlInterval,
-        OrderByExpr as SqlOrderByExpr,
+        OrderByExpr as SqlOrderByExpr, Value as SqlValue,
     },
 };
 
@@ -101,6 +101,12 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
         }),
         SqlExpr::Extract { field, expr } => translate_extract(field, expr),
         SqlExpr::Nested(expr) => translate_expr(expr).map(Box::new).map(Expr::Nested),
+        SqlExpr::Value(SqlValue::Placeholder(placeholder)) => placeholder
+            .strip_prefix('$')
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .map(Expr::Placeholder)
+            .ok_or_else(|| TranslateError::InvalidPlaceholder(placeholder.to_owned()).into()),
         SqlExpr::Value(value) => translate_ast_literal(value).map(Expr::Literal),
         SqlExpr::TypedString { data_type, value } => Ok(Expr::TypedString {
             data_type: translate_data_type(data_type)?,
@@ -190,12 +196,9 @@ pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<Ord
         nulls_first,
     } = sql_order_by_expr;
 
-    if matches!(nulls_first, Some(_)) {
-        return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
-    }
-
     Ok(OrderByExpr {
         expr: translate_expr(expr)?,
         asc: *asc,
+        nulls_first: *nulls_first,
     })
 }
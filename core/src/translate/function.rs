@@ -1,17 +1,22 @@
 use {
     super::{
         ast_literal::{translate_datetime_field, translate_trim_where_field},
-        expr::translate_expr,
+        expr::{translate_expr, translate_order_by_expr},
         translate_data_type, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{
+            Aggregate, AggregateFunction, CountArgExpr, Expr, Function, Window, WindowFrame,
+            WindowFrameBound, WindowFrameUnits, WindowFunction, WindowSpec,
+        },
         result::Result,
     },
     sqlparser::ast::{
         DataType, DateTimeField as SqlDateTimeField, Expr as SqlExpr, Function as SqlFunction,
         FunctionArg as SqlFunctionArg, FunctionArgExpr as SqlFunctionArgExpr,
-        TrimWhereField as SqlTrimWhereField,
+        TrimWhereField as SqlTrimWhereField, WindowFrame as SqlWindowFrame,
+        WindowFrameBound as SqlWindowFrameBound, WindowFrameUnits as SqlWindowFrameUnits,
+        WindowSpec as SqlWindowSpec, WindowType as SqlWindowType,
     },
 };
 
@@ -131,15 +136,17 @@ fn translate_function_one_arg<T: FnOnce(Expr) -> Function>(
         .map(Expr::Function)
 }
 
-fn translate_aggregate_one_arg<T: FnOnce(Expr) -> Aggregate>(
+fn translate_aggregate_one_arg<T: FnOnce(Expr) -> AggregateFunction>(
     func: T,
     args: Vec<&SqlExpr>,
     name: String,
+    distinct: bool,
 ) -> Result<Expr> {
     check_len(name, args.len(), 1)?;
 
     translate_expr(args[0])
         .map(func)
+        .map(|func| Aggregate { func, distinct })
         .map(Box::new)
         .map(Expr::Aggregate)
 }
@@ -178,7 +185,18 @@ pub fn translate_function_arg_exprs(
 }
 
 pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
-    let SqlFunction { name, args, .. } = sql_function;
+    let SqlFunction {
+        name,
+        args,
+        distinct,
+        over,
+        ..
+    } = sql_function;
+
+    if let Some(over) = over {
+        return translate_window(sql_function, over);
+    }
+    let distinct = *distinct;
     let name = translate_object_name(name)?.to_uppercase();
 
     let function_arg_exprs = args
@@ -202,21 +220,33 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
 
                 return Err(TranslateError::QualifiedWildcardInCountNotSupported(idents).into());
             }
+            SqlFunctionArgExpr::Wildcard if distinct => {
+                return Err(TranslateError::WildcardInCountDistinctNotSupported.into());
+            }
             SqlFunctionArgExpr::Wildcard => CountArgExpr::Wildcard,
         };
 
-        return Ok(Expr::Aggregate(Box::new(Aggregate::Count(count_arg))));
+        return Ok(Expr::Aggregate(Box::new(Aggregate {
+            func: AggregateFunction::Count(count_arg),
+            distinct,
+        })));
     }
 
     let args = translate_function_arg_exprs(function_arg_exprs)?;
 
     match name.as_str() {
-        "SUM" => translate_aggregate_one_arg(Aggregate::Sum, args, name),
-        "MIN" => translate_aggregate_one_arg(Aggregate::Min, args, name),
-        "MAX" => translate_aggregate_one_arg(Aggregate::Max, args, name),
-        "AVG" => translate_aggregate_one_arg(Aggregate::Avg, args, name),
-        "VARIANCE" => translate_aggregate_one_arg(Aggregate::Variance, args, name),
-        "STDEV" => translate_aggregate_one_arg(Aggregate::Stdev, args, name),
+        "SUM" => translate_aggregate_one_arg(AggregateFunction::Sum, args, name, distinct),
+        "MIN" => translate_aggregate_one_arg(AggregateFunction::Min, args, name, distinct),
+        "MAX" => translate_aggregate_one_arg(AggregateFunction::Max, args, name, distinct),
+        "AVG" => translate_aggregate_one_arg(AggregateFunction::Avg, args, name, distinct),
+        "VARIANCE" => {
+            translate_aggregate_one_arg(AggregateFunction::Variance, args, name, distinct)
+        }
+        "STDEV" => translate_aggregate_one_arg(AggregateFunction::Stdev, args, name, distinct),
+        _ if distinct => Err(TranslateError::DistinctInNonAggregateFunction(name).into()),
+        "ROW_NUMBER" | "RANK" | "DENSE_RANK" | "LAG" | "LEAD" | "FIRST_VALUE" | "LAST_VALUE" => {
+            Err(TranslateError::WindowFunctionWithoutOver(name).into())
+        }
         "CONCAT" => {
             let exprs = args
                 .into_iter()
@@ -507,6 +537,18 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let expr = translate_expr(args[0])?;
             Ok(Expr::Function(Box::new(Function::Md5(expr))))
         }
+        "NEXTVAL" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0])?;
+            Ok(Expr::Function(Box::new(Function::NextVal(expr))))
+        }
+        "CURRVAL" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0])?;
+            Ok(Expr::Function(Box::new(Function::CurrVal(expr))))
+        }
         "LENGTH" => {
             check_len(name, args.len(), 1)?;
 
@@ -586,3 +628,169 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         }
     }
 }
+
+fn translate_window(sql_function: &SqlFunction, over: &SqlWindowType) -> Result<Expr> {
+    let SqlFunction {
+        name,
+        args,
+        distinct,
+        ..
+    } = sql_function;
+    let name = translate_object_name(name)?.to_uppercase();
+
+    let over = match over {
+        SqlWindowType::WindowSpec(spec) => translate_window_spec(spec)?,
+        SqlWindowType::NamedWindow(ident) => {
+            return Err(TranslateError::NamedWindowNotSupported(ident.value.to_owned()).into());
+        }
+    };
+
+    let window_function = matches!(
+        name.as_str(),
+        "ROW_NUMBER" | "RANK" | "DENSE_RANK" | "LAG" | "LEAD" | "FIRST_VALUE" | "LAST_VALUE"
+    );
+    if !window_function {
+        let sql_function = SqlFunction {
+            over: None,
+            ..sql_function.clone()
+        };
+
+        return match translate_function(&sql_function)? {
+            Expr::Aggregate(aggregate) => Ok(Expr::Window(Box::new(Window {
+                func: WindowFunction::Aggregate(*aggregate),
+                over,
+            }))),
+            _ => Err(TranslateError::UnsupportedWindowFunction(name).into()),
+        };
+    }
+
+    if *distinct {
+        return Err(TranslateError::DistinctInNonAggregateFunction(name).into());
+    }
+
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            SqlFunctionArg::Named { .. } => {
+                Err(TranslateError::NamedFunctionArgNotSupported.into())
+            }
+            SqlFunctionArg::Unnamed(arg_expr) => Ok(arg_expr),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let args = translate_function_arg_exprs(args)?;
+    let translate_nth = |n: usize| args.get(n).map(|expr| translate_expr(expr)).transpose();
+
+    let func = match name.as_str() {
+        "ROW_NUMBER" => {
+            check_len(name, args.len(), 0)?;
+
+            WindowFunction::RowNumber
+        }
+        "RANK" => {
+            check_len(name, args.len(), 0)?;
+
+            WindowFunction::Rank
+        }
+        "DENSE_RANK" => {
+            check_len(name, args.len(), 0)?;
+
+            WindowFunction::DenseRank
+        }
+        "LAG" | "LEAD" => {
+            check_len_range(name.to_owned(), args.len(), 1, 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let offset = translate_nth(1)?;
+            let default = translate_nth(2)?;
+
+            match name.as_str() {
+                "LAG" => WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                },
+                _ => WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                },
+            }
+        }
+        "FIRST_VALUE" => {
+            check_len(name, args.len(), 1)?;
+
+            WindowFunction::FirstValue(translate_expr(args[0])?)
+        }
+        _ => {
+            check_len(name, args.len(), 1)?;
+
+            WindowFunction::LastValue(translate_expr(args[0])?)
+        }
+    };
+
+    Ok(Expr::Window(Box::new(Window { func, over })))
+}
+
+fn translate_window_spec(sql_window_spec: &SqlWindowSpec) -> Result<WindowSpec> {
+    let SqlWindowSpec {
+        partition_by,
+        order_by,
+        window_frame,
+    } = sql_window_spec;
+
+    let partition_by = partition_by
+        .iter()
+        .map(translate_expr)
+        .collect::<Result<_>>()?;
+    let order_by = order_by
+        .iter()
+        .map(translate_order_by_expr)
+        .collect::<Result<_>>()?;
+    let frame = window_frame
+        .as_ref()
+        .map(translate_window_frame)
+        .transpose()?;
+
+    Ok(WindowSpec {
+        partition_by,
+        order_by,
+        frame,
+    })
+}
+
+fn translate_window_frame(sql_window_frame: &SqlWindowFrame) -> Result<WindowFrame> {
+    let SqlWindowFrame {
+        units,
+        start_bound,
+        end_bound,
+    } = sql_window_frame;
+
+    let units = match units {
+        SqlWindowFrameUnits::Rows => WindowFrameUnits::Rows,
+        SqlWindowFrameUnits::Range => WindowFrameUnits::Range,
+        SqlWindowFrameUnits::Groups => {
+            return Err(TranslateError::UnsupportedWindowFrameUnits(units.to_string()).into());
+        }
+    };
+    let start = translate_window_frame_bound(start_bound)?;
+    let end = end_bound
+        .as_ref()
+        .map(translate_window_frame_bound)
+        .transpose()?
+        .unwrap_or(WindowFrameBound::CurrentRow);
+
+    Ok(WindowFrame { units, start, end })
+}
+
+fn translate_window_frame_bound(
+    sql_window_frame_bound: &SqlWindowFrameBound,
+) -> Result<WindowFrameBound> {
+    let translate =
+        |expr: &Option<Box<SqlExpr>>| expr.as_ref().map(|expr| translate_expr(expr)).transpose();
+
+    match sql_window_frame_bound {
+        SqlWindowFrameBound::CurrentRow => Ok(WindowFrameBound::CurrentRow),
+        SqlWindowFrameBound::Preceding(expr) => translate(expr).map(WindowFrameBound::Preceding),
+        SqlWindowFrameBound::Following(expr) => translate(expr).map(WindowFrameBound::Following),
+    }
+}
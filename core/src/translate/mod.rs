@@ -17,44 +17,83 @@ pub use self::{
 
 use {
     crate::{
-        ast::{Assignment, Statement, Variable},
+        ast::{
+            Assignment, JoinExecutor, OnConflict, OnConflictAction, SelectItem, Statement, Variable,
+        },
+        parse_sql::ParsedStatement,
         result::Result,
     },
-    ddl::translate_alter_table_operation,
+    ddl::{translate_alter_table_operation, translate_sequence_options, translate_table_elements},
+    query::translate_from,
     sqlparser::ast::{
-        Assignment as SqlAssignment, Ident as SqlIdent, ObjectName as SqlObjectName,
-        ObjectType as SqlObjectType, Statement as SqlStatement, TableFactor, TableWithJoins,
+        Assignment as SqlAssignment, ConflictTarget as SqlConflictTarget, DoUpdate as SqlDoUpdate,
+        Ident as SqlIdent, ObjectName as SqlObjectName, ObjectType as SqlObjectType,
+        OnConflict as SqlOnConflict, OnConflictAction as SqlOnConflictAction,
+        OnInsert as SqlOnInsert, SelectItem as SqlSelectItem, Statement as SqlStatement,
+        TableFactor, TableWithJoins,
     },
 };
 
-pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
+pub fn translate(parsed_statement: &ParsedStatement) -> Result<Statement> {
+    match parsed_statement {
+        ParsedStatement::Sql(sql_statement) => translate_statement(sql_statement),
+        ParsedStatement::RefreshMaterializedView { name } => {
+            Ok(Statement::RefreshMaterializedView {
+                name: translate_object_name(name)?,
+            })
+        }
+    }
+}
+
+fn translate_statement(sql_statement: &SqlStatement) -> Result<Statement> {
     match sql_statement {
         SqlStatement::Query(query) => translate_query(query).map(Statement::Query),
         SqlStatement::Insert {
             table_name,
             columns,
             source,
+            on,
+            returning,
             ..
         } => Ok(Statement::Insert {
             table_name: translate_object_name(table_name)?,
             columns: translate_idents(columns),
             source: translate_query(source)?,
+            on_conflict: on.as_ref().map(translate_on_insert).transpose()?,
+            returning: translate_returning(returning)?,
         }),
         SqlStatement::Update {
             table,
             assignments,
+            from,
             selection,
-            ..
-        } => Ok(Statement::Update {
-            table_name: translate_table_with_join(table)?,
-            assignments: assignments
+            returning,
+        } => {
+            let table_name = translate_table_with_join(table)?;
+            let assignments = assignments
                 .iter()
-                .map(translate_assignment)
-                .collect::<Result<_>>()?,
-            selection: selection.as_ref().map(translate_expr).transpose()?,
-        }),
+                .map(|assignment| translate_update_assignment(assignment, &table_name))
+                .collect::<Result<_>>()?;
+
+            Ok(Statement::Update {
+                table_name,
+                assignments,
+                from: from
+                    .as_ref()
+                    .map(|from| translate_from(std::slice::from_ref(from)))
+                    .transpose()?
+                    .flatten(),
+                join_executor: JoinExecutor::NestedLoop,
+                selection: selection.as_ref().map(translate_expr).transpose()?,
+                returning: translate_returning(returning)?,
+            })
+        }
         SqlStatement::Delete {
-            from, selection, ..
+            from,
+            using,
+            selection,
+            returning,
+            ..
         } => {
             let table_name = from
                 .iter()
@@ -64,33 +103,39 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
 
             Ok(Statement::Delete {
                 table_name,
+                using: using.as_deref().map(translate_from).transpose()?.flatten(),
+                join_executor: JoinExecutor::NestedLoop,
                 selection: selection.as_ref().map(translate_expr).transpose()?,
+                returning: translate_returning(returning)?,
             })
         }
         SqlStatement::CreateTable {
             if_not_exists,
             name,
             columns,
+            constraints,
             query,
             engine,
             ..
         } => {
-            let columns = columns
-                .iter()
-                .map(translate_column_def)
-                .collect::<Result<Vec<_>>>()?;
+            let name = translate_object_name(name)?;
+            let (columns, foreign_keys, checks, unique_constraints) =
+                translate_table_elements(&name, columns, constraints)?;
 
             let columns = (!columns.is_empty()).then_some(columns);
 
             Ok(Statement::CreateTable {
                 if_not_exists: *if_not_exists,
-                name: translate_object_name(name)?,
+                name,
                 columns,
                 source: match query {
                     Some(v) => Some(translate_query(v).map(Box::new)?),
                     None => None,
                 },
                 engine: engine.clone(),
+                foreign_keys,
+                checks,
+                unique_constraints,
             })
         }
         SqlStatement::AlterTable {
@@ -103,6 +148,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             object_type: SqlObjectType::Table,
             if_exists,
             names,
+            cascade,
             ..
         } => Ok(Statement::DropTable {
             if_exists: *if_exists,
@@ -110,6 +156,74 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .iter()
                 .map(translate_object_name)
                 .collect::<Result<Vec<_>>>()?,
+            cascade: *cascade,
+        }),
+        SqlStatement::CreateView {
+            or_replace,
+            materialized: false,
+            name,
+            columns,
+            query,
+            ..
+        } => Ok(Statement::CreateView {
+            or_replace: *or_replace,
+            name: translate_object_name(name)?,
+            columns: columns
+                .iter()
+                .map(|column| column.value.to_owned())
+                .collect(),
+            query: translate_query(query).map(Box::new)?,
+        }),
+        SqlStatement::CreateView {
+            or_replace: false,
+            materialized: true,
+            name,
+            columns,
+            query,
+            ..
+        } => Ok(Statement::CreateMaterializedView {
+            name: translate_object_name(name)?,
+            columns: columns
+                .iter()
+                .map(|column| column.value.to_owned())
+                .collect(),
+            query: translate_query(query).map(Box::new)?,
+        }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::View,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropView {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
+        SqlStatement::CreateSequence {
+            temporary: false,
+            if_not_exists,
+            name,
+            data_type: None,
+            sequence_options,
+            owned_by: None,
+        } => Ok(Statement::CreateSequence {
+            if_not_exists: *if_not_exists,
+            name: translate_object_name(name)?,
+            options: translate_sequence_options(sequence_options)?,
+        }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::Sequence,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropSequence {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
         }),
         SqlStatement::DropFunction {
             if_exists,
@@ -128,20 +242,24 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             columns,
             ..
         } => {
-            if columns.len() > 1 {
-                return Err(TranslateError::CompositeIndexNotSupported.into());
-            }
-
             let name = translate_object_name(name)?;
 
             if name.to_uppercase() == "PRIMARY" {
                 return Err(TranslateError::ReservedIndexName(name).into());
             };
 
+            let columns = columns
+                .iter()
+                .map(translate_order_by_expr)
+                .collect::<Result<Vec<_>>>()?;
+            if columns.iter().any(|column| column.nulls_first.is_some()) {
+                return Err(TranslateError::IndexNullsFirstOrLastNotSupported.into());
+            }
+
             Ok(Statement::CreateIndex {
                 name,
                 table_name: translate_object_name(table_name)?,
-                column: translate_order_by_expr(&columns[0])?,
+                columns,
             })
         }
         SqlStatement::Drop {
@@ -181,6 +299,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
         SqlStatement::ShowVariable { variable } => match (variable.len(), variable.get(0)) {
             (1, Some(keyword)) => match keyword.value.to_uppercase().as_str() {
                 "VERSION" => Ok(Statement::ShowVariable(Variable::Version)),
+                "VIEWS" => Ok(Statement::ShowVariable(Variable::Views)),
                 v => Err(TranslateError::UnsupportedShowVariableKeyword(v.to_owned()).into()),
             },
             (3, Some(keyword)) => match keyword.value.to_uppercase().as_str() {
@@ -200,6 +319,28 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 TranslateError::UnsupportedShowVariableStatement(sql_statement.to_string()).into(),
             ),
         },
+        SqlStatement::Explain {
+            analyze, statement, ..
+        } => Ok(Statement::Explain {
+            analyze: *analyze,
+            statement: Box::new(translate_statement(statement)?),
+        }),
+        SqlStatement::Analyze {
+            table_name,
+            partitions: None,
+            for_columns: false,
+            columns,
+            noscan: false,
+            compute_statistics: false,
+            ..
+        } if columns.is_empty() => {
+            let table_name = match table_name.0.is_empty() {
+                true => None,
+                false => Some(translate_object_name(table_name)?),
+            };
+
+            Ok(Statement::Analyze { table_name })
+        }
         SqlStatement::ShowColumns { table_name, .. } => Ok(Statement::ShowColumns {
             table_name: translate_object_name(table_name)?,
         }),
@@ -253,6 +394,62 @@ pub fn translate_assignment(sql_assignment: &SqlAssignment) -> Result<Assignment
     })
 }
 
+fn translate_update_assignment(
+    sql_assignment: &SqlAssignment,
+    table_name: &str,
+) -> Result<Assignment> {
+    match sql_assignment.id.as_slice() {
+        [qualifier, id] if qualifier.value == table_name => Ok(Assignment {
+            id: id.value.to_owned(),
+            value: translate_expr(&sql_assignment.value)?,
+        }),
+        _ => translate_assignment(sql_assignment),
+    }
+}
+
+fn translate_returning(sql_returning: &Option<Vec<SqlSelectItem>>) -> Result<Vec<SelectItem>> {
+    sql_returning
+        .iter()
+        .flatten()
+        .map(translate_select_item)
+        .collect()
+}
+
+fn translate_on_insert(sql_on_insert: &SqlOnInsert) -> Result<OnConflict> {
+    let SqlOnConflict {
+        conflict_target,
+        action,
+    } = match sql_on_insert {
+        SqlOnInsert::OnConflict(on_conflict) => on_conflict,
+        _ => {
+            return Err(TranslateError::UnsupportedOnInsert(sql_on_insert.to_string()).into());
+        }
+    };
+
+    let columns = match conflict_target {
+        Some(SqlConflictTarget::Columns(columns)) => translate_idents(columns),
+        Some(SqlConflictTarget::OnConstraint(_)) => {
+            return Err(TranslateError::UnsupportedOnInsert(sql_on_insert.to_string()).into());
+        }
+        None => Vec::new(),
+    };
+    let action = match action {
+        SqlOnConflictAction::DoNothing => OnConflictAction::DoNothing,
+        SqlOnConflictAction::DoUpdate(SqlDoUpdate {
+            assignments,
+            selection,
+        }) => OnConflictAction::DoUpdate {
+            assignments: assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+            selection: selection.as_ref().map(translate_expr).transpose()?,
+        },
+    };
+
+    Ok(OnConflict { columns, action })
+}
+
 fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
     if !table.joins.is_empty() {
         return Err(TranslateError::JoinOnUpdateNotSupported.into());
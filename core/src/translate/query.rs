@@ -5,22 +5,25 @@ use {
     },
     crate::{
         ast::{
-            AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            AstLiteral, Cte, Dictionary, Distinct, Expr, Join, JoinConstraint, JoinExecutor,
+            JoinOperator, Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+            TableWithJoins, Values, With,
         },
         result::Result,
     },
     sqlparser::ast::{
-        Expr as SqlExpr, FunctionArg as SqlFunctionArg, Join as SqlJoin,
-        JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator, Query as SqlQuery,
-        Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
+        Cte as SqlCte, Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
+        Join as SqlJoin, JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator,
+        Query as SqlQuery, Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
+        SetOperator as SqlSetOperator, SetQuantifier as SqlSetQuantifier,
         TableAlias as SqlTableAlias, TableFactor as SqlTableFactor,
-        TableWithJoins as SqlTableWithJoins,
+        TableWithJoins as SqlTableWithJoins, With as SqlWith,
     },
 };
 
 pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     let SqlQuery {
+        with,
         body,
         order_by,
         limit,
@@ -28,6 +31,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         ..
     } = sql_query;
 
+    let with = with.as_ref().map(translate_with).transpose()?;
     let body = translate_set_expr(body)?;
     let order_by = order_by
         .iter()
@@ -41,6 +45,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         .transpose()?;
 
     Ok(Query {
+        with,
         body,
         order_by,
         limit,
@@ -48,6 +53,33 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     })
 }
 
+fn translate_with(sql_with: &SqlWith) -> Result<With> {
+    let SqlWith {
+        recursive,
+        cte_tables,
+    } = sql_with;
+
+    let cte_tables = cte_tables
+        .iter()
+        .map(|SqlCte { alias, query, .. }| {
+            let SqlTableAlias { name, columns } = alias;
+
+            translate_query(query).map(|query| Cte {
+                alias: TableAlias {
+                    name: name.value.to_owned(),
+                    columns: translate_idents(columns),
+                },
+                query: Box::new(query),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(With {
+        recursive: *recursive,
+        cte_tables,
+    })
+}
+
 fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
     match sql_set_expr {
         SqlSetExpr::Select(select) => translate_select(select).map(Box::new).map(SetExpr::Select),
@@ -57,10 +89,52 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Union => SetOperator::Union,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+                SqlSetOperator::Except => SetOperator::Except,
+            };
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                SqlSetQuantifier::ByName | SqlSetQuantifier::AllByName => {
+                    return Err(TranslateError::UnsupportedSetQuantifier(
+                        set_quantifier.to_string(),
+                    )
+                    .into());
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all,
+                left: translate_set_operand(left).map(Box::new)?,
+                right: translate_set_operand(right).map(Box::new)?,
+            })
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
 
+fn translate_set_operand(sql_set_expr: &SqlSetExpr) -> Result<Query> {
+    match sql_set_expr {
+        SqlSetExpr::Query(query) => translate_query(query),
+        _ => translate_set_expr(sql_set_expr).map(|body| Query {
+            with: None,
+            body,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }),
+    }
+}
+
 fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
     let SqlSelect {
         projection,
@@ -72,16 +146,8 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         ..
     } = sql_select;
 
-    if from.len() > 1 {
-        return Err(TranslateError::TooManyTables.into());
-    }
-
-    if distinct.is_some() {
-        return Err(TranslateError::SelectDistinctNotSupported.into());
-    }
-
-    let from = match from.get(0) {
-        Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
+    let from = match translate_from(from)? {
+        Some(from) => from,
         None => TableWithJoins {
             relation: TableFactor::Series {
                 alias: TableAlias {
@@ -94,7 +160,19 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         },
     };
 
+    let distinct = match distinct {
+        Some(SqlDistinct::Distinct) => Some(Distinct::Distinct),
+        Some(SqlDistinct::On(exprs)) => exprs
+            .iter()
+            .map(translate_expr)
+            .collect::<Result<_>>()
+            .map(Distinct::On)
+            .map(Some)?,
+        None => None,
+    };
+
     Ok(Select {
+        distinct,
         projection: projection
             .iter()
             .map(translate_select_item)
@@ -103,6 +181,7 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         selection: selection.as_ref().map(translate_expr).transpose()?,
         group_by: group_by.iter().map(translate_expr).collect::<Result<_>>()?,
         having: having.as_ref().map(translate_expr).transpose()?,
+        plan_id: None,
     })
 }
 
@@ -135,6 +214,45 @@ pub fn translate_select_item(sql_select_item: &SqlSelectItem) -> Result<SelectIt
     }
 }
 
+pub fn translate_from(sql_from: &[SqlTableWithJoins]) -> Result<Option<TableWithJoins>> {
+    let (sql_table_with_joins, rest) = match sql_from.split_first() {
+        Some(split) => split,
+        None => return Ok(None),
+    };
+
+    let TableWithJoins { relation, joins } = translate_table_with_joins(sql_table_with_joins)?;
+    let joins = rest
+        .iter()
+        .try_fold(joins, |mut joins, sql_table_with_joins| -> Result<_> {
+            let TableWithJoins {
+                relation,
+                joins: rest_joins,
+            } = translate_table_with_joins(sql_table_with_joins)?;
+
+            // `a, b RIGHT JOIN c` pads `b` with NULLs per row of `a`,
+            // which a flat chain of joins cannot express
+            if rest_joins.iter().any(|Join { join_operator, .. }| {
+                matches!(
+                    join_operator,
+                    JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+                )
+            }) {
+                return Err(TranslateError::OuterJoinAfterCommaNotSupported.into());
+            }
+
+            joins.push(Join {
+                relation,
+                join_operator: JoinOperator::CrossJoin,
+                join_executor: JoinExecutor::NestedLoop,
+            });
+            joins.extend(rest_joins);
+
+            Ok(joins)
+        })?;
+
+    Ok(Some(TableWithJoins { relation, joins }))
+}
+
 fn translate_table_with_joins(sql_table_with_joins: &SqlTableWithJoins) -> Result<TableWithJoins> {
     let SqlTableWithJoins { relation, joins } = sql_table_with_joins;
 
@@ -243,12 +361,8 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
     let translate_constraint = |sql_join_constraint: &SqlJoinConstraint| match sql_join_constraint {
         SqlJoinConstraint::On(expr) => translate_expr(expr).map(JoinConstraint::On),
         SqlJoinConstraint::None => Ok(JoinConstraint::None),
-        SqlJoinConstraint::Using(_) => {
-            Err(TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into())
-        }
-        SqlJoinConstraint::Natural => {
-            Err(TranslateError::UnsupportedJoinConstraint("NATURAL".to_owned()).into())
-        }
+        SqlJoinConstraint::Using(idents) => Ok(JoinConstraint::Using(translate_idents(idents))),
+        SqlJoinConstraint::Natural => Ok(JoinConstraint::Natural),
     };
 
     let join_operator = match sql_join_operator {
@@ -258,6 +372,13 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
         SqlJoinOperator::LeftOuter(sql_join_constraint) => {
             translate_constraint(sql_join_constraint).map(JoinOperator::LeftOuter)
         }
+        SqlJoinOperator::RightOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::RightOuter)
+        }
+        SqlJoinOperator::FullOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::FullOuter)
+        }
+        SqlJoinOperator::CrossJoin => Ok(JoinOperator::CrossJoin),
         _ => {
             Err(TranslateError::UnsupportedJoinOperator(format!("{:?}", sql_join_operator)).into())
         }
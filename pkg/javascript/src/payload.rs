@@ -95,6 +95,7 @@ fn convert_payload(payload: Payload) -> Json {
         Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
         Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
         Payload::DropIndex => json!({ "type": "DROP INDEX" }),
+        Payload::Analyze => json!({ "type": "ANALYZE" }),
         Payload::StartTransaction => json!({ "type": "BEGIN" }),
         Payload::Commit => json!({ "type": "COMMIT" }),
         Payload::Rollback => json!({ "type": "ROLLBACK" }),
@@ -117,5 +118,14 @@ fn convert_payload(payload: Payload) -> Json {
                 "functions": function_names
             })
         }
+        Payload::DropView => json!({ "type": "DROP VIEW" }),
+        Payload::DropSequence => json!({ "type": "DROP SEQUENCE" }),
+        Payload::RefreshMaterializedView => json!({ "type": "REFRESH MATERIALIZED VIEW" }),
+        Payload::ShowVariable(PayloadVariable::Views(view_names)) => {
+            json!({
+                "type": "SHOW VIEWS",
+                "views": view_names
+            })
+        }
     }
 }
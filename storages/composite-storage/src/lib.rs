@@ -1,8 +1,11 @@
 #![deny(clippy::str_to_string)]
 
+mod sequence;
+mod statistics;
 mod store;
 mod store_mut;
 mod transaction;
+mod view;
 
 use {
     gluesql_core::{
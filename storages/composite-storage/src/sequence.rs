@@ -0,0 +1,83 @@
+use {
+    super::{CompositeStorage, IStorage},
+    async_trait::async_trait,
+    futures::stream::{self, StreamExt, TryStreamExt},
+    gluesql_core::{
+        data::Sequence as StructSequence,
+        error::{Error, Result},
+        store::{Sequence, SequenceMut},
+    },
+};
+
+impl CompositeStorage {
+    /// Engine which keeps the sequence, a new sequence goes to the default engine.
+    async fn fetch_sequence_engine(&self, sequence_name: &str) -> Result<String> {
+        for (engine, storage) in self.storages.iter() {
+            if storage.fetch_sequence(sequence_name).await?.is_some() {
+                return Ok(engine.to_owned());
+            }
+        }
+
+        self.default_engine.clone().ok_or_else(|| {
+            Error::StorageMsg(format!("engine not found for sequence: {sequence_name}"))
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for CompositeStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        for storage in self.storages.values() {
+            let sequence = storage.fetch_sequence(sequence_name).await?;
+
+            if sequence.is_some() {
+                return Ok(sequence);
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let mut sequences = stream::iter(self.storages.values())
+            .map(AsRef::as_ref)
+            .then(<dyn IStorage>::fetch_all_sequences)
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(sequences)
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for CompositeStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let engine = self.fetch_sequence_engine(&sequence.name).await?;
+
+        self.storages
+            .get_mut(&engine)
+            .ok_or_else(|| {
+                Error::StorageMsg(format!(
+                    "[insert_sequence] storage not found for sequence: {}",
+                    sequence.name
+                ))
+            })?
+            .insert_sequence(sequence)
+            .await
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        for storage in self.storages.values_mut() {
+            if storage.fetch_sequence(sequence_name).await?.is_some() {
+                storage.delete_sequence(sequence_name).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
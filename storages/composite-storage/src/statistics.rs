@@ -0,0 +1,46 @@
+use {
+    super::CompositeStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::TableStats,
+        error::Result,
+        store::{Statistics, StatisticsMut},
+    },
+};
+
+#[async_trait(?Send)]
+impl Statistics for CompositeStorage {
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStats>> {
+        for storage in self.storages.values() {
+            let statistics = storage.fetch_statistics(table_name).await?;
+
+            if statistics.is_some() {
+                return Ok(statistics);
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[async_trait(?Send)]
+impl StatisticsMut for CompositeStorage {
+    async fn insert_statistics(&mut self, statistics: TableStats) -> Result<()> {
+        self.fetch_storage_mut(&statistics.table_name)
+            .await?
+            .insert_statistics(statistics)
+            .await
+    }
+
+    /// Statistics are deleted once the table is gone, so every storage which keeps them
+    /// is looked up rather than the engine of the table.
+    async fn delete_statistics(&mut self, table_name: &str) -> Result<()> {
+        for storage in self.storages.values_mut() {
+            if storage.fetch_statistics(table_name).await?.is_some() {
+                storage.delete_statistics(table_name).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ use {
         error::Result,
         store::{DataRow, RowIter, Store},
     },
+    std::ops::Bound,
 };
 
 #[async_trait(?Send)]
@@ -49,4 +50,16 @@ impl Store for CompositeStorage {
             .scan_data(table_name)
             .await
     }
+
+    async fn scan_range_data(
+        &self,
+        table_name: &str,
+        range: (Bound<Key>, Bound<Key>),
+        asc: bool,
+    ) -> Result<RowIter> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_range_data(table_name, range, asc)
+            .await
+    }
 }
@@ -0,0 +1,83 @@
+use {
+    super::{CompositeStorage, IStorage},
+    async_trait::async_trait,
+    futures::stream::{self, StreamExt, TryStreamExt},
+    gluesql_core::{
+        data::View as StructView,
+        error::{Error, Result},
+        store::{View, ViewMut},
+    },
+};
+
+impl CompositeStorage {
+    /// Engine which keeps the view, a new view goes to the default engine.
+    async fn fetch_view_engine(&self, view_name: &str) -> Result<String> {
+        for (engine, storage) in self.storages.iter() {
+            if storage.fetch_view(view_name).await?.is_some() {
+                return Ok(engine.to_owned());
+            }
+        }
+
+        self.default_engine
+            .clone()
+            .ok_or_else(|| Error::StorageMsg(format!("engine not found for view: {view_name}")))
+    }
+}
+
+#[async_trait(?Send)]
+impl View for CompositeStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        for storage in self.storages.values() {
+            let view = storage.fetch_view(view_name).await?;
+
+            if view.is_some() {
+                return Ok(view);
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let mut views = stream::iter(self.storages.values())
+            .map(AsRef::as_ref)
+            .then(<dyn IStorage>::fetch_all_views)
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        views.sort_by(|a, b| a.view_name.cmp(&b.view_name));
+
+        Ok(views)
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for CompositeStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        let engine = self.fetch_view_engine(&view.view_name).await?;
+
+        self.storages
+            .get_mut(&engine)
+            .ok_or_else(|| {
+                Error::StorageMsg(format!(
+                    "[insert_view] storage not found for view: {}",
+                    view.view_name
+                ))
+            })?
+            .insert_view(view)
+            .await
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        for storage in self.storages.values_mut() {
+            if storage.fetch_view(view_name).await?.is_some() {
+                storage.delete_view(view_name).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
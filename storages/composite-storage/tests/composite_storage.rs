@@ -25,3 +25,9 @@ impl Tester<CompositeStorage> for CompositeTester {
 }
 
 generate_store_tests!(tokio::test, CompositeTester);
+generate_view_tests!(tokio::test, CompositeTester);
+
+// identity tests rename tables, which CompositeStorage does not support
+declare_test_fn!(tokio::test, CompositeTester, sequence, sequence::sequence);
+
+generate_statistics_tests!(tokio::test, CompositeTester);
@@ -72,7 +72,7 @@ macro_rules! test {
 
 #[tokio::test]
 async fn composite_storage_index() {
-    use {gluesql_core::store::Index, memory_storage::MemoryStorage};
+    use {gluesql_core::store::Index, memory_storage::MemoryStorage, std::ops::Bound::Unbounded};
 
     let mut storage = CompositeStorage::default();
     storage.push("mem", MemoryStorage::default());
@@ -95,7 +95,7 @@ async fn composite_storage_index() {
 
     assert_eq!(
         glue.storage
-            .scan_indexed_data("Idx", "hello", None, None)
+            .scan_indexed_data("Idx", "hello", None, &[], (Unbounded, Unbounded))
             .await
             .map(|_| ()),
         Err(Error::StorageMsg(
@@ -1,7 +1,13 @@
 use {
     super::JsonStorage,
-    gluesql_core::store::{CustomFunction, CustomFunctionMut},
+    gluesql_core::store::{
+        CustomFunction, CustomFunctionMut, Sequence, SequenceMut, Statistics, StatisticsMut,
+    },
 };
 
 impl CustomFunction for JsonStorage {}
 impl CustomFunctionMut for JsonStorage {}
+impl Sequence for JsonStorage {}
+impl SequenceMut for JsonStorage {}
+impl Statistics for JsonStorage {}
+impl StatisticsMut for JsonStorage {}
@@ -5,11 +5,11 @@ mod index;
 mod store;
 mod store_mut;
 mod transaction;
+mod view;
 
 use {
     error::{JsonStorageError, OptionExt, ResultExt},
     gluesql_core::{
-        ast::ColumnUniqueOption,
         data::{value::HashMapJsonExt, Key, Schema},
         error::{Error, Result},
         store::{DataRow, Metadata, RowIter},
@@ -51,7 +51,7 @@ impl JsonStorage {
         }
 
         let schema_path = self.schema_path(table_name);
-        let column_defs = match schema_path.exists() {
+        let (column_defs, foreign_keys, checks, unique_constraints) = match schema_path.exists() {
             true => {
                 let mut file = File::open(&schema_path).map_storage_err()?;
                 let mut ddl = String::new();
@@ -64,9 +64,14 @@ impl JsonStorage {
                     ));
                 }
 
-                schema.column_defs
+                (
+                    schema.column_defs,
+                    schema.foreign_keys,
+                    schema.checks,
+                    schema.unique_constraints,
+                )
             }
-            false => None,
+            false => (None, Vec::new(), Vec::new(), Vec::new()),
         };
 
         Ok(Some(Schema {
@@ -74,6 +79,10 @@ impl JsonStorage {
             column_defs,
             indexes: vec![],
             engine: None,
+            view_query: None,
+            foreign_keys,
+            checks,
+            unique_constraints,
         }))
     }
 
@@ -145,6 +154,7 @@ impl JsonStorage {
         };
 
         let schema2 = schema.clone();
+        let primary_key = schema.primary_key_indexes();
         let rows = jsons.enumerate().map(move |(index, json)| -> Result<_> {
             let json = json?;
             let get_index_key = || index.try_into().map(Key::I64).map_storage_err();
@@ -159,17 +169,13 @@ impl JsonStorage {
                 }
             };
 
-            let mut key: Option<Key> = None;
+            let mut key_values = Vec::new();
             let mut values = Vec::with_capacity(column_defs.len());
-            for column_def in column_defs {
+            for (i, column_def) in column_defs.iter().enumerate() {
                 let value = json.get(&column_def.name).map_storage_err(
                     JsonStorageError::ColumnDoesNotExist(column_def.name.clone()),
                 )?;
 
-                if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
-                    key = Some(value.clone().try_into().map_storage_err()?);
-                }
-
                 let value = match value.get_type() {
                     Some(data_type) if data_type != column_def.data_type => {
                         value.cast(&column_def.data_type)?
@@ -177,11 +183,20 @@ impl JsonStorage {
                     Some(_) | None => value.clone(),
                 };
 
+                if let Some(position) = primary_key
+                    .as_ref()
+                    .and_then(|indexes| indexes.iter().position(|&index| index == i))
+                {
+                    key_values.push((position, value.clone()));
+                }
+
                 values.push(value);
             }
 
-            let key = match key {
-                Some(key) => key,
+            key_values.sort_by_key(|(position, _)| *position);
+
+            let key = match primary_key {
+                Some(_) => Key::from_values(key_values.iter().map(|(_, value)| value))?,
                 None => get_index_key()?,
             };
             let row = DataRow::Vec(values);
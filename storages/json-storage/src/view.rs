@@ -0,0 +1,82 @@
+use {
+    crate::{error::ResultExt, JsonStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::View as StructView,
+        error::Result,
+        store::{View, ViewMut},
+    },
+    std::{
+        ffi::OsStr,
+        fs::{self, remove_file, File},
+        io::Write,
+        path::PathBuf,
+    },
+};
+
+const VIEW_EXTENSION: &str = "view.sql";
+
+impl JsonStorage {
+    fn view_path(&self, view_name: &str) -> PathBuf {
+        self.path_by(view_name, VIEW_EXTENSION)
+    }
+
+    fn read_view(&self, path: PathBuf) -> Result<StructView> {
+        let ddl = fs::read_to_string(path).map_storage_err()?;
+
+        StructView::from_ddl(&ddl)
+    }
+}
+
+#[async_trait(?Send)]
+impl View for JsonStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        let view_path = self.view_path(view_name);
+        if !view_path.exists() {
+            return Ok(None);
+        }
+
+        self.read_view(view_path).map(Some)
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let suffix = format!(".{VIEW_EXTENSION}");
+        let paths = fs::read_dir(&self.path).map_storage_err()?;
+        let mut views = paths
+            .map(|result| {
+                let path = result.map_storage_err()?.path();
+                let is_view = path
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .map(|file_name| file_name.ends_with(&suffix))
+                    .unwrap_or(false);
+
+                is_view.then(|| self.read_view(path)).transpose()
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>>>()?;
+
+        views.sort_by(|a, b| a.view_name.cmp(&b.view_name));
+
+        Ok(views)
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for JsonStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        let view_path = self.view_path(&view.view_name);
+        let mut file = File::create(view_path).map_storage_err()?;
+
+        file.write_all(view.to_ddl().as_bytes()).map_storage_err()
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        let view_path = self.view_path(view_name);
+        if view_path.exists() {
+            remove_file(view_path).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+}
@@ -165,4 +165,17 @@ impl AlterTable for MemoryStorage {
 
         Ok(())
     }
+
+    async fn drop_foreign_key(&mut self, table_name: &str, name: &str) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        item.schema
+            .foreign_keys
+            .retain(|foreign_key| foreign_key.name != name);
+
+        Ok(())
+    }
 }
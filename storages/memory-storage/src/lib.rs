@@ -9,14 +9,21 @@ use {
     async_trait::async_trait,
     gluesql_core::{
         chrono::Utc,
-        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        data::{
+            CustomFunction as StructCustomFunction, Key, Schema, Sequence as StructSequence,
+            TableStats, Value, View as StructView,
+        },
         error::Result,
-        store::{CustomFunction, CustomFunctionMut, DataRow, RowIter, Store, StoreMut},
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, RowIter, Sequence, SequenceMut, Statistics,
+            StatisticsMut, Store, StoreMut, View, ViewMut,
+        },
     },
     serde::{Deserialize, Serialize},
     std::{
         collections::{BTreeMap, HashMap},
         iter::empty,
+        ops::Bound,
     },
 };
 
@@ -32,6 +39,9 @@ pub struct MemoryStorage {
     pub items: HashMap<String, Item>,
     pub metadata: HashMap<String, HashMap<String, Value>>,
     pub functions: HashMap<String, StructCustomFunction>,
+    pub views: HashMap<String, StructView>,
+    pub sequences: HashMap<String, StructSequence>,
+    pub statistics: HashMap<String, TableStats>,
 }
 
 #[async_trait(?Send)]
@@ -57,6 +67,87 @@ impl CustomFunctionMut for MemoryStorage {
     }
 }
 
+#[async_trait(?Send)]
+impl View for MemoryStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        Ok(self.views.get(view_name).cloned())
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let mut views = self.views.values().cloned().collect::<Vec<_>>();
+        views.sort_by(|a, b| a.view_name.cmp(&b.view_name));
+
+        Ok(views)
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for MemoryStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        self.views.insert(view.view_name.to_owned(), view);
+
+        Ok(())
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        self.views.remove(view_name);
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for MemoryStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        Ok(self.sequences.get(sequence_name).cloned())
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let mut sequences = self.sequences.values().cloned().collect::<Vec<_>>();
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(sequences)
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for MemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        self.sequences.insert(sequence.name.to_owned(), sequence);
+
+        Ok(())
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        self.sequences.remove(sequence_name);
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl Statistics for MemoryStorage {
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStats>> {
+        Ok(self.statistics.get(table_name).cloned())
+    }
+}
+
+#[async_trait(?Send)]
+impl StatisticsMut for MemoryStorage {
+    async fn insert_statistics(&mut self, statistics: TableStats) -> Result<()> {
+        self.statistics
+            .insert(statistics.table_name.to_owned(), statistics);
+
+        Ok(())
+    }
+
+    async fn delete_statistics(&mut self, table_name: &str) -> Result<()> {
+        self.statistics.remove(table_name);
+
+        Ok(())
+    }
+}
+
 #[async_trait(?Send)]
 impl Store for MemoryStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -93,6 +184,40 @@ impl Store for MemoryStorage {
 
         Ok(rows)
     }
+
+    async fn scan_range_data(
+        &self,
+        table_name: &str,
+        range: (Bound<Key>, Bound<Key>),
+        asc: bool,
+    ) -> Result<RowIter> {
+        let item = match self.items.get(table_name) {
+            Some(item) if !is_empty(&range) => item,
+            _ => return Ok(Box::new(empty())),
+        };
+
+        let rows = item
+            .rows
+            .range(range)
+            .map(|(key, row)| Ok((key.clone(), row.clone())))
+            .collect::<Vec<_>>();
+        let rows: RowIter = match asc {
+            true => Box::new(rows.into_iter()),
+            false => Box::new(rows.into_iter().rev()),
+        };
+
+        Ok(rows)
+    }
+}
+
+/// `BTreeMap::range` panics on a range whose start is after its end.
+fn is_empty((start, end): &(Bound<Key>, Bound<Key>)) -> bool {
+    match (start, end) {
+        (Bound::Included(start), Bound::Included(end)) => start > end,
+        (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end)) => start >= end,
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+    }
 }
 
 #[async_trait(?Send)]
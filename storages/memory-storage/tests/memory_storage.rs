@@ -29,6 +29,12 @@ generate_metadata_table_tests!(tokio::test, MemoryTester);
 
 generate_custom_function_tests!(tokio::test, MemoryTester);
 
+generate_view_tests!(tokio::test, MemoryTester);
+
+generate_sequence_tests!(tokio::test, MemoryTester);
+
+generate_statistics_tests!(tokio::test, MemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -47,6 +53,7 @@ async fn memory_storage_index() {
         prelude::{Error, Glue, Result},
         store::{Index, Store},
     };
+    use std::ops::Bound::Unbounded;
 
     let storage = MemoryStorage::default();
 
@@ -63,7 +70,7 @@ async fn memory_storage_index() {
 
     assert_eq!(
         storage
-            .scan_indexed_data("Idx", "hello", None, None)
+            .scan_indexed_data("Idx", "hello", None, &[], (Unbounded, Unbounded))
             .await
             .map(|_| ()),
         Err(Error::StorageMsg(
@@ -48,4 +48,11 @@ impl AlterTable for SharedMemoryStorage {
             .drop_column(table_name, column_name, if_exists)
             .await
     }
+
+    async fn drop_foreign_key(&mut self, table_name: &str, name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.drop_foreign_key(table_name, name).await
+    }
 }
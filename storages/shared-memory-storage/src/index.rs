@@ -2,11 +2,12 @@ use {
     super::SharedMemoryStorage,
     async_trait::async_trait,
     gluesql_core::{
-        ast::{IndexOperator, OrderByExpr},
+        ast::OrderByExpr,
         data::Value,
         error::{Error, Result},
         store::{Index, IndexMut, RowIter},
     },
+    std::ops::Bound,
 };
 
 #[async_trait(?Send)]
@@ -16,7 +17,8 @@ impl Index for SharedMemoryStorage {
         _table_name: &str,
         _index_name: &str,
         _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        _prefix: &[Value],
+        _range: (Bound<Value>, Bound<Value>),
     ) -> Result<RowIter> {
         Err(Error::StorageMsg(
             "[Shared MemoryStorage] index is not supported".to_owned(),
@@ -30,7 +32,7 @@ impl IndexMut for SharedMemoryStorage {
         &mut self,
         _table_name: &str,
         _index_name: &str,
-        _column: &OrderByExpr,
+        _columns: &[OrderByExpr],
     ) -> Result<()> {
         Err(Error::StorageMsg(
             "[Shared MemoryStorage] index is not supported".to_owned(),
@@ -7,12 +7,15 @@ mod transaction;
 use {
     async_trait::async_trait,
     gluesql_core::{
-        data::{Key, Schema},
+        data::{Key, Schema, Sequence as StructSequence, TableStats, View as StructView},
         error::Result,
-        store::{DataRow, Metadata, RowIter, Store, StoreMut},
+        store::{
+            DataRow, Metadata, RowIter, Sequence, SequenceMut, Statistics, StatisticsMut, Store,
+            StoreMut, View, ViewMut,
+        },
     },
     memory_storage::MemoryStorage,
-    std::sync::Arc,
+    std::{ops::Bound, sync::Arc},
     tokio::sync::RwLock,
 };
 
@@ -71,6 +74,18 @@ impl Store for SharedMemoryStorage {
 
         database.scan_data(table_name).await
     }
+
+    async fn scan_range_data(
+        &self,
+        table_name: &str,
+        range: (Bound<Key>, Bound<Key>),
+        asc: bool,
+    ) -> Result<RowIter> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.scan_range_data(table_name, range, asc).await
+    }
 }
 
 #[async_trait(?Send)]
@@ -114,3 +129,98 @@ impl StoreMut for SharedMemoryStorage {
 impl Metadata for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunction for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunctionMut for SharedMemoryStorage {}
+
+#[async_trait(?Send)]
+impl View for SharedMemoryStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_view(view_name).await
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_views().await
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for SharedMemoryStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_view(view).await
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_view(view_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for SharedMemoryStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_sequence(sequence_name).await
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_sequences().await
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for SharedMemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_sequence(sequence).await
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_sequence(sequence_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl Statistics for SharedMemoryStorage {
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStats>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_statistics(table_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl StatisticsMut for SharedMemoryStorage {
+    async fn insert_statistics(&mut self, statistics: TableStats) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_statistics(statistics).await
+    }
+
+    async fn delete_statistics(&mut self, table_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_statistics(table_name).await
+    }
+}
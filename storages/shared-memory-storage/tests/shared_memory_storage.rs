@@ -25,6 +25,12 @@ generate_store_tests!(tokio::test, SharedMemoryTester);
 
 generate_alter_table_tests!(tokio::test, SharedMemoryTester);
 
+generate_view_tests!(tokio::test, SharedMemoryTester);
+
+generate_sequence_tests!(tokio::test, SharedMemoryTester);
+
+generate_statistics_tests!(tokio::test, SharedMemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -44,6 +50,7 @@ async fn shared_memory_storage_index() {
         prelude::Glue,
         store::{Index, Store},
     };
+    use std::ops::Bound::Unbounded;
 
     let storage = SharedMemoryStorage::new();
 
@@ -60,7 +67,7 @@ async fn shared_memory_storage_index() {
 
     assert_eq!(
         storage
-            .scan_indexed_data("Idx", "hello", None, None)
+            .scan_indexed_data("Idx", "hello", None, &[], (Unbounded, Unbounded))
             .await
             .map(|_| ()),
         Err(Error::StorageMsg(
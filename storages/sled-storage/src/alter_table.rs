@@ -51,6 +51,10 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = old_schema
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -61,6 +65,10 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
 
             bincode::serialize(&old_snapshot)
@@ -159,6 +167,10 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = snapshot
                 .get(txid, None)
@@ -190,6 +202,7 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                identity,
                 ..
             } = column_defs[i].clone();
 
@@ -199,6 +212,7 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                identity,
             };
             let column_defs = Vector::from(column_defs).update(i, column_def).into();
 
@@ -207,6 +221,10 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
             let value = bincode::serialize(&snapshot)
@@ -259,6 +277,10 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -356,6 +378,10 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -411,6 +437,10 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -494,6 +524,10 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -514,4 +548,50 @@ impl AlterTable for SledStorage {
 
         Ok(())
     }
+
+    async fn drop_foreign_key(&mut self, table_name: &str, name: &str) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+        let tx_result = self.tree.transaction(move |tree| {
+            let (txid, autocommit) = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, autocommit } => (txid, autocommit),
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let (schema_key, snapshot) = fetch_schema(tree, table_name)?;
+            let snapshot = snapshot
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let mut schema = snapshot
+                .get(txid, None)
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+            schema
+                .foreign_keys
+                .retain(|foreign_key| foreign_key.name != name);
+
+            let (snapshot, _) = snapshot.update(txid, schema);
+            let value = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+            tree.insert(schema_key.as_bytes(), value)?;
+
+            if !autocommit {
+                let temp_key = key::temp_schema(txid, table_name);
+
+                tree.insert(temp_key, schema_key.as_bytes())?;
+            }
+
+            Ok(TxPayload::Success)
+        });
+
+        if self.check_retry(tx_result)? {
+            self.drop_foreign_key(table_name, name).await?;
+        }
+
+        Ok(())
+    }
 }
@@ -22,6 +22,9 @@ pub enum StorageError {
     SystemTime(#[from] time::SystemTimeError),
     #[error(transparent)]
     TryFromSlice(#[from] std::array::TryFromSliceError),
+
+    #[error("storage format version {found} is not supported, expected {expected}: dump the database with the GlueSQL version which created it and execute the dump on a new one")]
+    IncompatibleFormatVersion { found: u64, expected: u64 },
 }
 
 impl From<StorageError> for Error {
@@ -34,6 +37,7 @@ impl From<StorageError> for Error {
             Str(e) => Error::StorageMsg(e.to_string()),
             SystemTime(e) => Error::StorageMsg(e.to_string()),
             TryFromSlice(e) => Error::StorageMsg(e.to_string()),
+            IncompatibleFormatVersion { .. } => Error::StorageMsg(e.to_string()),
             AlterTable(e) => e.into(),
             Index(e) => e.into(),
         }
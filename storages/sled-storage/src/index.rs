@@ -6,14 +6,13 @@ use {
     },
     async_trait::async_trait,
     gluesql_core::{
-        ast::IndexOperator,
         data::{Key, Value},
         error::{Error, IndexError, Result},
         store::{DataRow, Index, RowIter},
     },
-    iter_enum::{DoubleEndedIterator, Iterator},
+    iter_enum::Iterator,
     sled::IVec,
-    std::iter::{empty, once},
+    std::{iter::once, ops::Bound},
     utils::Vector,
 };
 
@@ -24,62 +23,48 @@ impl Index for SledStorage {
         table_name: &str,
         index_name: &str,
         asc: Option<bool>,
-        cmp_value: Option<(&IndexOperator, Value)>,
+        prefix: &[Value],
+        range: (Bound<Value>, Bound<Value>),
     ) -> Result<RowIter> {
         let data_keys = {
-            #[derive(Iterator, DoubleEndedIterator)]
-            enum DataIds<I1, I2, I3, I4> {
-                Empty(I1),
-                Once(I2),
-                Range(I3),
-                Full(I4),
-            }
-
             let map = |item: std::result::Result<_, _>| item.map(|(_, v)| v);
+            let base = build_index_key(table_name, index_name, prefix)?;
 
-            match cmp_value {
-                None => {
-                    let prefix = build_index_key_prefix(table_name, index_name);
+            let incr = |key: Vec<u8>| {
+                key.into_iter()
+                    .rev()
+                    .fold((false, Vector::new()), |(added, upper), v| {
+                        match (added, v) {
+                            (true, _) => (added, upper.push(v)),
+                            (false, u8::MAX) => (added, upper.push(v)),
+                            (false, _) => (true, upper.push(v + 1)),
+                        }
+                    })
+                    .1
+                    .reverse()
+                    .into()
+            };
+            let key = |value: Value| {
+                let values = prefix.iter().cloned().chain([value]).collect::<Vec<_>>();
 
-                    DataIds::Full(self.tree.scan_prefix(prefix).map(map))
-                }
-                Some((op, value)) => {
-                    let incr = |key: Vec<u8>| {
-                        key.into_iter()
-                            .rev()
-                            .fold((false, Vector::new()), |(added, upper), v| {
-                                match (added, v) {
-                                    (true, _) => (added, upper.push(v)),
-                                    (false, u8::MAX) => (added, upper.push(v)),
-                                    (false, _) => (true, upper.push(v + 1)),
-                                }
-                            })
-                            .1
-                            .reverse()
-                            .into()
-                    };
-                    let lower = || build_index_key_prefix(table_name, index_name);
-                    let upper = || incr(build_index_key_prefix(table_name, index_name));
-                    let key = build_index_key(table_name, index_name, value)?;
+                build_index_key(table_name, index_name, &values)
+            };
 
-                    match op {
-                        IndexOperator::Eq => match self.tree.get(&key).transpose() {
-                            Some(v) => DataIds::Once(once(v)),
-                            None => DataIds::Empty(empty()),
-                        },
-                        IndexOperator::Gt => {
-                            DataIds::Range(self.tree.range(incr(key)..upper()).map(map))
-                        }
-                        IndexOperator::GtEq => {
-                            DataIds::Range(self.tree.range(key..upper()).map(map))
-                        }
-                        IndexOperator::Lt => DataIds::Range(self.tree.range(lower()..key).map(map)),
-                        IndexOperator::LtEq => {
-                            DataIds::Range(self.tree.range(lower()..=key).map(map))
-                        }
-                    }
-                }
-            }
+            // keys of a value are followed by the keys of the following columns,
+            // so every key starting with the key of a bound value belongs to that value
+            let (lower, upper) = range;
+            let lower = match lower {
+                Bound::Included(value) => key(value)?,
+                Bound::Excluded(value) => incr(key(value)?),
+                Bound::Unbounded => base.clone(),
+            };
+            let upper = match upper {
+                Bound::Included(value) => incr(key(value)?),
+                Bound::Excluded(value) => key(value)?,
+                Bound::Unbounded => incr(base),
+            };
+
+            self.tree.range(lower..upper).map(map)
         };
 
         let (txid, created_at) = match self.state {
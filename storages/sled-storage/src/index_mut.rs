@@ -43,7 +43,7 @@ impl IndexMut for SledStorage {
         &mut self,
         table_name: &str,
         index_name: &str,
-        column: &OrderByExpr,
+        columns: &[OrderByExpr],
     ) -> Result<()> {
         let rows = self
             .scan_data(table_name)
@@ -60,7 +60,10 @@ impl IndexMut for SledStorage {
                 }
             };
 
-            let index_expr = &column.expr;
+            let index_exprs = columns
+                .iter()
+                .map(|OrderByExpr { expr, .. }| expr.clone())
+                .collect::<Vec<_>>();
 
             let (schema_key, schema_snapshot) = fetch_schema(tree, table_name)?;
             let schema_snapshot = schema_snapshot
@@ -72,6 +75,10 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -84,7 +91,7 @@ impl IndexMut for SledStorage {
 
             let index = SchemaIndex {
                 name: index_name.to_owned(),
-                expr: index_expr.clone(),
+                exprs: index_exprs,
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
             };
@@ -99,6 +106,10 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -130,7 +141,7 @@ impl IndexMut for SledStorage {
         });
 
         if self.check_retry(tx_result)? {
-            self.create_index(table_name, index_name, column).await?;
+            self.create_index(table_name, index_name, columns).await?;
         }
 
         Ok(())
@@ -162,6 +173,10 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -184,6 +199,10 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                view_query,
+                foreign_keys,
+                checks,
+                unique_constraints,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -2,7 +2,10 @@ use {
     super::{err_into, fetch_schema, key, Snapshot},
     gluesql_core::{
         ast::Expr,
-        data::schema::{Schema, SchemaIndex},
+        data::{
+            schema::{Schema, SchemaIndex},
+            Key,
+        },
         error::{Error, IndexError, Result},
         executor::evaluate_stateless,
         prelude::Value,
@@ -105,14 +108,14 @@ impl<'a> IndexSync<'a> {
     ) -> ConflictableTransactionResult<(), Error> {
         let SchemaIndex {
             name: index_name,
-            expr: index_expr,
+            exprs: index_exprs,
             ..
         } = index;
 
         let index_key = &evaluate_index_key(
             self.table_name,
             index_name,
-            index_expr,
+            index_exprs,
             self.columns.as_deref(),
             row,
         )
@@ -132,14 +135,14 @@ impl<'a> IndexSync<'a> {
         for index in self.indexes.iter() {
             let SchemaIndex {
                 name: index_name,
-                expr: index_expr,
+                exprs: index_exprs,
                 ..
             } = index;
 
             let old_index_key = &evaluate_index_key(
                 self.table_name,
                 index_name,
-                index_expr,
+                index_exprs,
                 self.columns.as_deref(),
                 old_row,
             )
@@ -148,7 +151,7 @@ impl<'a> IndexSync<'a> {
             let new_index_key = &evaluate_index_key(
                 self.table_name,
                 index_name,
-                index_expr,
+                index_exprs,
                 self.columns.as_deref(),
                 new_row,
             )
@@ -181,14 +184,14 @@ impl<'a> IndexSync<'a> {
     ) -> ConflictableTransactionResult<(), Error> {
         let SchemaIndex {
             name: index_name,
-            expr: index_expr,
+            exprs: index_exprs,
             ..
         } = index;
 
         let index_key = &evaluate_index_key(
             self.table_name,
             index_name,
-            index_expr,
+            index_exprs,
             self.columns.as_deref(),
             row,
         )
@@ -270,28 +273,41 @@ impl<'a> IndexSync<'a> {
 async fn evaluate_index_key(
     table_name: &str,
     index_name: &str,
-    index_expr: &Expr,
+    index_exprs: &[Expr],
     columns: Option<&[String]>,
     row: &DataRow,
 ) -> ConflictableTransactionResult<Vec<u8>, Error> {
-    let context = Some(row.as_context(columns));
-    let evaluated = evaluate_stateless(context, index_expr)
-        .await
-        .map_err(ConflictableTransactionError::Abort)?;
-    let value: Value = evaluated
-        .try_into()
-        .map_err(ConflictableTransactionError::Abort)?;
-
-    build_index_key(table_name, index_name, value).map_err(ConflictableTransactionError::Abort)
+    let mut values = Vec::with_capacity(index_exprs.len());
+    for index_expr in index_exprs {
+        let context = Some(row.as_context(columns));
+        let evaluated = evaluate_stateless(context, index_expr)
+            .await
+            .map_err(ConflictableTransactionError::Abort)?;
+        let value: Value = evaluated
+            .try_into()
+            .map_err(ConflictableTransactionError::Abort)?;
+
+        values.push(value);
+    }
+
+    build_index_key(table_name, index_name, &values).map_err(ConflictableTransactionError::Abort)
 }
 
 pub fn build_index_key_prefix(table_name: &str, index_name: &str) -> Vec<u8> {
     format!("index/{}/{}/", table_name, index_name).into_bytes()
 }
 
-pub fn build_index_key(table_name: &str, index_name: &str, value: Value) -> Result<Vec<u8>> {
+/// Index keys encode the indexed values as a [`Key::Composite`], so the key of leading
+/// values is a prefix of the full key, which lets index scans match prefixes and ranges on
+/// the next column.
+pub fn build_index_key(table_name: &str, index_name: &str, values: &[Value]) -> Result<Vec<u8>> {
+    let keys = values
+        .iter()
+        .map(Key::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
     Ok(build_index_key_prefix(table_name, index_name)
         .into_iter()
-        .chain(value.to_cmp_be_bytes()?)
+        .chain(Key::Composite(keys).to_cmp_be_bytes()?)
         .collect::<Vec<_>>())
 }
@@ -3,11 +3,19 @@ use sled::IVec;
 const TEMP_DATA: &str = "temp_data/";
 const TEMP_SCHEMA: &str = "temp_schema/";
 const TEMP_INDEX: &str = "temp_index/";
+const TEMP_SEQUENCE: &str = "temp_sequence/";
+const TEMP_STATISTICS: &str = "temp_statistics/";
+const TEMP_VIEW: &str = "temp_view/";
 
 pub fn data_prefix(table_name: &str) -> String {
     format!("data/{table_name}/")
 }
 
+/// Upper bound of the data keys of the table, `0` follows the `/` which ends the prefix
+pub fn data_prefix_end(table_name: &str) -> String {
+    format!("data/{table_name}0")
+}
+
 pub fn data(table_name: &str, key: Vec<u8>) -> IVec {
     let key = data_prefix(table_name)
         .into_bytes()
@@ -39,6 +47,18 @@ pub fn temp_index_prefix(txid: u64) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_INDEX))
 }
 
+pub fn temp_sequence_prefix(txid: u64) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_SEQUENCE))
+}
+
+pub fn temp_statistics_prefix(txid: u64) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_STATISTICS))
+}
+
+pub fn temp_view_prefix(txid: u64) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_VIEW))
+}
+
 pub fn temp_data(txid: u64, data_key: &IVec) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_DATA).chain(data_key.iter().copied()))
 }
@@ -54,3 +74,15 @@ pub fn temp_schema(txid: u64, table_name: &str) -> IVec {
 pub fn temp_index(txid: u64, index_key: &[u8]) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_INDEX).chain(index_key.iter().copied()))
 }
+
+pub fn temp_sequence(txid: u64, sequence_name: &str) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_SEQUENCE).chain(sequence_name.as_bytes().iter().copied()))
+}
+
+pub fn temp_statistics(txid: u64, table_name: &str) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_STATISTICS).chain(table_name.as_bytes().iter().copied()))
+}
+
+pub fn temp_view(txid: u64, view_name: &str) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_VIEW).chain(view_name.as_bytes().iter().copied()))
+}
@@ -8,17 +8,20 @@ mod index_mut;
 mod index_sync;
 mod key;
 mod lock;
+mod sequence;
 mod snapshot;
+mod statistics;
 mod store;
 mod store_mut;
 mod transaction;
+mod view;
 
 // re-export
 pub use sled;
 
 use {
     self::snapshot::Snapshot,
-    error::{err_into, tx_err_into},
+    error::{err_into, tx_err_into, StorageError},
     gluesql_core::{
         data::Schema,
         error::{Error, Result},
@@ -35,6 +38,13 @@ use {
 /// default transaction timeout : 1 hour
 const DEFAULT_TX_TIMEOUT: u128 = 3600 * 1000;
 
+/// Version of the stored schemas, rows and index keys, databases written before the version
+/// was recorded are version 1.
+///
+/// Version 2 adds constraints and view queries to schemas, multiple expressions to indexes
+/// and encodes index keys of all the indexed expressions.
+pub const FORMAT_VERSION: u64 = 2;
+
 #[derive(Debug, Clone)]
 pub enum State {
     Idle,
@@ -59,6 +69,7 @@ type ExportData<T> = (u64, Vec<(Vec<u8>, Vec<u8>, T)>);
 impl SledStorage {
     pub fn new(filename: &str) -> Result<Self> {
         let tree = sled::open(filename).map_err(err_into)?;
+        check_format_version(&tree)?;
         let id_offset = get_id_offset(&tree)?;
         let state = State::Idle;
         let tx_timeout = Some(DEFAULT_TX_TIMEOUT);
@@ -86,7 +97,30 @@ impl SledStorage {
         let (new_id_offset, data) = export;
         let old_id_offset = get_id_offset(&self.tree)?;
 
-        self.tree.import(data);
+        // the version is validated before the tree is touched, so the items are collected first
+        let data = data
+            .into_iter()
+            .map(|(collection_type, name, items)| {
+                (collection_type, name, items.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let version = data
+            .iter()
+            .filter(|(_, name, _)| name == b"__sled__default")
+            .flat_map(|(_, _, items)| items)
+            .find_map(|item| match item.as_slice() {
+                [key, value] if key == b"format_version" => Some(value.as_slice()),
+                _ => None,
+            });
+        validate_format_version(decode_format_version(version)?)?;
+
+        // sled imports into an empty tree only, the export carries its own format version
+        self.tree.remove("format_version").map_err(err_into)?;
+        self.tree.import(
+            data.into_iter()
+                .map(|(collection_type, name, items)| (collection_type, name, items.into_iter()))
+                .collect(),
+        );
 
         if new_id_offset > old_id_offset {
             self.tree
@@ -105,6 +139,7 @@ impl TryFrom<Config> for SledStorage {
 
     fn try_from(config: Config) -> Result<Self> {
         let tree = config.open().map_err(err_into)?;
+        check_format_version(&tree)?;
         let id_offset = get_id_offset(&tree)?;
         let state = State::Idle;
         let tx_timeout = Some(DEFAULT_TX_TIMEOUT);
@@ -130,6 +165,36 @@ fn get_id_offset(tree: &Db) -> Result<u64> {
         .unwrap_or(Ok(0))
 }
 
+/// Records the format version in a new database, and fails for one written in another format.
+fn check_format_version(tree: &Db) -> Result<()> {
+    let version = tree.get("format_version").map_err(err_into)?;
+
+    match decode_format_version(version.as_deref())? {
+        None if tree.is_empty() => tree
+            .insert("format_version", &FORMAT_VERSION.to_be_bytes())
+            .map(|_| ())
+            .map_err(err_into),
+        version => validate_format_version(version),
+    }
+}
+
+fn decode_format_version(version: Option<&[u8]>) -> Result<Option<u64>> {
+    version
+        .map(|version| version.try_into().map_err(err_into).map(u64::from_be_bytes))
+        .transpose()
+}
+
+/// Data without a recorded version is written by version 1.
+fn validate_format_version(version: Option<u64>) -> Result<()> {
+    match version {
+        Some(FORMAT_VERSION) => Ok(()),
+        found => Err(err_into(StorageError::IncompatibleFormatVersion {
+            found: found.unwrap_or(1),
+            expected: FORMAT_VERSION,
+        })),
+    }
+}
+
 fn fetch_schema(
     tree: &TransactionalTree,
     table_name: &str,
@@ -0,0 +1,173 @@
+use {
+    super::{
+        err_into, key,
+        lock::{self, LockAcquired},
+        transaction::TxPayload,
+        tx_err_into, SledStorage, Snapshot, State,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::Sequence as StructSequence,
+        error::Result,
+        store::{Sequence, SequenceMut},
+    },
+    sled::transaction::ConflictableTransactionError,
+};
+
+impl SledStorage {
+    const SEQUENCE_PREFIX: &str = "sequence/";
+}
+
+#[async_trait(?Send)]
+impl Sequence for SledStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        let (txid, created_at, temp) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at, false),
+            State::Idle => lock::register(&self.tree, self.id_offset)
+                .map(|(txid, created_at)| (txid, created_at, true))?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, sequence_name);
+        let sequence = self
+            .tree
+            .get(key.as_bytes())
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)?
+            .and_then(|snapshot: Snapshot<StructSequence>| snapshot.extract(txid, lock_txid));
+
+        if temp {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        Ok(sequence)
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => lock::register(&self.tree, self.id_offset)?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        self.tree
+            .scan_prefix(SledStorage::SEQUENCE_PREFIX)
+            .map(move |item| {
+                let (_, value) = item.map_err(err_into)?;
+                let snapshot: Snapshot<StructSequence> =
+                    bincode::deserialize(&value).map_err(err_into)?;
+
+                Ok(snapshot.extract(txid, lock_txid))
+            })
+            .filter_map(|result| result.transpose())
+            .collect::<Result<Vec<_>>>()
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for SledStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(|tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, sequence.name);
+            let temp_key = key::temp_sequence(txid, &sequence.name);
+
+            let snapshot: Option<Snapshot<StructSequence>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let sequence = sequence.clone();
+            let snapshot = match snapshot {
+                Some(snapshot) => snapshot.update(txid, sequence).0,
+                None => Snapshot::<StructSequence>::new(txid, sequence),
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.insert_sequence(sequence).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(move |tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, sequence_name);
+            let temp_key = key::temp_sequence(txid, sequence_name);
+
+            let snapshot: Option<Snapshot<StructSequence>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let snapshot = match snapshot.map(|snapshot| snapshot.delete(txid)) {
+                Some((snapshot, Some(_))) => snapshot,
+                Some((_, None)) | None => {
+                    return Ok(TxPayload::Success);
+                }
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.delete_sequence(sequence_name).await?;
+        }
+
+        Ok(())
+    }
+}
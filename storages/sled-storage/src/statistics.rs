@@ -0,0 +1,155 @@
+use {
+    super::{
+        err_into, key,
+        lock::{self, LockAcquired},
+        transaction::TxPayload,
+        tx_err_into, SledStorage, Snapshot, State,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::TableStats,
+        error::Result,
+        store::{Statistics, StatisticsMut},
+    },
+    sled::transaction::ConflictableTransactionError,
+};
+
+impl SledStorage {
+    const STATISTICS_PREFIX: &str = "statistics/";
+}
+
+#[async_trait(?Send)]
+impl Statistics for SledStorage {
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStats>> {
+        let (txid, created_at, temp) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at, false),
+            State::Idle => lock::register(&self.tree, self.id_offset)
+                .map(|(txid, created_at)| (txid, created_at, true))?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let key = format!("{}{}", SledStorage::STATISTICS_PREFIX, table_name);
+        let statistics = self
+            .tree
+            .get(key.as_bytes())
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)?
+            .and_then(|snapshot: Snapshot<TableStats>| snapshot.extract(txid, lock_txid));
+
+        if temp {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        Ok(statistics)
+    }
+}
+
+#[async_trait(?Send)]
+impl StatisticsMut for SledStorage {
+    async fn insert_statistics(&mut self, statistics: TableStats) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(|tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!(
+                "{}{}",
+                SledStorage::STATISTICS_PREFIX,
+                statistics.table_name
+            );
+            let temp_key = key::temp_statistics(txid, &statistics.table_name);
+
+            let snapshot: Option<Snapshot<TableStats>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let statistics = statistics.clone();
+            let snapshot = match snapshot {
+                Some(snapshot) => snapshot.update(txid, statistics).0,
+                None => Snapshot::<TableStats>::new(txid, statistics),
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.insert_statistics(statistics).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_statistics(&mut self, table_name: &str) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(move |tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!("{}{}", SledStorage::STATISTICS_PREFIX, table_name);
+            let temp_key = key::temp_statistics(txid, table_name);
+
+            let snapshot: Option<Snapshot<TableStats>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let snapshot = match snapshot.map(|snapshot| snapshot.delete(txid)) {
+                Some((snapshot, Some(_))) => snapshot,
+                Some((_, None)) | None => {
+                    return Ok(TxPayload::Success);
+                }
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.delete_statistics(table_name).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -6,7 +6,8 @@ use {
         error::{Error, Result},
         store::{DataRow, RowIter, Store},
     },
-    std::str,
+    sled::IVec,
+    std::{ops::Bound, str},
 };
 
 impl SledStorage {
@@ -123,4 +124,59 @@ impl Store for SledStorage {
 
         Ok(Box::new(result_set))
     }
+
+    async fn scan_range_data(
+        &self,
+        table_name: &str,
+        range: (Bound<Key>, Bound<Key>),
+        asc: bool,
+    ) -> Result<RowIter> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg(
+                    "conflict - scan_range_data failed, lock does not exist".to_owned(),
+                ));
+            }
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let data_key = |key: Key| key.to_cmp_be_bytes().map(|key| key::data(table_name, key));
+        let start = match range.0 {
+            Bound::Included(key) => Bound::Included(data_key(key)?),
+            Bound::Excluded(key) => Bound::Excluded(data_key(key)?),
+            Bound::Unbounded => {
+                Bound::Included(IVec::from(key::data_prefix(table_name).as_bytes()))
+            }
+        };
+        let end = match range.1 {
+            Bound::Included(key) => Bound::Included(data_key(key)?),
+            Bound::Excluded(key) => Bound::Excluded(data_key(key)?),
+            Bound::Unbounded => {
+                Bound::Excluded(IVec::from(key::data_prefix_end(table_name).as_bytes()))
+            }
+        };
+
+        let prefix_len = key::data_prefix(table_name).len();
+        let items = self.tree.range((start, end));
+        let items: Box<dyn Iterator<Item = _>> = match asc {
+            true => Box::new(items),
+            false => Box::new(items.rev()),
+        };
+        let result_set = items
+            .map(move |item| {
+                let (key, value) = item.map_err(err_into)?;
+                let key = key.subslice(prefix_len, key.len() - prefix_len).to_vec();
+                let snapshot: Snapshot<DataRow> = bincode::deserialize(&value).map_err(err_into)?;
+                let row = snapshot.extract(txid, lock_txid);
+                let item = row.map(|row| (Key::Bytea(key), row));
+
+                Ok(item)
+            })
+            .filter_map(|item| item.transpose());
+
+        Ok(Box::new(result_set))
+    }
 }
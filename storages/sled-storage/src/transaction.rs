@@ -6,7 +6,7 @@ use {
     },
     async_trait::async_trait,
     gluesql_core::{
-        data::Schema,
+        data::{Schema, Sequence, TableStats, View},
         error::{Error, Result},
         store::{DataRow, Transaction},
     },
@@ -170,11 +170,17 @@ impl SledStorage {
         let data_items = fetch_items(key::temp_data_prefix(txid))?;
         let schema_items = fetch_items(key::temp_schema_prefix(txid))?;
         let index_items = fetch_items(key::temp_index_prefix(txid))?;
+        let sequence_items = fetch_items(key::temp_sequence_prefix(txid))?;
+        let statistics_items = fetch_items(key::temp_statistics_prefix(txid))?;
+        let view_items = fetch_items(key::temp_view_prefix(txid))?;
 
         self.tree
             .transaction(move |tree| {
                 rollback_items::<DataRow>(tree, txid, &data_items)?;
                 rollback_items::<Schema>(tree, txid, &schema_items)?;
+                rollback_items::<Sequence>(tree, txid, &sequence_items)?;
+                rollback_items::<TableStats>(tree, txid, &statistics_items)?;
+                rollback_items::<View>(tree, txid, &view_items)?;
 
                 for (temp_key, value_key) in index_items.iter() {
                     tree.remove(temp_key)?;
@@ -0,0 +1,181 @@
+use {
+    super::{
+        err_into, key,
+        lock::{self, LockAcquired},
+        transaction::TxPayload,
+        tx_err_into, SledStorage, Snapshot, State,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::View as StructView,
+        error::Result,
+        store::{View, ViewMut},
+    },
+    sled::transaction::ConflictableTransactionError,
+};
+
+impl SledStorage {
+    const VIEW_PREFIX: &str = "view/";
+}
+
+#[async_trait(?Send)]
+impl View for SledStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        let (txid, created_at, temp) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at, false),
+            State::Idle => lock::register(&self.tree, self.id_offset)
+                .map(|(txid, created_at)| (txid, created_at, true))?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let key = format!("{}{}", SledStorage::VIEW_PREFIX, view_name);
+        let view = self
+            .tree
+            .get(key.as_bytes())
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)?
+            .and_then(|snapshot: Snapshot<StructView>| snapshot.extract(txid, lock_txid));
+
+        if temp {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        Ok(view)
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let (txid, created_at, temp) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at, false),
+            State::Idle => lock::register(&self.tree, self.id_offset)
+                .map(|(txid, created_at)| (txid, created_at, true))?,
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let views = self
+            .tree
+            .scan_prefix(SledStorage::VIEW_PREFIX)
+            .map(move |item| {
+                let (_, value) = item.map_err(err_into)?;
+                let snapshot: Snapshot<StructView> =
+                    bincode::deserialize(&value).map_err(err_into)?;
+
+                Ok(snapshot.extract(txid, lock_txid))
+            })
+            .filter_map(|result| result.transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        if temp {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        Ok(views)
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for SledStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(|tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!("{}{}", SledStorage::VIEW_PREFIX, view.view_name);
+            let temp_key = key::temp_view(txid, &view.view_name);
+
+            let snapshot: Option<Snapshot<StructView>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let view = view.clone();
+            let snapshot = match snapshot {
+                Some(snapshot) => snapshot.update(txid, view).0,
+                None => Snapshot::<StructView>::new(txid, view),
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.insert_view(view).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(move |tree| {
+            let txid = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, .. } => txid,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let key = format!("{}{}", SledStorage::VIEW_PREFIX, view_name);
+            let temp_key = key::temp_view(txid, view_name);
+
+            let snapshot: Option<Snapshot<StructView>> = tree
+                .get(key.as_bytes())?
+                .map(|v| bincode::deserialize(&v))
+                .transpose()
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let snapshot = match snapshot.map(|snapshot| snapshot.delete(txid)) {
+                Some((snapshot, Some(_))) => snapshot,
+                Some((_, None)) | None => {
+                    return Ok(TxPayload::Success);
+                }
+            };
+            let snapshot = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            tree.insert(key.as_bytes(), snapshot)?;
+            tree.insert(temp_key, key.as_bytes())?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if let TxPayload::RollbackAndRetry(lock_txid) = tx_result.map_err(tx_err_into)? {
+            self.rollback_txid(lock_txid)?;
+            self.tree
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+
+            self.delete_view(view_name).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use {
+    gluesql_core::{error::FetchError, prelude::*},
+    gluesql_sled_storage::{SledStorage, FORMAT_VERSION},
+    sled::Config,
+};
+
+/// sled releases the lock of a dropped database from its flusher thread, so opening it again
+/// right away can fail for a moment.
+fn reopen<T, E: ToString>(open: impl Fn() -> Result<T, E>) -> Result<T, E> {
+    for _ in 0..50 {
+        match open() {
+            Err(error) if error.to_string().contains("could not acquire lock") => {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            result => return result,
+        }
+    }
+
+    open()
+}
+
+#[tokio::test]
+async fn format_version() {
+    let path = "tmp/format_version";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage);
+    glue.execute("CREATE TABLE Foo (id INTEGER);")
+        .await
+        .unwrap();
+    drop(glue);
+
+    let storage = reopen(|| SledStorage::new(path)).unwrap();
+    assert_eq!(
+        storage.tree.get("format_version").unwrap().as_deref(),
+        Some(FORMAT_VERSION.to_be_bytes().as_slice())
+    );
+
+    storage.tree.remove("format_version").unwrap();
+    drop(storage);
+    assert_eq!(
+        reopen(|| SledStorage::new(path)).map(|_| ()),
+        Err(Error::StorageMsg(format!(
+            "storage format version 1 is not supported, expected {FORMAT_VERSION}: dump the database with the GlueSQL version which created it and execute the dump on a new one"
+        )))
+    );
+
+    let config = Config::default().path(path);
+    let storage = reopen(|| SledStorage::try_from(config.clone()));
+    assert!(storage.is_err());
+
+    let tree = reopen(|| config.open()).unwrap();
+    tree.insert("format_version", &(FORMAT_VERSION + 1).to_be_bytes())
+        .unwrap();
+    drop(tree);
+    assert_eq!(
+        reopen(|| SledStorage::new(path)).map(|_| ()),
+        Err(Error::StorageMsg(format!(
+            "storage format version {} is not supported, expected {FORMAT_VERSION}: dump the database with the GlueSQL version which created it and execute the dump on a new one",
+            FORMAT_VERSION + 1
+        )))
+    );
+}
+
+#[tokio::test]
+async fn import_incompatible_format_version() {
+    let source = Config::default()
+        .path("tmp/import_format_version1")
+        .temporary(true);
+    let target = Config::default()
+        .path("tmp/import_format_version2")
+        .temporary(true);
+
+    let storage = SledStorage::try_from(source).unwrap();
+    let mut glue = Glue::new(storage);
+    glue.execute("CREATE TABLE Foo (id INTEGER);")
+        .await
+        .unwrap();
+    glue.storage.tree.remove("format_version").unwrap();
+    let export = glue.storage.export().unwrap();
+
+    let mut storage = SledStorage::try_from(target).unwrap();
+    assert_eq!(
+        storage.import(export),
+        Err(Error::StorageMsg(format!(
+            "storage format version 1 is not supported, expected {FORMAT_VERSION}: dump the database with the GlueSQL version which created it and execute the dump on a new one"
+        )))
+    );
+    assert_eq!(
+        storage.tree.get("format_version").unwrap().as_deref(),
+        Some(FORMAT_VERSION.to_be_bytes().as_slice())
+    );
+
+    let mut glue = Glue::new(storage);
+    assert_eq!(
+        glue.execute("SELECT * FROM Foo;").await,
+        Err(FetchError::TableNotFound("Foo".to_owned()).into())
+    );
+}
@@ -94,3 +94,6 @@ generate_alter_table_index_tests!(tokio::test, SledTester);
 generate_transaction_alter_table_tests!(tokio::test, SledTester);
 generate_transaction_index_tests!(tokio::test, SledTester);
 generate_metadata_index_tests!(tokio::test, SledTester);
+generate_sequence_tests!(tokio::test, SledTester);
+generate_statistics_tests!(tokio::test, SledTester);
+generate_view_tests!(tokio::test, SledTester);
@@ -0,0 +1,103 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(distinct, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            quantity INTEGER NULL
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, quantity) VALUES
+            (1, 'Seoul',    3),
+            (2, 'Seoul',    3),
+            (3, 'Seoul',    5),
+            (4, 'Busan', NULL),
+            (5, 'Busan',    2),
+            (6, 'Busan',    2),
+            (7, 'Daegu', NULL);
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT COUNT(DISTINCT quantity) AS cnt, COUNT(quantity) AS cnt_all FROM Item;",
+            select!(
+                cnt | cnt_all;
+                I64 | I64;
+                3     5
+            ),
+        ),
+        (
+            "SELECT COUNT(DISTINCT city) AS cnt FROM Item;",
+            select!(
+                cnt;
+                I64;
+                3
+            ),
+        ),
+        (
+            "SELECT SUM(DISTINCT quantity) AS s, AVG(DISTINCT quantity) AS a FROM Item WHERE city = 'Seoul';",
+            select!(
+                s   | a;
+                I64 | F64;
+                8     4.0
+            ),
+        ),
+        (
+            "SELECT MIN(DISTINCT quantity) AS mn, MAX(DISTINCT quantity) AS mx FROM Item WHERE quantity IS NOT NULL;",
+            select!(
+                mn  | mx;
+                I64 | I64;
+                2     5
+            ),
+        ),
+        (
+            "SELECT
+                city,
+                COUNT(DISTINCT quantity) AS cnt,
+                SUM(DISTINCT quantity) AS s,
+                COUNT(*) AS total
+            FROM Item
+            WHERE quantity IS NOT NULL
+            GROUP BY city;",
+            select!(
+                city               | cnt | s   | total;
+                Str                | I64 | I64 | I64;
+                "Seoul".to_owned()   2     8     3;
+                "Busan".to_owned()   1     2     2
+            ),
+        ),
+        (
+            "SELECT city, COUNT(DISTINCT quantity) AS cnt
+            FROM Item
+            GROUP BY city
+            HAVING COUNT(DISTINCT quantity) > 1;",
+            select!(
+                city               | cnt;
+                Str                | I64;
+                "Seoul".to_owned()   2
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT COUNT(DISTINCT *) FROM Item;",
+        Err(TranslateError::WildcardInCountDistinctNotSupported.into())
+    );
+    test!(
+        "SELECT UPPER(DISTINCT city) FROM Item;",
+        Err(TranslateError::DistinctInNonAggregateFunction("UPPER".to_owned()).into())
+    );
+});
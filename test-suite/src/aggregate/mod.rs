@@ -1,5 +1,6 @@
 pub mod avg;
 pub mod count;
+pub mod distinct;
 pub mod error;
 pub mod group_by;
 pub mod max;
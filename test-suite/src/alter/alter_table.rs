@@ -59,6 +59,7 @@ test_case!(alter_table_add_drop, async move {
                 nullable: false,
                 default: None,
                 unique: None,
+                identity: None,
             })
             .into()),
         ),
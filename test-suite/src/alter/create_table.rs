@@ -60,8 +60,8 @@ test_case!(create_table, async move {
             Err(TranslateError::UnsupportedDataType("GLOBE".to_owned()).into()),
         ),
         (
-            "CREATE TABLE Gluery (id INTEGER CHECK (true));",
-            Err(TranslateError::UnsupportedColumnOption("CHECK (true)".to_owned()).into()),
+            "CREATE TABLE Gluery (id INTEGER COMMENT 'hello');",
+            Err(TranslateError::UnsupportedColumnOption("COMMENT 'hello'".to_owned()).into()),
         ),
         (
             "
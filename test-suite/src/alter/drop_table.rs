@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{AlterError, FetchError, TranslateError},
+        error::{AlterError, FetchError},
         prelude::{Payload, Value::*},
     },
 };
@@ -58,7 +58,7 @@ CREATE TABLE DropTable (
         ),
         (
             "DROP VIEW DropTable;",
-            Err(TranslateError::UnsupportedStatement("DROP VIEW DropTable".to_owned()).into()),
+            Err(AlterError::ViewNotFound("DropTable".to_owned()).into()),
         ),
         (
             "
@@ -0,0 +1,131 @@
+use {
+    crate::{explain::query_plan, *},
+    gluesql_core::{
+        error::ExecuteError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(analyze, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Score (player_id INTEGER, score INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'a'), (2, 'b');");
+    run!("INSERT INTO Score SELECT N % 2 + 1, N FROM SERIES(20);");
+
+    let sql = "EXPLAIN SELECT name, score FROM Player JOIN Score ON id = player_id";
+    let written_order = [
+        "Project: name, score",
+        "  -> Hash Join: INNER ON player_id = id",
+        "    -> Seq Scan on Player",
+        "    -> Seq Scan on Score",
+    ];
+    test!(sql, query_plan(&written_order));
+
+    test!("ANALYZE", Ok(Payload::Analyze));
+    test!("ANALYZE Player", Ok(Payload::Analyze));
+    test!("ANALYZE TABLE Score;", Ok(Payload::Analyze));
+    test!(
+        "ANALYZE Missing",
+        Err(ExecuteError::TableNotFound("Missing".to_owned()).into())
+    );
+
+    // the smaller table is hashed once the row counts are known
+    test!(
+        sql,
+        query_plan(&[
+            "Project: name, score",
+            "  -> Hash Join: INNER ON id = player_id",
+            "    -> Seq Scan on Score",
+            "    -> Seq Scan on Player",
+        ])
+    );
+    test!(
+        "SELECT name, score FROM Player JOIN Score ON id = player_id WHERE score <= 3 ORDER BY score",
+        Ok(select!(
+            name               | score
+            Str                | I64;
+            "b".to_owned()       1;
+            "a".to_owned()       2;
+            "b".to_owned()       3
+        ))
+    );
+    test!(
+        "EXPLAIN SELECT * FROM Player JOIN Score ON id = player_id",
+        query_plan(&[
+            "Project: *",
+            "  -> Hash Join: INNER ON player_id = id",
+            "    -> Seq Scan on Player",
+            "    -> Seq Scan on Score",
+        ])
+    );
+
+    // statistics are dropped along with the table
+    run!("DROP TABLE Player;");
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    test!(sql, query_plan(&written_order));
+});
+
+test_case!(index, async move {
+    run!("CREATE TABLE Item (id INTEGER, category INTEGER, price INTEGER);");
+    run!("INSERT INTO Item SELECT N, N % 2, N FROM SERIES(100);");
+    run!("CREATE INDEX idx_category ON Item (category);");
+    run!("CREATE INDEX idx_price ON Item (price);");
+
+    let sql = "EXPLAIN SELECT id FROM Item WHERE category = 1 AND price < 10";
+    test!(
+        sql,
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: price < 10",
+            "    -> Index Scan using idx_category on Item: category = 1",
+        ])
+    );
+
+    run!("ANALYZE Item;");
+
+    // half of the rows share a category, few have a price below 10
+    test!(
+        sql,
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: category = 1",
+            "    -> Index Scan using idx_price on Item: price < 10",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item WHERE category = 1 AND price < 10",
+        Ok(select!(id I64; 1; 3; 5; 7; 9))
+    );
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE category = 1",
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: category = 1",
+            "    -> Seq Scan on Item",
+        ])
+    );
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE price = 50",
+        query_plan(&[
+            "Project: id",
+            "  -> Index Scan using idx_price on Item: price = 50",
+        ])
+    );
+
+    // neither bound matches few enough rows alone, the range between them does
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE price > 40 AND price <= 60",
+        query_plan(&[
+            "Project: id",
+            "  -> Index Scan using idx_price on Item: price > 40 AND price <= 60",
+        ])
+    );
+    test!(
+        "SELECT COUNT(*) FROM Item WHERE price > 40 AND price <= 60",
+        Ok(select!("COUNT(*)" I64; 20))
+    );
+    test!(
+        "SELECT COUNT(*) FROM Item WHERE category = 1",
+        Ok(select!("COUNT(*)" I64; 50))
+    );
+});
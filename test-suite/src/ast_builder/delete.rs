@@ -67,4 +67,23 @@ test_case!(delete, async move {
         rows: vec![],
     });
     assert_eq!(actual, expected, "select * from Foo");
+
+    // delete with returning
+    table("Foo")
+        .insert()
+        .values(vec![vec![num(4), num(400), false.into()]])
+        .execute(glue)
+        .await
+        .unwrap();
+    let actual = table("Foo")
+        .delete()
+        .returning(vec!["id", "score"])
+        .execute(glue)
+        .await;
+    let expected = Ok(select!(
+        id  | score
+        I64 | I64;
+        4     400
+    ));
+    assert_eq!(actual, expected, "delete with returning");
 });
@@ -76,4 +76,18 @@ test_case!(insert, async move {
         3     "Drink".to_owned()
     ));
     assert_eq!(actual, expected, "select from Bar");
+
+    // insert with returning
+    let actual = table("Bar")
+        .insert()
+        .values(vec![vec![num(4), text("Snack")]])
+        .returning("id, name AS label")
+        .execute(glue)
+        .await;
+    let expected = Ok(select!(
+        id  | label
+        I64 | Str;
+        4     "Snack".to_owned()
+    ));
+    assert_eq!(actual, expected, "insert with returning");
 });
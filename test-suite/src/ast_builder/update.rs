@@ -77,4 +77,19 @@ test_case!(update, async move {
         3     70      true
     ));
     assert_eq!(actual, expected, "select * from Foo");
+
+    // update with returning
+    let actual = table("Foo")
+        .update()
+        .set("score", col("score").add(1))
+        .filter(col("id").eq(num(3)))
+        .returning("*")
+        .execute(glue)
+        .await;
+    let expected = Ok(select!(
+        id  | score | flag
+        I64 | I64   | Bool;
+        3     71      true
+    ));
+    assert_eq!(actual, expected, "update with returning");
 });
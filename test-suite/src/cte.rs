@@ -0,0 +1,172 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::SelectError,
+        prelude::{parse, translate, Payload, Value::*},
+    },
+};
+
+test_case!(cte, async move {
+    run!("CREATE TABLE Category (id INTEGER, parent_id INTEGER NULL, name TEXT);");
+    run!(
+        "
+        INSERT INTO Category VALUES
+            (1, NULL, 'root'),
+            (2, 1, 'books'),
+            (3, 1, 'music'),
+            (4, 2, 'novels'),
+            (5, 4, 'mystery'),
+            (6, 3, 'jazz');
+    "
+    );
+
+    test!(
+        "WITH Books AS (SELECT id, name FROM Category WHERE parent_id = 2)
+        SELECT * FROM Books",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            4     "novels".to_owned()
+        ))
+    );
+    test!(
+        "WITH Top (top_id, top_name) AS (SELECT id, name FROM Category WHERE parent_id = 1),
+              Sub AS (SELECT C.name, T.top_name FROM Category C JOIN Top T ON C.parent_id = T.top_id)
+        SELECT * FROM Sub ORDER BY name",
+        Ok(select!(
+            name                  | top_name
+            Str                   | Str;
+            "jazz".to_owned()       "music".to_owned();
+            "novels".to_owned()     "books".to_owned()
+        ))
+    );
+    test!(
+        "WITH Category AS (SELECT id FROM Category WHERE id > 4)
+        SELECT * FROM Category",
+        Ok(select!(id; I64; 5; 6))
+    );
+    test!(
+        "WITH Leaf AS (SELECT id FROM Category WHERE id > 4)
+        SELECT name FROM Category WHERE id IN (SELECT id FROM Leaf)",
+        Ok(select!(
+            name
+            Str;
+            "mystery".to_owned();
+            "jazz".to_owned()
+        ))
+    );
+    test!(
+        "WITH RECURSIVE Tree (id, depth) AS (
+            SELECT id, 0 FROM Category WHERE id = 2
+            UNION ALL
+            SELECT C.id, T.depth + 1 FROM Category C JOIN Tree T ON C.parent_id = T.id
+        )
+        SELECT * FROM Tree",
+        Ok(select!(
+            id  | depth
+            I64 | I64;
+            2     0;
+            4     1;
+            5     2
+        ))
+    );
+    test!(
+        "WITH RECURSIVE Ancestor AS (
+            SELECT id, parent_id FROM Category WHERE id = 5
+            UNION
+            SELECT C.id, C.parent_id FROM Ancestor A JOIN Category C ON A.parent_id = C.id
+        )
+        SELECT Category.name FROM Ancestor JOIN Category ON Ancestor.id = Category.id",
+        Ok(select!(
+            name
+            Str;
+            "mystery".to_owned();
+            "novels".to_owned();
+            "books".to_owned();
+            "root".to_owned()
+        ))
+    );
+    test!(
+        "WITH RECURSIVE Counter (n) AS (
+            SELECT 1
+            UNION
+            SELECT n + 1 FROM Counter WHERE n < 5
+        )
+        SELECT SUM(n) AS total FROM Counter",
+        Ok(select!(total; I64; 15))
+    );
+    test!(
+        "WITH RECURSIVE Leaf AS (SELECT id FROM Category WHERE id > 4)
+        SELECT * FROM Leaf",
+        Ok(select!(id; I64; 5; 6))
+    );
+    test!(
+        "WITH RECURSIVE Pair (n) AS (SELECT 1 UNION ALL SELECT 2)
+        SELECT * FROM Pair",
+        Ok(select!(n; I64; 1; 2))
+    );
+    test!(
+        "WITH RECURSIVE Loop (n) AS (SELECT n FROM Loop)
+        SELECT * FROM Loop",
+        Err(SelectError::RecursiveQueryWithoutUnion("Loop".to_owned()).into())
+    );
+
+    // Statements executed without planning keep their CTEs unexpanded
+    for (sql, expected) in [
+        (
+            "WITH RECURSIVE Leaf AS (SELECT id FROM Category WHERE id > 4) SELECT * FROM Leaf",
+            select!(id; I64; 5; 6),
+        ),
+        (
+            "WITH RECURSIVE Pair (n) AS (SELECT 1 UNION ALL SELECT 2) SELECT * FROM Pair",
+            select!(n; I64; 1; 2),
+        ),
+        (
+            "WITH RECURSIVE Counter (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM Counter WHERE n < 3)
+            SELECT * FROM Counter",
+            select!(n; I64; 1; 2; 3),
+        ),
+    ] {
+        let statement = translate(&parse(sql).unwrap()[0]).unwrap();
+
+        assert_eq!(get_glue!().execute_stmt(&statement).await, Ok(expected), "{sql}");
+    }
+
+    run!("CREATE TABLE Leaves (id INTEGER, name TEXT);");
+    test!(
+        "INSERT INTO Leaves
+        WITH Leaf AS (SELECT id, name FROM Category WHERE id > 4)
+        SELECT * FROM Leaf",
+        Ok(Payload::Insert(2))
+    );
+    test!(
+        "SELECT * FROM Leaves",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            5     "mystery".to_owned();
+            6     "jazz".to_owned()
+        ))
+    );
+
+    // CTEs in subqueries nested in function, aggregate and window arguments are expanded
+    for (sql, expected) in [
+        (
+            "WITH One AS (SELECT 1 AS x) SELECT ABS((SELECT x FROM One)) AS a",
+            select!(a; I64; 1),
+        ),
+        (
+            "WITH Two AS (SELECT 2 AS x)
+            SELECT SUM((SELECT x FROM Two)) AS s FROM Category WHERE id < 4",
+            select!(s; I64; 6),
+        ),
+        (
+            "WITH Three AS (SELECT 3 AS x)
+            SELECT id, SUM((SELECT x FROM Three)) OVER (ORDER BY id) AS s
+            FROM Category WHERE id < 3 ORDER BY id",
+            select!(id | s; I64 | I64; 1 3; 2 6),
+        ),
+    ] {
+        test!(sql, Ok(expected));
+    }
+});
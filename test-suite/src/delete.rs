@@ -64,3 +64,54 @@ test_case!(delete, async move {
         })
     };
 });
+
+test_case!(using, async move {
+    run!("CREATE TABLE Foo (id INTEGER PRIMARY KEY, name TEXT);");
+    run!("INSERT INTO Foo VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd');");
+    run!("CREATE TABLE Bar (foo_id INTEGER, flag BOOLEAN);");
+    run!("INSERT INTO Bar VALUES (1, TRUE), (1, TRUE), (3, FALSE), (4, TRUE);");
+
+    test! {
+        name: "delete rows matched by the USING table",
+        sql: "DELETE FROM Foo USING Bar WHERE Foo.id = Bar.foo_id AND Bar.flag = TRUE;",
+        expected: Ok(Payload::Delete(2))
+    };
+    test! {
+        sql: "SELECT * FROM Foo",
+        expected: Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "b".to_owned();
+            3     "c".to_owned()
+        ))
+    };
+
+    test! {
+        name: "no matching row",
+        sql: "DELETE FROM Foo USING Bar WHERE id = Bar.foo_id AND Bar.flag = TRUE;",
+        expected: Ok(Payload::Delete(0))
+    };
+
+    run!("CREATE TABLE Baz (flag BOOLEAN, label TEXT);");
+    run!("INSERT INTO Baz VALUES (FALSE, 'c');");
+
+    test! {
+        name: "comma separated USING tables",
+        sql: "DELETE FROM Foo USING Bar, Baz WHERE Foo.id = Bar.foo_id AND Bar.flag = Baz.flag AND Foo.name = Baz.label;",
+        expected: Ok(Payload::Delete(1))
+    };
+    test! {
+        sql: "SELECT * FROM Foo",
+        expected: Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "b".to_owned()
+        ))
+    };
+
+    test! {
+        name: "empty USING table deletes nothing",
+        sql: "DELETE FROM Foo USING (SELECT * FROM Bar WHERE foo_id > 100) AS Sub;",
+        expected: Ok(Payload::Delete(0))
+    };
+});
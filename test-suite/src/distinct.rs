@@ -0,0 +1,112 @@
+use {crate::*, gluesql_core::prelude::Value::*};
+
+test_case!(distinct, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            price INTEGER NULL
+        )"
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, price)
+        VALUES
+            (1, 'Seoul', 100),
+            (2, 'Busan', 300),
+            (3, 'Seoul', 200),
+            (4, 'Seoul', NULL),
+            (5, 'Busan', 300),
+            (6, 'Daegu', NULL);
+        "
+    );
+
+    test!(
+        "SELECT DISTINCT city FROM Item",
+        Ok(select!(
+            city
+            Str;
+            "Seoul".to_owned();
+            "Busan".to_owned();
+            "Daegu".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT city, price FROM Item ORDER BY id",
+        Ok(select_with_null!(
+            city                    | price;
+            Str("Seoul".to_owned())   I64(100);
+            Str("Busan".to_owned())   I64(300);
+            Str("Seoul".to_owned())   I64(200);
+            Str("Seoul".to_owned())   Null;
+            Str("Daegu".to_owned())   Null
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT price FROM Item WHERE price IS NULL",
+        Ok(select_with_null!(price; Null))
+    );
+
+    test!(
+        "SELECT DISTINCT city FROM Item ORDER BY city DESC LIMIT 2",
+        Ok(select!(
+            city
+            Str;
+            "Seoul".to_owned();
+            "Daegu".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT * FROM Item WHERE id > 10",
+        Ok(select!(id | city | price))
+    );
+
+    test!(
+        "SELECT DISTINCT ON (city) city, id FROM Item ORDER BY city, id DESC",
+        Ok(select!(
+            city              | id
+            Str               | I64;
+            "Busan".to_owned()  5;
+            "Daegu".to_owned()  6;
+            "Seoul".to_owned()  4
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT ON (price) id FROM Item ORDER BY price, id",
+        Ok(select!(
+            id
+            I64;
+            1;
+            3;
+            2;
+            4
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT COUNT(*) AS cnt FROM Item GROUP BY city",
+        Ok(select!(
+            cnt
+            I64;
+            3;
+            2;
+            1
+        ))
+    );
+
+    test!(
+        "SELECT DISTINCT ON (c) city AS c FROM Item",
+        Ok(select!(
+            c
+            Str;
+            "Seoul".to_owned();
+            "Busan".to_owned();
+            "Daegu".to_owned()
+        ))
+    );
+});
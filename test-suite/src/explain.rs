@@ -0,0 +1,276 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{ExplainError, Result},
+        prelude::{Payload, Value::*},
+    },
+};
+
+pub fn query_plan(lines: &[&str]) -> Result<Payload> {
+    Ok(Payload::Select {
+        labels: vec!["QUERY PLAN".to_owned()],
+        rows: lines
+            .iter()
+            .map(|line| vec![Str((*line).to_owned())])
+            .collect(),
+    })
+}
+
+test_case!(explain, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);");
+    run!("CREATE TABLE Review (item_id INTEGER, score INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 'apple', 3), (2, 'banana', 5);");
+    run!("INSERT INTO Review VALUES (1, 4), (1, 5), (2, 3);");
+
+    let test_cases = [
+        (
+            "EXPLAIN SELECT * FROM Item",
+            vec!["Project: *", "  -> Seq Scan on Item"],
+        ),
+        (
+            "EXPLAIN SELECT name FROM Item WHERE id = 1",
+            vec!["Project: name", "  -> Primary Key Scan on Item: id = 1"],
+        ),
+        (
+            "EXPLAIN SELECT name FROM Item AS i WHERE price > 1 ORDER BY name DESC LIMIT 1 OFFSET 1",
+            vec![
+                "Limit: 1 OFFSET 1",
+                "  -> Sort: name DESC",
+                "    -> Project: name",
+                "      -> Filter: price > 1",
+                "        -> Seq Scan on Item AS i",
+            ],
+        ),
+        (
+            "
+            EXPLAIN
+            SELECT i.name, COUNT(*) FROM Item i
+            JOIN Review r ON r.item_id = i.id AND r.score > 3
+            GROUP BY i.name HAVING COUNT(*) > 1
+            ",
+            vec![
+                "Project: i.name AS name, COUNT(*)",
+                "  -> Aggregate: GROUP BY i.name HAVING COUNT(*) > 1",
+                "    -> Hash Join: INNER ON r.item_id = i.id",
+                "      -> Seq Scan on Item AS i",
+                "      -> Filter: r.score > 3",
+                "        -> Seq Scan on Review AS r",
+            ],
+        ),
+        (
+            "EXPLAIN SELECT * FROM Item LEFT JOIN Review ON Review.score > Item.price",
+            vec![
+                "Project: *",
+                "  -> Nested Loop Join: LEFT OUTER ON Review.score > Item.price",
+                "    -> Seq Scan on Item",
+                "    -> Seq Scan on Review",
+            ],
+        ),
+        (
+            "
+            EXPLAIN
+            WITH Cheap AS (SELECT id FROM Item WHERE price < 5)
+            SELECT DISTINCT * FROM Cheap
+            ",
+            vec![
+                "Distinct",
+                "  -> Project: *",
+                "    -> Subquery Scan on Cheap",
+                "      -> Project: id",
+                "        -> Filter: price < 5",
+                "          -> Seq Scan on Item",
+            ],
+        ),
+        (
+            "
+            EXPLAIN
+            WITH RECURSIVE Nums (n) AS (
+                SELECT 1 UNION ALL SELECT n + 1 FROM Nums WHERE n < 3
+            )
+            SELECT n FROM Nums
+            ",
+            vec![
+                "Project: n",
+                "  -> Subquery Scan on Nums",
+                "    -> Project: *",
+                "      -> Recursive CTE Nums",
+                "        -> Set Operation: UNION ALL",
+                "          -> Project: 1",
+                "            -> Series Scan on Series: SERIES(1)",
+                "          -> Project: n + 1",
+                "            -> Filter: n < 3",
+                "              -> CTE Scan on Nums",
+                "      -> CTE Scan on Nums",
+            ],
+        ),
+        (
+            "EXPLAIN SELECT * FROM (SELECT id FROM Item) AS Sub UNION SELECT 1",
+            vec![
+                "Set Operation: UNION",
+                "  -> Project: *",
+                "    -> Subquery Scan on Sub",
+                "      -> Project: id",
+                "        -> Seq Scan on Item",
+                "  -> Project: 1",
+                "    -> Series Scan on Series: SERIES(1)",
+            ],
+        ),
+        (
+            "EXPLAIN INSERT INTO Item VALUES (3, 'carrot', 1), (4, 'durian', 9)",
+            vec!["Insert on Item", "  -> Values: 2 rows"],
+        ),
+        (
+            "EXPLAIN UPDATE Item SET price = price + 1 WHERE name = 'apple'",
+            vec![
+                "Update on Item: price = price + 1",
+                "  -> Filter: name = 'apple'",
+                "    -> Seq Scan on Item",
+            ],
+        ),
+        (
+            "EXPLAIN DELETE FROM Item USING Review WHERE Review.item_id = Item.id",
+            vec![
+                "Delete on Item",
+                "  -> Hash Join: Review.item_id = Item.id",
+                "    -> Seq Scan on Item",
+                "    -> Seq Scan on Review",
+            ],
+        ),
+        (
+            "EXPLAIN UPDATE Item SET price = score FROM Review
+            WHERE Item.id = Review.item_id AND score > 3 AND price < score",
+            vec![
+                "Update on Item: price = score",
+                "  -> Hash Join: Review.item_id = Item.id AND price < score",
+                "    -> Seq Scan on Item",
+                "    -> Filter: score > 3",
+                "      -> Seq Scan on Review",
+            ],
+        ),
+        (
+            "EXPLAIN DELETE FROM Item USING Review WHERE Review.item_id > Item.id",
+            vec![
+                "Delete on Item",
+                "  -> Nested Loop Join: Review.item_id > Item.id",
+                "    -> Seq Scan on Item",
+                "    -> Seq Scan on Review",
+            ],
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, query_plan(&expected));
+    }
+
+    test!(
+        "SELECT id, name, price FROM Item",
+        Ok(select!(
+            id  | name               | price
+            I64 | Str                | I64;
+            1     "apple".to_owned()   3;
+            2     "banana".to_owned()  5
+        ))
+    );
+    test!(
+        "EXPLAIN DROP TABLE Item",
+        Err(ExplainError::UnsupportedStatement(r#"DROP TABLE "Item";"#.to_owned()).into())
+    );
+});
+
+test_case!(index, async move {
+    run!("CREATE TABLE Event (tenant_id INTEGER, created_at INTEGER, name TEXT);");
+    run!("INSERT INTO Event VALUES (1, 10, 'a'), (2, 20, 'b');");
+    run!("CREATE INDEX idx_created ON Event (tenant_id, created_at);");
+    run!("CREATE INDEX idx_name ON Event (name);");
+
+    let test_cases = [
+        (
+            "EXPLAIN SELECT * FROM Event WHERE tenant_id = 1 AND created_at > 5",
+            vec![
+                "Project: *",
+                "  -> Index Scan using idx_created on Event: tenant_id = 1 AND created_at > 5",
+            ],
+        ),
+        (
+            "EXPLAIN SELECT * FROM Event WHERE name = 'a' AND created_at < 30",
+            vec![
+                "Project: *",
+                "  -> Filter: created_at < 30",
+                "    -> Index Scan using idx_name on Event: name = 'a'",
+            ],
+        ),
+        (
+            "EXPLAIN SELECT * FROM Event ORDER BY name DESC",
+            vec![
+                "Project: *",
+                "  -> Index Scan using idx_name on Event (DESC)",
+            ],
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, query_plan(&expected));
+    }
+});
+
+test_case!(analyze, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 'apple', 3), (2, 'banana', 5), (3, 'carrot', 7);");
+
+    let sql = "EXPLAIN ANALYZE SELECT name FROM Item WHERE price > 3 ORDER BY name LIMIT 1";
+    let (labels, rows) = match run!(sql) {
+        Payload::Select { labels, rows } => (labels, rows),
+        payload => panic!("unexpected payload: {payload:?}"),
+    };
+
+    assert_eq!(labels, ["QUERY PLAN", "ROWS IN", "ROWS OUT", "ELAPSED MS"]);
+
+    let expected = [
+        ("Limit: 1", I64(1), I64(1)),
+        ("  -> Sort: name", I64(2), I64(1)),
+        ("    -> Project: name", I64(2), I64(2)),
+        ("      -> Filter: price > 3", I64(3), I64(2)),
+        ("        -> Seq Scan on Item", Null, I64(3)),
+    ];
+    assert_eq!(rows.len(), expected.len());
+
+    for (row, (plan, rows_in, rows_out)) in rows.into_iter().zip(expected) {
+        assert_eq!(row[..3], [Str(plan.to_owned()), rows_in, rows_out]);
+        assert!(matches!(row[3], F64(elapsed) if elapsed >= 0.0));
+    }
+
+    let sql = "EXPLAIN ANALYZE
+        WITH Cheap AS (SELECT id FROM Item WHERE price < 6)
+        SELECT Sub.name FROM Cheap JOIN (SELECT id, name FROM Item) AS Sub ON Cheap.id = Sub.id";
+    let rows = match run!(sql) {
+        Payload::Select { rows, .. } => rows,
+        payload => panic!("unexpected payload: {payload:?}"),
+    };
+
+    let expected = [
+        ("Project: Sub.name AS name", I64(2), I64(2)),
+        (
+            "  -> Nested Loop Join: INNER ON Cheap.id = Sub.id",
+            I64(2),
+            I64(2),
+        ),
+        ("    -> Subquery Scan on Cheap", I64(2), I64(2)),
+        ("      -> Project: id", I64(2), I64(2)),
+        ("        -> Filter: price < 6", I64(3), I64(2)),
+        ("          -> Seq Scan on Item", Null, I64(3)),
+        ("    -> Subquery Scan on Sub", Null, Null),
+        ("      -> Project: id, name", I64(6), I64(6)),
+        ("        -> Seq Scan on Item", Null, I64(6)),
+    ];
+    assert_eq!(rows.len(), expected.len());
+
+    for (row, (plan, rows_in, rows_out)) in rows.into_iter().zip(expected) {
+        assert_eq!(row[..3], [Str(plan.to_owned()), rows_in, rows_out]);
+    }
+
+    test!(
+        "EXPLAIN ANALYZE DELETE FROM Item",
+        Err(ExplainError::AnalyzeUnsupportedStatement(r#"DELETE FROM "Item";"#.to_owned()).into())
+    );
+    test!("SELECT COUNT(*) FROM Item", Ok(select!("COUNT(*)" I64; 3)));
+});
@@ -0,0 +1,445 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{ForeignKeyError, TranslateError, UpdateError, ValidateError, ValueError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(foreign_key, async move {
+    run!("CREATE TABLE Parent (id INTEGER PRIMARY KEY, code TEXT UNIQUE, name TEXT);");
+    run!("CREATE TABLE NoKey (id INTEGER, name TEXT);");
+
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES Missing (id));",
+        Err(ForeignKeyError::ReferencedTableNotFound("Missing".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES NoKey);",
+        Err(ForeignKeyError::ReferencedPrimaryKeyNotFound("NoKey".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES Parent (name));",
+        Err(ForeignKeyError::ReferencedColumnNotUnique("Parent".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES Parent (uid));",
+        Err(ForeignKeyError::ReferencedColumnNotFound("uid".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id TEXT REFERENCES Parent);",
+        Err(ForeignKeyError::DataTypeMismatch("parent_id".to_owned(), "id".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, FOREIGN KEY (parent_id) REFERENCES Parent (id));",
+        Err(ForeignKeyError::ReferencingColumnNotFound("parent_id".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES Parent ON DELETE SET DEFAULT);",
+        Err(TranslateError::UnsupportedReferentialAction("SET DEFAULT".to_owned()).into())
+    );
+
+    run!("INSERT INTO Parent VALUES (1, 'a', 'Alpha'), (2, 'b', 'Beta'), (3, 'c', 'Gamma');");
+    test!(
+        "CREATE TABLE Child (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER REFERENCES Parent ON DELETE CASCADE,
+            parent_code TEXT,
+            CONSTRAINT child_code FOREIGN KEY (parent_code) REFERENCES Parent (code)
+                ON DELETE SET NULL ON UPDATE CASCADE
+        );",
+        Ok(Payload::Create)
+    );
+
+    test!(
+        "INSERT INTO Child VALUES (1, 1, 'a'), (2, 1, 'b'), (3, 2, NULL), (4, NULL, 'c');",
+        Ok(Payload::Insert(4))
+    );
+    test!(
+        "INSERT INTO Child VALUES (5, 9, NULL);",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Child".to_owned(),
+            "Child_parent_id_fkey".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "INSERT INTO Child VALUES (5, 1, 'z');",
+        Err(
+            ForeignKeyError::ReferencedRowNotFound("Child".to_owned(), "child_code".to_owned())
+                .into()
+        )
+    );
+    test!(
+        "UPDATE Child SET parent_id = 9 WHERE id = 1;",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Child".to_owned(),
+            "Child_parent_id_fkey".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "UPDATE Child SET parent_id = 3 WHERE id = 4;",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "INSERT INTO Child VALUES (4, 2, 'b') ON CONFLICT (id) DO UPDATE SET parent_code = 'x';",
+        Err(
+            ForeignKeyError::ReferencedRowNotFound("Child".to_owned(), "child_code".to_owned())
+                .into()
+        )
+    );
+
+    test!(
+        "UPDATE Parent SET code = 'bb' WHERE id = 2;",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "SELECT id, parent_id, parent_code FROM Child ORDER BY id",
+        Ok(select_with_null!(
+            id     | parent_id | parent_code;
+            I64(1)   I64(1)      Str("a".to_owned());
+            I64(2)   I64(1)      Str("bb".to_owned());
+            I64(3)   I64(2)      Null;
+            I64(4)   I64(3)      Str("c".to_owned())
+        ))
+    );
+
+    test!("DELETE FROM Parent WHERE id = 2;", Ok(Payload::Delete(1)));
+    test!(
+        "SELECT id, parent_id, parent_code FROM Child ORDER BY id",
+        Ok(select_with_null!(
+            id     | parent_id | parent_code;
+            I64(1)   I64(1)      Str("a".to_owned());
+            I64(2)   I64(1)      Null;
+            I64(4)   I64(3)      Str("c".to_owned())
+        ))
+    );
+
+    run!("CREATE TABLE GrandChild (id INTEGER, child_id INTEGER REFERENCES Child ON DELETE RESTRICT);");
+    run!("INSERT INTO GrandChild VALUES (1, 4);");
+    test!(
+        "DELETE FROM Parent WHERE id = 3;",
+        Err(ForeignKeyError::TableReferenced(
+            "Child".to_owned(),
+            "GrandChild_child_id_fkey".to_owned(),
+            "GrandChild".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "SELECT id FROM Parent ORDER BY id",
+        Ok(select!(
+            id
+            I64;
+            1;
+            3
+        ))
+    );
+    test!("DELETE FROM GrandChild;", Ok(Payload::Delete(1)));
+    test!("DELETE FROM Parent WHERE id = 3;", Ok(Payload::Delete(1)));
+    test!(
+        "SELECT id FROM Child ORDER BY id",
+        Ok(select!(
+            id
+            I64;
+            1;
+            2
+        ))
+    );
+
+    run!("CREATE TABLE Strict (id INTEGER, code TEXT NOT NULL REFERENCES Parent (code) ON DELETE SET NULL);");
+    run!("INSERT INTO Strict VALUES (1, 'a');");
+    test!(
+        "UPDATE Parent SET code = 'aa' WHERE id = 1;",
+        Err(ForeignKeyError::TableReferenced(
+            "Parent".to_owned(),
+            "Strict_code_fkey".to_owned(),
+            "Strict".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "DELETE FROM Parent WHERE id = 1;",
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+    test!(
+        "SELECT id, parent_code FROM Child ORDER BY id",
+        Ok(select_with_null!(
+            id     | parent_code;
+            I64(1)   Str("a".to_owned());
+            I64(2)   Null
+        ))
+    );
+});
+
+test_case!(self_reference, async move {
+    run!(
+        "CREATE TABLE Node (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER REFERENCES Node (id) ON DELETE CASCADE
+        );"
+    );
+
+    test!(
+        "INSERT INTO Node VALUES (1, NULL), (2, 1), (3, 2), (4, 4), (5, NULL);",
+        Ok(Payload::Insert(5))
+    );
+    test!(
+        "INSERT INTO Node VALUES (6, 7);",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Node".to_owned(),
+            "Node_parent_id_fkey".to_owned()
+        )
+        .into())
+    );
+    test!("DELETE FROM Node WHERE id = 1;", Ok(Payload::Delete(1)));
+    test!(
+        "SELECT id FROM Node ORDER BY id",
+        Ok(select!(
+            id
+            I64;
+            4;
+            5
+        ))
+    );
+    test!("DELETE FROM Node;", Ok(Payload::Delete(2)));
+});
+
+test_case!(cascade_key, async move {
+    run!("CREATE TABLE Parent (id INTEGER PRIMARY KEY, code INTEGER UNIQUE);");
+    run!(
+        "CREATE TABLE Child (
+            code INTEGER PRIMARY KEY REFERENCES Parent (code) ON DELETE CASCADE ON UPDATE CASCADE,
+            name TEXT
+        );"
+    );
+    run!("INSERT INTO Parent VALUES (1, 10), (2, 20);");
+    run!("INSERT INTO Child VALUES (10, 'a'), (20, 'b');");
+
+    test!(
+        "UPDATE Parent SET code = 11 WHERE id = 1;",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("code".to_owned()).into())
+    );
+    test!(
+        "SELECT code, name FROM Child WHERE code = 10",
+        Ok(select!(
+            code | name
+            I64  | Str;
+            10     "a".to_owned()
+        ))
+    );
+    test!("DELETE FROM Parent WHERE id = 1;", Ok(Payload::Delete(1)));
+    test!(
+        "SELECT code, name FROM Child",
+        Ok(select!(
+            code | name
+            I64  | Str;
+            20     "b".to_owned()
+        ))
+    );
+
+    run!("CREATE TABLE Pair (id INTEGER PRIMARY KEY, x INTEGER, y INTEGER, UNIQUE (x, y));");
+    run!(
+        "CREATE TABLE Entry (
+            id INTEGER PRIMARY KEY,
+            a INTEGER UNIQUE,
+            b INTEGER,
+            FOREIGN KEY (a, b) REFERENCES Pair (x, y) ON UPDATE CASCADE
+        );"
+    );
+    run!("INSERT INTO Pair VALUES (1, 1, 1), (2, 2, 2);");
+    run!("INSERT INTO Entry VALUES (1, 1, 1), (2, 2, 2);");
+
+    test!(
+        "UPDATE Pair SET x = 2 WHERE id = 1;",
+        Err(ValidateError::DuplicateEntryOnUniqueField(I64(2), "a".to_owned()).into())
+    );
+    test!(
+        "UPDATE Pair SET x = 3 WHERE id = 1;",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "SELECT id, a, b FROM Entry",
+        Ok(select!(
+            id  | a   | b
+            I64 | I64 | I64;
+            1     3     1;
+            2     2     2
+        ))
+    );
+});
+
+test_case!(drop_table, async move {
+    run!("CREATE TABLE Parent (id INTEGER PRIMARY KEY);");
+    run!("CREATE TABLE Child (id INTEGER, parent_id INTEGER REFERENCES Parent);");
+    run!("INSERT INTO Parent VALUES (1);");
+    run!("INSERT INTO Child VALUES (1, 1);");
+
+    test!(
+        "ALTER TABLE Child DROP COLUMN parent_id;",
+        Err(ForeignKeyError::ColumnReferenced(
+            "parent_id".to_owned(),
+            "Child_parent_id_fkey".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "ALTER TABLE Parent RENAME COLUMN id TO pid;",
+        Err(
+            ForeignKeyError::ColumnReferenced("id".to_owned(), "Child_parent_id_fkey".to_owned())
+                .into()
+        )
+    );
+    test!(
+        "ALTER TABLE Parent RENAME TO Base;",
+        Err(ForeignKeyError::TableReferenced(
+            "Parent".to_owned(),
+            "Child_parent_id_fkey".to_owned(),
+            "Child".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "DROP TABLE Parent;",
+        Err(ForeignKeyError::TableReferenced(
+            "Parent".to_owned(),
+            "Child_parent_id_fkey".to_owned(),
+            "Child".to_owned()
+        )
+        .into())
+    );
+    test!("DROP TABLE Parent CASCADE;", Ok(Payload::DropTable));
+    test!("INSERT INTO Child VALUES (2, 2);", Ok(Payload::Insert(1)));
+
+    run!("CREATE TABLE Parent (id INTEGER PRIMARY KEY);");
+    run!("CREATE TABLE Other (parent_id INTEGER REFERENCES Parent);");
+    test!("DROP TABLE Parent, Other;", Ok(Payload::DropTable));
+});
+
+test_case!(composite, async move {
+    run!(
+        "CREATE TABLE Shelf (
+            store INTEGER,
+            shelf INTEGER,
+            code TEXT,
+            serial INTEGER,
+            PRIMARY KEY (store, shelf),
+            UNIQUE (code, serial)
+        );"
+    );
+
+    test!(
+        "CREATE TABLE Item (id INTEGER, store INTEGER REFERENCES Shelf (store));",
+        Err(ForeignKeyError::ReferencedColumnNotUnique("Shelf".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Item (id INTEGER, store INTEGER REFERENCES Shelf);",
+        Err(ForeignKeyError::ColumnCountMismatch("Item_store_fkey".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Item (
+            id INTEGER,
+            store INTEGER,
+            FOREIGN KEY (store, store) REFERENCES Shelf (store, store)
+        );",
+        Err(ForeignKeyError::ReferencedColumnNotUnique("Shelf".to_owned()).into())
+    );
+
+    run!(
+        "CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            store INTEGER,
+            shelf INTEGER,
+            FOREIGN KEY (store, shelf) REFERENCES Shelf (store, shelf) ON DELETE CASCADE
+        );"
+    );
+    run!(
+        "CREATE TABLE Slot (
+            id INTEGER PRIMARY KEY,
+            shelf INTEGER,
+            store INTEGER,
+            FOREIGN KEY (shelf, store) REFERENCES Shelf (shelf, store)
+        );"
+    );
+    run!(
+        "CREATE TABLE Tag (
+            id INTEGER PRIMARY KEY,
+            serial INTEGER,
+            code TEXT,
+            FOREIGN KEY (serial, code) REFERENCES Shelf (serial, code)
+                ON DELETE SET NULL ON UPDATE CASCADE
+        );"
+    );
+
+    run!("INSERT INTO Shelf VALUES (1, 1, 'x', 1), (1, 2, 'x', 2), (2, 1, 'y', 1);");
+    test!(
+        "INSERT INTO Item VALUES (1, 1, 1), (2, 1, 2), (3, 2, 1), (4, NULL, 9);",
+        Ok(Payload::Insert(4))
+    );
+    test!(
+        "INSERT INTO Item VALUES (5, 2, 2);",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Item".to_owned(),
+            "Item_store_shelf_fkey".to_owned()
+        )
+        .into())
+    );
+    test!("INSERT INTO Slot VALUES (1, 2, 1);", Ok(Payload::Insert(1)));
+    test!(
+        "INSERT INTO Slot VALUES (2, 2, 2);",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Slot".to_owned(),
+            "Slot_shelf_store_fkey".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "INSERT INTO Tag VALUES (1, 1, 'x'), (2, 1, 'y');",
+        Ok(Payload::Insert(2))
+    );
+    test!(
+        "INSERT INTO Tag VALUES (3, 2, 'y');",
+        Err(ForeignKeyError::ReferencedRowNotFound(
+            "Tag".to_owned(),
+            "Tag_serial_code_fkey".to_owned()
+        )
+        .into())
+    );
+
+    test!(
+        "UPDATE Shelf SET serial = 5 WHERE store = 2;",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "DELETE FROM Shelf WHERE store = 1 AND shelf = 2;",
+        Err(ForeignKeyError::TableReferenced(
+            "Shelf".to_owned(),
+            "Slot_shelf_store_fkey".to_owned(),
+            "Slot".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "DELETE FROM Shelf WHERE store = 1 AND shelf = 1;",
+        Ok(Payload::Delete(1))
+    );
+    test!(
+        "SELECT id FROM Item ORDER BY id",
+        Ok(select!(
+            id
+            I64;
+            2;
+            3;
+            4
+        ))
+    );
+    test!(
+        "SELECT id, serial, code FROM Tag ORDER BY id",
+        Ok(select_with_null!(
+            id     | serial | code;
+            I64(1)   Null     Null;
+            I64(2)   I64(5)   Str("y".to_owned())
+        ))
+    );
+});
@@ -0,0 +1,156 @@
+use {
+    crate::*,
+    gluesql_core::{ast::IndexOperator::*, prelude::*},
+    Value::*,
+};
+
+test_case!(composite, async move {
+    run!(
+        "
+CREATE TABLE Event (
+    tenant_id INTEGER,
+    created_at INTEGER,
+    name TEXT
+)"
+    );
+
+    run!(
+        "
+        INSERT INTO Event
+            (tenant_id, created_at, name)
+        VALUES
+            (1, 10, 'a'),
+            (2, 5,  'b'),
+            (1, 30, 'c'),
+            (1, 20, 'd'),
+            (2, 15, 'e'),
+            (3, 10, 'f');
+    "
+    );
+
+    test!(
+        "CREATE INDEX idx_created ON Event (tenant_id, created_at)",
+        Ok(Payload::CreateIndex)
+    );
+    test!(
+        "CREATE INDEX idx_name ON Event (tenant_id, name)",
+        Ok(Payload::CreateIndex)
+    );
+
+    test!(
+        "SHOW INDEXES FROM Event",
+        Ok(select!(
+            TABLE_NAME         | INDEX_NAME               | ORDER             | EXPRESSION                          | UNIQUENESS;
+            Str                | Str                      | Str               | Str                                 | Bool;
+            "Event".to_owned()   "idx_created".to_owned()   "BOTH".to_owned()   "tenant_id, created_at".to_owned()    false;
+            "Event".to_owned()   "idx_name".to_owned()      "BOTH".to_owned()   "tenant_id, name".to_owned()          false
+        ))
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           20           "d".to_owned()
+        )),
+        idx!(idx_created, ["1"], Eq, "20"),
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at = 20"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           20           "d".to_owned();
+            1           30           "c".to_owned()
+        )),
+        idx!(idx_created, ["1"], Gt, "10"),
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at > 10"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            2           5            "b".to_owned();
+            2           15           "e".to_owned()
+        )),
+        idx!(idx_created, ["2"], LtEq, "15"),
+        "SELECT * FROM Event WHERE 15 >= created_at AND (tenant_id = 2)"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           20           "d".to_owned()
+        )),
+        idx!(idx_created, ["1"], Lt, "30"),
+        "
+        SELECT * FROM Event
+        WHERE
+            name <> 'a'
+            AND 1 = tenant_id
+            AND created_at < 30
+        "
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           30           "c".to_owned()
+        )),
+        idx!(idx_name, ["1"], Eq, "'c'"),
+        "SELECT * FROM Event WHERE name = 'c' AND tenant_id = 1"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           10           "a".to_owned();
+            1           20           "d".to_owned();
+            1           30           "c".to_owned()
+        )),
+        idx!(idx_created, Eq, "1"),
+        "SELECT * FROM Event WHERE tenant_id = 1"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            2           5            "b".to_owned();
+            2           15           "e".to_owned();
+            3           10           "f".to_owned()
+        )),
+        idx!(idx_created, Gt, "1"),
+        "SELECT * FROM Event WHERE tenant_id > 1"
+    );
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           10           "a".to_owned();
+            3           10           "f".to_owned()
+        )),
+        idx!(),
+        "SELECT * FROM Event WHERE created_at = 10"
+    );
+
+    run!("UPDATE Event SET created_at = 25 WHERE name = 'a';");
+    run!("DELETE FROM Event WHERE name = 'c';");
+
+    test_idx!(
+        Ok(select!(
+            tenant_id | created_at | name
+            I64       | I64        | Str;
+            1           20           "d".to_owned();
+            1           25           "a".to_owned()
+        )),
+        idx!(idx_created, ["1"], GtEq, "20"),
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at >= 20"
+    );
+});
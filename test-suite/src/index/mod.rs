@@ -1,19 +1,25 @@
 mod and;
 mod basic;
+mod composite;
 mod expr;
 mod nested;
 mod null;
 mod order_by;
+mod primary_key;
+mod range;
 mod showindexes;
 mod value;
 
 pub use {
     and::and,
     basic::basic,
+    composite::composite,
     expr::expr,
     nested::nested,
     null::null,
     order_by::{order_by, order_by_multi},
+    primary_key::primary_key,
+    range::range,
     showindexes::showindexes,
     value::value,
 };
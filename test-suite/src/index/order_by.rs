@@ -1,4 +1,8 @@
-use {crate::*, gluesql_core::prelude::*, Value::*};
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::*},
+    Value::*,
+};
 
 test_case!(order_by, async move {
     run!(
@@ -85,6 +89,33 @@ CREATE TABLE Test (
         idx!(idx_num_desc, DESC),
         "SELECT * FROM Test where id < 4 ORDER BY num DESC"
     );
+
+    test_idx!(
+        Ok(select_with_null!(
+            id     | num    | name;
+            I64(3)   Null     s!("World");
+            I64(1)   I64(9)   s!("Wild");
+            I64(1)   I64(2)   s!("Hello")
+        )),
+        idx!(idx_num_desc, DESC),
+        "SELECT * FROM Test where id < 4 ORDER BY num DESC NULLS FIRST"
+    );
+
+    test_idx!(
+        Ok(select_with_null!(
+            id     | num    | name;
+            I64(1)   I64(9)   s!("Wild");
+            I64(1)   I64(2)   s!("Hello");
+            I64(3)   Null     s!("World")
+        )),
+        idx!(),
+        "SELECT * FROM Test where id < 4 ORDER BY num DESC NULLS LAST"
+    );
+
+    test!(
+        "CREATE INDEX idx_num_nulls ON Test (num NULLS FIRST)",
+        Err(TranslateError::IndexNullsFirstOrLastNotSupported.into())
+    );
 });
 
 test_case!(order_by_multi, async move {
@@ -0,0 +1,43 @@
+use {
+    crate::{explain::query_plan, *},
+    gluesql_core::prelude::Value::*,
+};
+
+test_case!(primary_key, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, price INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 10), (2, 20), (3, 30), (4, 40);");
+    run!("CREATE INDEX idx_price ON Item (price);");
+
+    // index scans leave the tables planned onto the primary key as they are
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE id = 3 AND price > 10",
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: price > 10",
+            "    -> Primary Key Scan on Item: id = 3",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item WHERE id = 3 AND price > 10",
+        Ok(select!(id I64; 3))
+    );
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE id < 3 AND price > 10",
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: price > 10",
+            "    -> Primary Key Scan on Item: id < 3",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item WHERE id < 3 AND price > 10",
+        Ok(select!(id I64; 2))
+    );
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE price > 10",
+        query_plan(&[
+            "Project: id",
+            "  -> Index Scan using idx_price on Item: price > 10",
+        ])
+    );
+});
@@ -0,0 +1,107 @@
+use {
+    crate::{explain::query_plan, *},
+    gluesql_core::{ast::IndexOperator::*, prelude::*},
+    Value::*,
+};
+
+test_case!(range, async move {
+    run!("CREATE TABLE Event (id INTEGER, ts INTEGER NULL, name TEXT);");
+    run!(
+        "
+        INSERT INTO Event VALUES
+            (1, 1, 'a'),
+            (2, 2, 'b'),
+            (3, 3, 'a'),
+            (4, 4, 'a'),
+            (5, 5, 'b'),
+            (6, 6, 'a'),
+            (7, NULL, 'a');
+    "
+    );
+    run!("CREATE INDEX idx_ts ON Event (ts);");
+
+    test_idx!(
+        Ok(select!(id I64; 3; 4; 5)),
+        idx!(idx_ts, Included("3"), Excluded("6")),
+        "SELECT id FROM Event WHERE ts >= 3 AND ts < 6"
+    );
+    test_idx!(
+        Ok(select!(id I64; 3; 4; 5)),
+        idx!(idx_ts, Included("3"), Excluded("6")),
+        "SELECT id FROM Event WHERE 6 > ts AND name IS NOT NULL AND ts >= 3"
+    );
+    test_idx!(
+        Ok(select!(id I64; 4)),
+        idx!(idx_ts, Excluded("1"), Excluded("5")),
+        "SELECT id FROM Event WHERE ts > 1 AND ts > 3 AND ts < 5"
+    );
+    test_idx!(
+        Ok(select!(id I64; 2; 3; 4)),
+        idx!(idx_ts, Included("2"), Included("4")),
+        "SELECT id FROM Event WHERE ts BETWEEN 2 AND 4"
+    );
+    test_idx!(
+        Ok(select!(id)),
+        idx!(idx_ts, Included("5"), Included("2")),
+        "SELECT id FROM Event WHERE ts BETWEEN 5 AND 2"
+    );
+    test_idx!(
+        Ok(select!(id I64; 1; 6)),
+        idx!(),
+        "SELECT id FROM Event WHERE ts NOT BETWEEN 2 AND 5"
+    );
+    test_idx!(
+        Ok(select!(id I64; 1; 5)),
+        idx!(idx_ts, IN, ["5", "1", "5"]),
+        "SELECT id FROM Event WHERE ts IN (5, 1, 5)"
+    );
+    test_idx!(
+        Ok(select!(id I64; 5)),
+        idx!(idx_ts, IN, ["5", "1"]),
+        "SELECT id FROM Event WHERE ts IN (5, 1) AND name = 'b'"
+    );
+    test_idx!(
+        Ok(select!(id I64; 2; 3; 4; 5)),
+        idx!(),
+        "SELECT id FROM Event WHERE ts NOT IN (1, 6) AND id < 7"
+    );
+    test_idx!(
+        Ok(select!(id I64; 3; 4; 5; 6)),
+        idx!(idx_ts, Gt, "2"),
+        "SELECT id FROM Event WHERE ts > 2 AND ts IS NOT NULL"
+    );
+
+    test!(
+        "EXPLAIN SELECT id FROM Event WHERE ts >= 3 AND ts < 6",
+        query_plan(&[
+            "Project: id",
+            "  -> Index Scan using idx_ts on Event: ts >= 3 AND ts < 6",
+        ])
+    );
+    test!(
+        "EXPLAIN SELECT id FROM Event WHERE ts IN (5, 1) AND name = 'b'",
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: name = 'b'",
+            "    -> Index Scan using idx_ts on Event: ts IN (5, 1)",
+        ])
+    );
+
+    run!("CREATE INDEX idx_name_ts ON Event (name, ts);");
+
+    test!(
+        "EXPLAIN SELECT id FROM Event WHERE ts <= 4 AND name = 'a' AND ts > 1",
+        query_plan(&[
+            "Project: id",
+            "  -> Index Scan using idx_name_ts on Event: name = 'a' AND ts > 1 AND ts <= 4",
+        ])
+    );
+    test!(
+        "SELECT id FROM Event WHERE ts <= 4 AND name = 'a' AND ts > 1",
+        Ok(select!(id I64; 3; 4))
+    );
+    test!(
+        "SELECT id FROM Event WHERE name = 'a' AND ts IN (6, 2, 3)",
+        Ok(select!(id I64; 3; 6))
+    );
+});
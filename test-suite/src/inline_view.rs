@@ -235,19 +235,29 @@ test_case!(inline_view, async move {
             )),
         ),
         (
-            // unsupported implicit join
+            // implicit join with inline view referencing the preceding table
             "SELECT *
             FROM OuterTable, (
-                    SELECT id
+                    SELECT id AS inner_id
                     FROM InnerTable
                     WHERE InnerTable.id = OuterTable.id
                 ) AS InlineView",
-            Err(TranslateError::TooManyTables.into()),
+            Ok(select!(
+                id  | name                 | inner_id
+                I64 | Str                  | I64;
+                1     "WORKS!".to_owned()    1;
+                2     "EXTRA".to_owned()     2
+            )),
         ),
         (
-            // unsupported select distinct
-            "SELECT DISTINCT id FROM OuterTable",
-            Err(TranslateError::SelectDistinctNotSupported.into()),
+            // inline view with select distinct
+            "SELECT * FROM (SELECT DISTINCT name FROM InnerTable) AS InlineView",
+            Ok(select!(
+                name
+                Str;
+                "GLUE".to_owned();
+                "SQL".to_owned()
+            )),
         ),
         (
             // inline view subquery + join with inline view
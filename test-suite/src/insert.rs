@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::InsertError,
+        error::{InsertError, UpdateError, ValidateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -88,3 +88,138 @@ CREATE TABLE Test (
         ))
     };
 });
+
+test_case!(on_conflict, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, code TEXT UNIQUE, qty INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 'a', 10), (2, 'b', 20);");
+
+    test! {
+        name: "DO NOTHING skips conflicting rows",
+        sql: "INSERT INTO Item VALUES (1, 'x', 5), (3, 'c', 30) ON CONFLICT (id) DO NOTHING;",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        name: "DO UPDATE with EXCLUDED",
+        sql: "
+            INSERT INTO Item VALUES (1, 'a', 5)
+            ON CONFLICT (id) DO UPDATE SET qty = Item.qty + EXCLUDED.qty;
+        ",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        name: "DO UPDATE only when WHERE passes",
+        sql: "
+            INSERT INTO Item VALUES (2, 'b', 1), (3, 'c', 100)
+            ON CONFLICT (id) DO UPDATE SET qty = excluded.qty WHERE excluded.qty > Item.qty;
+        ",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        name: "conflict on UNIQUE column",
+        sql: "INSERT INTO Item VALUES (4, 'a', 1) ON CONFLICT (code) DO UPDATE SET qty = 0;",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        name: "DO NOTHING without conflict target",
+        sql: "INSERT INTO Item VALUES (5, 'b', 1), (6, 'f', 6) ON CONFLICT DO NOTHING;",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        name: "DO NOTHING on rows conflicting with each other",
+        sql: "INSERT INTO Item VALUES (7, 'g', 1), (7, 'h', 2) ON CONFLICT (id) DO NOTHING;",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        sql: "SELECT * FROM Item ORDER BY id;",
+        expected: Ok(select!(
+            id  | code                | qty
+            I64 | Str                 | I64;
+            1     "a".to_owned()        0;
+            2     "b".to_owned()        20;
+            3     "c".to_owned()        100;
+            6     "f".to_owned()        6;
+            7     "g".to_owned()        1
+        ))
+    };
+
+    run!("CREATE TABLE Tag (name TEXT UNIQUE, cnt INTEGER);");
+    run!("INSERT INTO Tag VALUES ('x', 1);");
+    test! {
+        name: "DO UPDATE on table without primary key",
+        sql: "
+            INSERT INTO Tag VALUES ('x', 1), ('y', 1)
+            ON CONFLICT (name) DO UPDATE SET cnt = Tag.cnt + 1;
+        ",
+        expected: Ok(Payload::Insert(2))
+    };
+    test! {
+        sql: "SELECT * FROM Tag ORDER BY name;",
+        expected: Ok(select!(
+            name           | cnt
+            Str            | I64;
+            "x".to_owned()   2;
+            "y".to_owned()   1
+        ))
+    };
+
+    run!("CREATE TABLE Stock (id INTEGER PRIMARY KEY, qty INTEGER);");
+    run!("INSERT INTO Stock VALUES (1, 10), (2, 20), (3, 30);");
+    test! {
+        name: "DO UPDATE on rows looked up by primary key",
+        sql: "
+            INSERT INTO Stock VALUES (2, 5), (4, 40), (3, 1)
+            ON CONFLICT (id) DO UPDATE SET qty = Stock.qty + EXCLUDED.qty WHERE EXCLUDED.qty > 1;
+        ",
+        expected: Ok(Payload::Insert(2))
+    };
+    test! {
+        name: "DO NOTHING on rows looked up by primary key",
+        sql: "INSERT INTO Stock VALUES (1, 0), (5, 50), (5, 0) ON CONFLICT DO NOTHING;",
+        expected: Ok(Payload::Insert(1))
+    };
+    test! {
+        sql: "SELECT * FROM Stock ORDER BY id;",
+        expected: Ok(select!(
+            id  | qty
+            I64 | I64;
+            1     10;
+            2     25;
+            3     30;
+            4     40;
+            5     50
+        ))
+    };
+
+    run!("CREATE TABLE Logs;");
+    let error_cases = [
+        (
+            "INSERT INTO Item VALUES (8, 'i', 1), (8, 'j', 2) ON CONFLICT (id) DO UPDATE SET qty = 1;",
+            InsertError::ConflictRowAffectedTwice.into(),
+        ),
+        (
+            "INSERT INTO Stock VALUES (1, 1), (1, 2) ON CONFLICT (id) DO UPDATE SET qty = 0;",
+            InsertError::ConflictRowAffectedTwice.into(),
+        ),
+        (
+            "INSERT INTO Item VALUES (1, 'a', 1) ON CONFLICT (qty) DO NOTHING;",
+            InsertError::ConflictTargetNotUnique("qty".to_owned()).into(),
+        ),
+        (
+            "INSERT INTO Item VALUES (9, 'a', 1) ON CONFLICT (id) DO NOTHING;",
+            ValidateError::DuplicateEntryOnUniqueField(Str("a".to_owned()), "code".to_owned())
+                .into(),
+        ),
+        (
+            "INSERT INTO Item VALUES (1, 'a', 1) ON CONFLICT (id) DO UPDATE SET id = 10;",
+            UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into(),
+        ),
+        (
+            r#"INSERT INTO Logs VALUES ('{"a": 1}') ON CONFLICT DO NOTHING;"#,
+            InsertError::OnConflictOnSchemalessTable("Logs".to_owned()).into(),
+        ),
+    ];
+
+    for (sql, error) in error_cases {
+        test!(sql, Err(error));
+    }
+});
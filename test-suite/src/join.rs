@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{PlanError, TranslateError},
+        error::{PlanError, SelectError, TranslateError},
         prelude::*,
     },
     Value::*,
@@ -137,6 +137,16 @@ test_case!(join, async move {
         (15, "SELECT * FROM Player INNER JOIN Item ON Player.id = Item.player_id;"),
         (25, "SELECT * FROM Player p1 LEFT JOIN Player p2 ON 1 = 1"),
         (30, "SELECT * FROM Item INNER JOIN Item i2 ON i2.id IN (101, 103);"),
+        // comma separated tables
+        (75, "SELECT * FROM Item, Player"),
+        (15, "SELECT * FROM Item, Player WHERE Player.id = Item.player_id;"),
+        (7, "SELECT * FROM Item i, Player p WHERE p.id = i.player_id AND p.id = 1;"),
+        (5, "SELECT * FROM Item i, Player p WHERE i.player_id = p.id AND i.quantity = 1;"),
+        (15, "SELECT * FROM Item i1, Item i2, Player p
+            WHERE i1.id = i2.id AND p.id = i2.player_id;"),
+        (7, "SELECT * FROM Player p, Item i
+            LEFT JOIN Player p2 ON p2.id = i.player_id AND p2.id = 1
+            WHERE p.id = i.player_id AND p2.id IS NOT NULL;"),
     ];
 
     for (num, sql) in select_sqls {
@@ -262,12 +272,8 @@ test_case!(project, async move {
 
     let error_cases = [
         (
-            "SELECT * FROM TableA JOIN TableA USING (id);",
-            TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into(),
-        ),
-        (
-            "SELECT * FROM TableA CROSS JOIN TableA as A;",
-            TranslateError::UnsupportedJoinOperator("CrossJoin".to_owned()).into(),
+            "SELECT * FROM TableA CROSS APPLY TableA as A;",
+            TranslateError::UnsupportedJoinOperator("CrossApply".to_owned()).into(),
         ),
         (
             "SELECT id FROM Users JOIN Testers ON Users.id = Testers.id;",
@@ -287,8 +293,248 @@ test_case!(project, async move {
             PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
         ),
         (
-            "SELECT * FROM ProjectUser, ProjectItem",
-            TranslateError::TooManyTables.into(),
+            "SELECT * FROM Users, Testers RIGHT JOIN Users u2 ON u2.id = Testers.id",
+            TranslateError::OuterJoinAfterCommaNotSupported.into(),
+        ),
+    ];
+
+    for (sql, error) in error_cases {
+        test!(sql, Err(error));
+    }
+});
+
+test_case!(outer, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Item (id INTEGER, player_id INTEGER);");
+    run!("CREATE TABLE Empty (id INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno');");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (101, 1),
+            (102, 1),
+            (103, 3),
+            (104, 7),
+            (105, NULL);
+    "
+    );
+
+    let expected = || {
+        select_with_null!(
+            id     | id;
+            I64(1)   I64(101);
+            I64(1)   I64(102);
+            I64(3)   I64(103);
+            Null     I64(104);
+            Null     I64(105)
+        )
+    };
+    test!(
+        "SELECT p.id, i.id FROM Player p RIGHT JOIN Item i ON p.id = i.player_id",
+        Ok(expected())
+    );
+    test!(
+        "SELECT p.id, i.id FROM Player p
+        RIGHT OUTER JOIN Item i ON p.id = i.player_id OR 1 = 0",
+        Ok(expected())
+    );
+    test!(
+        "SELECT p.id, i.id FROM Player p RIGHT JOIN Item i ON p.id = i.player_id WHERE p.id = 1",
+        Ok(select!(
+            id  | id
+            I64 | I64;
+            1     101;
+            1     102
+        ))
+    );
+    test!(
+        "SELECT * FROM Player p RIGHT JOIN Item i ON p.id = i.player_id WHERE p.id IS NULL",
+        Ok(select_with_null!(
+            id   | name | id       | player_id;
+            Null   Null   I64(104)   I64(7);
+            Null   Null   I64(105)   Null
+        ))
+    );
+    test!(
+        "SELECT p.id, i.id FROM Player p FULL JOIN Item i ON p.id = i.player_id",
+        Ok(select_with_null!(
+            id     | id;
+            I64(1)   I64(101);
+            I64(1)   I64(102);
+            I64(2)   Null;
+            I64(3)   I64(103);
+            Null     I64(104);
+            Null     I64(105)
+        ))
+    );
+    test!(
+        "SELECT p.id, i.id FROM Player p
+        FULL OUTER JOIN Item i ON p.id = i.player_id AND i.id > 101",
+        Ok(select_with_null!(
+            id     | id;
+            I64(1)   I64(102);
+            I64(2)   Null;
+            I64(3)   I64(103);
+            Null     I64(101);
+            Null     I64(104);
+            Null     I64(105)
+        ))
+    );
+    test!(
+        "SELECT p.id AS pid, i.id AS iid, q.id AS qid FROM Player p
+        JOIN Item i ON p.id = i.player_id
+        RIGHT JOIN Player q ON q.id = p.id",
+        Ok(select_with_null!(
+            pid    | iid      | qid;
+            I64(1)   I64(101)   I64(1);
+            I64(1)   I64(102)   I64(1);
+            I64(3)   I64(103)   I64(3);
+            Null     Null       I64(2)
+        ))
+    );
+    test!(
+        "SELECT e.id, p.id FROM Empty e RIGHT JOIN Player p ON e.id = p.id",
+        Ok(select_with_null!(
+            id     | id;
+            Null     I64(1);
+            Null     I64(2);
+            Null     I64(3)
+        ))
+    );
+    test!(
+        "SELECT e.id, p.id FROM Empty e FULL JOIN Player p ON e.id = p.id WHERE p.id > 1",
+        Ok(select_with_null!(
+            id     | id;
+            Null     I64(2);
+            Null     I64(3)
+        ))
+    );
+
+    count!(15, "SELECT * FROM Player CROSS JOIN Item");
+    count!(0, "SELECT * FROM Empty CROSS JOIN Player");
+    test!(
+        "SELECT p.id, i.id FROM Player p CROSS JOIN Item i WHERE i.id = 101",
+        Ok(select!(
+            id  | id
+            I64 | I64;
+            1     101;
+            2     101;
+            3     101
+        ))
+    );
+});
+
+test_case!(using, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Badge (id INTEGER, name TEXT, badge TEXT);");
+    run!("CREATE TABLE Score (name TEXT, score INTEGER);");
+    run!("CREATE TABLE Logs;");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno');");
+    run!(
+        "
+        INSERT INTO Badge VALUES
+            (1, 'Taehoon', 'gold'),
+            (3, 'Jorn', 'silver'),
+            (4, 'Berry', 'bronze'),
+            (NULL, 'Nobody', 'none');
+    "
+    );
+    run!("INSERT INTO Score VALUES ('Taehoon', 100), ('Mike', 50);");
+
+    test!(
+        "SELECT * FROM Player JOIN Badge USING (id)",
+        Ok(select!(
+            id  | name | name | badge
+            I64 | Str  | Str  | Str;
+            1     "Taehoon".to_owned()   "Taehoon".to_owned()   "gold".to_owned();
+            3     "Jorno".to_owned()     "Jorn".to_owned()      "silver".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id, Player.id, Badge.id FROM Player JOIN Badge USING (id) WHERE id > 1",
+        Ok(select!(
+            id  | id  | id
+            I64 | I64 | I64;
+            3     3     3
+        ))
+    );
+    test!(
+        "SELECT Badge.* FROM Player JOIN Badge USING (id)",
+        Ok(select!(
+            id  | name | badge
+            I64 | Str  | Str;
+            1     "Taehoon".to_owned()   "gold".to_owned();
+            3     "Jorn".to_owned()      "silver".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id, Badge.id, badge FROM Player LEFT JOIN Badge USING (id)",
+        Ok(select_with_null!(
+            id     | id     | badge;
+            I64(1)   I64(1)   Str("gold".to_owned());
+            I64(2)   Null     Null;
+            I64(3)   I64(3)   Str("silver".to_owned())
+        ))
+    );
+    test!(
+        "SELECT id, Player.id, badge FROM Player RIGHT JOIN Badge USING (id)",
+        Ok(select_with_null!(
+            id     | id     | badge;
+            I64(1)   I64(1)   Str("gold".to_owned());
+            I64(3)   I64(3)   Str("silver".to_owned());
+            I64(4)   Null     Str("bronze".to_owned());
+            Null     Null     Str("none".to_owned())
+        ))
+    );
+    test!(
+        "SELECT * FROM Player FULL JOIN Badge USING (id)",
+        Ok(select_with_null!(
+            id     | name                     | name                     | badge;
+            I64(1)   Str("Taehoon".to_owned())  Str("Taehoon".to_owned())  Str("gold".to_owned());
+            I64(2)   Str("Mike".to_owned())     Null                       Null;
+            I64(3)   Str("Jorno".to_owned())    Str("Jorn".to_owned())     Str("silver".to_owned());
+            I64(4)   Null                       Str("Berry".to_owned())    Str("bronze".to_owned());
+            Null     Null                       Str("Nobody".to_owned())   Str("none".to_owned())
+        ))
+    );
+    test!(
+        "SELECT * FROM Player NATURAL JOIN Badge",
+        Ok(select!(
+            id  | name | badge
+            I64 | Str  | Str;
+            1     "Taehoon".to_owned()   "gold".to_owned()
+        ))
+    );
+    test!(
+        "SELECT * FROM Player NATURAL LEFT JOIN Badge",
+        Ok(select_with_null!(
+            id     | name                     | badge;
+            I64(1)   Str("Taehoon".to_owned())  Str("gold".to_owned());
+            I64(2)   Str("Mike".to_owned())     Null;
+            I64(3)   Str("Jorno".to_owned())    Null
+        ))
+    );
+    test!(
+        "SELECT * FROM Player NATURAL JOIN Badge NATURAL JOIN Score",
+        Ok(select!(
+            name | id  | badge | score
+            Str  | I64 | Str   | I64;
+            "Taehoon".to_owned()   1   "gold".to_owned()   100
+        ))
+    );
+    count!(
+        6,
+        "SELECT * FROM Player NATURAL JOIN Score AS s(player, score)"
+    );
+
+    let error_cases = [
+        (
+            "SELECT * FROM Player JOIN Badge USING (badge)",
+            SelectError::UsingColumnNotFound("badge".to_owned()).into(),
+        ),
+        (
+            "SELECT * FROM Player NATURAL JOIN Logs",
+            SelectError::NaturalJoinOnSchemaless.into(),
         ),
     ];
 
@@ -2,19 +2,24 @@
 
 pub mod aggregate;
 pub mod alter;
+pub mod analyze;
 pub mod arithmetic;
 pub mod ast_builder;
 pub mod basic;
 pub mod case;
 pub mod column_alias;
 pub mod concat;
+pub mod cte;
 pub mod custom_function;
 pub mod data_type;
 pub mod default;
 pub mod delete;
 pub mod dictionary;
 pub mod dictionary_index;
+pub mod distinct;
+pub mod explain;
 pub mod filter;
+pub mod foreign_key;
 pub mod function;
 pub mod index;
 pub mod inline_view;
@@ -22,16 +27,21 @@ pub mod insert;
 pub mod join;
 pub mod like_ilike;
 pub mod limit;
+pub mod materialized_view;
 pub mod metadata;
 pub mod migrate;
 pub mod nested_select;
 pub mod nullable;
 pub mod order_by;
 pub mod ordering;
+pub mod prepared;
 pub mod primary_key;
 pub mod project;
+pub mod returning;
 pub mod schemaless;
+pub mod sequence;
 pub mod series;
+pub mod set_operation;
 pub mod show_columns;
 pub mod synthesize;
 pub mod transaction;
@@ -40,6 +50,8 @@ pub mod unary_operator;
 pub mod update;
 pub mod validate;
 pub mod values;
+pub mod view;
+pub mod window;
 
 pub mod tester;
 
@@ -67,11 +79,20 @@ macro_rules! generate_store_tests {
             };
         }
         glue!(update, update::update);
+        glue!(update_from, update::from);
         glue!(insert, insert::insert);
+        glue!(insert_on_conflict, insert::on_conflict);
         glue!(delete, delete::delete);
+        glue!(delete_using, delete::using);
+        glue!(returning, returning::returning);
+        glue!(foreign_key, foreign_key::foreign_key);
+        glue!(foreign_key_self_reference, foreign_key::self_reference);
+        glue!(foreign_key_composite, foreign_key::composite);
+        glue!(foreign_key_cascade_key, foreign_key::cascade_key);
         glue!(basic, basic::basic);
         glue!(aggregate_avg, aggregate::avg::avg);
         glue!(aggregate_count, aggregate::count::count);
+        glue!(aggregate_distinct, aggregate::distinct::distinct);
         glue!(aggregate_group_by, aggregate::group_by::group_by);
         glue!(aggregate_max, aggregate::max::max);
         glue!(aggregate_min, aggregate::min::min);
@@ -88,9 +109,13 @@ macro_rules! generate_store_tests {
         glue!(drop_table, alter::drop_table);
         glue!(default, default::default);
         glue!(limit, limit::limit);
+        glue!(distinct, distinct::distinct);
         glue!(like_ilike, like_ilike::like_ilike);
         glue!(filter, filter::filter);
         glue!(inline_view, inline_view::inline_view);
+        glue!(set_operation, set_operation::set_operation);
+        glue!(cte, cte::cte);
+        glue!(window, window::window);
         glue!(values, values::values);
         glue!(unary_operator, unary_operator::unary_operator);
         glue!(function_upper_lower, function::upper_lower::upper_lower);
@@ -144,9 +169,16 @@ macro_rules! generate_store_tests {
         );
         glue!(join, join::join);
         glue!(join_project, join::project);
+        glue!(join_outer, join::outer);
+        glue!(join_using, join::using);
         glue!(migrate, migrate::migrate);
         glue!(nested_select, nested_select::nested_select);
+        glue!(explain, explain::explain);
+        glue!(explain_analyze, explain::analyze);
+        glue!(prepared, prepared::prepared);
         glue!(primary_key, primary_key::primary_key);
+        glue!(primary_key_composite, primary_key::composite);
+        glue!(primary_key_range, primary_key::range);
         glue!(series, series::series);
         glue!(nullable, nullable::nullable);
         glue!(nullable_text, nullable::nullable_text);
@@ -177,6 +209,8 @@ macro_rules! generate_store_tests {
         glue!(point, data_type::point::point);
         glue!(synthesize, synthesize::synthesize);
         glue!(validate_unique, validate::unique::unique);
+        glue!(validate_unique_composite, validate::unique::composite);
+        glue!(validate_check, validate::check::check);
         glue!(validate_types, validate::types::types);
         glue!(function_extract, function::extract::extract);
         glue!(function_radians, function::radians::radians);
@@ -274,6 +308,10 @@ macro_rules! generate_alter_table_tests {
 
         glue!(alter_table_rename, alter::alter_table_rename);
         glue!(alter_table_add_drop, alter::alter_table_add_drop);
+        glue!(foreign_key_drop_table, foreign_key::drop_table);
+        glue!(validate_check_alter_table, validate::check::alter_table);
+        glue!(validate_unique_alter_table, validate::unique::alter_table);
+        glue!(materialized_view, materialized_view::materialized_view);
     };
 }
 
@@ -290,6 +328,46 @@ macro_rules! generate_custom_function_tests {
     };
 }
 
+#[macro_export]
+macro_rules! generate_view_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(view, view::view);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_sequence_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(sequence, sequence::sequence);
+        glue!(identity, sequence::identity);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_statistics_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(analyze, analyze::analyze);
+    };
+}
+
 #[macro_export]
 macro_rules! generate_index_tests {
     ($test: meta, $storage: ident) => {
@@ -301,12 +379,17 @@ macro_rules! generate_index_tests {
 
         glue!(index_basic, index::basic);
         glue!(index_and, index::and);
+        glue!(index_composite, index::composite);
         glue!(index_nested, index::nested);
         glue!(index_null, index::null);
         glue!(index_expr, index::expr);
         glue!(index_value, index::value);
         glue!(index_order_by, index::order_by);
         glue!(index_order_by_multi, index::order_by_multi);
+        glue!(index_primary_key, index::primary_key);
+        glue!(index_range, index::range);
+        glue!(index_explain, explain::index);
+        glue!(index_analyze, analyze::index);
         glue!(showindexes, index::showindexes);
         glue!(dictionary_index, dictionary_index::ditionary_index);
     };
@@ -327,6 +410,13 @@ macro_rules! generate_transaction_tests {
             transaction::create_drop_table
         );
         glue!(transaction_dictionary, transaction::dictionary);
+        glue!(
+            transaction_materialized_view,
+            transaction::materialized_view
+        );
+        glue!(transaction_sequence, transaction::sequence);
+        glue!(transaction_analyze, transaction::analyze);
+        glue!(transaction_view, transaction::view);
     };
 }
 
@@ -0,0 +1,177 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{AlterError, ExecuteError, FetchError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(materialized_view, async move {
+    run!("CREATE TABLE Sale (id INTEGER, region TEXT, amount INTEGER);");
+    run!(
+        "
+        INSERT INTO Sale VALUES
+            (1, 'east', 100),
+            (2, 'west', 200),
+            (3, 'east', 50);
+    "
+    );
+
+    test!(
+        "CREATE MATERIALIZED VIEW RegionTotal AS
+            SELECT region, SUM(amount) AS total FROM Sale GROUP BY region",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT * FROM RegionTotal ORDER BY region",
+        Ok(select!(
+            region            | total
+            Str               | I64;
+            "east".to_owned()   150;
+            "west".to_owned()   200
+        ))
+    );
+    test!(
+        "SHOW COLUMNS FROM RegionTotal",
+        Ok(Payload::ShowColumns(vec![
+            ("region".to_owned(), DataType::Text),
+            ("total".to_owned(), DataType::Int),
+        ]))
+    );
+
+    run!("INSERT INTO Sale VALUES (4, 'north', 10), (5, 'west', 25);");
+    test!(
+        "SELECT * FROM RegionTotal ORDER BY region",
+        Ok(select!(
+            region            | total
+            Str               | I64;
+            "east".to_owned()   150;
+            "west".to_owned()   200
+        ))
+    );
+    test!(
+        "REFRESH MATERIALIZED VIEW RegionTotal",
+        Ok(Payload::RefreshMaterializedView)
+    );
+    test!(
+        "SELECT * FROM RegionTotal ORDER BY region",
+        Ok(select!(
+            region             | total
+            Str                | I64;
+            "east".to_owned()    150;
+            "north".to_owned()   10;
+            "west".to_owned()    225
+        ))
+    );
+
+    test!(
+        "CREATE MATERIALIZED VIEW Named (sale_id) AS SELECT id, amount FROM Sale WHERE id < 3",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT sale_id, amount FROM Named ORDER BY sale_id",
+        Ok(select!(
+            sale_id | amount
+            I64     | I64;
+            1         100;
+            2         200
+        ))
+    );
+    test!(
+        "SELECT OBJECT_NAME, OBJECT_TYPE FROM GLUE_OBJECTS ORDER BY OBJECT_NAME",
+        Ok(select!(
+            OBJECT_NAME                | OBJECT_TYPE
+            Str                        | Str;
+            "Named".to_owned()           "MATERIALIZED VIEW".to_owned();
+            "RegionTotal".to_owned()     "MATERIALIZED VIEW".to_owned();
+            "Sale".to_owned()            "TABLE".to_owned()
+        ))
+    );
+
+    test!(
+        "CREATE MATERIALIZED VIEW Typed AS
+            SELECT
+                S.id,
+                amount * 2 AS doubled,
+                CAST(NULL AS FLOAT) AS ratio,
+                region = 'east' AS east,
+                AVG(amount) OVER () AS average
+            FROM Sale AS S
+            WHERE id > 100",
+        Err(AlterError::MaterializedViewColumnTypeUnknown(
+            "Typed".to_owned(),
+            "average".to_owned()
+        )
+        .into())
+    );
+    test!(
+        "CREATE MATERIALIZED VIEW Typed AS
+            SELECT S.id, amount * 2 AS doubled, CAST(NULL AS FLOAT) AS ratio, region = 'east' AS east
+            FROM Sale AS S
+            WHERE id > 100",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SHOW COLUMNS FROM Typed",
+        Ok(Payload::ShowColumns(vec![
+            ("id".to_owned(), DataType::Int),
+            ("doubled".to_owned(), DataType::Int),
+            ("ratio".to_owned(), DataType::Float),
+            ("east".to_owned(), DataType::Boolean),
+        ]))
+    );
+    test!(
+        "CREATE MATERIALIZED VIEW Nothing AS SELECT id, NULL AS nothing FROM Sale",
+        Err(AlterError::MaterializedViewColumnTypeUnknown(
+            "Nothing".to_owned(),
+            "nothing".to_owned()
+        )
+        .into())
+    );
+
+    test!(
+        "CREATE MATERIALIZED VIEW Sale AS SELECT 1",
+        Err(AlterError::TableAlreadyExists("Sale".to_owned()).into())
+    );
+    test!(
+        "CREATE MATERIALIZED VIEW TooMany (a, b, c) AS SELECT id, amount FROM Sale",
+        Err(FetchError::TooManyColumnAliases("TooMany".to_owned(), 2, 3).into())
+    );
+    test!(
+        "CREATE MATERIALIZED VIEW Dup (a, a) AS SELECT id, amount FROM Sale",
+        Err(AlterError::DuplicateColumnName("a".to_owned()).into())
+    );
+    test!(
+        "REFRESH MATERIALIZED VIEW Sale",
+        Err(AlterError::NotMaterializedView("Sale".to_owned()).into())
+    );
+    test!(
+        "REFRESH MATERIALIZED VIEW Nothing",
+        Err(AlterError::MaterializedViewNotFound("Nothing".to_owned()).into())
+    );
+
+    run!("CREATE MATERIALIZED VIEW Everything AS SELECT * FROM Sale");
+    run!("ALTER TABLE Sale ADD COLUMN note TEXT NULL");
+    test!(
+        "REFRESH MATERIALIZED VIEW Everything",
+        Err(AlterError::MaterializedViewColumnsChanged("Everything".to_owned()).into())
+    );
+
+    for sql in [
+        "INSERT INTO RegionTotal VALUES ('south', 9)",
+        "UPDATE RegionTotal SET total = 0",
+        "DELETE FROM RegionTotal",
+    ] {
+        test!(
+            sql,
+            Err(ExecuteError::MaterializedViewModified("RegionTotal".to_owned()).into())
+        );
+    }
+
+    test!("DROP TABLE RegionTotal", Ok(Payload::DropTable));
+    test!(
+        "SELECT * FROM RegionTotal",
+        Err(FetchError::TableNotFound("RegionTotal".to_owned()).into())
+    );
+});
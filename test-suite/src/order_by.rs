@@ -1,9 +1,6 @@
 use {
     crate::*,
-    gluesql_core::{
-        error::{SortError, TranslateError},
-        prelude::Value::*,
-    },
+    gluesql_core::{error::SortError, prelude::Value::*},
 };
 
 test_case!(order_by, async move {
@@ -150,8 +147,44 @@ CREATE TABLE Test (
     );
 
     test!(
-        "SELECT * FROM Test ORDER BY id NULLS FIRST",
-        Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into())
+        "SELECT id, name FROM Test ORDER BY name NULLS FIRST",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   Null;
+            I64(1)   Str("Hello".to_owned());
+            I64(4)   Str("Thursday".to_owned());
+            I64(3)   Str("World".to_owned())
+        ))
+    );
+    test!(
+        "SELECT id, name FROM Test ORDER BY name ASC NULLS LAST",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   Str("Hello".to_owned());
+            I64(4)   Str("Thursday".to_owned());
+            I64(3)   Str("World".to_owned());
+            I64(1)   Null
+        ))
+    );
+    test!(
+        "SELECT id, name FROM Test ORDER BY name DESC NULLS LAST",
+        Ok(select_with_null!(
+            id     | name;
+            I64(3)   Str("World".to_owned());
+            I64(4)   Str("Thursday".to_owned());
+            I64(1)   Str("Hello".to_owned());
+            I64(1)   Null
+        ))
+    );
+    test!(
+        "SELECT id, rate FROM Test ORDER BY rate NULLS FIRST, id DESC",
+        Ok(select_with_null!(
+            id     | rate;
+            I64(4)   Null;
+            I64(1)   Null;
+            I64(3)   F64(1.0);
+            I64(1)   F64(3.0)
+        ))
     );
     test! {
         name: "ORDER BY aliases",
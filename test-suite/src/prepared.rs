@@ -0,0 +1,148 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, PrepareError, ValueError},
+        prelude::{DataType, Payload, Value::*},
+    },
+};
+
+test_case!(prepared, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INT8);");
+
+    let insert = get_glue!()
+        .prepare("INSERT INTO Item VALUES (?, ?, ?);")
+        .await
+        .unwrap();
+    assert_eq!(
+        insert.param_types(),
+        &[
+            Some(DataType::Int),
+            Some(DataType::Text),
+            Some(DataType::Int8)
+        ]
+    );
+
+    for (id, name, price) in [(1, "apple", 3), (2, "banana", 5), (3, "carrot", 1)] {
+        let params = vec![I64(id), Str(name.to_owned()), I8(price)];
+
+        assert_eq!(
+            get_glue!().execute_prepared(&insert, params).await,
+            Ok(Payload::Insert(1))
+        );
+    }
+
+    let select = get_glue!()
+        .prepare("SELECT id, name FROM Item WHERE id = $1")
+        .await
+        .unwrap();
+    assert_eq!(select.param_types(), &[Some(DataType::Int)]);
+    assert_eq!(
+        get_glue!().execute_prepared(&select, vec![I64(2)]).await,
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "banana".to_owned()
+        ))
+    );
+    assert_eq!(
+        get_glue!().execute_prepared(&select, vec![I64(3)]).await,
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "carrot".to_owned()
+        ))
+    );
+
+    let select = get_glue!()
+        .prepare("SELECT name FROM Item WHERE price BETWEEN $1 AND $2 ORDER BY name")
+        .await
+        .unwrap();
+    assert_eq!(
+        get_glue!()
+            .execute_prepared(&select, vec![I8(2), I8(5)])
+            .await,
+        Ok(select!(name Str; "apple".to_owned(); "banana".to_owned()))
+    );
+
+    assert_eq!(
+        get_glue!()
+            .execute_prepared(&insert, vec![I64(4), Null, Null])
+            .await,
+        Ok(Payload::Insert(1))
+    );
+
+    let update = get_glue!()
+        .prepare("UPDATE Item SET name = UPPER($1) WHERE id = $2")
+        .await
+        .unwrap();
+    assert_eq!(update.param_types(), &[None, Some(DataType::Int)]);
+    assert_eq!(
+        get_glue!()
+            .execute_prepared(&update, vec![Str("melon".to_owned()), I64(1)])
+            .await,
+        Ok(Payload::Update(1))
+    );
+
+    let delete = get_glue!()
+        .prepare("DELETE FROM Item WHERE name = ?")
+        .await
+        .unwrap();
+    assert_eq!(
+        get_glue!()
+            .execute_prepared(&delete, vec![Str("banana".to_owned())])
+            .await,
+        Ok(Payload::Delete(1))
+    );
+
+    let select = get_glue!()
+        .prepare("SELECT id FROM Item WHERE id IN (SELECT id FROM Item WHERE price < $1)")
+        .await
+        .unwrap();
+    assert_eq!(select.param_types(), &[None]);
+    assert_eq!(
+        get_glue!().execute_prepared(&select, vec![I64(5)]).await,
+        Ok(select!(id I64; 1; 3))
+    );
+
+    test!(
+        "SELECT id, name FROM Item",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   Str("MELON".to_owned());
+            I64(3)   Str("carrot".to_owned());
+            I64(4)   Null
+        ))
+    );
+
+    let select = get_glue!()
+        .prepare("SELECT id FROM Item WHERE id = $1")
+        .await
+        .unwrap();
+    assert_eq!(
+        get_glue!()
+            .execute_prepared(&select, vec![Str("1".to_owned())])
+            .await,
+        Err(ValueError::IncompatibleDataType {
+            data_type: DataType::Int,
+            value: Str("1".to_owned()),
+        }
+        .into())
+    );
+    assert_eq!(
+        get_glue!().execute_prepared(&select, Vec::new()).await,
+        Err(PrepareError::ParamsLengthNotMatching {
+            expected: 1,
+            found: 0
+        }
+        .into())
+    );
+    assert_eq!(
+        get_glue!().prepare("SELECT 1; SELECT 2;").await,
+        Err(PrepareError::SingleStatementRequired(2).into())
+    );
+
+    test!(
+        "SELECT id FROM Item WHERE name = $1",
+        Err(EvaluateError::UnboundPlaceholder(1).into())
+    );
+});
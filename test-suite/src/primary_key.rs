@@ -1,8 +1,8 @@
 use {
-    crate::*,
+    crate::{explain::query_plan, *},
     gluesql_core::{
         data::Value::*,
-        error::{UpdateError, ValidateError, ValueError},
+        error::{TranslateError, UpdateError, ValidateError, ValueError},
         prelude::{Key, Payload},
     },
 };
@@ -133,3 +133,209 @@ test_case!(primary_key, async move {
         Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into())
     );
 });
+
+test_case!(composite, async move {
+    run!(
+        "
+        CREATE TABLE Enrollment (
+            student_id INTEGER,
+            course_id INTEGER,
+            grade TEXT,
+            PRIMARY KEY (student_id, course_id)
+        );
+    "
+    );
+    test!(
+        "INSERT INTO Enrollment VALUES (2, 1, 'B'), (1, 2, 'A'), (1, 1, 'C');",
+        Ok(Payload::Insert(3))
+    );
+
+    test!(
+        "SELECT student_id, course_id, grade FROM Enrollment",
+        Ok(select!(
+            student_id | course_id | grade
+            I64        | I64       | Str;
+            1            1           "C".to_owned();
+            1            2           "A".to_owned();
+            2            1           "B".to_owned()
+        ))
+    );
+    test!(
+        "SHOW INDEXES FROM Enrollment",
+        Ok(select!(
+            TABLE_NAME              | INDEX_NAME           | ORDER             | EXPRESSION                            | UNIQUENESS;
+            Str                     | Str                  | Str               | Str                                   | Bool;
+            "Enrollment".to_owned()   "PRIMARY".to_owned()   "BOTH".to_owned()   "student_id, course_id".to_owned()      true
+        ))
+    );
+
+    test!(
+        "INSERT INTO Enrollment VALUES (1, 2, 'F');",
+        Err(
+            ValidateError::DuplicateEntryOnPrimaryKeyField(Key::Composite(vec![
+                Key::I64(1),
+                Key::I64(2)
+            ]))
+            .into()
+        )
+    );
+    test!(
+        "INSERT INTO Enrollment VALUES (NULL, 3, 'F');",
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+    test!(
+        "UPDATE Enrollment SET course_id = 3 WHERE student_id = 2",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("course_id".to_owned()).into())
+    );
+
+    test!(
+        "UPDATE Enrollment SET grade = 'A+' WHERE student_id = 1 AND course_id = 2",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "DELETE FROM Enrollment WHERE student_id = 1 AND course_id = 1",
+        Ok(Payload::Delete(1))
+    );
+    test!(
+        "SELECT student_id, course_id, grade FROM Enrollment",
+        Ok(select!(
+            student_id | course_id | grade
+            I64        | I64       | Str;
+            1            2           "A+".to_owned();
+            2            1           "B".to_owned()
+        ))
+    );
+
+    test!(
+        "INSERT INTO Enrollment VALUES (2, 1, 'C') ON CONFLICT (course_id, student_id) DO UPDATE SET grade = EXCLUDED.grade;",
+        Ok(Payload::Insert(1))
+    );
+    test!(
+        "SELECT grade FROM Enrollment WHERE student_id = 2",
+        Ok(select!(grade Str; "C".to_owned()))
+    );
+
+    test!(
+        "CREATE TABLE Wrong (a INTEGER, b INTEGER, PRIMARY KEY (a, c));",
+        Err(TranslateError::UniqueConstraintColumnNotFound("c".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Wrong (a INTEGER PRIMARY KEY, b INTEGER, PRIMARY KEY (a, b));",
+        Err(TranslateError::MultiplePrimaryKeysNotAllowed.into())
+    );
+});
+
+test_case!(range, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, price INTEGER);");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (5, 50), (1, 10), (8, 80), (3, 30), (10, 100),
+            (2, 20), (7, 70), (4, 40), (9, 90), (6, 60);
+    "
+    );
+
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE id BETWEEN 3 AND 5",
+        query_plan(&[
+            "Project: id",
+            "  -> Primary Key Scan on Item: id >= 3 AND id <= 5",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item WHERE id BETWEEN 3 AND 5",
+        Ok(select!(id I64; 3; 4; 5))
+    );
+    test!(
+        "SELECT id FROM Item WHERE 7 < id",
+        Ok(select!(id I64; 8; 9; 10))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id > 3 AND id <= 6 AND price > 40",
+        Ok(select!(id I64; 5; 6))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id >= 2.5 AND id < 4.5",
+        Ok(select!(id I64; 3; 4))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id > 8.5 AND id <= 10.0",
+        Ok(select!(id I64; 9; 10))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id BETWEEN 5 AND 3",
+        Ok(select!(id))
+    );
+    test!("SELECT id FROM Item WHERE id > NULL", Ok(select!(id)));
+
+    // only the first lower bound is scanned, the other one stays as a filter
+    test!(
+        "EXPLAIN SELECT id FROM Item WHERE id > 8 AND id > 3",
+        query_plan(&[
+            "Project: id",
+            "  -> Filter: id > 3",
+            "    -> Primary Key Scan on Item: id > 8",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item WHERE id > 8 AND id > 3",
+        Ok(select!(id I64; 9; 10))
+    );
+
+    // the scan is in key order, so no sort is needed
+    test!(
+        "EXPLAIN SELECT id FROM Item ORDER BY id DESC LIMIT 3",
+        query_plan(&[
+            "Limit: 3",
+            "  -> Project: id",
+            "    -> Primary Key Scan on Item (DESC)",
+        ])
+    );
+    test!(
+        "SELECT id FROM Item ORDER BY id DESC LIMIT 3",
+        Ok(select!(id I64; 10; 9; 8))
+    );
+    test!(
+        "EXPLAIN SELECT id, price FROM Item WHERE id > 3 ORDER BY id LIMIT 2",
+        query_plan(&[
+            "Limit: 2",
+            "  -> Project: id, price",
+            "    -> Primary Key Scan on Item: id > 3",
+        ])
+    );
+    test!(
+        "SELECT id, price FROM Item WHERE id > 3 ORDER BY id LIMIT 2",
+        Ok(select!(
+            id  | price
+            I64 | I64;
+            4     40;
+            5     50
+        ))
+    );
+    // ORDER BY takes the column of the table before the alias
+    test!(
+        "SELECT 10 - id AS id FROM Item ORDER BY id LIMIT 2",
+        Ok(select!(id I64; 9; 8))
+    );
+
+    run!("CREATE TABLE Tag (name TEXT PRIMARY KEY);");
+    run!("INSERT INTO Tag VALUES ('c'), ('a'), ('d'), ('b');");
+    test!(
+        "SELECT name FROM Tag WHERE name >= 'b' AND name < 'd' ORDER BY name DESC",
+        Ok(select!(name Str; "c".to_owned(); "b".to_owned()))
+    );
+
+    // values are cast into the type of the key before they are looked up
+    run!("CREATE TABLE Small (id INT8 PRIMARY KEY, name TEXT);");
+    run!("INSERT INTO Small VALUES (1, 'a'), (2, 'b'), (3, 'c');");
+    test!(
+        "SELECT name FROM Small WHERE id = 2",
+        Ok(select!(name Str; "b".to_owned()))
+    );
+    test!("SELECT name FROM Small WHERE id = 2.5", Ok(select!(name)));
+    test!("SELECT name FROM Small WHERE id = 1000", Ok(select!(name)));
+    test!(
+        "SELECT name FROM Small WHERE id > 1 ORDER BY id DESC",
+        Ok(select!(name Str; "c".to_owned(); "b".to_owned()))
+    );
+});
@@ -0,0 +1,141 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, FetchError},
+        prelude::{
+            Payload,
+            Value::{self, *},
+        },
+    },
+    serde_json::json,
+};
+
+test_case!(returning, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            code UUID DEFAULT GENERATE_UUID()
+        );
+    "
+    );
+
+    test! {
+        name: "INSERT RETURNING evaluates the select items over the inserted rows",
+        sql: "
+            INSERT INTO Item (id, name) VALUES (1, 'apple'), (2, 'banana')
+            RETURNING id, UPPER(name) AS name, code IS NOT NULL AS has_code;
+        ",
+        expected: Ok(select!(
+            id  | name                | has_code
+            I64 | Str                 | Bool;
+            1     "APPLE".to_owned()    true;
+            2     "BANANA".to_owned()   true
+        ))
+    };
+
+    let inserted = run!("INSERT INTO Item (id, name) VALUES (3, 'cherry') RETURNING code;");
+    test! {
+        name: "INSERT RETURNING reads back the generated default",
+        sql: "SELECT code FROM Item WHERE id = 3;",
+        expected: Ok(inserted)
+    };
+
+    test! {
+        name: "UPDATE RETURNING returns the updated values",
+        sql: "UPDATE Item SET name = name || '!' WHERE id > 1 RETURNING Item.id, name;",
+        expected: Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "banana!".to_owned();
+            3     "cherry!".to_owned()
+        ))
+    };
+
+    test! {
+        name: "UPDATE RETURNING without matching rows",
+        sql: "UPDATE Item SET name = 'none' WHERE id = 100 RETURNING id;",
+        expected: Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        })
+    };
+
+    test! {
+        name: "DELETE RETURNING returns the deleted rows",
+        sql: "DELETE FROM Item WHERE id <> 2 RETURNING id, name;",
+        expected: Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "apple".to_owned();
+            3     "cherry!".to_owned()
+        ))
+    };
+
+    test! {
+        name: "INSERT ... ON CONFLICT RETURNING returns the updated and inserted rows",
+        sql: "
+            INSERT INTO Item (id, name) VALUES (2, 'blueberry'), (4, 'durian')
+            ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name
+            RETURNING id, name;
+        ",
+        expected: Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "blueberry".to_owned();
+            4     "durian".to_owned()
+        ))
+    };
+
+    run!("CREATE TABLE Tag (id INTEGER, label TEXT);");
+    test! {
+        name: "RETURNING wildcard",
+        sql: "INSERT INTO Tag VALUES (1, 'a'), (2, 'b') RETURNING *;",
+        expected: Ok(select!(
+            id  | label
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned()
+        ))
+    };
+    test! {
+        name: "RETURNING qualified wildcard",
+        sql: "UPDATE Tag SET label = 'c' WHERE id = 2 RETURNING Tag.*;",
+        expected: Ok(select!(
+            id  | label
+            I64 | Str;
+            2     "c".to_owned()
+        ))
+    };
+
+    test! {
+        name: "RETURNING unknown table alias",
+        sql: "DELETE FROM Tag WHERE id > 0 RETURNING Other.*;",
+        expected: Err(FetchError::TableAliasNotFound("Other".to_owned()).into())
+    };
+    test! {
+        name: "RETURNING unknown column does not delete",
+        sql: "DELETE FROM Tag WHERE id > 0 RETURNING missing;",
+        expected: Err(EvaluateError::ValueNotFound("missing".to_owned()).into())
+    };
+    test! {
+        sql: "SELECT * FROM Tag;",
+        expected: Ok(select!(
+            id  | label
+            I64 | Str;
+            1     "a".to_owned();
+            2     "c".to_owned()
+        ))
+    };
+
+    run!("CREATE TABLE Doc;");
+    test! {
+        name: "RETURNING on schemaless table",
+        sql: &format!(
+            "INSERT INTO Doc VALUES ('{}') RETURNING *;",
+            json!({ "id": 1, "title": "glue" })
+        ),
+        expected: Ok(select_map![json!({ "id": 1, "title": "glue" })])
+    };
+});
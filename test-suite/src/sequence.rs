@@ -0,0 +1,279 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{AlterError, InsertError, SequenceError, TranslateError, ValueError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(sequence, async move {
+    test!("CREATE SEQUENCE Seq", Ok(Payload::Create));
+    test!(
+        "CREATE SEQUENCE Seq",
+        Err(SequenceError::SequenceAlreadyExists("Seq".to_owned()).into())
+    );
+    test!("CREATE SEQUENCE IF NOT EXISTS Seq", Ok(Payload::Create));
+
+    test!(
+        "SELECT CURRVAL('Seq') AS v",
+        Err(SequenceError::CurrentValueNotDefined("Seq".to_owned()).into())
+    );
+    test!(
+        "SELECT NEXTVAL('Seq') AS a, NEXTVAL('Seq') AS b, CURRVAL('Seq') AS c",
+        Ok(select!(
+            a   | b   | c
+            I64 | I64 | I64;
+            1     2     2
+        ))
+    );
+
+    run!("CREATE TABLE Item (id INTEGER, name TEXT);");
+    test!(
+        "INSERT INTO Item VALUES (NEXTVAL('Seq'), 'a'), (NEXTVAL('Seq'), 'b'), (CURRVAL('Seq') * 10, 'c');",
+        Ok(Payload::Insert(3))
+    );
+    test!(
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "a".to_owned();
+            4     "b".to_owned();
+            40    "c".to_owned()
+        ))
+    );
+    test!(
+        "SELECT CURRVAL('Seq') AS v FROM Item WHERE name = 'a'",
+        Ok(select!(v; I64; 4))
+    );
+    test!(
+        "SELECT NEXTVAL('Seq') AS v FROM Item",
+        Err(SequenceError::NextValNotSupported.into())
+    );
+    test!(
+        "UPDATE Item SET id = NEXTVAL('Seq') WHERE id = NEXTVAL('Seq')",
+        Err(SequenceError::NextValNotSupported.into())
+    );
+    test!(
+        "UPDATE Item SET id = NEXTVAL('Seq')",
+        Ok(Payload::Update(3))
+    );
+    test!(
+        "INSERT INTO Item VALUES (ABS(-NEXTVAL('Seq')), 'd'), (CAST(CURRVAL('Seq') AS INTEGER) + 1, 'e');",
+        Ok(Payload::Insert(2))
+    );
+    test!(
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            5     "a".to_owned();
+            6     "b".to_owned();
+            7     "c".to_owned();
+            8     "d".to_owned();
+            9     "e".to_owned()
+        ))
+    );
+    test!("SELECT CURRVAL('Seq') AS v", Ok(select!(v; I64; 8)));
+
+    test!(
+        "CREATE SEQUENCE Odd INCREMENT BY 2 MAXVALUE 5 START WITH 3 CYCLE",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT NEXTVAL('Odd') AS a, NEXTVAL('Odd') AS b, NEXTVAL('Odd') AS c",
+        Ok(select!(
+            a   | b   | c
+            I64 | I64 | I64;
+            3     5     1
+        ))
+    );
+
+    run!("CREATE SEQUENCE Limited MAXVALUE 2 START WITH 2");
+    run!("SELECT NEXTVAL('Limited')");
+    test!(
+        "SELECT NEXTVAL('Limited')",
+        Err(SequenceError::ReachedMaxValue("Limited".to_owned()).into())
+    );
+
+    test!(
+        "CREATE SEQUENCE Single MINVALUE 1 MAXVALUE 1",
+        Err(SequenceError::InvalidRange(1, 1).into())
+    );
+    test!(
+        "CREATE SEQUENCE Zero INCREMENT BY 0",
+        Err(SequenceError::ZeroIncrement.into())
+    );
+    test!(
+        "CREATE SEQUENCE Cached CACHE 10",
+        Err(TranslateError::UnsupportedSequenceOption("CACHE 10".to_owned()).into())
+    );
+    test!(
+        "SELECT NEXTVAL('Unknown')",
+        Err(SequenceError::SequenceNotFound("Unknown".to_owned()).into())
+    );
+    test!(
+        "SELECT NEXTVAL(1)",
+        Err(SequenceError::SequenceNameNotString("1".to_owned()).into())
+    );
+
+    test!(
+        "SELECT OBJECT_NAME FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'SEQUENCE'",
+        Ok(select!(
+            OBJECT_NAME
+            Str;
+            "Limited".to_owned();
+            "Odd".to_owned();
+            "Seq".to_owned()
+        ))
+    );
+
+    test!("DROP SEQUENCE Seq, Odd", Ok(Payload::DropSequence));
+    test!(
+        "DROP SEQUENCE Seq",
+        Err(SequenceError::SequenceNotFound("Seq".to_owned()).into())
+    );
+    test!("DROP SEQUENCE IF EXISTS Seq", Ok(Payload::DropSequence));
+    test!(
+        "SELECT NEXTVAL('Seq')",
+        Err(SequenceError::SequenceNotFound("Seq".to_owned()).into())
+    );
+});
+
+test_case!(identity, async move {
+    run!("CREATE TABLE Serial (id SERIAL PRIMARY KEY, name TEXT);");
+    test!(
+        "INSERT INTO Serial (name) VALUES ('a'), ('b');",
+        Ok(Payload::Insert(2))
+    );
+    run!("INSERT INTO Serial VALUES (10, 'c');");
+    run!("INSERT INTO Serial (name) VALUES ('d');");
+    test!(
+        "SELECT id, name FROM Serial",
+        Ok(select!(
+            id  | name
+            I32 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "d".to_owned();
+            10    "c".to_owned()
+        ))
+    );
+    test!(
+        "INSERT INTO Serial VALUES (NULL, 'e')",
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+
+    run!(
+        "CREATE TABLE Always (
+            id INTEGER GENERATED ALWAYS AS IDENTITY (INCREMENT BY 10 START WITH 100),
+            name TEXT
+        );"
+    );
+    run!("INSERT INTO Always (name) VALUES ('a'), ('b');");
+    test!(
+        "INSERT INTO Always VALUES (1, 'c')",
+        Err(InsertError::IdentityColumnAlwaysGenerated("id".to_owned()).into())
+    );
+    test!(
+        "INSERT INTO Always SELECT id, name FROM Always",
+        Err(InsertError::IdentityColumnAlwaysGenerated("id".to_owned()).into())
+    );
+    test!(
+        "INSERT INTO Always (name) VALUES ('c') RETURNING id",
+        Ok(select!(id; I64; 120))
+    );
+    test!(
+        "SELECT id, name FROM Always",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            100   "a".to_owned();
+            110   "b".to_owned();
+            120   "c".to_owned()
+        ))
+    );
+    test!(
+        "INSERT INTO Always (name) SELECT name FROM Always WHERE id > 100",
+        Ok(Payload::Insert(2))
+    );
+    test!(
+        "INSERT INTO Always (id, name) SELECT id, name FROM Always",
+        Err(InsertError::IdentityColumnAlwaysGenerated("id".to_owned()).into())
+    );
+    test!(
+        "SELECT id, name FROM Always WHERE id > 120",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            130   "b".to_owned();
+            140   "c".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT OBJECT_NAME FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'SEQUENCE'",
+        Ok(select!(
+            OBJECT_NAME
+            Str;
+            "Always_id_seq".to_owned();
+            "Serial_id_seq".to_owned()
+        ))
+    );
+    test!(
+        "DROP SEQUENCE Serial_id_seq",
+        Err(
+            SequenceError::SequenceOwnedByTable("Serial_id_seq".to_owned(), "Serial".to_owned())
+                .into()
+        )
+    );
+    test!(
+        "SELECT NEXTVAL('Serial_id_seq') AS v",
+        Ok(select!(v; I64; 4))
+    );
+
+    run!("ALTER TABLE Serial RENAME TO Renamed;");
+    run!("ALTER TABLE Renamed RENAME COLUMN id TO no;");
+    run!("INSERT INTO Renamed (name) VALUES ('e');");
+    test!(
+        "SELECT no, name FROM Renamed WHERE name = 'e'",
+        Ok(select!(
+            no  | name
+            I32 | Str;
+            5     "e".to_owned()
+        ))
+    );
+
+    test!(
+        "CREATE TABLE Copied AS SELECT * FROM Always",
+        Ok(Payload::Create)
+    );
+    test!("INSERT INTO Copied VALUES (1, 'd')", Ok(Payload::Insert(1)));
+
+    test!(
+        "ALTER TABLE Renamed ADD COLUMN extra INTEGER GENERATED ALWAYS AS IDENTITY",
+        Err(AlterError::UnsupportedAddIdentityColumn("extra".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Wrong (id TEXT GENERATED ALWAYS AS IDENTITY)",
+        Err(
+            AlterError::UnsupportedDataTypeForIdentityColumn("id".to_owned(), DataType::Text)
+                .into()
+        )
+    );
+    test!(
+        "CREATE TABLE Wrong (id INTEGER DEFAULT 1 GENERATED BY DEFAULT AS IDENTITY)",
+        Err(AlterError::IdentityColumnWithDefault("id".to_owned()).into())
+    );
+
+    run!("DROP TABLE Renamed;");
+    run!("ALTER TABLE Always DROP COLUMN id;");
+    test!(
+        "SELECT OBJECT_NAME FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'SEQUENCE'",
+        Ok(Payload::Select {
+            labels: vec!["OBJECT_NAME".to_owned()],
+            rows: Vec::new(),
+        })
+    );
+});
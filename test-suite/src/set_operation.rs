@@ -0,0 +1,111 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::SelectError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(set_operation, async move {
+    run!("CREATE TABLE Foo (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Bar (id INTEGER, name TEXT);");
+    run!("INSERT INTO Foo VALUES (1, 'a'), (2, 'b'), (2, 'b'), (3, 'c');");
+    run!("INSERT INTO Bar VALUES (2, 'b'), (3, 'c'), (3, 'c'), (4, 'd');");
+
+    test!(
+        "SELECT id FROM Foo UNION SELECT id FROM Bar",
+        Ok(select!(id; I64; 1; 2; 3; 4))
+    );
+    test!(
+        "SELECT id FROM Foo UNION ALL SELECT id FROM Bar",
+        Ok(select!(id; I64; 1; 2; 2; 3; 2; 3; 3; 4))
+    );
+    test!(
+        "SELECT id, name FROM Foo INTERSECT SELECT id, name FROM Bar",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "b".to_owned();
+            3     "c".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id FROM Foo INTERSECT ALL SELECT id FROM Bar",
+        Ok(select!(id; I64; 2; 3))
+    );
+    test!(
+        "SELECT id FROM Bar INTERSECT ALL SELECT id FROM Foo",
+        Ok(select!(id; I64; 2; 3))
+    );
+    test!(
+        "SELECT id FROM Foo EXCEPT SELECT id FROM Bar",
+        Ok(select!(id; I64; 1))
+    );
+    test!(
+        "SELECT id FROM Foo EXCEPT ALL SELECT id FROM Bar",
+        Ok(select!(id; I64; 1; 2))
+    );
+    test!(
+        "SELECT id FROM Bar EXCEPT ALL SELECT id FROM Foo",
+        Ok(select!(id; I64; 3; 4))
+    );
+    test!(
+        "SELECT id AS num FROM Foo UNION SELECT id FROM Bar ORDER BY num DESC LIMIT 3",
+        Ok(select!(num; I64; 4; 3; 2))
+    );
+    test!(
+        "SELECT id FROM Foo UNION SELECT id FROM Bar EXCEPT SELECT id FROM Foo WHERE id < 3",
+        Ok(select!(id; I64; 3; 4))
+    );
+    test!(
+        "SELECT id FROM Foo WHERE id = 1 UNION ALL (SELECT id FROM Bar ORDER BY id DESC LIMIT 1)",
+        Ok(select!(id; I64; 1; 4))
+    );
+    test!(
+        "SELECT id FROM Foo UNION VALUES (10), (1)",
+        Ok(select!(id; I64; 1; 2; 3; 10))
+    );
+    test!(
+        "SELECT * FROM (SELECT id, name FROM Foo EXCEPT SELECT id, name FROM Bar) AS Sub",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned()
+        ))
+    );
+    test!(
+        "SELECT * FROM Foo WHERE id IN (SELECT id FROM Bar INTERSECT SELECT 3)",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "c".to_owned()
+        ))
+    );
+
+    run!("CREATE TABLE Baz (id INTEGER, name TEXT);");
+    test!(
+        "INSERT INTO Baz SELECT * FROM Foo UNION SELECT * FROM Bar",
+        Ok(Payload::Insert(4))
+    );
+    test!(
+        "SELECT id, name FROM Baz",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned();
+            4     "d".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT id FROM Foo UNION SELECT id, name FROM Bar",
+        Err(SelectError::SetOperationColumnCountMismatch {
+            op: "UNION".to_owned(),
+            left: 1,
+            right: 2,
+        }
+        .into())
+    );
+});
@@ -10,38 +10,95 @@ macro_rules! idx {
     () => {
         vec![]
     };
+    ($name: path, $lower: ident($lower_expr: literal), $upper: ident($upper_expr: literal)) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![],
+            condition: Some(gluesql_core::ast::IndexCondition::Range {
+                lower: std::ops::Bound::$lower(
+                    gluesql_core::translate::translate_expr(
+                        &gluesql_core::parse_sql::parse_expr($lower_expr).unwrap(),
+                    )
+                    .unwrap(),
+                ),
+                upper: std::ops::Bound::$upper(
+                    gluesql_core::translate::translate_expr(
+                        &gluesql_core::parse_sql::parse_expr($upper_expr).unwrap(),
+                    )
+                    .unwrap(),
+                ),
+            }),
+        }]
+    };
+    ($name: path, IN, [$($sql_expr: literal),+]) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![],
+            condition: Some(gluesql_core::ast::IndexCondition::InList(vec![$(
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
+                )
+                .unwrap()
+            ),+])),
+        }]
+    };
     ($name: path, $op: path, $sql_expr: literal) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: None,
-            cmp_expr: Some((
+            prefix: vec![],
+            condition: Some(gluesql_core::ast::IndexCondition::from((
+                $op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
+                )
+                .unwrap(),
+            ))),
+        }]
+    };
+    ($name: path, [$($prefix: literal),+], $op: path, $sql_expr: literal) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![$(
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($prefix).unwrap(),
+                )
+                .unwrap()
+            ),+],
+            condition: Some(gluesql_core::ast::IndexCondition::from((
                 $op,
                 gluesql_core::translate::translate_expr(
                     &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
                 )
                 .unwrap(),
-            )),
+            ))),
         }]
     };
     ($name: path) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: None,
-            cmp_expr: None,
+            prefix: vec![],
+            condition: None,
         }]
     };
     ($name: path, ASC) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: Some(true),
-            cmp_expr: None,
+            prefix: vec![],
+            condition: None,
         }]
     };
     ($name: path, DESC) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: Some(false),
-            cmp_expr: None,
+            prefix: vec![],
+            condition: None,
         }]
     };
 }
@@ -0,0 +1,41 @@
+use {crate::explain::query_plan, crate::*};
+
+test_case!(analyze, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Score (player_id INTEGER, score INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'a');");
+    run!("INSERT INTO Score VALUES (1, 10), (1, 20);");
+
+    let sql = "EXPLAIN SELECT name, score FROM Player JOIN Score ON id = player_id";
+    let written_order = [
+        "Project: name, score",
+        "  -> Hash Join: INNER ON player_id = id",
+        "    -> Seq Scan on Player",
+        "    -> Seq Scan on Score",
+    ];
+    let reordered = [
+        "Project: name, score",
+        "  -> Hash Join: INNER ON id = player_id",
+        "    -> Seq Scan on Score",
+        "    -> Seq Scan on Player",
+    ];
+
+    // ANALYZE && ROLLBACK
+    run!("BEGIN;");
+    run!("ANALYZE;");
+    test!(sql, query_plan(&reordered));
+    run!("ROLLBACK;");
+    test!(sql, query_plan(&written_order));
+
+    // ANALYZE && COMMIT
+    run!("BEGIN;");
+    run!("ANALYZE;");
+    run!("COMMIT;");
+    test!(sql, query_plan(&reordered));
+
+    // DROP TABLE && ROLLBACK
+    run!("BEGIN;");
+    run!("DROP TABLE Player;");
+    run!("ROLLBACK;");
+    test!(sql, query_plan(&reordered));
+});
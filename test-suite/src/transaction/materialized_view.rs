@@ -0,0 +1,27 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(materialized_view, async move {
+    run!("CREATE TABLE Source (id INTEGER);");
+    run!("INSERT INTO Source VALUES (1), (2);");
+    run!("CREATE MATERIALIZED VIEW Total AS SELECT SUM(id) AS total FROM Source;");
+    run!("INSERT INTO Source VALUES (3);");
+
+    // REFRESH && ROLLBACK
+    run!("BEGIN;");
+    test!(
+        "REFRESH MATERIALIZED VIEW Total;",
+        Ok(Payload::RefreshMaterializedView)
+    );
+    test!("SELECT * FROM Total;", Ok(select!(total I64; 6)));
+    run!("ROLLBACK;");
+    test!("SELECT * FROM Total;", Ok(select!(total I64; 3)));
+
+    // REFRESH && COMMIT
+    run!("BEGIN;");
+    run!("REFRESH MATERIALIZED VIEW Total;");
+    run!("COMMIT;");
+    test!("SELECT * FROM Total;", Ok(select!(total I64; 6)));
+});
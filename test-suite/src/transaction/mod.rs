@@ -1,11 +1,19 @@
 mod alter_table;
+mod analyze;
 mod basic;
 mod dictionary;
 mod index;
+mod materialized_view;
+mod sequence;
 mod table;
+mod view;
 
 pub use alter_table::*;
+pub use analyze::analyze;
 pub use basic::basic;
 pub use dictionary::dictionary;
 pub use index::*;
+pub use materialized_view::materialized_view;
+pub use sequence::sequence;
 pub use table::*;
+pub use view::view;
@@ -0,0 +1,52 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(sequence, async move {
+    run!("CREATE SEQUENCE Seq;");
+    run!("CREATE TABLE Item (id BIGSERIAL PRIMARY KEY, name TEXT);");
+    run!("INSERT INTO Item (name) VALUES ('a');");
+
+    // NEXTVAL && ROLLBACK
+    run!("BEGIN;");
+    test!("SELECT NEXTVAL('Seq') AS v", Ok(select!(v; I64; 1)));
+    test!(
+        "INSERT INTO Item (name) VALUES ('b'), ('c');",
+        Ok(Payload::Insert(2))
+    );
+    run!("ROLLBACK;");
+    test!("SELECT NEXTVAL('Seq') AS v", Ok(select!(v; I64; 1)));
+    run!("INSERT INTO Item (name) VALUES ('b');");
+    test!(
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned()
+        ))
+    );
+
+    // CREATE SEQUENCE && ROLLBACK
+    run!("BEGIN;");
+    run!("CREATE SEQUENCE Temp;");
+    run!("DROP SEQUENCE Seq;");
+    run!("ROLLBACK;");
+    test!("SELECT CURRVAL('Seq') AS v", Ok(select!(v; I64; 1)));
+    test!(
+        "SELECT OBJECT_NAME FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'SEQUENCE'",
+        Ok(select!(
+            OBJECT_NAME
+            Str;
+            "Item_id_seq".to_owned();
+            "Seq".to_owned()
+        ))
+    );
+
+    // NEXTVAL && COMMIT
+    run!("BEGIN;");
+    run!("SELECT NEXTVAL('Seq');");
+    run!("COMMIT;");
+    test!("SELECT CURRVAL('Seq') AS v", Ok(select!(v; I64; 2)));
+});
@@ -0,0 +1,49 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::FetchError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(view, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT);");
+    run!("INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');");
+    run!("CREATE VIEW Fruit AS SELECT name FROM Item;");
+
+    // CREATE VIEW && ROLLBACK
+    run!("BEGIN;");
+    test!(
+        "CREATE VIEW Temp AS SELECT id FROM Item WHERE id > 1;",
+        Ok(Payload::Create)
+    );
+    test!("SELECT id FROM Temp", Ok(select!(id; I64; 2)));
+    run!("ROLLBACK;");
+    test!(
+        "SELECT id FROM Temp",
+        Err(FetchError::TableNotFound("Temp".to_owned()).into())
+    );
+
+    // DROP VIEW && ROLLBACK
+    run!("BEGIN;");
+    test!("DROP VIEW Fruit;", Ok(Payload::DropView));
+    run!("ROLLBACK;");
+    test!(
+        "SELECT name FROM Fruit",
+        Ok(select!(
+            name
+            Str;
+            "apple".to_owned();
+            "banana".to_owned()
+        ))
+    );
+
+    // DROP VIEW && COMMIT
+    run!("BEGIN;");
+    run!("DROP VIEW Fruit;");
+    run!("COMMIT;");
+    test!(
+        "SELECT name FROM Fruit",
+        Err(FetchError::TableNotFound("Fruit".to_owned()).into())
+    );
+});
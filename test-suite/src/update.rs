@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{ExecuteError, TranslateError, UpdateError},
+        error::{ExecuteError, FetchError, TranslateError, UpdateError},
         prelude::*,
     },
     Value::*,
@@ -115,11 +115,11 @@ test_case!(update, async move {
             ),
         ),
         (
-            "UPDATE ErrTestTable SET ErrTestTable.id = 1 WHERE id = 1",
-            Err(TranslateError::CompoundIdentOnUpdateNotSupported(
-                "ErrTestTable.id = 1".to_owned(),
-            )
-            .into()),
+            "UPDATE ErrTestTable SET TableA.id = 1 WHERE id = 1",
+            Err(
+                TranslateError::CompoundIdentOnUpdateNotSupported("TableA.id = 1".to_owned())
+                    .into(),
+            ),
         ),
         (
             "UPDATE Nothing SET a = 1;",
@@ -134,3 +134,115 @@ test_case!(update, async move {
         test!(sql, expected);
     }
 });
+
+test_case!(from, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 'apple', 100), (2, 'banana', 200), (3, 'cherry', 300);");
+    run!("CREATE TABLE PriceFix (item_id INTEGER, price INTEGER);");
+    run!("INSERT INTO PriceFix VALUES (1, 150), (3, 350), (4, 400);");
+
+    test! {
+        name: "update rows matched by the FROM table",
+        sql: "UPDATE Item SET price = PriceFix.price FROM PriceFix WHERE Item.id = PriceFix.item_id;",
+        expected: Ok(Payload::Update(2))
+    };
+    test! {
+        sql: "SELECT * FROM Item;",
+        expected: Ok(select!(
+            id  | name           | price
+            I64 | Str            | I64;
+            1     "apple".to_owned()  150;
+            2     "banana".to_owned() 200;
+            3     "cherry".to_owned() 350
+        ))
+    };
+
+    test! {
+        name: "qualified assignment target and aliased FROM table",
+        sql: "
+            UPDATE Item SET Item.price = p.price + 1
+            FROM PriceFix AS p
+            WHERE id = p.item_id AND p.price > 300;
+        ",
+        expected: Ok(Payload::Update(1))
+    };
+    test! {
+        sql: "SELECT price FROM Item WHERE id = 3;",
+        expected: Ok(select!(price I64; 351))
+    };
+
+    test! {
+        name: "no matching row",
+        sql: "UPDATE Item SET price = 0 FROM PriceFix WHERE PriceFix.item_id = 100;",
+        expected: Ok(Payload::Update(0))
+    };
+
+    run!("CREATE TABLE Category (id INTEGER, name TEXT);");
+    run!("INSERT INTO Category VALUES (1, 'fruit'), (2, 'berry');");
+    run!("CREATE TABLE ItemCategory (item_id INTEGER, category_id INTEGER);");
+    run!("INSERT INTO ItemCategory VALUES (1, 1), (2, 1), (3, 2);");
+
+    test! {
+        name: "FROM clause with joins",
+        sql: "
+            UPDATE Item SET name = Item.name || ' (' || Category.name || ')'
+            FROM ItemCategory JOIN Category ON ItemCategory.category_id = Category.id
+            WHERE Item.id = ItemCategory.item_id AND Category.name = 'berry';
+        ",
+        expected: Ok(Payload::Update(1))
+    };
+    test! {
+        sql: "SELECT name FROM Item WHERE id = 3;",
+        expected: Ok(select!(name Str; "cherry (berry)".to_owned()))
+    };
+
+    test! {
+        name: "a row matched several times is updated once",
+        sql: "UPDATE Item SET price = price - 1 FROM ItemCategory WHERE ItemCategory.category_id = 1;",
+        expected: Ok(Payload::Update(3))
+    };
+    test! {
+        sql: "SELECT id, price FROM Item;",
+        expected: Ok(select!(
+            id  | price
+            I64 | I64;
+            1     149;
+            2     199;
+            3     350
+        ))
+    };
+
+    run!("INSERT INTO PriceFix VALUES (NULL, 0), (2, 210), (2, 220);");
+    test! {
+        name: "first of the rows sharing the join key",
+        sql: "
+            UPDATE Item SET price = PriceFix.price FROM PriceFix
+            WHERE PriceFix.item_id = Item.id AND PriceFix.price > 200 AND Item.price < PriceFix.price;
+        ",
+        expected: Ok(Payload::Update(1))
+    };
+    test! {
+        sql: "SELECT id, price FROM Item;",
+        expected: Ok(select!(
+            id  | price
+            I64 | I64;
+            1     149;
+            2     210;
+            3     350
+        ))
+    };
+
+    test! {
+        name: "assignment target must be the updated table",
+        sql: "UPDATE Item SET PriceFix.price = 1 FROM PriceFix;",
+        expected: Err(
+            TranslateError::CompoundIdentOnUpdateNotSupported("PriceFix.price = 1".to_owned())
+                .into()
+        )
+    };
+    test! {
+        name: "FROM table not found",
+        sql: "UPDATE Item SET price = 1 FROM Nothing;",
+        expected: Err(FetchError::TableNotFound("Nothing".to_owned()).into())
+    };
+});
@@ -0,0 +1,143 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, EvaluateError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(check, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            price INTEGER CHECK (price > 0),
+            discount INTEGER CONSTRAINT discount_range CHECK (discount BETWEEN 0 AND 100),
+            CHECK (discount < price),
+            CHECK (id <> 0)
+        );
+    "
+    );
+
+    test!(
+        "INSERT INTO Item VALUES (1, 100, 10), (2, 50, 0);",
+        Ok(Payload::Insert(2))
+    );
+
+    let error_cases = [
+        ("INSERT INTO Item VALUES (3, 0, 0);", "Item_price_check"),
+        ("INSERT INTO Item VALUES (3, 10, 200);", "discount_range"),
+        ("INSERT INTO Item VALUES (3, 10, 20);", "Item_check"),
+        ("INSERT INTO Item VALUES (0, 10, 5);", "Item_check1"),
+        (
+            "INSERT INTO Item VALUES (3, 10, 5), (4, -1, 0);",
+            "Item_price_check",
+        ),
+        ("UPDATE Item SET price = 5 WHERE id = 1;", "Item_check"),
+        (
+            "INSERT INTO Item VALUES (1, 1, 0) ON CONFLICT (id) DO UPDATE SET discount = 500;",
+            "discount_range",
+        ),
+    ];
+
+    for (sql, name) in error_cases {
+        test!(
+            sql,
+            Err(ValidateError::CheckConstraintViolated(name.to_owned()).into())
+        );
+    }
+
+    test!("UPDATE Item SET price = price * 2;", Ok(Payload::Update(2)));
+    test!(
+        "SELECT id, price, discount FROM Item ORDER BY id",
+        Ok(select!(
+            id  | price | discount
+            I64 | I64   | I64;
+            1     200     10;
+            2     100     0
+        ))
+    );
+
+    run!("CREATE TABLE Flag (flag BOOLEAN CHECK (flag));");
+    test!("INSERT INTO Flag VALUES (NULL);", Ok(Payload::Insert(1)));
+    test!(
+        "INSERT INTO Flag VALUES (FALSE);",
+        Err(ValidateError::CheckConstraintViolated("Flag_flag_check".to_owned()).into())
+    );
+
+    run!(
+        "
+        CREATE TABLE Price (
+            price INTEGER CHECK (price > 0),
+            low INTEGER,
+            high INTEGER,
+            CHECK (price BETWEEN low AND high),
+            CHECK (NOT (price = 13) AND price <> 7)
+        );
+    "
+    );
+    test!(
+        "INSERT INTO Price VALUES (NULL, 1, 10), (5, NULL, 10), (5, 1, NULL);",
+        Ok(Payload::Insert(3))
+    );
+    test!(
+        "INSERT INTO Price VALUES (NULL, 1, 10), (-1, NULL, NULL);",
+        Err(ValidateError::CheckConstraintViolated("Price_price_check".to_owned()).into())
+    );
+    test!(
+        "INSERT INTO Price VALUES (7, NULL, NULL);",
+        Err(ValidateError::CheckConstraintViolated("Price_check1".to_owned()).into())
+    );
+
+    run!("CREATE TABLE NotBoolean (n INTEGER CHECK (n + 1));");
+    test!(
+        "INSERT INTO NotBoolean VALUES (1);",
+        Err(EvaluateError::BooleanTypeRequired("I64(2)".to_owned()).into())
+    );
+
+    run!("CREATE TABLE Parent (id INTEGER PRIMARY KEY);");
+    run!(
+        "
+        CREATE TABLE Child (
+            id INTEGER,
+            parent_id INTEGER REFERENCES Parent ON DELETE SET NULL CHECK (parent_id IS NOT NULL)
+        );
+    "
+    );
+    run!("INSERT INTO Parent VALUES (1);");
+    run!("INSERT INTO Child VALUES (1, 1);");
+    test!(
+        "DELETE FROM Parent WHERE id = 1;",
+        Err(ValidateError::CheckConstraintViolated("Child_parent_id_check".to_owned()).into())
+    );
+});
+
+test_case!(alter_table, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            price INTEGER,
+            memo TEXT,
+            CONSTRAINT positive_price CHECK (ABS(price) = price)
+        );
+    "
+    );
+
+    test!(
+        "ALTER TABLE Item RENAME COLUMN price TO cost;",
+        Err(AlterError::ColumnUsedByCheck("price".to_owned(), "positive_price".to_owned()).into())
+    );
+    test!(
+        "ALTER TABLE Item DROP COLUMN price;",
+        Err(AlterError::ColumnUsedByCheck("price".to_owned(), "positive_price".to_owned()).into())
+    );
+    test!(
+        "ALTER TABLE Item DROP COLUMN memo;",
+        Ok(Payload::AlterTable)
+    );
+    test!(
+        "INSERT INTO Item VALUES (1, -1);",
+        Err(ValidateError::CheckConstraintViolated("positive_price".to_owned()).into())
+    );
+});
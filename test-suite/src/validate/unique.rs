@@ -1,6 +1,12 @@
 use {
     crate::*,
-    gluesql_core::{error::ValidateError, prelude::Value},
+    gluesql_core::{
+        error::{AlterError, ValidateError},
+        prelude::{
+            Payload,
+            Value::{self, *},
+        },
+    },
 };
 
 test_case!(unique, async move {
@@ -86,3 +92,95 @@ CREATE TABLE TestC (
         test!(sql, Err(error));
     }
 });
+
+test_case!(composite, async move {
+    run!(
+        "
+        CREATE TABLE Seat (
+            hall INTEGER,
+            seat_no INTEGER,
+            label TEXT UNIQUE,
+            UNIQUE (hall, seat_no)
+        );
+    "
+    );
+
+    test!(
+        "INSERT INTO Seat VALUES (1, 1, 'A'), (1, 2, 'B'), (2, 1, 'C');",
+        Ok(Payload::Insert(3))
+    );
+    test!(
+        "INSERT INTO Seat VALUES (NULL, 1, 'D'), (NULL, 1, 'E'), (2, NULL, 'F');",
+        Ok(Payload::Insert(3))
+    );
+
+    let duplicate = |hall, seat_no| {
+        ValidateError::DuplicateEntryOnUniqueField(
+            Value::List(vec![Value::I64(hall), Value::I64(seat_no)]),
+            "hall, seat_no".to_owned(),
+        )
+        .into()
+    };
+    let error_cases = [
+        ("INSERT INTO Seat VALUES (1, 2, 'G')", duplicate(1, 2)),
+        (
+            "INSERT INTO Seat VALUES (3, 1, 'G'), (3, 1, 'H')",
+            duplicate(3, 1),
+        ),
+        (
+            "UPDATE Seat SET seat_no = 2 WHERE label = 'A'",
+            duplicate(1, 2),
+        ),
+        (
+            "INSERT INTO Seat VALUES (3, 3, 'A')",
+            ValidateError::DuplicateEntryOnUniqueField(
+                Value::Str("A".to_owned()),
+                "label".to_owned(),
+            )
+            .into(),
+        ),
+    ];
+
+    for (sql, error) in error_cases {
+        test!(sql, Err(error));
+    }
+
+    test!(
+        "INSERT INTO Seat VALUES (2, 1, 'G') ON CONFLICT (hall, seat_no) DO UPDATE SET label = 'CC';",
+        Ok(Payload::Insert(1))
+    );
+    test!(
+        "SELECT hall, seat_no, label FROM Seat WHERE hall = 2 AND seat_no = 1",
+        Ok(select!(
+            hall | seat_no | label
+            I64  | I64     | Str;
+            2      1         "CC".to_owned()
+        ))
+    );
+});
+
+test_case!(alter_table, async move {
+    run!(
+        "
+        CREATE TABLE Seat (
+            hall INTEGER,
+            seat_no INTEGER,
+            memo TEXT,
+            UNIQUE (hall, seat_no)
+        );
+    "
+    );
+
+    test!(
+        "ALTER TABLE Seat RENAME COLUMN hall TO room;",
+        Err(AlterError::ColumnUsedByUniqueConstraint("hall".to_owned()).into())
+    );
+    test!(
+        "ALTER TABLE Seat DROP COLUMN seat_no;",
+        Err(AlterError::ColumnUsedByUniqueConstraint("seat_no".to_owned()).into())
+    );
+    test!(
+        "ALTER TABLE Seat DROP COLUMN memo;",
+        Ok(Payload::AlterTable)
+    );
+});
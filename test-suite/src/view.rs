@@ -0,0 +1,185 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, FetchError, PlanError},
+        prelude::{Payload, PayloadVariable, Value::*},
+    },
+};
+
+test_case!(view, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT, price INTEGER);");
+    run!("INSERT INTO Item VALUES (1, 'apple', 300), (2, 'banana', 150), (3, 'cherry', 700);");
+
+    test!(
+        "CREATE VIEW Cheap AS SELECT id, name FROM Item WHERE price < 500",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT * FROM Cheap ORDER BY id",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "apple".to_owned();
+            2     "banana".to_owned()
+        ))
+    );
+    test!(
+        "SELECT C.name FROM Cheap AS C WHERE C.id = 2",
+        Ok(select!(name; Str; "banana".to_owned()))
+    );
+
+    run!("INSERT INTO Item VALUES (4, 'dates', 100);");
+    test!(
+        "SELECT COUNT(*) AS cnt FROM Cheap",
+        Ok(select!(cnt; I64; 3))
+    );
+
+    test!(
+        "CREATE VIEW Named (item_id, item_name) AS SELECT id, name FROM Item",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT item_name FROM Named WHERE item_id = 3",
+        Ok(select!(item_name; Str; "cherry".to_owned()))
+    );
+    test!(
+        "SELECT n FROM Named AS N (n) WHERE item_name = 'apple'",
+        Ok(select!(n; I64; 1))
+    );
+
+    test!(
+        "CREATE VIEW CheapNames AS SELECT name FROM Cheap WHERE id > 1",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT * FROM CheapNames ORDER BY name",
+        Ok(select!(
+            name
+            Str;
+            "banana".to_owned();
+            "dates".to_owned()
+        ))
+    );
+    test!(
+        "SELECT Item.name FROM Item JOIN Cheap ON Item.id = Cheap.id WHERE Item.price > 200",
+        Ok(select!(name; Str; "apple".to_owned()))
+    );
+    test!(
+        "WITH Cheap AS (SELECT id FROM Item WHERE id = 3) SELECT * FROM Cheap",
+        Ok(select!(id; I64; 3))
+    );
+
+    test!(
+        "CREATE VIEW Cheap AS SELECT * FROM Item",
+        Err(AlterError::ViewAlreadyExists("Cheap".to_owned()).into())
+    );
+    test!(
+        "CREATE OR REPLACE VIEW Cheap AS SELECT id, name FROM Item WHERE price < 200",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT * FROM CheapNames",
+        Ok(select!(
+            name
+            Str;
+            "banana".to_owned();
+            "dates".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id FROM Cheap ORDER BY id",
+        Ok(select!(id; I64; 2; 4))
+    );
+
+    test!(
+        "CREATE VIEW Item AS SELECT 1",
+        Err(AlterError::TableAlreadyExists("Item".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Cheap (id INTEGER)",
+        Err(AlterError::ViewAlreadyExists("Cheap".to_owned()).into())
+    );
+    test!(
+        "CREATE VIEW Dup (a, a) AS SELECT id, name FROM Item",
+        Err(AlterError::DuplicateColumnName("a".to_owned()).into())
+    );
+    test!(
+        "CREATE VIEW TooMany (a, b, c, d) AS SELECT id, name FROM Item",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT * FROM TooMany",
+        Err(FetchError::TooManyColumnAliases("TooMany".to_owned(), 2, 4).into())
+    );
+    test!(
+        "CREATE VIEW Loop AS SELECT * FROM Loop",
+        Err(PlanError::RecursiveView("Loop".to_owned()).into())
+    );
+    test!(
+        "CREATE VIEW LoopA AS SELECT * FROM LoopB",
+        Ok(Payload::Create)
+    );
+    test!(
+        "CREATE VIEW LoopB AS SELECT * FROM LoopA",
+        Err(PlanError::RecursiveView("LoopB".to_owned()).into())
+    );
+
+    test!(
+        "SHOW VIEWS",
+        Ok(Payload::ShowVariable(PayloadVariable::Views(vec![
+            "Cheap".to_owned(),
+            "CheapNames".to_owned(),
+            "LoopA".to_owned(),
+            "Named".to_owned(),
+            "TooMany".to_owned(),
+        ])))
+    );
+    test!(
+        "SELECT OBJECT_NAME, OBJECT_TYPE FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'VIEW' ORDER BY OBJECT_NAME",
+        Ok(select!(
+            OBJECT_NAME                | OBJECT_TYPE
+            Str                        | Str;
+            "Cheap".to_owned()           "VIEW".to_owned();
+            "CheapNames".to_owned()      "VIEW".to_owned();
+            "LoopA".to_owned()           "VIEW".to_owned();
+            "Named".to_owned()           "VIEW".to_owned();
+            "TooMany".to_owned()         "VIEW".to_owned()
+        ))
+    );
+
+    test!("DROP VIEW TooMany, LoopA", Ok(Payload::DropView));
+    test!(
+        "DROP VIEW TooMany",
+        Err(AlterError::ViewNotFound("TooMany".to_owned()).into())
+    );
+    test!("DROP VIEW IF EXISTS TooMany, Named", Ok(Payload::DropView));
+    test!(
+        "SELECT * FROM Named",
+        Err(FetchError::TableNotFound("Named".to_owned()).into())
+    );
+    test!(
+        "SHOW VIEWS",
+        Ok(Payload::ShowVariable(PayloadVariable::Views(vec![
+            "Cheap".to_owned(),
+            "CheapNames".to_owned(),
+        ])))
+    );
+
+    // views in subqueries nested in function, aggregate and window arguments are expanded
+    run!("CREATE VIEW One AS SELECT 1 AS x");
+    for (sql, expected) in [
+        ("SELECT ABS((SELECT x FROM One)) AS a", select!(a; I64; 1)),
+        (
+            "SELECT SUM((SELECT x FROM One)) AS s FROM Item WHERE id < 3",
+            select!(s; I64; 2),
+        ),
+        (
+            "SELECT id, SUM((SELECT x FROM One)) OVER (ORDER BY id) AS s
+            FROM Item WHERE id < 3 ORDER BY id",
+            select!(id | s; I64 | I64; 1 1; 2 2),
+        ),
+    ] {
+        test!(sql, Ok(expected));
+    }
+    run!("DROP VIEW One");
+});
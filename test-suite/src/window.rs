@@ -0,0 +1,206 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError, WindowError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(window, async move {
+    run!("CREATE TABLE Sales (id INTEGER, region TEXT, amount INTEGER);");
+    run!(
+        "
+        INSERT INTO Sales VALUES
+            (1, 'east', 100),
+            (2, 'east', 200),
+            (3, 'east', 200),
+            (4, 'west', 50),
+            (5, 'west', 150);
+    "
+    );
+
+    test!(
+        "SELECT id, ROW_NUMBER() OVER (PARTITION BY region ORDER BY amount DESC) AS rn
+        FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | rn
+            I64 | I64;
+            1     3;
+            2     1;
+            3     2;
+            4     2;
+            5     1
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            RANK() OVER (ORDER BY amount DESC) AS rank,
+            DENSE_RANK() OVER (ORDER BY amount DESC) AS dense_rank
+        FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | rank | dense_rank
+            I64 | I64  | I64;
+            1     4      3;
+            2     1      1;
+            3     1      1;
+            4     5      4;
+            5     3      2
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            LAG(amount) OVER (ORDER BY id) AS prev,
+            LEAD(amount, 2, 0) OVER (ORDER BY id) AS next
+        FROM Sales ORDER BY id",
+        Ok(select_with_null!(
+            id     | prev     | next;
+            I64(1)   Null       I64(200);
+            I64(2)   I64(100)   I64(50);
+            I64(3)   I64(200)   I64(150);
+            I64(4)   I64(200)   I64(0);
+            I64(5)   I64(50)    I64(0)
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            SUM(amount) OVER (PARTITION BY region) AS total,
+            SUM(amount) OVER (PARTITION BY region ORDER BY id) AS running
+        FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | total | running
+            I64 | I64   | I64;
+            1     500     100;
+            2     500     300;
+            3     500     500;
+            4     200     50;
+            5     200     200
+        ))
+    );
+    test!(
+        "SELECT id, SUM(amount) OVER (ORDER BY amount) AS peers FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | peers
+            I64 | I64;
+            1     150;
+            2     700;
+            3     700;
+            4     50;
+            5     300
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            SUM(amount) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS moving,
+            COUNT(*) OVER (ORDER BY id ROWS 2 PRECEDING) AS cnt
+        FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | moving | cnt
+            I64 | I64    | I64;
+            1     300      1;
+            2     500      2;
+            3     450      3;
+            4     400      3;
+            5     200      3
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            FIRST_VALUE(id) OVER (PARTITION BY region ORDER BY amount DESC) AS first,
+            LAST_VALUE(id) OVER (
+                PARTITION BY region ORDER BY amount DESC
+                ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+            ) AS last
+        FROM Sales ORDER BY id",
+        Ok(select!(
+            id  | first | last
+            I64 | I64   | I64;
+            1     2       1;
+            2     2       1;
+            3     2       1;
+            4     5       4;
+            5     5       4
+        ))
+    );
+    test!(
+        "SELECT id, COUNT(*) OVER () AS cnt FROM Sales WHERE amount > 100 ORDER BY id",
+        Ok(select!(
+            id  | cnt
+            I64 | I64;
+            2     3;
+            3     3;
+            5     3
+        ))
+    );
+    test!(
+        "SELECT region, RANK() OVER (ORDER BY SUM(amount)) AS rank
+        FROM Sales GROUP BY region ORDER BY region",
+        Ok(select!(
+            region              | rank
+            Str                 | I64;
+            "east".to_owned()     2;
+            "west".to_owned()     1
+        ))
+    );
+    test!(
+        "SELECT id FROM Sales ORDER BY ROW_NUMBER() OVER (ORDER BY amount DESC, id)",
+        Ok(select!(id; I64; 2; 3; 5; 1; 4))
+    );
+    test!(
+        "SELECT id, ROW_NUMBER() OVER (ORDER BY id) + 10 AS n FROM Sales WHERE region = 'west'",
+        Ok(select!(
+            id  | n
+            I64 | I64;
+            4     11;
+            5     12
+        ))
+    );
+    test!(
+        "SELECT id, CAST(ROW_NUMBER() OVER (ORDER BY id) AS TEXT) AS n
+        FROM Sales WHERE region = 'west'",
+        Ok(select!(
+            id  | n
+            I64 | Str;
+            4     "1".to_owned();
+            5     "2".to_owned()
+        ))
+    );
+    test!(
+        "SELECT
+            id,
+            ROW_NUMBER() OVER (ORDER BY id) IN (1, 3) AS odd,
+            CAST(RANK() OVER (ORDER BY amount) AS TEXT) LIKE '1%' AS first
+        FROM Sales WHERE region = 'west'",
+        Ok(select!(
+            id  | odd   | first
+            I64 | Bool  | Bool;
+            4     true    true;
+            5     false   false
+        ))
+    );
+
+    test!(
+        "SELECT id FROM Sales WHERE ROW_NUMBER() OVER () > 1",
+        Err(EvaluateError::WindowFunctionNotAllowed("ROW_NUMBER() OVER ()".to_owned()).into())
+    );
+    test!(
+        "SELECT ROW_NUMBER() FROM Sales",
+        Err(TranslateError::WindowFunctionWithoutOver("ROW_NUMBER".to_owned()).into())
+    );
+    test!(
+        "SELECT LOWER(region) OVER () FROM Sales",
+        Err(TranslateError::UnsupportedWindowFunction("LOWER".to_owned()).into())
+    );
+    test!(
+        "SELECT SUM(amount) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM Sales",
+        Err(WindowError::RangeOffsetNotSupported.into())
+    );
+    test!(
+        "SELECT SUM(amount) OVER (ROWS BETWEEN CURRENT ROW AND UNBOUNDED PRECEDING) FROM Sales",
+        Err(WindowError::FrameEndUnboundedPreceding.into())
+    );
+});